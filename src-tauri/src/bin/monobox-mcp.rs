@@ -2,6 +2,8 @@ use std::fmt;
 use std::io::{self, BufRead, BufReader, BufWriter, Write};
 
 use serde_json::{json, Value};
+use tauri_app_lib::config::{load_config, AppConfig};
+use tauri_app_lib::database;
 use tauri_app_lib::mcp::{handle_json_rpc_request, RpcRequest};
 
 #[derive(Debug)]
@@ -9,6 +11,7 @@ enum ServerError {
     Io(io::Error),
     Json(serde_json::Error),
     InvalidRequest(String),
+    Config(String),
 }
 
 impl fmt::Display for ServerError {
@@ -17,6 +20,7 @@ impl fmt::Display for ServerError {
             ServerError::Io(err) => write!(f, "I/O error: {}", err),
             ServerError::Json(err) => write!(f, "JSON error: {}", err),
             ServerError::InvalidRequest(message) => write!(f, "Invalid request: {}", message),
+            ServerError::Config(message) => write!(f, "Config error: {}", message),
         }
     }
 }
@@ -42,6 +46,14 @@ fn main() {
     }
 }
 
+fn load_app_config() -> Result<AppConfig, ServerError> {
+    let proj_dirs = directories::ProjectDirs::from("com", "m2tkl", "monobox").ok_or_else(|| {
+        ServerError::Config("Failed to determine project directories".to_string())
+    })?;
+    load_config(proj_dirs.config_dir(), proj_dirs.data_dir())
+        .map_err(|err| ServerError::Config(err.to_string()))
+}
+
 fn run() -> Result<(), ServerError> {
     let stdin = io::stdin();
     let stdout = io::stdout();
@@ -52,7 +64,10 @@ fn run() -> Result<(), ServerError> {
         let request: RpcRequest = serde_json::from_slice(&message)?;
         let request_id = request.id.clone();
 
-        match handle_json_rpc_request(request) {
+        // The app may finish setup or move the database while this process runs.
+        let config = load_app_config()?;
+        database::configure(&config.database_path);
+        match handle_json_rpc_request(request, &config) {
             Ok(Some(response)) => {
                 if let Some(id) = request_id {
                     let payload = json!({
@@ -64,15 +79,12 @@ fn run() -> Result<(), ServerError> {
                 }
             }
             Ok(None) => {}
-            Err(message) => {
+            Err(error) => {
                 if let Some(id) = request_id {
                     let payload = json!({
                         "jsonrpc": "2.0",
                         "id": id,
-                        "error": {
-                            "code": -32000,
-                            "message": message,
-                        }
+                        "error": error.to_value(),
                     });
                     write_message(&mut writer, &payload)?;
                 }
@@ -1,6 +1,12 @@
-use crate::config::AppConfig;
+use crate::config::SharedConfig;
+use crate::database::get_conn;
+use crate::errors::AppError;
+use crate::models::asset::{OrphanedAssetCleanup, OrphanedAssetScan};
+use crate::repositories::asset_repository::strip_asset_url_prefix;
+use crate::repositories::AssetRepository;
 use base64::{self, engine::general_purpose, Engine};
 use mime_guess::{from_path, get_mime_extensions};
+use sha2::{Digest, Sha256};
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::PathBuf;
@@ -19,7 +25,22 @@ pub struct ReadImageArgs {
 }
 
 #[command]
-pub fn save_image(args: SaveImageArgs, config: State<AppConfig>) -> Result<String, String> {
+pub fn save_image(args: SaveImageArgs, config: State<SharedConfig>) -> Result<String, AppError> {
+    let config = config.snapshot();
+    let decoded_data = general_purpose::STANDARD
+        .decode(&args.data)
+        .map_err(|e| AppError::validation(format!("Failed to decode file data: {}", e)))?;
+    let content_hash = format!("{:x}", Sha256::digest(&decoded_data));
+    let save_dir = PathBuf::from(&config.asset_dir_path);
+
+    // Pasting the same image again reuses the stored copy.
+    let conn = get_conn()?;
+    if let Some(file_name) = AssetRepository::find_by_hash(&conn, &content_hash)? {
+        if save_dir.join(&file_name).is_file() {
+            return Ok(format!("asset://localhost/monobox/{}", file_name));
+        }
+    }
+
     // Generate UUID
     let uuid = Uuid::new_v4();
 
@@ -27,25 +48,24 @@ pub fn save_image(args: SaveImageArgs, config: State<AppConfig>) -> Result<Strin
     let mime_type = &args
         .mime_type
         .parse()
-        .map_err(|_| "Invalid MIME type".to_string())?;
+        .map_err(|_| AppError::validation("Invalid MIME type"))?;
     let extension = get_mime_extensions(&mime_type)
         .and_then(|exts| exts.first().cloned())
         .unwrap_or("bin"); // default "bin"
     let file_name = format!("{}.{}", uuid, extension);
 
-    let save_dir = PathBuf::from(&config.asset_dir_path);
     if !save_dir.exists() {
-        fs::create_dir_all(&save_dir).map_err(|e| format!("Failed to create directory: {}", e))?;
+        fs::create_dir_all(&save_dir)
+            .map_err(|e| AppError::io(format!("Failed to create directory: {}", e)))?;
     }
 
     let file_path = save_dir.join(&file_name);
 
-    let decoded_data = general_purpose::STANDARD
-        .decode(&args.data)
-        .map_err(|e| format!("Failed to decode file data: {}", e))?;
-    let mut file = File::create(&file_path).map_err(|e| format!("Failed to create file: {}", e))?;
+    let mut file = File::create(&file_path)
+        .map_err(|e| AppError::io(format!("Failed to create file: {}", e)))?;
     file.write_all(&decoded_data)
-        .map_err(|e| format!("Failed to write to file: {}", e))?;
+        .map_err(|e| AppError::io(format!("Failed to write to file: {}", e)))?;
+    AssetRepository::record_hash(&conn, &content_hash, &file_name)?;
 
     Ok(format!("asset://localhost/monobox/{}", file_name))
 }
@@ -53,25 +73,56 @@ pub fn save_image(args: SaveImageArgs, config: State<AppConfig>) -> Result<Strin
 #[command]
 pub fn read_image_as_data_url(
     args: ReadImageArgs,
-    config: State<AppConfig>,
-) -> Result<String, String> {
+    config: State<SharedConfig>,
+) -> Result<String, AppError> {
+    let config = config.snapshot();
     let file_name = resolve_asset_file_name(&args.src)?;
     let file_path = PathBuf::from(&config.asset_dir_path).join(file_name);
-    let content = fs::read(&file_path).map_err(|e| format!("Failed to read image: {}", e))?;
+    let content =
+        fs::read(&file_path).map_err(|e| AppError::io(format!("Failed to read image: {}", e)))?;
     let mime = from_path(&file_path).first_or_octet_stream();
     let data = general_purpose::STANDARD.encode(content);
 
     Ok(format!("data:{};base64,{}", mime.as_ref(), data))
 }
 
-fn resolve_asset_file_name(src: &str) -> Result<&str, String> {
-    let file_name = src
-        .strip_prefix("asset://localhost/monobox/")
-        .or_else(|| src.strip_prefix("http://asset.localhost/monobox/"))
-        .ok_or_else(|| "Unsupported asset URL".to_string())?;
+#[command]
+pub fn scan_orphaned_assets(config: State<SharedConfig>) -> Result<OrphanedAssetScan, AppError> {
+    let config = config.snapshot();
+    let conn = get_conn()?;
+    AssetRepository::scan_orphans(&conn, &PathBuf::from(&config.asset_dir_path))
+}
+
+#[derive(serde::Deserialize)]
+pub struct CleanOrphanedAssetsArgs {
+    /// Token from `scan_orphaned_assets`.
+    pub confirmation_token: String,
+    /// Delete the files instead of moving them to the quarantine folder.
+    #[serde(default)]
+    pub permanently_delete: bool,
+}
+
+#[command]
+pub fn clean_orphaned_assets(
+    args: CleanOrphanedAssetsArgs,
+    config: State<SharedConfig>,
+) -> Result<OrphanedAssetCleanup, AppError> {
+    let config = config.snapshot();
+    let conn = get_conn()?;
+    AssetRepository::clean_orphans(
+        &conn,
+        &PathBuf::from(&config.asset_dir_path),
+        &args.confirmation_token,
+        args.permanently_delete,
+    )
+}
+
+fn resolve_asset_file_name(src: &str) -> Result<&str, AppError> {
+    let file_name =
+        strip_asset_url_prefix(src).ok_or_else(|| AppError::validation("Unsupported asset URL"))?;
 
     if file_name.is_empty() || file_name.contains('/') || file_name.contains('\\') {
-        return Err("Invalid asset file name".to_string());
+        return Err(AppError::validation("Invalid asset file name"));
     }
 
     Ok(file_name)
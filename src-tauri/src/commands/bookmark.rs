@@ -1,4 +1,5 @@
 use crate::database::get_conn;
+use crate::errors::AppError;
 use crate::models::bookmark;
 use crate::repositories::{BookmarkRepository, MemoRepository, WorkspaceRepository};
 use serde::Deserialize;
@@ -10,12 +11,16 @@ pub struct GetBookmarksArgs {
 }
 
 #[command]
-pub fn list_bookmarks(args: GetBookmarksArgs) -> Result<Vec<bookmark::Bookmark>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn list_bookmarks(args: GetBookmarksArgs) -> Result<Vec<bookmark::Bookmark>, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     BookmarkRepository::list_by_workspace(&conn, workspace.id)
 }
@@ -27,18 +32,23 @@ pub struct CreateBookmarkArgs {
 }
 
 #[command]
-pub fn add_bookmark(args: CreateBookmarkArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
-
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
-
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
-
-    BookmarkRepository::create(&conn, workspace.id, memo.id).map_err(|e| e.to_string())
+pub fn add_bookmark(args: CreateBookmarkArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
+
+    BookmarkRepository::create(&conn, workspace.id, memo.id).map_err(AppError::from)
 }
 
 #[derive(Deserialize)]
@@ -48,18 +58,23 @@ pub struct DeleteBookmarkArgs {
 }
 
 #[command]
-pub fn delete_bookmark(args: DeleteBookmarkArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
-
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
-
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
-
-    BookmarkRepository::delete(&conn, workspace.id, memo.id).map_err(|e| e.to_string())
+pub fn delete_bookmark(args: DeleteBookmarkArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
+
+    BookmarkRepository::delete(&conn, workspace.id, memo.id).map_err(AppError::from)
 }
 
 #[derive(Deserialize)]
@@ -71,20 +86,29 @@ pub struct ReorderBookmarkArgs {
 }
 
 #[command]
-pub fn reorder_bookmark(args: ReorderBookmarkArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
-
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
-
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+pub fn reorder_bookmark(args: ReorderBookmarkArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
     let target_memo =
-        MemoRepository::find_by_slug(&conn, workspace.id, &args.target_memo_slug_title)
-            .map_err(|e| e.to_string())?
-            .ok_or_else(|| format!("Memo not found for slug: {}", args.target_memo_slug_title))?;
+        MemoRepository::find_by_slug(&conn, workspace.id, &args.target_memo_slug_title)?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "Memo not found for slug: {}",
+                    args.target_memo_slug_title
+                ))
+            })?;
 
     BookmarkRepository::reorder_by_memo_id(
         &conn,
@@ -93,5 +117,4 @@ pub fn reorder_bookmark(args: ReorderBookmarkArgs) -> Result<(), String> {
         target_memo.id,
         &args.position,
     )
-    .map_err(|e| e.to_string())
 }
@@ -1,8 +1,16 @@
+use std::fs;
+
 use crate::database::get_conn;
-use crate::models::calendar_day::CalendarDay;
-use crate::repositories::{CalendarDayRepository, MemoRepository, WorkspaceRepository};
+use crate::errors::AppError;
+use crate::mcp::McpServerInfo;
+use crate::models::calendar_day::{CalendarDay, CalendarImportSummary};
+use crate::repositories::calendar_import_repository::CalendarImportNoteMode;
+use crate::repositories::{
+    CalendarDayRepository, CalendarExportRepository, CalendarImportRepository, MemoRepository,
+    WorkspaceRepository,
+};
 use serde::Deserialize;
-use tauri::command;
+use tauri::{command, State};
 
 #[derive(Deserialize)]
 pub struct ListCalendarDaysArgs {
@@ -32,28 +40,28 @@ pub struct ListCalendarMemoDatesArgs {
 }
 
 #[command]
-pub fn list_calendar_days(args: ListCalendarDaysArgs) -> Result<Vec<CalendarDay>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn list_calendar_days(args: ListCalendarDaysArgs) -> Result<Vec<CalendarDay>, AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
 
-    CalendarDayRepository::list_by_year(&conn, workspace.id, args.year).map_err(|e| e.to_string())
+    CalendarDayRepository::list_by_year(&conn, workspace.id, args.year).map_err(AppError::from)
 }
 
 #[command]
-pub fn list_calendar_memo_dates(args: ListCalendarMemoDatesArgs) -> Result<Vec<String>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn list_calendar_memo_dates(args: ListCalendarMemoDatesArgs) -> Result<Vec<String>, AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
-    CalendarDayRepository::list_dates_by_memo(&conn, workspace.id, memo.id)
-        .map_err(|e| e.to_string())
+    CalendarDayRepository::list_dates_by_memo(&conn, workspace.id, memo.id).map_err(AppError::from)
 }
 
 #[command]
-pub fn update_calendar_day(args: UpdateCalendarDayArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn update_calendar_day(args: UpdateCalendarDayArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
     validate_date(&conn, &args.date)?;
 
@@ -64,53 +72,135 @@ pub fn update_calendar_day(args: UpdateCalendarDayArgs) -> Result<(), String> {
         args.note.as_deref(),
         args.is_non_working,
     )
-    .map_err(|e| e.to_string())
+    .map_err(AppError::from)
 }
 
 #[command]
-pub fn add_calendar_day_memo(args: CalendarDayMemoArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn add_calendar_day_memo(args: CalendarDayMemoArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
     validate_date(&conn, &args.date)?;
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
     CalendarDayRepository::add_memo(&conn, workspace.id, &args.date, memo.id)
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 #[command]
-pub fn remove_calendar_day_memo(args: CalendarDayMemoArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn remove_calendar_day_memo(args: CalendarDayMemoArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
     validate_date(&conn, &args.date)?;
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
     CalendarDayRepository::remove_memo(&conn, workspace.id, &args.date, memo.id)
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
+}
+
+#[derive(Deserialize)]
+pub struct ExportCalendarIcsArgs {
+    pub workspace_slug_name: String,
+    pub path: String,
+}
+
+#[derive(Deserialize)]
+pub struct GetCalendarFeedUrlArgs {
+    pub workspace_slug_name: String,
+}
+
+#[command]
+pub fn export_calendar_ics(args: ExportCalendarIcsArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
+    let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
+    let content = CalendarExportRepository::to_ics(&conn, &workspace)?;
+
+    fs::write(&args.path, content)
+        .map_err(|e| AppError::io(format!("Failed to save calendar export: {}", e)))
+}
+
+#[derive(Deserialize)]
+pub struct ImportCalendarIcsArgs {
+    pub workspace_slug_name: String,
+    pub path: String,
+    /// `overwrite` or `merge`.
+    pub note_mode: String,
+    /// Returns what the import would change without writing anything.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[command]
+pub fn import_calendar_ics(args: ImportCalendarIcsArgs) -> Result<CalendarImportSummary, AppError> {
+    let note_mode = CalendarImportNoteMode::parse(&args.note_mode)?;
+    let text = fs::read_to_string(&args.path)
+        .map_err(|e| AppError::io(format!("Failed to read calendar file: {}", e)))?;
+    let events = CalendarImportRepository::parse_ics(&text)?;
+
+    let mut conn = get_conn()?;
+    let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
+
+    let tx = conn.transaction()?;
+    let summary = CalendarImportRepository::import_events(
+        &tx,
+        workspace.id,
+        &events,
+        note_mode,
+        args.dry_run,
+    )?;
+    tx.commit()?;
+
+    Ok(summary)
+}
+
+/// The feed is served by the MCP HTTP listener, so it shares its host, port and token.
+#[command]
+pub fn get_calendar_feed_url(
+    args: GetCalendarFeedUrlArgs,
+    mcp_server_info: State<McpServerInfo>,
+) -> Result<String, AppError> {
+    if !mcp_server_info.enabled {
+        return Err(AppError::config(
+            "The local server is not running, so the calendar feed is unavailable.",
+        ));
+    }
+    let conn = get_conn()?;
+    let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
+
+    Ok(crate::mcp::build_calendar_feed_url(
+        &mcp_server_info.url_host,
+        mcp_server_info.port,
+        &mcp_server_info.token,
+        &workspace.slug_name,
+    ))
 }
 
 fn resolve_workspace(
     conn: &rusqlite::Connection,
     workspace_slug_name: &str,
-) -> Result<crate::models::Workspace, String> {
-    WorkspaceRepository::find_by_slug(conn, workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", workspace_slug_name))
+) -> Result<crate::models::Workspace, AppError> {
+    WorkspaceRepository::find_by_slug(conn, workspace_slug_name)?.ok_or_else(|| {
+        AppError::not_found(format!(
+            "Workspace not found for slug: {}",
+            workspace_slug_name
+        ))
+    })
 }
 
-fn validate_date(conn: &rusqlite::Connection, date: &str) -> Result<(), String> {
-    let is_valid: bool = conn
-        .query_row("SELECT date(?, '+0 days') = ?", (date, date), |row| {
-            row.get(0)
-        })
-        .map_err(|e| e.to_string())?;
+fn validate_date(conn: &rusqlite::Connection, date: &str) -> Result<(), AppError> {
+    let is_valid: bool = conn.query_row("SELECT date(?, '+0 days') = ?", (date, date), |row| {
+        row.get(0)
+    })?;
 
     if !is_valid {
-        return Err(format!("Invalid calendar date: {date}"));
+        return Err(AppError::validation(format!(
+            "Invalid calendar date: {date}"
+        )));
     }
 
     Ok(())
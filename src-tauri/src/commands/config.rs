@@ -6,11 +6,17 @@ use directories::ProjectDirs;
 use tauri::{command, AppHandle, State};
 use uuid::Uuid;
 
-use crate::config::{default_selection_copy_format, load_config, save_config};
+use crate::config::{
+    default_selection_copy_format, save_config, AppConfig, InboxRule, SharedConfig, WatchedFolder,
+};
+use crate::database;
+use crate::errors::AppError;
 use crate::global_shortcuts::{
     normalize_shortcut, update_global_shortcuts, GlobalShortcutSettings,
 };
 use crate::mcp::McpServerInfo;
+use crate::models::file::ImportPolicy;
+use crate::repositories::file_repository::ensure_import_policy_supported;
 
 #[derive(serde::Serialize)]
 pub struct ConfigPayload {
@@ -24,6 +30,9 @@ pub struct ConfigPayload {
     pub focus_app_shortcut: String,
     pub new_memo_shortcut: String,
     pub selection_copy_format: String,
+    pub import_policy: String,
+    pub watched_folders: Vec<WatchedFolder>,
+    pub watch_interval_seconds: u64,
     pub mcp_server_url: String,
 }
 
@@ -74,16 +83,28 @@ pub struct SelectionCopyFormatArgs {
     pub format: String,
 }
 
+#[derive(serde::Deserialize)]
+pub struct ImportPolicyArgs {
+    pub policy: String,
+}
+
+#[derive(serde::Deserialize)]
+pub struct WatchedFoldersArgs {
+    pub watched_folders: Vec<WatchedFolder>,
+    pub watch_interval_seconds: Option<u64>,
+}
+
 #[command]
-pub fn get_app_config(mcp_server_info: State<McpServerInfo>) -> Result<ConfigPayload, String> {
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+pub fn get_app_config(
+    mcp_server_info: State<McpServerInfo>,
+    app_config: State<SharedConfig>,
+) -> Result<ConfigPayload, AppError> {
+    let config = app_config.snapshot();
 
     Ok(build_config_payload(config, &mcp_server_info.url))
 }
 
-fn build_config_payload(config: crate::config::AppConfig, mcp_server_url: &str) -> ConfigPayload {
+fn build_config_payload(config: AppConfig, mcp_server_url: &str) -> ConfigPayload {
     ConfigPayload {
         database_path: config.database_path,
         asset_dir_path: config.asset_dir_path,
@@ -95,10 +116,24 @@ fn build_config_payload(config: crate::config::AppConfig, mcp_server_url: &str)
         focus_app_shortcut: config.focus_app_shortcut,
         new_memo_shortcut: config.new_memo_shortcut,
         selection_copy_format: normalize_selection_copy_format(&config.selection_copy_format),
+        import_policy: ImportPolicy::from_setting(&config.import_policy)
+            .as_setting()
+            .to_string(),
+        watched_folders: config.watched_folders,
+        watch_interval_seconds: config.watch_interval_seconds,
         mcp_server_url: mcp_server_url.to_string(),
     }
 }
 
+/// Writes `config` to config.json and makes it the live config.
+fn store_config(app_config: &SharedConfig, config: &AppConfig) -> Result<(), AppError> {
+    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
+        .ok_or_else(|| AppError::config("Failed to determine project directories"))?;
+    save_config(config, &proj_dirs.config_dir().join("config.json"))?;
+    app_config.replace(config.clone());
+    Ok(())
+}
+
 fn normalize_inbox_ignore_file_names(file_names: &[String]) -> Vec<String> {
     let mut names: Vec<String> = file_names
         .iter()
@@ -120,18 +155,24 @@ fn normalize_selection_copy_format(format: &str) -> String {
 }
 
 #[command]
-pub fn get_mcp_server_info(mcp_server_info: State<McpServerInfo>) -> Result<McpServerInfo, String> {
-    Ok(mcp_server_info.inner().clone())
+pub fn get_mcp_server_info(
+    mcp_server_info: State<McpServerInfo>,
+    app_config: State<SharedConfig>,
+) -> Result<McpServerInfo, AppError> {
+    // The server itself is fixed at startup, but setup can be completed while it runs.
+    Ok(McpServerInfo {
+        setup_complete: app_config.snapshot().setup_complete,
+        ..mcp_server_info.inner().clone()
+    })
 }
 
 #[command]
-pub fn regenerate_mcp_server_token() -> Result<McpServerInfo, String> {
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config_path = proj_dirs.config_dir().join("config.json");
-    let mut config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+pub fn regenerate_mcp_server_token(
+    app_config: State<SharedConfig>,
+) -> Result<McpServerInfo, AppError> {
+    let mut config = app_config.snapshot();
     config.mcp_token = Uuid::new_v4().to_string();
-    save_config(&config, &config_path)?;
+    store_config(&app_config, &config)?;
     Ok(McpServerInfo {
         enabled: false,
         bind_host: config.mcp_bind_host.clone(),
@@ -144,9 +185,9 @@ pub fn regenerate_mcp_server_token() -> Result<McpServerInfo, String> {
 }
 
 #[command]
-pub fn detect_storage_candidates() -> Result<StorageCandidates, String> {
+pub fn detect_storage_candidates() -> Result<StorageCandidates, AppError> {
     let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
+        .ok_or_else(|| AppError::config("Failed to determine project directories"))?;
 
     let mut database_paths: Vec<String> = Vec::new();
     let mut asset_dir_paths: Vec<String> = Vec::new();
@@ -176,9 +217,9 @@ pub fn detect_storage_candidates() -> Result<StorageCandidates, String> {
 }
 
 #[command]
-pub fn get_default_storage_paths() -> Result<DefaultStoragePaths, String> {
+pub fn get_default_storage_paths() -> Result<DefaultStoragePaths, AppError> {
     let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
+        .ok_or_else(|| AppError::config("Failed to determine project directories"))?;
     let data_dir = proj_dirs.data_dir();
 
     Ok(DefaultStoragePaths {
@@ -191,7 +232,8 @@ pub fn get_default_storage_paths() -> Result<DefaultStoragePaths, String> {
 pub fn save_app_config(
     args: SaveConfigArgs,
     mcp_server_info: State<McpServerInfo>,
-) -> Result<ConfigPayload, String> {
+    app_config: State<SharedConfig>,
+) -> Result<ConfigPayload, AppError> {
     validate_storage_paths(
         &args.database_path,
         &args.asset_dir_path,
@@ -199,17 +241,18 @@ pub fn save_app_config(
         args.create_missing,
     )?;
 
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config_path = proj_dirs.config_dir().join("config.json");
-
-    let mut config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+    let mut config = app_config.snapshot();
     config.database_path = args.database_path.clone();
     config.asset_dir_path = args.asset_dir_path.clone();
     config.files_storage_root = args.files_storage_root.clone();
     config.setup_complete = args.setup_complete;
 
-    save_config(&config, &config_path)?;
+    store_config(&app_config, &config)?;
+
+    database::configure(&config.database_path);
+    if config.setup_complete {
+        database::initialize_database()?;
+    }
 
     Ok(build_config_payload(config, &mcp_server_info.url))
 }
@@ -219,25 +262,26 @@ fn validate_storage_paths(
     asset_dir_path: &str,
     files_storage_root: &str,
     create_missing: bool,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let db_path = PathBuf::from(database_path);
     if db_path.is_dir() {
-        return Err("DB_PATH_IS_DIR:Database path points to a directory".to_string());
+        return Err(
+            AppError::config("Database path points to a directory").with_reason("DB_PATH_IS_DIR")
+        );
     }
 
-    let db_parent = db_path
-        .parent()
-        .ok_or_else(|| "DB_PARENT_MISSING:Database path has no parent".to_string())?;
+    let db_parent = db_path.parent().ok_or_else(|| {
+        AppError::validation("Database path has no parent").with_reason("DB_PARENT_MISSING")
+    })?;
     if !db_parent.exists() {
         if create_missing {
             fs::create_dir_all(db_parent).map_err(|e| {
-                format!(
-                    "DB_PARENT_CREATE_FAILED:Failed to create db directory: {}",
-                    e
-                )
+                AppError::io(format!("Failed to create db directory: {}", e))
+                    .with_reason("DB_PARENT_CREATE_FAILED")
             })?;
         } else {
-            return Err("DB_PARENT_MISSING:Database directory does not exist".to_string());
+            return Err(AppError::not_found("Database directory does not exist")
+                .with_reason("DB_PARENT_MISSING"));
         }
     }
 
@@ -247,45 +291,53 @@ fn validate_storage_paths(
                 .write(true)
                 .create(true)
                 .open(&db_path)
-                .map_err(|e| format!("DB_FILE_CREATE_FAILED:Failed to create db file: {}", e))?;
+                .map_err(|e| {
+                    AppError::io(format!("Failed to create db file: {}", e))
+                        .with_reason("DB_FILE_CREATE_FAILED")
+                })?;
         } else {
-            return Err("DB_FILE_MISSING:Database file does not exist".to_string());
+            return Err(
+                AppError::not_found("Database file does not exist").with_reason("DB_FILE_MISSING")
+            );
         }
     }
 
     let asset_path = PathBuf::from(asset_dir_path);
     if asset_path.exists() && !asset_path.is_dir() {
-        return Err("ASSET_PATH_NOT_DIR:Asset path is not a directory".to_string());
+        return Err(
+            AppError::validation("Asset path is not a directory").with_reason("ASSET_PATH_NOT_DIR")
+        );
     }
     if !asset_path.exists() {
         if create_missing {
             fs::create_dir_all(&asset_path).map_err(|e| {
-                format!(
-                    "ASSET_DIR_CREATE_FAILED:Failed to create asset directory: {}",
-                    e
-                )
+                AppError::io(format!("Failed to create asset directory: {}", e))
+                    .with_reason("ASSET_DIR_CREATE_FAILED")
             })?;
         } else {
-            return Err("ASSET_DIR_MISSING:Asset directory does not exist".to_string());
+            return Err(AppError::not_found("Asset directory does not exist")
+                .with_reason("ASSET_DIR_MISSING"));
         }
     }
 
     if !files_storage_root.trim().is_empty() {
         let files_path = PathBuf::from(files_storage_root);
         if files_path.exists() && !files_path.is_dir() {
-            return Err("FILES_STORAGE_NOT_DIR:Files storage path is not a directory".to_string());
+            return Err(
+                AppError::validation("Files storage path is not a directory")
+                    .with_reason("FILES_STORAGE_NOT_DIR"),
+            );
         }
         if !files_path.exists() {
             if create_missing {
                 fs::create_dir_all(&files_path).map_err(|e| {
-                    format!(
-                        "FILES_STORAGE_CREATE_FAILED:Failed to create files storage directory: {}",
-                        e
-                    )
+                    AppError::io(format!("Failed to create files storage directory: {}", e))
+                        .with_reason("FILES_STORAGE_CREATE_FAILED")
                 })?;
             } else {
                 return Err(
-                    "FILES_STORAGE_MISSING:Files storage directory does not exist".to_string(),
+                    AppError::not_found("Files storage directory does not exist")
+                        .with_reason("FILES_STORAGE_MISSING"),
                 );
             }
         }
@@ -295,10 +347,8 @@ fn validate_storage_paths(
 }
 
 #[command]
-pub fn validate_app_config() -> Result<(), String> {
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+pub fn validate_app_config(app_config: State<SharedConfig>) -> Result<(), AppError> {
+    let config = app_config.snapshot();
     validate_storage_paths(
         &config.database_path,
         &config.asset_dir_path,
@@ -311,15 +361,12 @@ pub fn validate_app_config() -> Result<(), String> {
 pub fn set_theme_preference(
     args: ThemePreferenceArgs,
     mcp_server_info: State<McpServerInfo>,
-) -> Result<ConfigPayload, String> {
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config_path = proj_dirs.config_dir().join("config.json");
-
-    let mut config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+    app_config: State<SharedConfig>,
+) -> Result<ConfigPayload, AppError> {
+    let mut config = app_config.snapshot();
     config.theme_preference = Some(args.mode);
 
-    save_config(&config, &config_path)?;
+    store_config(&app_config, &config)?;
 
     Ok(build_config_payload(config, &mcp_server_info.url))
 }
@@ -328,20 +375,19 @@ pub fn set_theme_preference(
 pub fn set_app_window_opacity(
     args: WindowOpacityArgs,
     mcp_server_info: State<McpServerInfo>,
-) -> Result<ConfigPayload, String> {
+    app_config: State<SharedConfig>,
+) -> Result<ConfigPayload, AppError> {
     if !args.opacity.is_finite() {
-        return Err("INVALID_OPACITY:Opacity must be a finite number".to_string());
+        return Err(
+            AppError::validation("Opacity must be a finite number").with_reason("INVALID_OPACITY")
+        );
     }
 
     let opacity = args.opacity.clamp(0.2, 1.0);
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config_path = proj_dirs.config_dir().join("config.json");
-
-    let mut config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+    let mut config = app_config.snapshot();
     config.app_window_opacity = opacity;
 
-    save_config(&config, &config_path)?;
+    store_config(&app_config, &config)?;
 
     Ok(build_config_payload(config, &mcp_server_info.url))
 }
@@ -350,31 +396,121 @@ pub fn set_app_window_opacity(
 pub fn set_selection_copy_format(
     args: SelectionCopyFormatArgs,
     mcp_server_info: State<McpServerInfo>,
-) -> Result<ConfigPayload, String> {
+    app_config: State<SharedConfig>,
+) -> Result<ConfigPayload, AppError> {
     let format = normalize_selection_copy_format(&args.format);
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config_path = proj_dirs.config_dir().join("config.json");
-
-    let mut config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+    let mut config = app_config.snapshot();
     config.selection_copy_format = format;
-    save_config(&config, &config_path)?;
+    store_config(&app_config, &config)?;
+
+    Ok(build_config_payload(config, &mcp_server_info.url))
+}
+
+#[command]
+pub fn set_import_policy(
+    args: ImportPolicyArgs,
+    mcp_server_info: State<McpServerInfo>,
+    app_config: State<SharedConfig>,
+) -> Result<ConfigPayload, AppError> {
+    let policy = match args.policy.as_str() {
+        "move" | "copy" | "copy_then_trash" => args.policy,
+        _ => {
+            return Err(AppError::validation(format!(
+                "Unknown import policy: {}",
+                args.policy
+            )))
+        }
+    };
+    ensure_import_policy_supported(ImportPolicy::from_setting(&policy))?;
+    let mut config = app_config.snapshot();
+    config.import_policy = policy;
+    store_config(&app_config, &config)?;
 
     Ok(build_config_payload(config, &mcp_server_info.url))
 }
 
+#[command]
+pub fn set_watched_folders(
+    args: WatchedFoldersArgs,
+    mcp_server_info: State<McpServerInfo>,
+    app_config: State<SharedConfig>,
+) -> Result<ConfigPayload, AppError> {
+    let watched_folders = normalize_watched_folders(args.watched_folders)?;
+    let mut config = app_config.snapshot();
+    config.watched_folders = watched_folders;
+    if let Some(interval) = args.watch_interval_seconds {
+        if interval < 5 {
+            return Err(AppError::validation(
+                "Watch interval must be at least 5 seconds",
+            ));
+        }
+        config.watch_interval_seconds = interval;
+    }
+    store_config(&app_config, &config)?;
+
+    Ok(build_config_payload(config, &mcp_server_info.url))
+}
+
+fn normalize_watched_folders(folders: Vec<WatchedFolder>) -> Result<Vec<WatchedFolder>, AppError> {
+    let mut normalized: Vec<WatchedFolder> = Vec::with_capacity(folders.len());
+    for folder in folders {
+        let path = folder.path.trim().to_string();
+        if path.is_empty() {
+            return Err(AppError::validation("Watched folder path is required"));
+        }
+        if normalized.iter().any(|existing| existing.path == path) {
+            return Err(AppError::validation(format!(
+                "Folder is watched more than once: {}",
+                path
+            )));
+        }
+
+        let mut rules = Vec::with_capacity(folder.rules.len());
+        for rule in folder.rules {
+            let pattern = rule.pattern.trim().to_string();
+            if pattern.is_empty() {
+                return Err(AppError::validation("Rule pattern is required"));
+            }
+            let workspace_slug_name = trimmed_or_none(rule.workspace_slug_name);
+            let link_to = trimmed_or_none(rule.link_to);
+            if link_to.is_some() && workspace_slug_name.is_none() {
+                return Err(AppError::validation(format!(
+                    "Rule {} links a memo but has no workspace",
+                    pattern
+                )));
+            }
+            rules.push(InboxRule {
+                pattern,
+                note: trimmed_or_none(rule.note),
+                workspace_slug_name,
+                link_to,
+            });
+        }
+
+        normalized.push(WatchedFolder {
+            path,
+            enabled: folder.enabled,
+            rules,
+        });
+    }
+    Ok(normalized)
+}
+
+fn trimmed_or_none(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
 #[command]
 pub fn set_inbox_ignore_file_names(
     args: InboxIgnoreFileNamesArgs,
     mcp_server_info: State<McpServerInfo>,
-) -> Result<ConfigPayload, String> {
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config_path = proj_dirs.config_dir().join("config.json");
-
-    let mut config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+    app_config: State<SharedConfig>,
+) -> Result<ConfigPayload, AppError> {
+    let mut config = app_config.snapshot();
     config.inbox_ignore_file_names = normalize_inbox_ignore_file_names(&args.file_names);
-    save_config(&config, &config_path)?;
+    store_config(&app_config, &config)?;
 
     Ok(build_config_payload(config, &mcp_server_info.url))
 }
@@ -384,19 +520,18 @@ pub fn set_global_shortcuts(
     app: AppHandle,
     args: GlobalShortcutArgs,
     mcp_server_info: State<McpServerInfo>,
-) -> Result<ConfigPayload, String> {
+    app_config: State<SharedConfig>,
+) -> Result<ConfigPayload, AppError> {
     let focus_app_shortcut = normalize_shortcut(&args.focus_app_shortcut)?;
     let new_memo_shortcut = normalize_shortcut(&args.new_memo_shortcut)?;
 
     if focus_app_shortcut == new_memo_shortcut {
-        return Err("DUPLICATE_SHORTCUT:Shortcuts must be different".to_string());
+        return Err(
+            AppError::validation("Shortcuts must be different").with_reason("DUPLICATE_SHORTCUT")
+        );
     }
 
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config_path = proj_dirs.config_dir().join("config.json");
-
-    let mut config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+    let mut config = app_config.snapshot();
     update_global_shortcuts(
         &app,
         &GlobalShortcutSettings {
@@ -411,7 +546,7 @@ pub fn set_global_shortcuts(
 
     config.focus_app_shortcut = focus_app_shortcut;
     config.new_memo_shortcut = new_memo_shortcut;
-    save_config(&config, &config_path)?;
+    store_config(&app_config, &config)?;
 
     Ok(build_config_payload(config, &mcp_server_info.url))
 }
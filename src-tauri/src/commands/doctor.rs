@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use tauri::{command, State};
+
+use crate::config::SharedConfig;
+use crate::database::get_conn;
+use crate::errors::AppError;
+use crate::models::doctor::DoctorReport;
+use crate::repositories::DoctorRepository;
+
+#[derive(Deserialize)]
+pub struct RunDatabaseDoctorArgs {
+    #[serde(default)]
+    pub repair: bool,
+}
+
+#[command]
+pub fn run_database_doctor(
+    args: RunDatabaseDoctorArgs,
+    app_config: State<SharedConfig>,
+) -> Result<DoctorReport, AppError> {
+    let config = app_config.snapshot();
+    let storage_root = PathBuf::from(config.files_storage_root);
+    let mut conn = get_conn()?;
+
+    if args.repair {
+        DoctorRepository::repair(&mut conn, Some(&storage_root))
+    } else {
+        DoctorRepository::check(&conn, Some(&storage_root))
+    }
+}
@@ -1,18 +1,22 @@
 use std::path::PathBuf;
 use std::process::Command as ProcessCommand;
 
-use directories::{ProjectDirs, UserDirs};
+use directories::UserDirs;
 use serde::Deserialize;
-use tauri::command;
+use tauri::{command, State};
 use tauri_plugin_opener::reveal_item_in_dir;
 
-use crate::config::load_config;
+use crate::config::SharedConfig;
 use crate::database::get_conn;
+use crate::errors::AppError;
 use crate::models::file::{
-    InboxFilePage, ManagedFileDetail, ManagedFileListPage, ManagedFileRecord, MemoLinkedFileItem,
-    ResolvedFileOpenTarget,
+    ImportPolicy, InboxFilePage, ManagedFileDetail, ManagedFileListPage, ManagedFileRecord,
+    MemoLinkedFileItem, ResolvedFileOpenTarget, StorageVerificationReport,
+};
+use crate::models::inbox_activity::InboxActivityPage;
+use crate::repositories::{
+    FileRepository, InboxActivityRepository, MemoRepository, WorkspaceRepository,
 };
-use crate::repositories::{FileRepository, MemoRepository, WorkspaceRepository};
 
 #[derive(Deserialize)]
 pub struct ImportInboxFileArgs {
@@ -30,6 +34,12 @@ pub struct FileIdArgs {
     pub file_id: String,
 }
 
+#[derive(Deserialize)]
+pub struct AdoptStorageEntryArgs {
+    /// Path relative to the files storage folder, as listed by `verify_file_storage`.
+    pub relative_path: String,
+}
+
 #[derive(Deserialize)]
 pub struct LinkFileToMemoArgs {
     pub workspace_slug_name: String,
@@ -48,6 +58,7 @@ pub struct ListFilesArgs {
     pub limit: Option<i64>,
     pub offset: Option<i64>,
     pub unlinked_only: Option<bool>,
+    pub query: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -74,16 +85,29 @@ pub struct OpenLocalPathArgs {
     pub path: String,
 }
 
+#[derive(Deserialize)]
+pub struct ListInboxActivityArgs {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 #[command]
-pub fn list_inbox_files(args: ListFilesArgs) -> Result<InboxFilePage, String> {
-    let user_dirs =
-        UserDirs::new().ok_or_else(|| "Failed to determine the user directories.".to_string())?;
+pub fn list_inbox_activity(args: ListInboxActivityArgs) -> Result<InboxActivityPage, AppError> {
+    let conn = get_conn()?;
+    InboxActivityRepository::list(&conn, args.limit.unwrap_or(50), args.offset.unwrap_or(0))
+}
+
+#[command]
+pub fn list_inbox_files(
+    args: ListFilesArgs,
+    app_config: State<SharedConfig>,
+) -> Result<InboxFilePage, AppError> {
+    let user_dirs = UserDirs::new()
+        .ok_or_else(|| AppError::config("Failed to determine the user directories."))?;
     let downloads_dir = user_dirs
         .download_dir()
-        .ok_or_else(|| "Downloads folder could not be resolved.".to_string())?;
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+        .ok_or_else(|| AppError::config("Downloads folder could not be resolved."))?;
+    let config = app_config.snapshot();
     let limit = args.limit.unwrap_or(20);
     let offset = args.offset.unwrap_or(0);
     FileRepository::list_inbox_files(
@@ -95,91 +119,122 @@ pub fn list_inbox_files(args: ListFilesArgs) -> Result<InboxFilePage, String> {
 }
 
 #[command]
-pub fn import_inbox_file(args: ImportInboxFileArgs) -> Result<ManagedFileRecord, String> {
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+pub fn import_inbox_file(
+    args: ImportInboxFileArgs,
+    app_config: State<SharedConfig>,
+) -> Result<ManagedFileRecord, AppError> {
+    let config = app_config.snapshot();
     if config.files_storage_root.trim().is_empty() {
-        return Err("Files storage folder is not configured.".to_string());
+        return Err(AppError::config("Files storage folder is not configured."));
     }
 
-    let mut conn = get_conn().map_err(|e| e.to_string())?;
     let source_path = PathBuf::from(args.source_path);
     let storage_root = PathBuf::from(config.files_storage_root);
-    FileRepository::import_local_file(&mut conn, &source_path, &storage_root)
+    let policy = ImportPolicy::from_setting(&config.import_policy);
+    FileRepository::import_local_file(get_conn, &source_path, &storage_root, policy)
 }
 
 #[command]
-pub fn import_inbox_entry(args: ImportInboxFileArgs) -> Result<ManagedFileRecord, String> {
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+pub fn import_inbox_entry(
+    args: ImportInboxFileArgs,
+    app_config: State<SharedConfig>,
+) -> Result<ManagedFileRecord, AppError> {
+    let config = app_config.snapshot();
     if config.files_storage_root.trim().is_empty() {
-        return Err("Files storage folder is not configured.".to_string());
+        return Err(AppError::config("Files storage folder is not configured."));
     }
 
-    let mut conn = get_conn().map_err(|e| e.to_string())?;
     let source_path = PathBuf::from(args.source_path);
     let storage_root = PathBuf::from(config.files_storage_root);
-    FileRepository::import_local_entry(&mut conn, &source_path, &storage_root)
+    let policy = ImportPolicy::from_setting(&config.import_policy);
+    FileRepository::import_local_entry(get_conn, &source_path, &storage_root, policy)
 }
 
 #[command]
 pub fn create_external_file_link(
     args: CreateExternalFileLinkArgs,
-) -> Result<ManagedFileRecord, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+) -> Result<ManagedFileRecord, AppError> {
+    let conn = get_conn()?;
     FileRepository::create_external_link(&conn, &args.display_name, &args.url)
 }
 
 #[command]
-pub fn list_files(args: ListFilesArgs) -> Result<ManagedFileListPage, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn list_files(args: ListFilesArgs) -> Result<ManagedFileListPage, AppError> {
+    let conn = get_conn()?;
     let limit = args.limit.unwrap_or(20);
     let offset = args.offset.unwrap_or(0);
     let unlinked_only = args.unlinked_only.unwrap_or(false);
-    FileRepository::list_files(&conn, limit, offset, unlinked_only)
+    FileRepository::list_files(&conn, limit, offset, unlinked_only, args.query.as_deref())
 }
 
 #[command]
-pub fn get_file_detail(args: FileIdArgs) -> Result<ManagedFileDetail, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn get_file_detail(args: FileIdArgs) -> Result<ManagedFileDetail, AppError> {
+    let conn = get_conn()?;
     FileRepository::get_file_detail(&conn, &args.file_id)?
-        .ok_or_else(|| "File record was not found.".to_string())
+        .ok_or_else(|| AppError::not_found("File record was not found."))
 }
 
 #[command]
-pub fn resolve_file_open_target(args: FileIdArgs) -> Result<ResolvedFileOpenTarget, String> {
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+pub fn resolve_file_open_target(
+    args: FileIdArgs,
+    app_config: State<SharedConfig>,
+) -> Result<ResolvedFileOpenTarget, AppError> {
+    let config = app_config.snapshot();
     let storage_root = PathBuf::from(config.files_storage_root);
-    let conn = get_conn().map_err(|e| e.to_string())?;
+    let conn = get_conn()?;
     FileRepository::resolve_open_target(&conn, &storage_root, &args.file_id)
 }
 
 #[command]
-pub fn open_managed_file(args: FileIdArgs) -> Result<(), String> {
-    let proj_dirs = ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    let config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())?;
+pub fn verify_file_storage(
+    app_config: State<SharedConfig>,
+) -> Result<StorageVerificationReport, AppError> {
+    let config = app_config.snapshot();
+    if config.files_storage_root.trim().is_empty() {
+        return Err(AppError::config("Files storage folder is not configured."));
+    }
+    let storage_root = PathBuf::from(config.files_storage_root);
+    let conn = get_conn()?;
+    FileRepository::verify_storage(&conn, &storage_root)
+}
+
+#[command]
+pub fn adopt_storage_entry(
+    args: AdoptStorageEntryArgs,
+    app_config: State<SharedConfig>,
+) -> Result<ManagedFileRecord, AppError> {
+    let config = app_config.snapshot();
+    if config.files_storage_root.trim().is_empty() {
+        return Err(AppError::config("Files storage folder is not configured."));
+    }
+    let storage_root = PathBuf::from(config.files_storage_root);
+    let conn = get_conn()?;
+    FileRepository::adopt_storage_entry(&conn, &storage_root, &args.relative_path)
+}
+
+#[command]
+pub fn open_managed_file(
+    args: FileIdArgs,
+    app_config: State<SharedConfig>,
+) -> Result<(), AppError> {
+    let config = app_config.snapshot();
     let storage_root = PathBuf::from(config.files_storage_root);
-    let conn = get_conn().map_err(|e| e.to_string())?;
+    let conn = get_conn()?;
     let target = FileRepository::resolve_open_target(&conn, &storage_root, &args.file_id)?;
 
     match target.open_kind.as_str() {
         "path" => open_file_with_fallback(&target.value),
         "url" => open_with_system_background(&target.value),
-        _ => Err("Unsupported open target.".to_string()),
+        _ => Err(AppError::validation("Unsupported open target.")),
     }
 }
 
 #[command]
-pub fn open_local_path(args: OpenLocalPathArgs) -> Result<(), String> {
+pub fn open_local_path(args: OpenLocalPathArgs) -> Result<(), AppError> {
     open_file_with_fallback(&args.path)
 }
 
-fn open_with_system_background(target: &str) -> Result<(), String> {
+fn open_with_system_background(target: &str) -> Result<(), AppError> {
     #[cfg(target_os = "macos")]
     let mut command = {
         let mut cmd = ProcessCommand::new("open");
@@ -203,17 +258,20 @@ fn open_with_system_background(target: &str) -> Result<(), String> {
 
     command
         .spawn()
-        .map_err(|e| format!("Failed to launch opener: {}", e))?;
+        .map_err(|e| AppError::io(format!("Failed to launch opener: {}", e)))?;
 
     Ok(())
 }
 
-fn open_file_with_fallback(path: &str) -> Result<(), String> {
+fn open_file_with_fallback(path: &str) -> Result<(), AppError> {
     match open_with_system_background(path) {
         Ok(()) => Ok(()),
         Err(open_error) => {
             reveal_item_in_dir(path).map_err(|reveal_error| {
-                format!("{}; fallback reveal failed: {}", open_error, reveal_error)
+                AppError::io(format!(
+                    "{}; fallback reveal failed: {}",
+                    open_error, reveal_error
+                ))
             })?;
             Ok(())
         }
@@ -221,47 +279,52 @@ fn open_file_with_fallback(path: &str) -> Result<(), String> {
 }
 
 #[command]
-pub fn delete_file_record(args: FileIdArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn delete_file_record(args: FileIdArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     FileRepository::delete_file_record(&conn, &args.file_id)
 }
 
 #[command]
 pub fn update_file_display_name(
     args: UpdateFileDisplayNameArgs,
-) -> Result<ManagedFileRecord, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+) -> Result<ManagedFileRecord, AppError> {
+    let conn = get_conn()?;
     FileRepository::update_display_name(&conn, &args.file_id, &args.display_name)
 }
 
 #[command]
 pub fn update_external_file_link(
     args: UpdateExternalFileLinkArgs,
-) -> Result<ManagedFileRecord, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+) -> Result<ManagedFileRecord, AppError> {
+    let conn = get_conn()?;
     FileRepository::update_external_link(&conn, &args.file_id, &args.display_name, &args.url)
 }
 
 #[command]
-pub fn update_file_note(args: UpdateFileNoteArgs) -> Result<ManagedFileRecord, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn update_file_note(args: UpdateFileNoteArgs) -> Result<ManagedFileRecord, AppError> {
+    let conn = get_conn()?;
     FileRepository::update_note(&conn, &args.file_id, &args.note)
 }
 
 #[command]
-pub fn link_file_to_memo(args: LinkFileToMemoArgs) -> Result<(), String> {
-    let mut conn = get_conn().map_err(|e| e.to_string())?;
-
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
-
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+pub fn link_file_to_memo(args: LinkFileToMemoArgs) -> Result<(), AppError> {
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
     let file_record = FileRepository::find_record(&conn, &args.file_id)?
-        .ok_or_else(|| "File record was not found.".to_string())?;
+        .ok_or_else(|| AppError::not_found("File record was not found."))?;
 
     FileRepository::append_file_link_to_memo(
         &mut conn,
@@ -272,16 +335,21 @@ pub fn link_file_to_memo(args: LinkFileToMemoArgs) -> Result<(), String> {
 }
 
 #[command]
-pub fn list_files_for_memo(args: MemoFilesArgs) -> Result<Vec<MemoLinkedFileItem>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
-
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
-
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+pub fn list_files_for_memo(args: MemoFilesArgs) -> Result<Vec<MemoLinkedFileItem>, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
     FileRepository::list_files_for_memo(&conn, memo.id)
 }
@@ -1,4 +1,5 @@
 use crate::database::get_conn;
+use crate::errors::AppError;
 use crate::models::focus_daily_state;
 use crate::repositories::{FocusDailyStateRepository, MemoRepository, WorkspaceRepository};
 use rusqlite::Connection;
@@ -13,12 +14,16 @@ pub struct GetFocusDailyStatesArgs {
 #[command]
 pub fn list_focus_daily_states(
     args: GetFocusDailyStatesArgs,
-) -> Result<Vec<focus_daily_state::FocusDailyState>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+) -> Result<Vec<focus_daily_state::FocusDailyState>, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     FocusDailyStateRepository::list_by_workspace(&conn, workspace.id)
 }
@@ -32,30 +37,35 @@ pub struct FocusDailyStateArgs {
 fn resolve_workspace_and_memo_ids(
     conn: &Connection,
     args: &FocusDailyStateArgs,
-) -> Result<(i32, i32), String> {
-    let workspace = WorkspaceRepository::find_by_slug(conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+) -> Result<(i32, i32), AppError> {
+    let workspace = WorkspaceRepository::find_by_slug(conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
-    let memo = MemoRepository::find_by_slug(conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+    let memo = MemoRepository::find_by_slug(conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
     Ok((workspace.id, memo.id))
 }
 
 #[command]
-pub fn mark_focus_done_for_today(args: FocusDailyStateArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn mark_focus_done_for_today(args: FocusDailyStateArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     let (workspace_id, memo_id) = resolve_workspace_and_memo_ids(&conn, &args)?;
     FocusDailyStateRepository::mark_done_for_today(&conn, workspace_id, memo_id)
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 #[command]
-pub fn clear_focus_done_for_today(args: FocusDailyStateArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn clear_focus_done_for_today(args: FocusDailyStateArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     let (workspace_id, memo_id) = resolve_workspace_and_memo_ids(&conn, &args)?;
     FocusDailyStateRepository::clear_done_for_today(&conn, workspace_id, memo_id)
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
@@ -1,7 +1,13 @@
 use crate::database::get_conn;
+use crate::errors::AppError;
 use crate::models::kanban::Kanban;
-use crate::repositories::{KanbanRepository, WorkspaceRepository};
+use crate::models::kanban_template::KanbanCloneResult;
+use crate::models::kanban_transfer::KanbanImportSummary;
+use crate::repositories::{
+    KanbanRepository, KanbanTemplateRepository, KanbanTransferRepository, WorkspaceRepository,
+};
 use serde::Deserialize;
+use std::fs;
 use tauri::command;
 
 #[derive(Deserialize)]
@@ -10,15 +16,18 @@ pub struct ListKanbansArgs {
 }
 
 #[command]
-pub fn list_kanbans(args: ListKanbansArgs) -> Result<Vec<Kanban>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn list_kanbans(args: ListKanbansArgs) -> Result<Vec<Kanban>, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
-    let kanban = KanbanRepository::ensure_global_status_board(&conn, workspace.id)
-        .map_err(|e| e.to_string())?;
+    let kanban = KanbanRepository::ensure_global_status_board(&conn, workspace.id)?;
 
     Ok(vec![kanban])
 }
@@ -27,17 +36,37 @@ pub fn list_kanbans(args: ListKanbansArgs) -> Result<Vec<Kanban>, String> {
 pub struct CreateKanbanArgs {
     pub workspace_slug_name: String,
     pub name: String,
+    /// Seeds the board with the statuses of this kanban template.
+    pub template_id: Option<i32>,
 }
 
 #[command]
-pub fn create_kanban(args: CreateKanbanArgs) -> Result<Kanban, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn create_kanban(args: CreateKanbanArgs) -> Result<Kanban, AppError> {
+    let mut conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
-    KanbanRepository::create(&conn, workspace.id, &args.name).map_err(|e| e.to_string())
+    let Some(template_id) = args.template_id else {
+        return KanbanRepository::create(&conn, workspace.id, &args.name).map_err(AppError::from);
+    };
+
+    let template = KanbanTemplateRepository::find_by_id(&conn, workspace.id, template_id)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Kanban template not found: {}", template_id))
+        })?;
+
+    let tx = conn.transaction()?;
+    let (kanban, _) =
+        KanbanTemplateRepository::create_kanban(&tx, workspace.id, &args.name, &template.statuses)?;
+    tx.commit()?;
+
+    Ok(kanban)
 }
 
 #[derive(Deserialize)]
@@ -46,15 +75,20 @@ pub struct UpdateKanbanStatusRolesArgs {
     pub id: i32,
     pub default_status_id: Option<i32>,
     pub focus_status_id: Option<i32>,
+    pub done_status_id: Option<i32>,
 }
 
 #[command]
-pub fn update_kanban_status_roles(args: UpdateKanbanStatusRolesArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn update_kanban_status_roles(args: UpdateKanbanStatusRolesArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     let updated = KanbanRepository::update_status_roles(
         &conn,
@@ -62,11 +96,14 @@ pub fn update_kanban_status_roles(args: UpdateKanbanStatusRolesArgs) -> Result<(
         args.id,
         args.default_status_id,
         args.focus_status_id,
-    )
-    .map_err(|e| e.to_string())?;
+        args.done_status_id,
+    )?;
 
     if !updated {
-        return Err(format!("Kanban not found: {}", args.id));
+        return Err(AppError::not_found(format!(
+            "Kanban not found: {}",
+            args.id
+        )));
     }
 
     Ok(())
@@ -79,19 +116,173 @@ pub struct DeleteKanbanArgs {
 }
 
 #[command]
-pub fn delete_kanban(args: DeleteKanbanArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn delete_kanban(args: DeleteKanbanArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
-    let deleted =
-        KanbanRepository::delete(&conn, workspace.id, args.id).map_err(|e| e.to_string())?;
+    let deleted = KanbanRepository::delete(&conn, workspace.id, args.id)?;
 
     if !deleted {
-        return Err(format!("Kanban not found: {}", args.id));
+        return Err(AppError::not_found(format!(
+            "Kanban not found: {}",
+            args.id
+        )));
     }
 
     Ok(())
 }
+
+#[derive(Deserialize)]
+pub struct CloneKanbanArgs {
+    pub workspace_slug_name: String,
+    pub kanban_id: i32,
+    pub name: String,
+    /// Workspace that receives the copy. Defaults to the board's own workspace.
+    pub target_workspace_slug_name: Option<String>,
+    /// Copies the cards too. In another workspace they are matched to memos by slug.
+    #[serde(default)]
+    pub include_assignments: bool,
+}
+
+#[command]
+pub fn clone_kanban(args: CloneKanbanArgs) -> Result<KanbanCloneResult, AppError> {
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let target_workspace_id = match args.target_workspace_slug_name.as_deref() {
+        Some(target_slug_name) => {
+            WorkspaceRepository::find_by_slug(&conn, target_slug_name)?
+                .ok_or_else(|| {
+                    AppError::not_found(format!(
+                        "Workspace not found for slug: {}",
+                        target_slug_name
+                    ))
+                })?
+                .id
+        }
+        None => workspace.id,
+    };
+
+    let kanban = KanbanRepository::find_by_id(&conn, workspace.id, args.kanban_id)?
+        .ok_or_else(|| AppError::not_found(format!("Kanban not found: {}", args.kanban_id)))?;
+
+    let tx = conn.transaction()?;
+    let result = KanbanTemplateRepository::clone_kanban(
+        &tx,
+        &kanban,
+        target_workspace_id,
+        &args.name,
+        args.include_assignments,
+    )?;
+    tx.commit()?;
+
+    Ok(result)
+}
+
+#[derive(Deserialize)]
+pub struct ExportKanbanBoardArgs {
+    pub workspace_slug_name: String,
+    /// Defaults to the workspace's Status board.
+    pub kanban_id: Option<i32>,
+    pub path: String,
+    /// `csv` or `json`.
+    pub format: String,
+}
+
+#[command]
+pub fn export_kanban_board(args: ExportKanbanBoardArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let kanban = find_kanban(&conn, workspace.id, args.kanban_id)?;
+    let export = KanbanTransferRepository::export_board(&conn, workspace.id, &kanban)?;
+    let content = match args.format.as_str() {
+        "csv" => KanbanTransferRepository::to_csv(&export)?,
+        "json" => serde_json::to_string_pretty(&export)?,
+        other => {
+            return Err(AppError::validation(format!(
+                "Unsupported kanban export format: {}",
+                other
+            )))
+        }
+    };
+
+    fs::write(&args.path, content)
+        .map_err(|e| AppError::io(format!("Failed to save kanban export: {}", e)))
+}
+
+#[derive(Deserialize)]
+pub struct ImportKanbanCsvArgs {
+    pub workspace_slug_name: String,
+    /// Defaults to the workspace's Status board.
+    pub kanban_id: Option<i32>,
+    pub path: String,
+    /// Adds statuses the board doesn't have yet instead of skipping their rows.
+    #[serde(default)]
+    pub create_missing_statuses: bool,
+}
+
+#[command]
+pub fn import_kanban_csv(args: ImportKanbanCsvArgs) -> Result<KanbanImportSummary, AppError> {
+    let text = fs::read_to_string(&args.path)
+        .map_err(|e| AppError::io(format!("Failed to read kanban CSV: {}", e)))?;
+    let rows = KanbanTransferRepository::parse_csv(&text)?;
+
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let tx = conn.transaction()?;
+    let kanban = find_kanban(&tx, workspace.id, args.kanban_id)?;
+    let summary = KanbanTransferRepository::import_rows(
+        &tx,
+        workspace.id,
+        &kanban,
+        &rows,
+        args.create_missing_statuses,
+    )?;
+    tx.commit()?;
+
+    Ok(summary)
+}
+
+fn find_kanban(
+    conn: &rusqlite::Connection,
+    workspace_id: i32,
+    kanban_id: Option<i32>,
+) -> Result<Kanban, AppError> {
+    match kanban_id {
+        Some(kanban_id) => KanbanRepository::find_by_id(conn, workspace_id, kanban_id)?
+            .ok_or_else(|| AppError::not_found(format!("Kanban not found: {}", kanban_id))),
+        None => {
+            KanbanRepository::ensure_global_status_board(conn, workspace_id).map_err(AppError::from)
+        }
+    }
+}
@@ -1,6 +1,17 @@
 use crate::database::get_conn;
-use crate::models::kanban_assignment::{KanbanAssignmentEntry, KanbanAssignmentItem};
-use crate::repositories::{KanbanAssignmentRepository, MemoRepository, WorkspaceRepository};
+use crate::errors::AppError;
+use crate::models::kanban_assignment::{
+    KanbanAssignmentEntry, KanbanAssignmentItem, KanbanBulkMoveResult, KanbanCardMoveResult,
+    KanbanDatedItem,
+};
+use crate::models::kanban_automation::KanbanAutomationOutcome;
+use crate::models::kanban_lane::KanbanLane;
+use crate::repositories::kanban_assignment_repository::KanbanDateFilter;
+use crate::repositories::kanban_lane_repository::KanbanLaneDimension;
+use crate::repositories::{
+    KanbanAssignmentRepository, KanbanAutomationRepository, KanbanLaneRepository, KanbanRepository,
+    MemoRepository, WorkspaceRepository,
+};
 use serde::Deserialize;
 use tauri::command;
 
@@ -13,15 +24,52 @@ pub struct ListKanbanAssignmentItemsArgs {
 #[command]
 pub fn list_kanban_assignment_items(
     args: ListKanbanAssignmentItemsArgs,
-) -> Result<Vec<KanbanAssignmentItem>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+) -> Result<Vec<KanbanAssignmentItem>, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     KanbanAssignmentRepository::list_items_by_kanban(&conn, workspace.id, args.kanban_id)
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
+}
+
+#[derive(Deserialize)]
+pub struct ListKanbanLanesArgs {
+    pub workspace_slug_name: String,
+    pub kanban_id: i32,
+    /// `milestone`, `bookmark` or `kanban_status`.
+    pub group_by: String,
+    /// Kanban whose statuses form the lanes when grouping by `kanban_status`.
+    pub group_kanban_id: Option<i32>,
+}
+
+#[command]
+pub fn list_kanban_lanes(args: ListKanbanLanesArgs) -> Result<Vec<KanbanLane>, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let kanban = KanbanRepository::find_by_id(&conn, workspace.id, args.kanban_id)?
+        .ok_or_else(|| AppError::not_found(format!("Kanban not found: {}", args.kanban_id)))?;
+
+    let dimension =
+        KanbanLaneDimension::parse(&args.group_by, args.group_kanban_id).ok_or_else(|| {
+            AppError::validation(format!("Unknown kanban lane grouping: {}", args.group_by))
+        })?;
+
+    KanbanLaneRepository::list_lanes(&conn, &kanban, dimension)
 }
 
 #[derive(Deserialize)]
@@ -33,19 +81,24 @@ pub struct ListKanbanAssignmentEntriesArgs {
 #[command]
 pub fn list_kanban_assignment_entries(
     args: ListKanbanAssignmentEntriesArgs,
-) -> Result<Vec<KanbanAssignmentEntry>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+) -> Result<Vec<KanbanAssignmentEntry>, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
     KanbanAssignmentRepository::list_entries_by_memo(&conn, workspace.id, memo.id)
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 #[derive(Deserialize)]
@@ -55,35 +108,310 @@ pub struct UpsertKanbanAssignmentStatusArgs {
     pub kanban_id: i32,
     pub kanban_status_id: Option<i32>,
     pub position: Option<i64>,
+    /// Skips the WIP limit and transition rule checks.
+    pub force: Option<bool>,
 }
 
 #[command]
 pub fn upsert_kanban_assignment_status(
     args: UpsertKanbanAssignmentStatusArgs,
-) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+) -> Result<Vec<KanbanAutomationOutcome>, AppError> {
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+    let tx = conn.transaction()?;
+
+    let from_status_id =
+        KanbanAssignmentRepository::find_status(&tx, workspace.id, memo.id, args.kanban_id)?
+            .flatten();
+
+    if !args.force.unwrap_or(false) {
+        KanbanAssignmentRepository::ensure_status_move_allowed(
+            &tx,
+            workspace.id,
+            memo.id,
+            args.kanban_id,
+            args.kanban_status_id,
+        )?;
+    }
 
     KanbanAssignmentRepository::upsert_status(
-        &conn,
+        &tx,
         workspace.id,
         memo.id,
         args.kanban_id,
         args.kanban_status_id,
         args.position,
-    )
-    .map_err(|e| e.to_string())?;
+    )?;
+
+    let outcomes = KanbanAutomationRepository::run_for_status_change(
+        &tx,
+        workspace.id,
+        args.kanban_id,
+        memo.id,
+        from_status_id,
+        args.kanban_status_id,
+    )?;
+
+    tx.commit()?;
+
+    Ok(outcomes)
+}
+
+#[derive(Deserialize)]
+pub struct MoveKanbanCardArgs {
+    pub workspace_slug_name: String,
+    pub memo_slug_title: String,
+    pub kanban_id: i32,
+    pub kanban_status_id: i32,
+    /// Card that ends up directly above the moved card.
+    pub after_memo_slug_title: Option<String>,
+    /// Card that ends up directly below the moved card.
+    pub before_memo_slug_title: Option<String>,
+    /// Skips the WIP limit and transition rule checks.
+    pub force: Option<bool>,
+}
+
+#[command]
+pub fn move_kanban_card(args: MoveKanbanCardArgs) -> Result<KanbanCardMoveResult, AppError> {
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let find_memo_id = |slug_title: &str| -> Result<i32, AppError> {
+        MemoRepository::find_by_slug(&conn, workspace.id, slug_title)?
+            .map(|memo| memo.id)
+            .ok_or_else(|| AppError::not_found(format!("Memo not found for slug: {}", slug_title)))
+    };
+    let memo_id = find_memo_id(&args.memo_slug_title)?;
+    let after_memo_id = args
+        .after_memo_slug_title
+        .as_deref()
+        .map(find_memo_id)
+        .transpose()?;
+    let before_memo_id = args
+        .before_memo_slug_title
+        .as_deref()
+        .map(find_memo_id)
+        .transpose()?;
+
+    let tx = conn.transaction()?;
+
+    let from_status_id =
+        KanbanAssignmentRepository::find_status(&tx, workspace.id, memo_id, args.kanban_id)?
+            .flatten();
+
+    if !args.force.unwrap_or(false) {
+        KanbanAssignmentRepository::ensure_status_move_allowed(
+            &tx,
+            workspace.id,
+            memo_id,
+            args.kanban_id,
+            Some(args.kanban_status_id),
+        )?;
+    }
+
+    let positions = KanbanAssignmentRepository::move_card(
+        &tx,
+        workspace.id,
+        memo_id,
+        args.kanban_id,
+        args.kanban_status_id,
+        after_memo_id,
+        before_memo_id,
+    )?;
+
+    let outcomes = KanbanAutomationRepository::run_for_status_change(
+        &tx,
+        workspace.id,
+        args.kanban_id,
+        memo_id,
+        from_status_id,
+        Some(args.kanban_status_id),
+    )?;
+
+    tx.commit()?;
+
+    Ok(KanbanCardMoveResult {
+        positions,
+        outcomes,
+    })
+}
+
+#[derive(Deserialize)]
+pub struct MoveKanbanCardsToStatusArgs {
+    pub workspace_slug_name: String,
+    pub kanban_id: i32,
+    pub memo_slug_titles: Vec<String>,
+    pub kanban_status_id: i32,
+    /// Skips the WIP limit and transition rule checks.
+    pub force: Option<bool>,
+}
+
+/// Appends every memo to the end of the status in one transaction. A memo that is blocked
+/// by the board rules rolls back the whole move.
+#[command]
+pub fn move_kanban_cards_to_status(
+    args: MoveKanbanCardsToStatusArgs,
+) -> Result<KanbanBulkMoveResult, AppError> {
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let tx = conn.transaction()?;
+    let mut moved_count = 0;
+    let mut outcomes = Vec::new();
+
+    for memo_slug_title in &args.memo_slug_titles {
+        let memo =
+            MemoRepository::find_by_slug(&tx, workspace.id, memo_slug_title)?.ok_or_else(|| {
+                AppError::not_found(format!("Memo not found for slug: {}", memo_slug_title))
+            })?;
+
+        let from_status_id =
+            KanbanAssignmentRepository::find_status(&tx, workspace.id, memo.id, args.kanban_id)?
+                .flatten();
+        if from_status_id == Some(args.kanban_status_id) {
+            continue;
+        }
+
+        if !args.force.unwrap_or(false) {
+            KanbanAssignmentRepository::ensure_status_move_allowed(
+                &tx,
+                workspace.id,
+                memo.id,
+                args.kanban_id,
+                Some(args.kanban_status_id),
+            )?;
+        }
+
+        KanbanAssignmentRepository::append_to_status(
+            &tx,
+            workspace.id,
+            memo.id,
+            args.kanban_id,
+            args.kanban_status_id,
+        )?;
+
+        outcomes.extend(KanbanAutomationRepository::run_for_status_change(
+            &tx,
+            workspace.id,
+            args.kanban_id,
+            memo.id,
+            from_status_id,
+            Some(args.kanban_status_id),
+        )?);
+        moved_count += 1;
+    }
+
+    tx.commit()?;
+
+    Ok(KanbanBulkMoveResult {
+        moved_count,
+        outcomes,
+    })
+}
+
+#[derive(Deserialize)]
+pub struct UpdateKanbanAssignmentDatesArgs {
+    pub workspace_slug_name: String,
+    pub memo_slug_title: String,
+    pub kanban_id: i32,
+    pub due_on: Option<String>,
+    pub scheduled_on: Option<String>,
+}
+
+#[command]
+pub fn update_kanban_assignment_dates(
+    args: UpdateKanbanAssignmentDatesArgs,
+) -> Result<(), AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
+
+    let due_on = normalize_date(&conn, args.due_on.as_deref())?;
+    let scheduled_on = normalize_date(&conn, args.scheduled_on.as_deref())?;
+
+    let updated = KanbanAssignmentRepository::update_dates(
+        &conn,
+        workspace.id,
+        memo.id,
+        args.kanban_id,
+        due_on.as_deref(),
+        scheduled_on.as_deref(),
+    )?;
+
+    if !updated {
+        return Err(AppError::validation("Memo is not in the kanban."));
+    }
 
     Ok(())
 }
 
+#[derive(Deserialize)]
+pub struct ListKanbanDatedItemsArgs {
+    pub workspace_slug_name: String,
+    /// `overdue`, `due_this_week` or `scheduled_today`.
+    pub filter: String,
+}
+
+#[command]
+pub fn list_kanban_dated_items(
+    args: ListKanbanDatedItemsArgs,
+) -> Result<Vec<KanbanDatedItem>, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let filter = KanbanDateFilter::parse(&args.filter).ok_or_else(|| {
+        AppError::validation(format!("Unknown kanban date filter: {}", args.filter))
+    })?;
+
+    KanbanAssignmentRepository::list_dated_items(&conn, workspace.id, filter)
+        .map_err(AppError::from)
+}
+
 #[derive(Deserialize)]
 pub struct RemoveKanbanAssignmentArgs {
     pub workspace_slug_name: String,
@@ -92,24 +420,44 @@ pub struct RemoveKanbanAssignmentArgs {
 }
 
 #[command]
-pub fn remove_kanban_assignment(args: RemoveKanbanAssignmentArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn remove_kanban_assignment(args: RemoveKanbanAssignmentArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
     let deleted =
-        KanbanAssignmentRepository::delete_entry(&conn, workspace.id, memo.id, args.kanban_id)
-            .map_err(|e| e.to_string())?;
+        KanbanAssignmentRepository::delete_entry(&conn, workspace.id, memo.id, args.kanban_id)?;
 
     if !deleted {
-        return Err("Memo is not in the kanban.".to_string());
+        return Err(AppError::validation("Memo is not in the kanban."));
     }
 
     Ok(())
 }
+
+fn normalize_date(
+    conn: &rusqlite::Connection,
+    date: Option<&str>,
+) -> Result<Option<String>, AppError> {
+    let Some(date) = date.map(str::trim).filter(|date| !date.is_empty()) else {
+        return Ok(None);
+    };
+    let is_valid: bool = conn.query_row("SELECT date(?, '+0 days') = ?", (date, date), |row| {
+        row.get(0)
+    })?;
+    if !is_valid {
+        return Err(AppError::validation(format!("Invalid date: {date}")));
+    }
+    Ok(Some(date.to_string()))
+}
@@ -0,0 +1,121 @@
+use crate::database::get_conn;
+use crate::errors::AppError;
+use crate::models::kanban_automation::{KanbanAutomationRule, KanbanAutomationRuleInput};
+use crate::repositories::{KanbanAutomationRepository, KanbanRepository, WorkspaceRepository};
+use serde::Deserialize;
+use tauri::command;
+
+#[derive(Deserialize)]
+pub struct ListKanbanAutomationRulesArgs {
+    pub workspace_slug_name: String,
+    pub kanban_id: Option<i32>,
+}
+
+#[command]
+pub fn list_kanban_automation_rules(
+    args: ListKanbanAutomationRulesArgs,
+) -> Result<Vec<KanbanAutomationRule>, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let kanban_id = if let Some(id) = args.kanban_id {
+        id
+    } else {
+        KanbanRepository::ensure_global_status_board(&conn, workspace.id)?.id
+    };
+
+    KanbanAutomationRepository::list_by_kanban(&conn, workspace.id, kanban_id)
+}
+
+#[derive(Deserialize)]
+pub struct CreateKanbanAutomationRuleArgs {
+    pub workspace_slug_name: String,
+    pub kanban_id: Option<i32>,
+    pub rule: KanbanAutomationRuleInput,
+}
+
+#[command]
+pub fn create_kanban_automation_rule(
+    args: CreateKanbanAutomationRuleArgs,
+) -> Result<KanbanAutomationRule, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let kanban_id = if let Some(id) = args.kanban_id {
+        KanbanRepository::find_by_id(&conn, workspace.id, id)?
+            .ok_or_else(|| AppError::not_found(format!("Kanban not found: {}", id)))?
+            .id
+    } else {
+        KanbanRepository::ensure_global_status_board(&conn, workspace.id)?.id
+    };
+
+    KanbanAutomationRepository::create(&conn, workspace.id, kanban_id, &args.rule)
+}
+
+#[derive(Deserialize)]
+pub struct UpdateKanbanAutomationRuleArgs {
+    pub workspace_slug_name: String,
+    pub id: i32,
+    pub rule: KanbanAutomationRuleInput,
+}
+
+#[command]
+pub fn update_kanban_automation_rule(
+    args: UpdateKanbanAutomationRuleArgs,
+) -> Result<KanbanAutomationRule, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    KanbanAutomationRepository::update(&conn, workspace.id, args.id, &args.rule)
+}
+
+#[derive(Deserialize)]
+pub struct DeleteKanbanAutomationRuleArgs {
+    pub workspace_slug_name: String,
+    pub id: i32,
+}
+
+#[command]
+pub fn delete_kanban_automation_rule(args: DeleteKanbanAutomationRuleArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let deleted = KanbanAutomationRepository::delete(&conn, workspace.id, args.id)?;
+
+    if !deleted {
+        return Err(AppError::not_found(format!(
+            "Automation rule not found: {}",
+            args.id
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use crate::database::get_conn;
+use crate::errors::AppError;
+use crate::models::kanban_report::{KanbanReport, KanbanStatusTransition};
+use crate::repositories::{
+    KanbanReportRepository, KanbanRepository, MemoRepository, WorkspaceRepository,
+};
+use serde::Deserialize;
+use tauri::command;
+
+#[derive(Deserialize)]
+pub struct GetKanbanReportArgs {
+    pub workspace_slug_name: String,
+    pub kanban_id: i32,
+    pub date_from: Option<String>,
+    pub date_to: Option<String>,
+}
+
+#[command]
+pub fn get_kanban_report(args: GetKanbanReportArgs) -> Result<KanbanReport, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let kanban = KanbanRepository::find_by_id(&conn, workspace.id, args.kanban_id)?
+        .ok_or_else(|| AppError::not_found(format!("Kanban not found: {}", args.kanban_id)))?;
+
+    KanbanReportRepository::build(
+        &conn,
+        &kanban,
+        args.date_from.as_deref(),
+        args.date_to.as_deref(),
+    )
+}
+
+#[derive(Deserialize)]
+pub struct ListKanbanStatusTransitionsArgs {
+    pub workspace_slug_name: String,
+    pub kanban_id: i32,
+    pub memo_slug_title: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[command]
+pub fn list_kanban_status_transitions(
+    args: ListKanbanStatusTransitionsArgs,
+) -> Result<Vec<KanbanStatusTransition>, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let memo_id = match args.memo_slug_title.as_deref() {
+        Some(memo_slug_title) => Some(
+            MemoRepository::find_by_slug(&conn, workspace.id, memo_slug_title)?
+                .ok_or_else(|| {
+                    AppError::not_found(format!("Memo not found for slug: {}", memo_slug_title))
+                })?
+                .id,
+        ),
+        None => None,
+    };
+
+    KanbanReportRepository::list_transitions(
+        &conn,
+        workspace.id,
+        args.kanban_id,
+        memo_id,
+        args.limit.unwrap_or(50),
+        args.offset.unwrap_or(0),
+    )
+}
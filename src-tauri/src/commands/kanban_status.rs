@@ -1,5 +1,6 @@
 use crate::database::get_conn;
-use crate::models::kanban_status::KanbanStatus;
+use crate::errors::AppError;
+use crate::models::kanban_status::{KanbanStatus, KanbanStatusTransitionRule};
 use crate::repositories::{KanbanStatusRepository, WorkspaceRepository};
 use serde::Deserialize;
 use tauri::command;
@@ -11,24 +12,26 @@ pub struct ListKanbanStatusesArgs {
 }
 
 #[command]
-pub fn list_kanban_statuses(args: ListKanbanStatusesArgs) -> Result<Vec<KanbanStatus>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn list_kanban_statuses(args: ListKanbanStatusesArgs) -> Result<Vec<KanbanStatus>, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     let kanban_id = if let Some(id) = args.kanban_id {
         id
     } else {
         let kanban =
-            crate::repositories::KanbanRepository::ensure_global_status_board(&conn, workspace.id)
-                .map_err(|e| e.to_string())?;
+            crate::repositories::KanbanRepository::ensure_global_status_board(&conn, workspace.id)?;
         kanban.id
     };
 
-    KanbanStatusRepository::list_by_kanban(&conn, workspace.id, kanban_id)
-        .map_err(|e| e.to_string())
+    KanbanStatusRepository::list_by_kanban(&conn, workspace.id, kanban_id).map_err(AppError::from)
 }
 
 #[derive(Deserialize)]
@@ -40,19 +43,22 @@ pub struct CreateKanbanStatusArgs {
 }
 
 #[command]
-pub fn create_kanban_status(args: CreateKanbanStatusArgs) -> Result<KanbanStatus, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn create_kanban_status(args: CreateKanbanStatusArgs) -> Result<KanbanStatus, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     let kanban_id = if let Some(id) = args.kanban_id {
         id
     } else {
         let kanban =
-            crate::repositories::KanbanRepository::ensure_global_status_board(&conn, workspace.id)
-                .map_err(|e| e.to_string())?;
+            crate::repositories::KanbanRepository::ensure_global_status_board(&conn, workspace.id)?;
         kanban.id
     };
 
@@ -63,7 +69,7 @@ pub fn create_kanban_status(args: CreateKanbanStatusArgs) -> Result<KanbanStatus
         &args.name,
         args.color.as_deref(),
     )
-    .map_err(|e| e.to_string())
+    .map_err(AppError::from)
 }
 
 #[derive(Deserialize)]
@@ -75,12 +81,16 @@ pub struct UpdateKanbanStatusArgs {
 }
 
 #[command]
-pub fn update_kanban_status(args: UpdateKanbanStatusArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn update_kanban_status(args: UpdateKanbanStatusArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     let updated = KanbanStatusRepository::update(
         &conn,
@@ -88,13 +98,139 @@ pub fn update_kanban_status(args: UpdateKanbanStatusArgs) -> Result<(), String>
         args.id,
         &args.name,
         args.color.as_deref(),
-    )
-    .map_err(|e| e.to_string())?;
+    )?;
+
+    if !updated {
+        return Err(AppError::not_found(format!(
+            "Kanban status not found: {}",
+            args.id
+        )));
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+pub struct UpdateKanbanStatusWipLimitArgs {
+    pub workspace_slug_name: String,
+    pub id: i32,
+    pub wip_limit: Option<i32>,
+}
+
+#[command]
+pub fn update_kanban_status_wip_limit(
+    args: UpdateKanbanStatusWipLimitArgs,
+) -> Result<(), AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    if matches!(args.wip_limit, Some(limit) if limit < 1) {
+        return Err(AppError::validation("WIP limit must be at least 1."));
+    }
+
+    let updated =
+        KanbanStatusRepository::update_wip_limit(&conn, workspace.id, args.id, args.wip_limit)?;
 
     if !updated {
-        return Err(format!("Kanban status not found: {}", args.id));
+        return Err(AppError::not_found(format!(
+            "Kanban status not found: {}",
+            args.id
+        )));
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+pub struct ListKanbanTransitionRulesArgs {
+    pub workspace_slug_name: String,
+    pub kanban_id: Option<i32>,
+}
+
+#[command]
+pub fn list_kanban_transition_rules(
+    args: ListKanbanTransitionRulesArgs,
+) -> Result<Vec<KanbanStatusTransitionRule>, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let kanban_id = if let Some(id) = args.kanban_id {
+        id
+    } else {
+        let kanban =
+            crate::repositories::KanbanRepository::ensure_global_status_board(&conn, workspace.id)?;
+        kanban.id
+    };
+
+    KanbanStatusRepository::list_transition_rules(&conn, workspace.id, kanban_id)
+        .map_err(AppError::from)
+}
+
+#[derive(Deserialize)]
+pub struct SetKanbanTransitionRulesArgs {
+    pub workspace_slug_name: String,
+    pub kanban_id: Option<i32>,
+    pub rules: Vec<KanbanStatusTransitionRule>,
+}
+
+#[command]
+pub fn set_kanban_transition_rules(args: SetKanbanTransitionRulesArgs) -> Result<(), AppError> {
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let kanban_id = if let Some(id) = args.kanban_id {
+        id
+    } else {
+        let kanban =
+            crate::repositories::KanbanRepository::ensure_global_status_board(&conn, workspace.id)?;
+        kanban.id
+    };
+
+    let statuses = KanbanStatusRepository::list_by_kanban(&conn, workspace.id, kanban_id)?;
+    for rule in &args.rules {
+        if rule.from_status_id == rule.to_status_id {
+            return Err(AppError::validation(
+                "A transition rule must connect two different statuses.",
+            ));
+        }
+        for status_id in [rule.from_status_id, rule.to_status_id] {
+            if !statuses.iter().any(|status| status.id == status_id) {
+                return Err(AppError::validation(format!(
+                    "Kanban status {} does not belong to kanban {}.",
+                    status_id, kanban_id
+                )));
+            }
+        }
     }
 
+    KanbanStatusRepository::replace_transition_rules(
+        &mut conn,
+        workspace.id,
+        kanban_id,
+        &args.rules,
+    )?;
+
     Ok(())
 }
 
@@ -105,30 +241,35 @@ pub struct DeleteKanbanStatusArgs {
 }
 
 #[command]
-pub fn delete_kanban_status(args: DeleteKanbanStatusArgs) -> Result<(), String> {
-    let mut conn = get_conn().map_err(|e| e.to_string())?;
+pub fn delete_kanban_status(args: DeleteKanbanStatusArgs) -> Result<(), AppError> {
+    let mut conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
-    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    let tx = conn.transaction()?;
 
-    let deleted =
-        KanbanStatusRepository::delete(&tx, workspace.id, args.id).map_err(|e| e.to_string())?;
+    let deleted = KanbanStatusRepository::delete(&tx, workspace.id, args.id)?;
 
     if !deleted {
-        return Err(format!("Kanban status not found: {}", args.id));
+        return Err(AppError::not_found(format!(
+            "Kanban status not found: {}",
+            args.id
+        )));
     }
 
     tx.execute(
         "DELETE FROM kanban_assignment
         WHERE workspace_id = ? AND kanban_status_id = ?",
         (workspace.id, args.id),
-    )
-    .map_err(|e| e.to_string())?;
+    )?;
 
-    tx.commit().map_err(|e| e.to_string())?;
+    tx.commit()?;
 
     Ok(())
 }
@@ -146,12 +287,16 @@ pub struct UpdateKanbanStatusOrdersArgs {
 }
 
 #[command]
-pub fn update_kanban_status_orders(args: UpdateKanbanStatusOrdersArgs) -> Result<(), String> {
-    let mut conn = get_conn().map_err(|e| e.to_string())?;
+pub fn update_kanban_status_orders(args: UpdateKanbanStatusOrdersArgs) -> Result<(), AppError> {
+    let mut conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     let updates: Vec<(i32, i32)> = args
         .updates
@@ -159,8 +304,7 @@ pub fn update_kanban_status_orders(args: UpdateKanbanStatusOrdersArgs) -> Result
         .map(|update| (update.id, update.order_index))
         .collect();
 
-    KanbanStatusRepository::update_orders(&mut conn, workspace.id, &updates)
-        .map_err(|e| e.to_string())?;
+    KanbanStatusRepository::update_orders(&mut conn, workspace.id, &updates)?;
 
     Ok(())
 }
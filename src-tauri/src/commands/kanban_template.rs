@@ -0,0 +1,144 @@
+use crate::database::get_conn;
+use crate::errors::AppError;
+use crate::models::kanban_template::{KanbanTemplate, KanbanTemplateStatus};
+use crate::repositories::{KanbanRepository, KanbanTemplateRepository, WorkspaceRepository};
+use serde::Deserialize;
+use tauri::command;
+
+#[derive(Deserialize)]
+pub struct ListKanbanTemplatesArgs {
+    pub workspace_slug_name: String,
+}
+
+#[command]
+pub fn list_kanban_templates(
+    args: ListKanbanTemplatesArgs,
+) -> Result<Vec<KanbanTemplate>, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    KanbanTemplateRepository::list(&conn, workspace.id)
+}
+
+#[derive(Deserialize)]
+pub struct CreateKanbanTemplateArgs {
+    pub workspace_slug_name: String,
+    pub name: String,
+    pub statuses: Vec<KanbanTemplateStatus>,
+}
+
+#[command]
+pub fn create_kanban_template(args: CreateKanbanTemplateArgs) -> Result<KanbanTemplate, AppError> {
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let tx = conn.transaction()?;
+    let template = KanbanTemplateRepository::create(&tx, workspace.id, &args.name, &args.statuses)?;
+    tx.commit()?;
+
+    Ok(template)
+}
+
+#[derive(Deserialize)]
+pub struct CreateKanbanTemplateFromBoardArgs {
+    pub workspace_slug_name: String,
+    pub kanban_id: i32,
+    pub name: String,
+}
+
+#[command]
+pub fn create_kanban_template_from_board(
+    args: CreateKanbanTemplateFromBoardArgs,
+) -> Result<KanbanTemplate, AppError> {
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let kanban = KanbanRepository::find_by_id(&conn, workspace.id, args.kanban_id)?
+        .ok_or_else(|| AppError::not_found(format!("Kanban not found: {}", args.kanban_id)))?;
+
+    let tx = conn.transaction()?;
+    let statuses = KanbanTemplateRepository::statuses_of_kanban(&tx, &kanban)?;
+    let template = KanbanTemplateRepository::create(&tx, workspace.id, &args.name, &statuses)?;
+    tx.commit()?;
+
+    Ok(template)
+}
+
+#[derive(Deserialize)]
+pub struct UpdateKanbanTemplateArgs {
+    pub workspace_slug_name: String,
+    pub id: i32,
+    pub name: String,
+    pub statuses: Vec<KanbanTemplateStatus>,
+}
+
+#[command]
+pub fn update_kanban_template(args: UpdateKanbanTemplateArgs) -> Result<KanbanTemplate, AppError> {
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let tx = conn.transaction()?;
+    let template =
+        KanbanTemplateRepository::update(&tx, workspace.id, args.id, &args.name, &args.statuses)?;
+    tx.commit()?;
+
+    Ok(template)
+}
+
+#[derive(Deserialize)]
+pub struct DeleteKanbanTemplateArgs {
+    pub workspace_slug_name: String,
+    pub id: i32,
+}
+
+#[command]
+pub fn delete_kanban_template(args: DeleteKanbanTemplateArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let deleted = KanbanTemplateRepository::delete(&conn, workspace.id, args.id)?;
+
+    if !deleted {
+        return Err(AppError::not_found(format!(
+            "Kanban template not found: {}",
+            args.id
+        )));
+    }
+
+    Ok(())
+}
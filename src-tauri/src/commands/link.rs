@@ -1,4 +1,5 @@
 use crate::database::get_conn;
+use crate::errors::AppError;
 use crate::models::{Link, LinkId, MemoLinkCount};
 use crate::repositories::{LinkRepository, MemoRepository, WorkspaceRepository};
 use serde::Deserialize;
@@ -11,16 +12,21 @@ pub struct GetLinksArgs {
 }
 
 #[command]
-pub fn get_links(args: GetLinksArgs) -> Result<Vec<Link>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
-
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
-
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+pub fn get_links(args: GetLinksArgs) -> Result<Vec<Link>, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
     let links = LinkRepository::list(&conn, memo.id);
 
@@ -35,12 +41,16 @@ pub struct ListWorkspaceLinkCountsArgs {
 #[command]
 pub fn list_workspace_link_counts(
     args: ListWorkspaceLinkCountsArgs,
-) -> Result<Vec<MemoLinkCount>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+) -> Result<Vec<MemoLinkCount>, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     LinkRepository::list_counts_by_workspace(&conn, workspace.id)
 }
@@ -53,22 +63,31 @@ pub struct CreateLinkArgs {
 }
 
 #[command]
-pub fn create_link(args: CreateLinkArgs) -> Result<LinkId, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
-
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
-
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
-
-    let to_memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.to_memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo to link not found for slug: {}", args.memo_slug_title))?;
-
-    let link = LinkRepository::create(&conn, memo.id, to_memo.id).map_err(|e| e.to_string());
+pub fn create_link(args: CreateLinkArgs) -> Result<LinkId, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
+
+    let to_memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.to_memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Memo to link not found for slug: {}",
+                args.memo_slug_title
+            ))
+        })?;
+
+    let link = LinkRepository::create(&conn, memo.id, to_memo.id).map_err(AppError::from);
 
     link
 }
@@ -82,28 +101,39 @@ pub struct DeleteLinkArgs {
 }
 
 #[command]
-pub fn delete_link(args: DeleteLinkArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
-
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
-
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+pub fn delete_link(args: DeleteLinkArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
     let linked_workspace =
-        WorkspaceRepository::find_by_slug(&conn, &args.linked_workspace_slug_name)
-            .map_err(|e| e.to_string())?
-            .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+        WorkspaceRepository::find_by_slug(&conn, &args.linked_workspace_slug_name)?.ok_or_else(
+            || {
+                AppError::not_found(format!(
+                    "Workspace not found for slug: {}",
+                    args.workspace_slug_name
+                ))
+            },
+        )?;
 
     let linked_memo =
-        MemoRepository::find_by_slug(&conn, linked_workspace.id, &args.linked_memo_slug_title)
-            .map_err(|e| e.to_string())?
-            .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+        MemoRepository::find_by_slug(&conn, linked_workspace.id, &args.linked_memo_slug_title)?
+            .ok_or_else(|| {
+                AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+            })?;
 
-    LinkRepository::delete(&conn, memo.id, linked_memo.id).map_err(|e| e.to_string())?;
+    LinkRepository::delete(&conn, memo.id, linked_memo.id)?;
 
     Ok(())
 }
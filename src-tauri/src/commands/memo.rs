@@ -1,9 +1,13 @@
+use std::collections::HashMap;
+
 use crate::database::get_conn;
+use crate::errors::AppError;
 use crate::models::memo::{CurrentMemoDetail, MemoDetail, MemoSearchItem};
 use crate::models::MemoIndexItem;
+use crate::repositories::memo_template_repository::MemoTemplateVariables;
 use crate::repositories::{
-    KanbanAssignmentRepository, KanbanRepository, MemoRepository, MemoViewRepository,
-    WorkspaceRepository,
+    KanbanAssignmentRepository, KanbanRepository, MemoRepository, MemoTemplateRepository,
+    MemoViewRepository, WorkspaceRepository,
 };
 use serde::Deserialize;
 use tauri::command;
@@ -14,14 +18,18 @@ pub struct GetMemosArgs {
 }
 
 #[command]
-pub fn get_workspace_memos(args: GetMemosArgs) -> Result<Vec<MemoIndexItem>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn get_workspace_memos(args: GetMemosArgs) -> Result<Vec<MemoIndexItem>, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
-    MemoRepository::list(&conn, workspace.id).map_err(|e| e.to_string())
+    MemoRepository::list(&conn, workspace.id)
 }
 
 #[derive(Deserialize)]
@@ -31,37 +39,49 @@ pub struct GetMemoArgs {
 }
 
 #[command]
-pub fn get_memo(args: GetMemoArgs) -> Result<MemoDetail, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn get_memo(args: GetMemoArgs) -> Result<MemoDetail, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     match MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title) {
         Ok(Some(memo)) => Ok(memo),
-        Ok(None) => Err(format!("Memo not found for slug: {}", args.memo_slug_title)),
-        Err(e) => Err(e.to_string()),
+        Ok(None) => Err(AppError::not_found(format!(
+            "Memo not found for slug: {}",
+            args.memo_slug_title
+        ))),
+        Err(e) => Err(e.into()),
     }
 }
 
 #[command]
-pub fn get_current_memo() -> Result<Option<CurrentMemoDetail>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn get_current_memo() -> Result<Option<CurrentMemoDetail>, AppError> {
+    let conn = get_conn()?;
     MemoViewRepository::get_current_memo(&conn)
 }
 
 #[command]
-pub fn record_memo_view(args: GetMemoArgs) -> Result<(), String> {
-    let mut conn = get_conn().map_err(|e| e.to_string())?;
+pub fn record_memo_view(args: GetMemoArgs) -> Result<(), AppError> {
+    let mut conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
     MemoViewRepository::record_view(&mut conn, workspace.id, memo.id)
 }
@@ -72,45 +92,77 @@ pub struct CreateMemoArgs {
     pub slug_title: String,
     pub title: String,
     pub content: String,
+    /// When set, the memo content is the expanded template instead of `content`.
+    #[serde(default)]
+    pub template_slug_name: Option<String>,
+    #[serde(default)]
+    pub template_prompt_values: HashMap<String, String>,
 }
 
 #[command]
-pub fn create_memo(args: CreateMemoArgs) -> Result<MemoDetail, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn create_memo(args: CreateMemoArgs) -> Result<MemoDetail, AppError> {
+    let mut conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
         .ok_or_else(|| {
-            format!(
+            AppError::not_found(format!(
                 "Workspace not found for slug: {}",
                 &args.workspace_slug_name
-            )
+            ))
         })?;
 
-    let memo = MemoRepository::create(
-        &conn,
-        workspace.id,
-        &args.slug_title,
-        &args.title,
-        &args.content,
-    )
-    .map_err(|e| e.to_string())?;
-
-    let kanban = KanbanRepository::ensure_global_status_board(&conn, workspace.id)
-        .map_err(|e| e.to_string())?;
+    let template = match &args.template_slug_name {
+        Some(template_slug_name) => Some(
+            MemoTemplateRepository::find_by_slug(&conn, workspace.id, template_slug_name)?
+                .ok_or_else(|| {
+                    AppError::not_found(format!(
+                        "Memo template not found for slug: {}",
+                        template_slug_name
+                    ))
+                })?,
+        ),
+        None => None,
+    };
+
+    let content = match &template {
+        Some(template) => {
+            MemoTemplateRepository::expand_content(
+                &conn,
+                &template.content,
+                &MemoTemplateVariables {
+                    title: &args.title,
+                    workspace_name: &workspace.name,
+                    prompt_values: &args.template_prompt_values,
+                },
+            )?
+            .content
+        }
+        None => args.content.clone(),
+    };
+
+    let tx = conn.transaction()?;
+
+    let memo = MemoRepository::create(&tx, workspace.id, &args.slug_title, &args.title, &content)?;
+
+    let kanban = KanbanRepository::ensure_global_status_board(&tx, workspace.id)?;
 
     if let Some(default_status_id) = kanban.default_status_id {
         KanbanAssignmentRepository::upsert_status(
-            &conn,
+            &tx,
             workspace.id,
             memo.id,
             kanban.id,
             Some(default_status_id),
             None,
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
+    }
+
+    if let Some(template) = &template {
+        MemoTemplateRepository::apply_workflow(&tx, template, memo.id)?;
     }
 
+    tx.commit()?;
+
     Ok(memo)
 }
 
@@ -126,21 +178,24 @@ pub struct SaveMemoArgs {
 }
 
 #[command]
-pub fn save_memo(args: SaveMemoArgs) -> Result<(), String> {
-    let mut conn = get_conn().map_err(|e| e.to_string())?;
+pub fn save_memo(args: SaveMemoArgs) -> Result<(), AppError> {
+    let mut conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
         .ok_or_else(|| {
-            format!(
+            AppError::not_found(format!(
                 "Workspace not found for slug: {}",
                 &args.workspace_slug_name
-            )
+            ))
         })?;
 
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.target_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.target_slug_title))?;
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.target_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Memo not found for slug: {}",
+                args.target_slug_title
+            ))
+        })?;
 
     MemoRepository::save(
         &mut conn,
@@ -155,7 +210,7 @@ pub fn save_memo(args: SaveMemoArgs) -> Result<(), String> {
         &args.new_description,
         &args.new_thumbnail_image,
     )
-    .map_err(|e| e.to_string())
+    .map_err(AppError::from)
 }
 
 #[derive(Deserialize)]
@@ -165,23 +220,23 @@ pub struct DeleteMemoArgs {
 }
 
 #[command]
-pub fn delete_memo(args: DeleteMemoArgs) -> Result<(), String> {
-    let mut conn = get_conn().map_err(|e| e.to_string())?;
+pub fn delete_memo(args: DeleteMemoArgs) -> Result<(), AppError> {
+    let mut conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
         .ok_or_else(|| {
-            format!(
+            AppError::not_found(format!(
                 "Workspace not found for slug: {}",
                 &args.workspace_slug_name
-            )
+            ))
         })?;
 
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
 
-    MemoRepository::delete(&mut conn, memo.id).map_err(|e| e.to_string())?;
+    MemoRepository::delete(&mut conn, memo.id)?;
 
     Ok(())
 }
@@ -195,13 +250,16 @@ pub struct SearchMemosArgs {
 }
 
 #[command]
-pub fn search_memos(args: SearchMemosArgs) -> Result<Vec<MemoSearchItem>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn search_memos(args: SearchMemosArgs) -> Result<Vec<MemoSearchItem>, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     MemoRepository::search(&conn, workspace.id, &args.query, args.limit, args.offset)
-        .map_err(|e| e.to_string())
 }
@@ -1,5 +1,13 @@
+use std::collections::HashMap;
+use std::fs;
+
 use crate::database::get_conn;
-use crate::models::memo_template::{MemoTemplateDetail, MemoTemplateIndexItem};
+use crate::errors::AppError;
+use crate::models::memo_template::{
+    ExpandedMemoTemplate, MemoTemplateCopyResult, MemoTemplateDetail, MemoTemplateIndexItem,
+    MemoTemplatePack, MemoTemplatePackImportSummary, MemoTemplateWorkflow,
+};
+use crate::repositories::memo_template_repository::MemoTemplateVariables;
 use crate::repositories::{MemoTemplateRepository, WorkspaceRepository};
 use serde::Deserialize;
 use tauri::command;
@@ -12,12 +20,16 @@ pub struct GetMemoTemplatesArgs {
 #[command]
 pub fn get_workspace_memo_templates(
     args: GetMemoTemplatesArgs,
-) -> Result<Vec<MemoTemplateIndexItem>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+) -> Result<Vec<MemoTemplateIndexItem>, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     MemoTemplateRepository::list(&conn, workspace.id)
 }
@@ -29,19 +41,23 @@ pub struct GetMemoTemplateArgs {
 }
 
 #[command]
-pub fn get_memo_template(args: GetMemoTemplateArgs) -> Result<MemoTemplateDetail, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn get_memo_template(args: GetMemoTemplateArgs) -> Result<MemoTemplateDetail, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     match MemoTemplateRepository::find_by_slug(&conn, workspace.id, &args.template_slug_name) {
         Ok(Some(template)) => Ok(template),
-        Ok(None) => Err(format!(
+        Ok(None) => Err(AppError::not_found(format!(
             "Memo template not found for slug: {}",
             args.template_slug_name
-        )),
+        ))),
         Err(error) => Err(error),
     }
 }
@@ -55,12 +71,16 @@ pub struct CreateMemoTemplateArgs {
 }
 
 #[command]
-pub fn create_memo_template(args: CreateMemoTemplateArgs) -> Result<MemoTemplateDetail, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn create_memo_template(args: CreateMemoTemplateArgs) -> Result<MemoTemplateDetail, AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     MemoTemplateRepository::create(
         &conn,
@@ -81,20 +101,24 @@ pub struct SaveMemoTemplateArgs {
 }
 
 #[command]
-pub fn save_memo_template(args: SaveMemoTemplateArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn save_memo_template(args: SaveMemoTemplateArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     let template =
         MemoTemplateRepository::find_by_slug(&conn, workspace.id, &args.target_slug_name)?
             .ok_or_else(|| {
-                format!(
+                AppError::not_found(format!(
                     "Memo template not found for slug: {}",
                     args.target_slug_name
-                )
+                ))
             })?;
 
     MemoTemplateRepository::save(
@@ -106,6 +130,37 @@ pub fn save_memo_template(args: SaveMemoTemplateArgs) -> Result<(), String> {
     )
 }
 
+#[derive(Deserialize)]
+pub struct SaveMemoTemplateWorkflowArgs {
+    pub workspace_slug_name: String,
+    pub template_slug_name: String,
+    pub workflow: MemoTemplateWorkflow,
+}
+
+#[command]
+pub fn save_memo_template_workflow(args: SaveMemoTemplateWorkflowArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let template =
+        MemoTemplateRepository::find_by_slug(&conn, workspace.id, &args.template_slug_name)?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "Memo template not found for slug: {}",
+                    args.template_slug_name
+                ))
+            })?;
+
+    MemoTemplateRepository::save_workflow(&conn, workspace.id, template.id, &args.workflow)
+}
+
 #[derive(Deserialize)]
 pub struct DeleteMemoTemplateArgs {
     pub workspace_slug_name: String,
@@ -113,20 +168,24 @@ pub struct DeleteMemoTemplateArgs {
 }
 
 #[command]
-pub fn delete_memo_template(args: DeleteMemoTemplateArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn delete_memo_template(args: DeleteMemoTemplateArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     let template =
         MemoTemplateRepository::find_by_slug(&conn, workspace.id, &args.template_slug_name)?
             .ok_or_else(|| {
-                format!(
+                AppError::not_found(format!(
                     "Memo template not found for slug: {}",
                     args.template_slug_name
-                )
+                ))
             })?;
 
     MemoTemplateRepository::delete(&conn, template.id)
@@ -139,20 +198,24 @@ pub struct SetDefaultMemoTemplateArgs {
 }
 
 #[command]
-pub fn set_default_memo_template(args: SetDefaultMemoTemplateArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn set_default_memo_template(args: SetDefaultMemoTemplateArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     let template =
         MemoTemplateRepository::find_by_slug(&conn, workspace.id, &args.template_slug_name)?
             .ok_or_else(|| {
-                format!(
+                AppError::not_found(format!(
                     "Memo template not found for slug: {}",
                     args.template_slug_name
-                )
+                ))
             })?;
 
     MemoTemplateRepository::set_default(&conn, workspace.id, template.id)
@@ -164,12 +227,211 @@ pub struct ClearDefaultMemoTemplateArgs {
 }
 
 #[command]
-pub fn clear_default_memo_template(args: ClearDefaultMemoTemplateArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn clear_default_memo_template(args: ClearDefaultMemoTemplateArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
 
     MemoTemplateRepository::clear_default(&conn, workspace.id)
 }
+
+#[derive(Deserialize)]
+pub struct GetMemoTemplatePromptsArgs {
+    pub workspace_slug_name: String,
+    pub template_slug_name: String,
+}
+
+#[command]
+pub fn get_memo_template_prompts(
+    args: GetMemoTemplatePromptsArgs,
+) -> Result<Vec<String>, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let template =
+        MemoTemplateRepository::find_by_slug(&conn, workspace.id, &args.template_slug_name)?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "Memo template not found for slug: {}",
+                    args.template_slug_name
+                ))
+            })?;
+
+    Ok(MemoTemplateRepository::list_prompts(&template.content))
+}
+
+#[derive(Deserialize)]
+pub struct RenderMemoTemplateArgs {
+    pub workspace_slug_name: String,
+    pub template_slug_name: String,
+    pub title: String,
+    #[serde(default)]
+    pub prompt_values: HashMap<String, String>,
+}
+
+#[command]
+pub fn render_memo_template(
+    args: RenderMemoTemplateArgs,
+) -> Result<ExpandedMemoTemplate, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let template =
+        MemoTemplateRepository::find_by_slug(&conn, workspace.id, &args.template_slug_name)?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "Memo template not found for slug: {}",
+                    args.template_slug_name
+                ))
+            })?;
+
+    MemoTemplateRepository::expand_content(
+        &conn,
+        &template.content,
+        &MemoTemplateVariables {
+            title: &args.title,
+            workspace_name: &workspace.name,
+            prompt_values: &args.prompt_values,
+        },
+    )
+}
+
+#[derive(Deserialize)]
+pub struct CopyMemoTemplateArgs {
+    pub workspace_slug_name: String,
+    pub template_slug_name: String,
+    pub target_workspace_slug_name: String,
+}
+
+#[command]
+pub fn copy_memo_template(args: CopyMemoTemplateArgs) -> Result<MemoTemplateCopyResult, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let target_workspace =
+        WorkspaceRepository::find_by_slug(&conn, &args.target_workspace_slug_name)?.ok_or_else(
+            || {
+                AppError::not_found(format!(
+                    "Workspace not found for slug: {}",
+                    args.target_workspace_slug_name
+                ))
+            },
+        )?;
+
+    let template =
+        MemoTemplateRepository::find_by_slug(&conn, workspace.id, &args.template_slug_name)?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "Memo template not found for slug: {}",
+                    args.template_slug_name
+                ))
+            })?;
+
+    MemoTemplateRepository::copy_to_workspace(
+        &conn,
+        &template,
+        &workspace.slug_name,
+        target_workspace.id,
+        &target_workspace.slug_name,
+    )
+}
+
+#[derive(Deserialize)]
+pub struct ExportMemoTemplatePackArgs {
+    pub workspace_slug_name: String,
+    /// Templates to include; every template in the workspace when empty.
+    #[serde(default)]
+    pub template_slug_names: Vec<String>,
+    pub path: String,
+}
+
+#[command]
+pub fn export_memo_template_pack(args: ExportMemoTemplatePackArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let pack = MemoTemplateRepository::export_pack(
+        &conn,
+        workspace.id,
+        &workspace.slug_name,
+        &args.template_slug_names,
+    )?;
+    let json = serde_json::to_string_pretty(&pack)?;
+
+    fs::write(&args.path, json)
+        .map_err(|e| AppError::io(format!("Failed to save template pack: {}", e)))
+}
+
+#[derive(Deserialize)]
+pub struct ImportMemoTemplatePackArgs {
+    pub workspace_slug_name: String,
+    pub path: String,
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+#[command]
+pub fn import_memo_template_pack(
+    args: ImportMemoTemplatePackArgs,
+) -> Result<MemoTemplatePackImportSummary, AppError> {
+    let json = fs::read_to_string(&args.path)
+        .map_err(|e| AppError::io(format!("Failed to read template pack: {}", e)))?;
+    let pack: MemoTemplatePack = serde_json::from_str(&json)
+        .map_err(|e| AppError::validation(format!("Invalid template pack: {}", e)))?;
+
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let tx = conn.transaction()?;
+    let summary = MemoTemplateRepository::import_pack(
+        &tx,
+        workspace.id,
+        &workspace.slug_name,
+        &pack,
+        args.overwrite,
+    )?;
+    tx.commit()?;
+
+    Ok(summary)
+}
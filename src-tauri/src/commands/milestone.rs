@@ -1,4 +1,5 @@
 use crate::database::get_conn;
+use crate::errors::AppError;
 use crate::models::milestone::Milestone;
 use crate::repositories::{MemoRepository, MilestoneRepository, WorkspaceRepository};
 use serde::Deserialize;
@@ -46,93 +47,105 @@ pub struct SetMilestoneCompletedArgs {
 }
 
 #[command]
-pub fn list_milestones(args: ListMilestonesArgs) -> Result<Vec<Milestone>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn list_milestones(args: ListMilestonesArgs) -> Result<Vec<Milestone>, AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
-    MilestoneRepository::list_by_year(&conn, workspace.id, args.year).map_err(|e| e.to_string())
+    MilestoneRepository::list_by_year(&conn, workspace.id, args.year).map_err(AppError::from)
 }
 
 #[command]
-pub fn create_milestone(args: CreateMilestoneArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn create_milestone(args: CreateMilestoneArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
     validate_date(&conn, &args.date)?;
     let title = args.title.trim();
     if title.is_empty() {
-        return Err("Milestone title is required.".to_string());
+        return Err(AppError::validation("Milestone title is required."));
     }
     MilestoneRepository::create(&conn, workspace.id, &args.date, title)
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
         .map(|_| ())
 }
 
 #[command]
-pub fn update_milestone(args: UpdateMilestoneArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn update_milestone(args: UpdateMilestoneArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
     validate_date(&conn, &args.date)?;
     let title = args.title.trim();
     if title.is_empty() {
-        return Err("Milestone title is required.".to_string());
+        return Err(AppError::validation("Milestone title is required."));
     }
-    let updated = MilestoneRepository::update(&conn, workspace.id, args.id, &args.date, title)
-        .map_err(|e| e.to_string())?;
+    let updated = MilestoneRepository::update(&conn, workspace.id, args.id, &args.date, title)?;
     if !updated {
-        return Err(format!("Milestone not found: {}", args.id));
+        return Err(AppError::not_found(format!(
+            "Milestone not found: {}",
+            args.id
+        )));
     }
     Ok(())
 }
 
 #[command]
-pub fn add_milestone_memo(args: MilestoneMemoArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn add_milestone_memo(args: MilestoneMemoArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
-    let updated = MilestoneRepository::add_memo(&conn, workspace.id, args.id, memo.id)
-        .map_err(|e| e.to_string())?;
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
+    let updated = MilestoneRepository::add_memo(&conn, workspace.id, args.id, memo.id)?;
     if !updated {
-        return Err(format!("Milestone not found: {}", args.id));
+        return Err(AppError::not_found(format!(
+            "Milestone not found: {}",
+            args.id
+        )));
     }
     Ok(())
 }
 
 #[command]
-pub fn remove_milestone_memo(args: MilestoneMemoArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn remove_milestone_memo(args: MilestoneMemoArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
-    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Memo not found for slug: {}", args.memo_slug_title))?;
-    let updated = MilestoneRepository::remove_memo(&conn, workspace.id, args.id, memo.id)
-        .map_err(|e| e.to_string())?;
+    let memo = MemoRepository::find_by_slug(&conn, workspace.id, &args.memo_slug_title)?
+        .ok_or_else(|| {
+            AppError::not_found(format!("Memo not found for slug: {}", args.memo_slug_title))
+        })?;
+    let updated = MilestoneRepository::remove_memo(&conn, workspace.id, args.id, memo.id)?;
     if !updated {
-        return Err(format!("Milestone not found: {}", args.id));
+        return Err(AppError::not_found(format!(
+            "Milestone not found: {}",
+            args.id
+        )));
     }
     Ok(())
 }
 
 #[command]
-pub fn set_milestone_completed(args: SetMilestoneCompletedArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn set_milestone_completed(args: SetMilestoneCompletedArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
-    let updated = MilestoneRepository::set_completed(&conn, workspace.id, args.id, args.completed)
-        .map_err(|e| e.to_string())?;
+    let updated = MilestoneRepository::set_completed(&conn, workspace.id, args.id, args.completed)?;
     if !updated {
-        return Err(format!("Milestone not found: {}", args.id));
+        return Err(AppError::not_found(format!(
+            "Milestone not found: {}",
+            args.id
+        )));
     }
     Ok(())
 }
 
 #[command]
-pub fn delete_milestone(args: DeleteMilestoneArgs) -> Result<(), String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn delete_milestone(args: DeleteMilestoneArgs) -> Result<(), AppError> {
+    let conn = get_conn()?;
     let workspace = resolve_workspace(&conn, &args.workspace_slug_name)?;
-    let deleted =
-        MilestoneRepository::delete(&conn, workspace.id, args.id).map_err(|e| e.to_string())?;
+    let deleted = MilestoneRepository::delete(&conn, workspace.id, args.id)?;
     if !deleted {
-        return Err(format!("Milestone not found: {}", args.id));
+        return Err(AppError::not_found(format!(
+            "Milestone not found: {}",
+            args.id
+        )));
     }
     Ok(())
 }
@@ -140,20 +153,23 @@ pub fn delete_milestone(args: DeleteMilestoneArgs) -> Result<(), String> {
 fn resolve_workspace(
     conn: &rusqlite::Connection,
     workspace_slug_name: &str,
-) -> Result<crate::models::Workspace, String> {
-    WorkspaceRepository::find_by_slug(conn, workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", workspace_slug_name))
+) -> Result<crate::models::Workspace, AppError> {
+    WorkspaceRepository::find_by_slug(conn, workspace_slug_name)?.ok_or_else(|| {
+        AppError::not_found(format!(
+            "Workspace not found for slug: {}",
+            workspace_slug_name
+        ))
+    })
 }
 
-fn validate_date(conn: &rusqlite::Connection, date: &str) -> Result<(), String> {
-    let is_valid: bool = conn
-        .query_row("SELECT date(?, '+0 days') = ?", (date, date), |row| {
-            row.get(0)
-        })
-        .map_err(|e| e.to_string())?;
+fn validate_date(conn: &rusqlite::Connection, date: &str) -> Result<(), AppError> {
+    let is_valid: bool = conn.query_row("SELECT date(?, '+0 days') = ?", (date, date), |row| {
+        row.get(0)
+    })?;
     if !is_valid {
-        return Err(format!("Invalid milestone date: {date}"));
+        return Err(AppError::validation(format!(
+            "Invalid milestone date: {date}"
+        )));
     }
     Ok(())
 }
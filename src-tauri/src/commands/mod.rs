@@ -2,12 +2,16 @@ pub mod asset;
 pub mod bookmark;
 pub mod calendar_day;
 pub mod config;
+pub mod doctor;
 pub mod file;
 pub mod focus_daily_state;
 pub mod html_export;
 pub mod kanban;
 pub mod kanban_assignment;
+pub mod kanban_automation;
+pub mod kanban_report;
 pub mod kanban_status;
+pub mod kanban_template;
 pub mod link;
 pub mod memo;
 pub mod memo_template;
@@ -1,13 +1,20 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config::SharedConfig;
 use crate::database::get_conn;
+use crate::errors::AppError;
+use crate::models::workspace::WorkspaceDeletionSummary;
+use crate::models::workspace_transfer::WorkspaceExport;
 use crate::models::Workspace;
-use crate::repositories::WorkspaceRepository;
+use crate::repositories::{WorkspaceRepository, WorkspaceTransferRepository};
 use serde::Deserialize;
-use tauri::command;
+use tauri::{command, State};
 
 #[command]
-pub fn get_workspaces() -> Result<Vec<Workspace>, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
-    WorkspaceRepository::list(&conn).map_err(|e| e.to_string())
+pub fn get_workspaces() -> Result<Vec<Workspace>, AppError> {
+    let conn = get_conn()?;
+    WorkspaceRepository::list(&conn)
 }
 
 #[derive(Deserialize)]
@@ -16,15 +23,15 @@ pub struct GetWorkspaceArgs {
 }
 
 #[command]
-pub fn get_workspace(args: GetWorkspaceArgs) -> Result<Workspace, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn get_workspace(args: GetWorkspaceArgs) -> Result<Workspace, AppError> {
+    let conn = get_conn()?;
     match WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name) {
         Ok(Some(workspace)) => Ok(workspace),
-        Ok(None) => Err(format!(
+        Ok(None) => Err(AppError::not_found(format!(
             "Workspace not found for slug: {}",
             args.workspace_slug_name
-        )),
-        Err(e) => Err(e.to_string()),
+        ))),
+        Err(e) => Err(e.into()),
     }
 }
 
@@ -35,10 +42,10 @@ pub struct CreateWorkspaceArgs {
 }
 
 #[command]
-pub fn create_workspace(args: CreateWorkspaceArgs) -> Result<Workspace, String> {
-    let conn = get_conn().map_err(|e| e.to_string())?;
+pub fn create_workspace(args: CreateWorkspaceArgs) -> Result<Workspace, AppError> {
+    let conn = get_conn()?;
     WorkspaceRepository::create(&conn, &args.workspace_slug_name, &args.workspace_name)
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 #[derive(Deserialize)]
@@ -48,12 +55,16 @@ pub struct SaveWorkspaceArgs {
 }
 
 #[command]
-pub fn save_workspace(args: SaveWorkspaceArgs) -> Result<(), String> {
-    let mut conn = get_conn().map_err(|e| e.to_string())?;
+pub fn save_workspace(args: SaveWorkspaceArgs) -> Result<(), AppError> {
+    let mut conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("worspace not found for slug: {}", &args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "worspace not found for slug: {}",
+                &args.workspace_slug_name
+            ))
+        })?;
 
     WorkspaceRepository::save(
         &mut conn,
@@ -61,23 +72,200 @@ pub fn save_workspace(args: SaveWorkspaceArgs) -> Result<(), String> {
         &args.workspace_slug_name,
         &args.workspace_name,
     )
-    .map_err(|e| e.to_string())
+    .map_err(AppError::from)
+}
+
+#[command]
+pub fn get_archived_workspaces() -> Result<Vec<Workspace>, AppError> {
+    let conn = get_conn()?;
+    WorkspaceRepository::list_archived(&conn)
+}
+
+#[derive(Deserialize)]
+pub struct SetWorkspaceArchivedArgs {
+    pub workspace_slug_name: String,
+    pub archived: bool,
+}
+
+#[command]
+pub fn set_workspace_archived(args: SetWorkspaceArchivedArgs) -> Result<Workspace, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    WorkspaceRepository::set_archived(&conn, workspace.id, args.archived)?;
+
+    WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?.ok_or_else(|| {
+        AppError::not_found(format!(
+            "Workspace not found for slug: {}",
+            args.workspace_slug_name
+        ))
+    })
+}
+
+#[derive(Deserialize)]
+pub struct PrepareWorkspaceDeletionArgs {
+    pub workspace_slug_name: String,
+}
+
+#[command]
+pub fn prepare_workspace_deletion(
+    args: PrepareWorkspaceDeletionArgs,
+) -> Result<WorkspaceDeletionSummary, AppError> {
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    WorkspaceRepository::deletion_summary(&conn, &workspace).map_err(AppError::from)
 }
 
 #[derive(Deserialize)]
 pub struct DeleteWorkspaceArgs {
     pub workspace_slug_name: String,
+    /// Token from `prepare_workspace_deletion`.
+    pub confirmation_token: String,
 }
 
 #[command]
-pub fn delete_workspace(args: DeleteWorkspaceArgs) -> Result<(), String> {
-    let mut conn = get_conn().map_err(|e| e.to_string())?;
+pub fn delete_workspace(args: DeleteWorkspaceArgs) -> Result<(), AppError> {
+    let mut conn = get_conn()?;
 
-    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("worspace not found for slug: {}", &args.workspace_slug_name))?;
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "worspace not found for slug: {}",
+                &args.workspace_slug_name
+            ))
+        })?;
 
-    WorkspaceRepository::delete(&mut conn, workspace.id).map_err(|e| e.to_string())?;
+    let summary = WorkspaceRepository::deletion_summary(&conn, &workspace)?;
+    if summary.confirmation_token != args.confirmation_token {
+        return Err(AppError::conflict(
+            "The workspace changed since the deletion summary was prepared. Review it again before deleting.",
+        ));
+    }
+
+    WorkspaceRepository::delete(&mut conn, workspace.id)?;
 
     Ok(())
 }
+
+#[derive(Deserialize)]
+pub struct CloneWorkspaceArgs {
+    pub workspace_slug_name: String,
+    pub new_workspace_slug_name: String,
+    pub new_workspace_name: String,
+}
+
+#[command]
+pub fn clone_workspace(args: CloneWorkspaceArgs) -> Result<Workspace, AppError> {
+    let mut conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let tx = conn.transaction()?;
+    let cloned = WorkspaceTransferRepository::clone_workspace(
+        &tx,
+        &workspace,
+        &args.new_workspace_slug_name,
+        &args.new_workspace_name,
+    )?;
+    tx.commit()?;
+
+    Ok(cloned)
+}
+
+#[derive(Deserialize)]
+pub struct ExportWorkspaceArgs {
+    pub workspace_slug_name: String,
+    pub path: String,
+}
+
+#[command]
+pub fn export_workspace(
+    args: ExportWorkspaceArgs,
+    config: State<SharedConfig>,
+) -> Result<(), AppError> {
+    let config = config.snapshot();
+    let conn = get_conn()?;
+
+    let workspace = WorkspaceRepository::find_by_slug(&conn, &args.workspace_slug_name)?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                args.workspace_slug_name
+            ))
+        })?;
+
+    let mut export = WorkspaceTransferRepository::export_workspace(&conn, &workspace)?;
+    WorkspaceTransferRepository::attach_payloads(
+        &mut export,
+        &PathBuf::from(&config.asset_dir_path),
+        &PathBuf::from(&config.files_storage_root),
+    )?;
+
+    let json = serde_json::to_string(&export)?;
+    fs::write(&args.path, json)
+        .map_err(|e| AppError::io(format!("Failed to save workspace export: {}", e)))
+}
+
+#[derive(Deserialize)]
+pub struct ImportWorkspaceArgs {
+    pub path: String,
+    /// Defaults to the slug stored in the export file.
+    pub workspace_slug_name: Option<String>,
+    pub workspace_name: Option<String>,
+}
+
+#[command]
+pub fn import_workspace(
+    args: ImportWorkspaceArgs,
+    config: State<SharedConfig>,
+) -> Result<Workspace, AppError> {
+    let config = config.snapshot();
+    let json = fs::read_to_string(&args.path)
+        .map_err(|e| AppError::io(format!("Failed to read workspace export: {}", e)))?;
+    let export: WorkspaceExport = serde_json::from_str(&json)
+        .map_err(|e| AppError::validation(format!("Invalid workspace export: {}", e)))?;
+
+    let slug_name = args
+        .workspace_slug_name
+        .unwrap_or_else(|| export.workspace.slug_name.clone());
+    let name = args
+        .workspace_name
+        .unwrap_or_else(|| export.workspace.name.clone());
+
+    let mut conn = get_conn()?;
+    let tx = conn.transaction()?;
+    let workspace = WorkspaceTransferRepository::import_workspace(&tx, &export, &slug_name, &name)?;
+
+    let restored = WorkspaceTransferRepository::restore_payloads(
+        &export,
+        &PathBuf::from(&config.asset_dir_path),
+        &PathBuf::from(&config.files_storage_root),
+    )?;
+    if let Err(error) = tx.commit() {
+        WorkspaceTransferRepository::remove_restored_payloads(&restored);
+        return Err(error.into());
+    }
+
+    Ok(workspace)
+}
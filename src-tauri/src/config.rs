@@ -2,6 +2,9 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use crate::errors::AppError;
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct AppConfig {
@@ -23,6 +26,9 @@ pub struct AppConfig {
     pub new_memo_shortcut: String,
     #[serde(default = "default_selection_copy_format")]
     pub selection_copy_format: String,
+    /// `move`, `copy` or `copy_then_trash`; see `ImportPolicy`.
+    #[serde(default = "default_import_policy")]
+    pub import_policy: String,
     #[serde(default = "default_mcp_port")]
     pub mcp_port: u16,
     #[serde(default = "default_mcp_host")]
@@ -31,6 +37,65 @@ pub struct AppConfig {
     pub mcp_url_host: String,
     #[serde(default = "default_mcp_token")]
     pub mcp_token: String,
+    #[serde(default)]
+    pub watched_folders: Vec<WatchedFolder>,
+    #[serde(default = "default_watch_interval_seconds")]
+    pub watch_interval_seconds: u64,
+}
+
+/// A folder the inbox watcher imports from. Entries are only imported when a rule matches.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WatchedFolder {
+    /// Absolute path; a leading `~/` is resolved against the home directory.
+    pub path: String,
+    #[serde(default = "default_watched_folder_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub rules: Vec<InboxRule>,
+}
+
+/// The first rule whose `pattern` matches an entry name decides what happens to it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InboxRule {
+    /// File name pattern with `*` and `?`, matched case-insensitively, e.g. `invoice-*.pdf`.
+    pub pattern: String,
+    /// Note set on the imported file record.
+    #[serde(default)]
+    pub note: Option<String>,
+    /// Workspace of the memo in `link_to`.
+    #[serde(default)]
+    pub workspace_slug_name: Option<String>,
+    /// Memo slug to link the file from, or `today` for the first memo on today's calendar day.
+    #[serde(default)]
+    pub link_to: Option<String>,
+}
+
+/// The live config shared by Tauri commands, the MCP server and the background threads.
+/// Commands that change settings store the saved config here, so readers see it without a
+/// restart.
+#[derive(Clone)]
+pub struct SharedConfig(Arc<RwLock<AppConfig>>);
+
+impl SharedConfig {
+    pub fn new(config: AppConfig) -> Self {
+        SharedConfig(Arc::new(RwLock::new(config)))
+    }
+
+    /// Returns a copy of the current config.
+    pub fn snapshot(&self) -> AppConfig {
+        self.0
+            .read()
+            // The lock only guards a plain value, which stays usable after a panic.
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    pub fn replace(&self, config: AppConfig) {
+        *self
+            .0
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = config;
+    }
 }
 
 fn default_setup_complete() -> bool {
@@ -61,10 +126,22 @@ pub fn default_inbox_ignore_file_names() -> Vec<String> {
     vec!["desktop.ini".to_string()]
 }
 
+fn default_watched_folder_enabled() -> bool {
+    true
+}
+
+pub fn default_watch_interval_seconds() -> u64 {
+    30
+}
+
 pub fn default_selection_copy_format() -> String {
     "html".to_string()
 }
 
+pub fn default_import_policy() -> String {
+    "move".to_string()
+}
+
 fn default_mcp_token() -> String {
     String::new()
 }
@@ -82,26 +159,29 @@ impl Default for AppConfig {
             focus_app_shortcut: default_focus_app_shortcut(),
             new_memo_shortcut: default_new_memo_shortcut(),
             selection_copy_format: default_selection_copy_format(),
+            import_policy: default_import_policy(),
             mcp_port: default_mcp_port(),
             mcp_bind_host: default_mcp_host(),
             mcp_url_host: default_mcp_host(),
             mcp_token: default_mcp_token(),
+            watched_folders: Vec::new(),
+            watch_interval_seconds: default_watch_interval_seconds(),
         }
     }
 }
 
 // Load the configuration and replace `${app_data_dir}` with the actual data directory path.
-pub fn load_config(config_dir: &Path, data_dir: &Path) -> Result<AppConfig, String> {
+pub fn load_config(config_dir: &Path, data_dir: &Path) -> Result<AppConfig, AppError> {
     let config_path = config_dir.join("config.json");
 
     ensure_config_directory_exists(&config_path)?;
 
     if config_path.exists() {
         let content = fs::read_to_string(&config_path)
-            .map_err(|e| format!("Failed to read config file: {}", e))?;
+            .map_err(|e| AppError::config(format!("Failed to read config file: {}", e)))?;
 
-        let mut config: AppConfig =
-            serde_json::from_str(&content).map_err(|e| format!("Failed to parse config: {}", e))?;
+        let mut config: AppConfig = serde_json::from_str(&content)
+            .map_err(|e| AppError::config(format!("Failed to parse config: {}", e)))?;
 
         // Replace placeholders in the configuration
         config.database_path = replace_placeholders(&config.database_path, data_dir);
@@ -136,43 +216,44 @@ fn replace_placeholders(path: &str, data_dir: &Path) -> String {
     }
 }
 
-pub fn ensure_config_directory_exists(config_path: &Path) -> Result<(), String> {
+pub fn ensure_config_directory_exists(config_path: &Path) -> Result<(), AppError> {
     if let Some(parent_dir) = config_path.parent() {
         if !parent_dir.exists() {
-            fs::create_dir_all(parent_dir)
-                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+            fs::create_dir_all(parent_dir).map_err(|e| {
+                AppError::config(format!("Failed to create config directory: {}", e))
+            })?;
         }
     }
     Ok(())
 }
 
-pub fn save_config(config: &AppConfig, config_path: &PathBuf) -> Result<(), String> {
+pub fn save_config(config: &AppConfig, config_path: &PathBuf) -> Result<(), AppError> {
     let json = serde_json::to_string_pretty(config)
-        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+        .map_err(|e| AppError::config(format!("Failed to serialize config: {}", e)))?;
 
     let parent_dir = config_path
         .parent()
-        .ok_or_else(|| "Failed to resolve config directory".to_string())?;
+        .ok_or_else(|| AppError::config("Failed to resolve config directory"))?;
     let file_name = config_path
         .file_name()
-        .ok_or_else(|| "Failed to resolve config filename".to_string())?;
+        .ok_or_else(|| AppError::config("Failed to resolve config filename"))?;
     let tmp_path = parent_dir.join(format!(".{}.tmp", file_name.to_string_lossy()));
 
     let mut file = fs::File::create(&tmp_path)
-        .map_err(|e| format!("Failed to create temp config file: {}", e))?;
+        .map_err(|e| AppError::config(format!("Failed to create temp config file: {}", e)))?;
     file.write_all(json.as_bytes())
-        .map_err(|e| format!("Failed to write config file: {}", e))?;
+        .map_err(|e| AppError::config(format!("Failed to write config file: {}", e)))?;
     file.sync_all()
-        .map_err(|e| format!("Failed to flush config file: {}", e))?;
+        .map_err(|e| AppError::config(format!("Failed to flush config file: {}", e)))?;
 
     #[cfg(windows)]
     if config_path.exists() {
         fs::remove_file(config_path)
-            .map_err(|e| format!("Failed to remove old config file: {}", e))?;
+            .map_err(|e| AppError::config(format!("Failed to remove old config file: {}", e)))?;
     }
 
     fs::rename(&tmp_path, config_path)
-        .map_err(|e| format!("Failed to replace config file: {}", e))?;
+        .map_err(|e| AppError::config(format!("Failed to replace config file: {}", e)))?;
 
     Ok(())
 }
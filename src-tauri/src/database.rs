@@ -1,44 +1,119 @@
-use crate::config::load_config;
+use std::fs;
+use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::Duration;
+
 use crate::errors::AppError;
 use crate::migrations;
 use crate::repositories::MemoRepository;
 use rusqlite::Connection;
 
-pub fn get_conn() -> Result<Connection, AppError> {
-    let proj_dirs = directories::ProjectDirs::from("com", "m2tkl", "monobox")
-        .expect("Failed to determine project directories");
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The connection shared by Tauri commands and the MCP server. It is opened lazily and
+/// reopened when `configure` is given a different database path.
+#[derive(Default)]
+struct SharedConnection {
+    database_path: Option<String>,
+    conn: Option<Connection>,
+}
+
+static SHARED_CONNECTION: OnceLock<Mutex<SharedConnection>> = OnceLock::new();
+
+pub struct DbConn {
+    guard: MutexGuard<'static, SharedConnection>,
+}
+
+impl Deref for DbConn {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.guard
+            .conn
+            .as_ref()
+            .expect("shared connection is opened before it is handed out")
+    }
+}
+
+impl DerefMut for DbConn {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.guard
+            .conn
+            .as_mut()
+            .expect("shared connection is opened before it is handed out")
+    }
+}
+
+fn lock_shared_connection() -> MutexGuard<'static, SharedConnection> {
+    SHARED_CONNECTION
+        .get_or_init(|| Mutex::new(SharedConnection::default()))
+        .lock()
+        // A panic while holding the lock leaves the connection itself usable.
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
 
-    let app_config = load_config(proj_dirs.config_dir(), proj_dirs.data_dir())
-        .expect("Failed to load or create config");
+/// Points the shared connection at `database_path`. The previous connection is closed
+/// when the path changes, so the next `get_conn` opens the new database.
+pub fn configure(database_path: &str) {
+    let mut shared = lock_shared_connection();
+    if shared.database_path.as_deref() != Some(database_path) {
+        shared.database_path = Some(database_path.to_string());
+        shared.conn = None;
+    }
+}
 
-    let conn = Connection::open(&app_config.database_path).map_err(AppError::DatabaseError)?;
+/// Locks and returns the shared connection. Callers hold the lock until the returned
+/// value is dropped, so never call this while another `DbConn` is alive on the same thread.
+pub fn get_conn() -> Result<DbConn, AppError> {
+    let mut shared = lock_shared_connection();
 
-    conn.execute("PRAGMA foreign_keys = ON;", [])
-        .map_err(AppError::DatabaseError)?;
+    if shared.conn.is_none() {
+        // Every process calls `configure` with the startup config before touching the database.
+        let database_path = shared
+            .database_path
+            .clone()
+            .ok_or_else(|| AppError::config("The database path has not been configured."))?;
+        shared.conn = Some(open_connection(&database_path)?);
+    }
+
+    Ok(DbConn { guard: shared })
+}
 
+fn open_connection(database_path: &str) -> Result<Connection, AppError> {
+    let conn = Connection::open(database_path)?;
+    conn.busy_timeout(BUSY_TIMEOUT)?;
+    conn.execute_batch(
+        "PRAGMA journal_mode = WAL;
+        PRAGMA foreign_keys = ON;",
+    )?;
+    migrations::ensure_known_schema(&conn)?;
     Ok(conn)
 }
 
-pub fn initialize_database() -> Result<(), String> {
-    let mut conn = get_conn().map_err(|e| e.to_string())?;
+pub fn initialize_database() -> Result<(), AppError> {
+    let mut conn = get_conn()?;
 
     conn.execute(
         "CREATE TABLE IF NOT EXISTS schema_migrations (
         version TEXT PRIMARY KEY,
-        applied_at TEXT DEFAULT CURRENT_TIMESTAMP
+        applied_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        checksum TEXT
     )",
         [],
-    )
-    .map_err(|e| e.to_string())?;
+    )?;
 
+    let pending = migrations::pending_migrations(&conn)?;
+    // A brand-new database has nothing worth keeping a copy of.
+    if let Some(first_pending) = pending.first() {
+        if pending.len() < migrations::MIGRATIONS.len() {
+            backup_before_migration(&conn, first_pending)?;
+        }
+    }
     migrations::apply_migrations(&conn)?;
 
-    let memo_count: i64 = conn
-        .query_row("SELECT COUNT(*) FROM memo", [], |row| row.get(0))
-        .map_err(|e| e.to_string())?;
-    let fts_count: i64 = conn
-        .query_row("SELECT COUNT(*) FROM memo_fts", [], |row| row.get(0))
-        .map_err(|e| e.to_string())?;
+    let memo_count: i64 = conn.query_row("SELECT COUNT(*) FROM memo", [], |row| row.get(0))?;
+    let fts_count: i64 = conn.query_row("SELECT COUNT(*) FROM memo_fts", [], |row| row.get(0))?;
 
     if memo_count > 0 && fts_count == 0 {
         MemoRepository::rebuild_search_index(&mut conn)?;
@@ -46,3 +121,29 @@ pub fn initialize_database() -> Result<(), String> {
 
     Ok(())
 }
+
+/// Writes a consistent copy of the database next to it as
+/// `<database>.before-<first pending migration>.bak`, replacing an older copy for the same
+/// migration.
+fn backup_before_migration(conn: &Connection, first_pending: &str) -> Result<(), AppError> {
+    let Some(database_path) = conn.path() else {
+        return Ok(());
+    };
+    let mut backup_path = database_path.as_os_str().to_owned();
+    backup_path.push(format!(".before-{}.bak", first_pending));
+    let backup_path = PathBuf::from(backup_path);
+
+    if backup_path.exists() {
+        fs::remove_file(&backup_path)?;
+    }
+    println!("Backing up database to: {}", backup_path.display());
+    conn.execute("VACUUM INTO ?", [backup_path.to_string_lossy().as_ref()])
+        .map_err(|e| {
+            AppError::storage(format!(
+                "Failed to back up the database before migrating: {}",
+                e
+            ))
+            .with_reason("MIGRATION_BACKUP_FAILED")
+        })?;
+    Ok(())
+}
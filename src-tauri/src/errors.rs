@@ -1,24 +1,164 @@
 use rusqlite::Error as SqliteError;
+use serde::Serialize;
 
-#[derive(Debug)]
-pub enum AppError {
-    // ConfigLoadError(String),
-    DatabaseError(SqliteError),
+/// Stable error categories shared by Tauri commands and the MCP server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    NotFound,
+    Conflict,
+    Validation,
+    Storage,
+    Io,
+    Config,
 }
 
-impl std::fmt::Display for AppError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
         match self {
-            // AppError::ConfigLoadError(msg) => write!(f, "Config Load Error: {}", msg),
-            AppError::DatabaseError(err) => write!(f, "Database Error: {}", err),
+            ErrorCode::NotFound => "NOT_FOUND",
+            ErrorCode::Conflict => "CONFLICT",
+            ErrorCode::Validation => "VALIDATION",
+            ErrorCode::Storage => "STORAGE",
+            ErrorCode::Io => "IO",
+            ErrorCode::Config => "CONFIG",
         }
     }
 }
 
+/// Error returned by repositories, commands and MCP tools. Tauri commands serialise it as
+/// `{"code": "NOT_FOUND", "message": "...", "reason": "..."}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AppError {
+    pub code: ErrorCode,
+    pub message: String,
+    /// Finer grained cause the UI can match on, such as `DUPLICATE_SHORTCUT`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl AppError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        AppError {
+            code,
+            message: message.into(),
+            reason: None,
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::NotFound, message)
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Conflict, message)
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Validation, message)
+    }
+
+    pub fn storage(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Storage, message)
+    }
+
+    pub fn io(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Io, message)
+    }
+
+    pub fn config(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Config, message)
+    }
+
+    pub fn with_reason(mut self, reason: &str) -> Self {
+        self.reason = Some(reason.to_string());
+        self
+    }
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
 impl std::error::Error for AppError {}
 
 impl From<SqliteError> for AppError {
     fn from(err: SqliteError) -> Self {
-        AppError::DatabaseError(err)
+        match &err {
+            SqliteError::QueryReturnedNoRows => AppError::not_found(err.to_string()),
+            SqliteError::SqliteFailure(failure, _)
+                if failure.code == rusqlite::ErrorCode::ConstraintViolation =>
+            {
+                AppError::conflict(err.to_string())
+            }
+            _ => AppError::storage(err.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        AppError::io(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(err: serde_json::Error) -> Self {
+        AppError::validation(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AppError, ErrorCode};
+    use rusqlite::Connection;
+    use serde_json::json;
+
+    #[test]
+    fn sqlite_errors_map_to_stable_codes() {
+        let conn = Connection::open_in_memory().expect("in-memory database should open");
+        conn.execute_batch("CREATE TABLE item (name TEXT NOT NULL UNIQUE);")
+            .expect("table should be created");
+        conn.execute("INSERT INTO item (name) VALUES ('a')", [])
+            .expect("first insert should succeed");
+
+        let duplicate = conn
+            .execute("INSERT INTO item (name) VALUES ('a')", [])
+            .expect_err("duplicate insert should fail");
+        assert_eq!(AppError::from(duplicate).code, ErrorCode::Conflict);
+
+        let missing = conn
+            .query_row("SELECT name FROM item WHERE name = 'b'", [], |row| {
+                row.get::<_, String>(0)
+            })
+            .expect_err("missing row should fail");
+        assert_eq!(AppError::from(missing).code, ErrorCode::NotFound);
+
+        let broken = conn
+            .execute("INSERT INTO missing_table (name) VALUES ('a')", [])
+            .expect_err("unknown table should fail");
+        assert_eq!(AppError::from(broken).code, ErrorCode::Storage);
+    }
+
+    #[test]
+    fn serialises_code_message_and_reason() {
+        let error =
+            AppError::validation("Shortcuts must be different").with_reason("DUPLICATE_SHORTCUT");
+
+        assert_eq!(
+            serde_json::to_value(&error).expect("error should serialise"),
+            json!({
+                "code": "VALIDATION",
+                "message": "Shortcuts must be different",
+                "reason": "DUPLICATE_SHORTCUT"
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(AppError::not_found("Memo not found"))
+                .expect("error should serialise"),
+            json!({"code": "NOT_FOUND", "message": "Memo not found"})
+        );
     }
 }
@@ -0,0 +1,280 @@
+use std::fs;
+use std::ops::DerefMut;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use rusqlite::Connection;
+
+use crate::config::SharedConfig;
+use crate::database::get_conn;
+use crate::errors::AppError;
+use crate::repositories::FileRepository;
+
+/// Files handled per pass, so a large backlog does not hold the database for long.
+const EXTRACTION_BATCH_SIZE: i64 = 20;
+/// Pause between passes once there is nothing left to extract.
+const IDLE_INTERVAL_SECONDS: u64 = 30;
+/// Larger files are indexed without their contents.
+const MAX_SOURCE_BYTES: u64 = 50 * 1024 * 1024;
+/// Upper bound on the text stored for one file.
+const MAX_BODY_TEXT_BYTES: usize = 1024 * 1024;
+
+/// Extracts text from plain-text, Markdown, HTML and PDF files in the background and stores
+/// it in `file_fts`. The config is read on every pass, so indexing starts once setup is
+/// complete and follows changes to the storage root.
+pub fn spawn_file_text_indexer(config: SharedConfig) {
+    thread::spawn(move || loop {
+        let config = config.snapshot();
+        let interval = if !config.setup_complete || config.files_storage_root.trim().is_empty() {
+            IDLE_INTERVAL_SECONDS
+        } else {
+            let storage_root = PathBuf::from(&config.files_storage_root);
+            match index_pending_files(get_conn, &storage_root, EXTRACTION_BATCH_SIZE) {
+                Ok(indexed) if indexed > 0 => 1,
+                Ok(_) => IDLE_INTERVAL_SECONDS,
+                Err(error) => {
+                    eprintln!("File text indexing failed: {}", error);
+                    IDLE_INTERVAL_SECONDS
+                }
+            }
+        };
+        thread::sleep(Duration::from_secs(interval));
+    });
+}
+
+/// Extracts text for up to `limit` files that have not been indexed yet and returns how many
+/// were handled. `lock` is called once to read the pending files and once to store their text;
+/// the connection is not held while the files are read.
+pub fn index_pending_files<C>(
+    lock: impl Fn() -> Result<C, AppError>,
+    storage_root: &Path,
+    limit: i64,
+) -> Result<usize, AppError>
+where
+    C: DerefMut<Target = Connection>,
+{
+    let pending = FileRepository::list_pending_text_extractions(&*lock()?, limit)?;
+    if pending.is_empty() {
+        return Ok(0);
+    }
+
+    let extracted = pending
+        .into_iter()
+        .map(|(file_id, relative_path)| {
+            let body_text = extract_text(&storage_root.join(relative_path)).unwrap_or_default();
+            (file_id, body_text)
+        })
+        .collect::<Vec<_>>();
+
+    let mut conn = lock()?;
+    for (file_id, body_text) in &extracted {
+        FileRepository::store_extracted_text(&mut conn, file_id, body_text)?;
+    }
+    Ok(extracted.len())
+}
+
+/// Returns the text of a supported file, or `None` when the type is not supported or the
+/// file cannot be read.
+pub fn extract_text(path: &Path) -> Option<String> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    if fs::metadata(path).ok()?.len() > MAX_SOURCE_BYTES {
+        return None;
+    }
+
+    let text = match extension.as_str() {
+        "txt" | "text" | "log" | "csv" | "tsv" | "md" | "markdown" => {
+            String::from_utf8_lossy(&fs::read(path).ok()?).into_owned()
+        }
+        "html" | "htm" => html_to_text(&String::from_utf8_lossy(&fs::read(path).ok()?)),
+        "pdf" => {
+            let bytes = fs::read(path).ok()?;
+            // pdf-extract panics on some malformed documents.
+            panic::catch_unwind(|| pdf_extract::extract_text_from_mem(&bytes))
+                .ok()?
+                .ok()?
+        }
+        _ => return None,
+    };
+    Some(truncate_to_char_boundary(
+        collapse_whitespace(&text),
+        MAX_BODY_TEXT_BYTES,
+    ))
+}
+
+/// Drops tags, `<script>` and `<style>` contents and decodes the common entities.
+fn html_to_text(html: &str) -> String {
+    let lower = html.to_ascii_lowercase();
+    let mut text = String::with_capacity(html.len());
+    let mut index = 0;
+
+    while let Some(offset) = html[index..].find('<') {
+        text.push_str(&decode_entities(&html[index..index + offset]));
+        let tag_start = index + offset;
+        let Some(tag_len) = html[tag_start..].find('>') else {
+            index = html.len();
+            break;
+        };
+        let tag_end = tag_start + tag_len + 1;
+        index = tag_end;
+        text.push(' ');
+
+        for skipped in ["script", "style"] {
+            if lower[tag_start + 1..].starts_with(skipped) {
+                let closing = format!("</{}", skipped);
+                index = lower[tag_end..]
+                    .find(&closing)
+                    .map(|position| tag_end + position)
+                    .unwrap_or(html.len());
+            }
+        }
+    }
+    text.push_str(&decode_entities(&html[index..]));
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+        let replacement = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" | "#39" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        });
+        match (entity, replacement) {
+            (Some(entity), Some(ch)) => {
+                decoded.push(ch);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn truncate_to_char_boundary(mut text: String, max_bytes: usize) -> String {
+    if text.len() > max_bytes {
+        let mut end = max_bytes;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{html_to_text, index_pending_files};
+    use crate::migrations::apply_migrations;
+    use crate::models::file::ImportPolicy;
+    use crate::repositories::FileRepository;
+    use rusqlite::Connection;
+    use std::fs;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    #[test]
+    fn html_to_text_drops_markup_scripts_and_decodes_entities() {
+        let text = html_to_text(
+            "<html><head><style>p { color: red; }</style><script>alert('x')</script></head>\
+             <body><p>Fish &amp; chips</p><p>caf&#233; &lt;3</p></body></html>",
+        );
+        assert_eq!(
+            text.split_whitespace().collect::<Vec<_>>().join(" "),
+            "Fish & chips café <3"
+        );
+    }
+
+    #[test]
+    fn indexed_file_text_is_searchable_through_list_files() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        let shared = Mutex::new(conn);
+        let lock = || {
+            Ok(shared
+                .lock()
+                .expect("test connection lock should not be poisoned"))
+        };
+
+        let temp_root =
+            std::env::temp_dir().join(format!("monobox-file-text-test-{}", Uuid::new_v4()));
+        let storage_root = temp_root.join("Storage");
+        fs::create_dir_all(&storage_root).expect("storage folder should be created");
+        let source = temp_root.join("minutes.md");
+        fs::write(&source, "# Minutes\n\nWe agreed on the quarterly budget.")
+            .expect("source file should be written");
+        let record =
+            FileRepository::import_local_file(lock, &source, &storage_root, ImportPolicy::Move)
+                .expect("file should be imported");
+
+        let before = FileRepository::list_files(
+            &shared
+                .lock()
+                .expect("test connection lock should not be poisoned"),
+            20,
+            0,
+            false,
+            Some("quarterly"),
+        )
+        .expect("search should run");
+        assert_eq!(before.total_count, 0);
+
+        assert_eq!(
+            index_pending_files(lock, &storage_root, 20).expect("indexing should run"),
+            1
+        );
+        assert_eq!(
+            index_pending_files(lock, &storage_root, 20).expect("indexing should run"),
+            0
+        );
+        let conn = shared
+            .into_inner()
+            .expect("test connection lock should not be poisoned");
+
+        let page = FileRepository::list_files(&conn, 20, 0, false, Some("quarterly"))
+            .expect("search should run");
+        assert_eq!(page.total_count, 1);
+        assert_eq!(page.items[0].id, record.id);
+        assert!(page.items[0]
+            .snippet
+            .as_deref()
+            .is_some_and(|snippet| snippet.contains("quarterly budget")));
+
+        let by_name = FileRepository::list_files(&conn, 20, 0, false, Some("minutes.md"))
+            .expect("search should run");
+        assert_eq!(by_name.total_count, 1);
+
+        fs::remove_dir_all(temp_root).expect("temp folders should be removed");
+    }
+}
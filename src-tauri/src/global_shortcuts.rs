@@ -4,6 +4,7 @@ use tauri::{App, AppHandle, Emitter, Manager};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 
 use crate::config::AppConfig;
+use crate::errors::AppError;
 
 pub const FOCUS_APP_EVENT: &str = "monobox:shortcut:global:focus-app";
 pub const NEW_MEMO_EVENT: &str = "monobox:shortcut:global:new-memo";
@@ -91,12 +92,14 @@ pub fn update_global_shortcuts(
     app: &AppHandle,
     previous: &GlobalShortcutSettings,
     next: GlobalShortcutSettings,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let focus_app_shortcut = parse_shortcut(&next.focus_app_shortcut)?;
     let new_memo_shortcut = parse_shortcut(&next.new_memo_shortcut)?;
 
     if focus_app_shortcut == new_memo_shortcut {
-        return Err("DUPLICATE_SHORTCUT:Shortcuts must be different".to_string());
+        return Err(
+            AppError::validation("Shortcuts must be different").with_reason("DUPLICATE_SHORTCUT")
+        );
     }
 
     let _ = app
@@ -111,7 +114,9 @@ pub fn update_global_shortcuts(
         .register(next.focus_app_shortcut.as_str())
     {
         reregister_previous_shortcuts(app, previous);
-        return Err(format!("FOCUS_SHORTCUT_REGISTER_FAILED:{}", error));
+        return Err(
+            AppError::conflict(error.to_string()).with_reason("FOCUS_SHORTCUT_REGISTER_FAILED")
+        );
     }
 
     if let Err(error) = app
@@ -122,7 +127,9 @@ pub fn update_global_shortcuts(
             .global_shortcut()
             .unregister(next.focus_app_shortcut.as_str());
         reregister_previous_shortcuts(app, previous);
-        return Err(format!("NEW_MEMO_SHORTCUT_REGISTER_FAILED:{}", error));
+        return Err(
+            AppError::conflict(error.to_string()).with_reason("NEW_MEMO_SHORTCUT_REGISTER_FAILED")
+        );
     }
 
     app.state::<GlobalShortcutState>().set_settings(next);
@@ -130,18 +137,18 @@ pub fn update_global_shortcuts(
     Ok(())
 }
 
-pub fn normalize_shortcut(shortcut: &str) -> Result<String, String> {
+pub fn normalize_shortcut(shortcut: &str) -> Result<String, AppError> {
     let normalized = shortcut.trim().replace(' ', "");
     if normalized.is_empty() {
-        return Err("EMPTY_SHORTCUT:Shortcut is required".to_string());
+        return Err(AppError::validation("Shortcut is required").with_reason("EMPTY_SHORTCUT"));
     }
     Ok(normalized)
 }
 
-fn parse_shortcut(shortcut: &str) -> Result<Shortcut, String> {
+fn parse_shortcut(shortcut: &str) -> Result<Shortcut, AppError> {
     shortcut
         .parse()
-        .map_err(|error| format!("INVALID_SHORTCUT:{}", error))
+        .map_err(|error| AppError::validation(error.to_string()).with_reason("INVALID_SHORTCUT"))
 }
 
 fn shortcut_matches(shortcut: &Shortcut, configured: &str) -> bool {
@@ -0,0 +1,512 @@
+use std::collections::HashMap;
+use std::fs;
+use std::ops::DerefMut;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, UNIX_EPOCH};
+
+use directories::BaseDirs;
+use rusqlite::Connection;
+
+use crate::config::{AppConfig, InboxRule, SharedConfig};
+use crate::database::get_conn;
+use crate::errors::AppError;
+use crate::models::file::{ImportPolicy, ManagedFileRecord};
+use crate::repositories::file_repository::{should_ignore_inbox_file, ALREADY_IMPORTED_NOTICE};
+use crate::repositories::inbox_activity_repository::NewInboxActivity;
+use crate::repositories::{
+    FileRepository, InboxActivityRepository, MemoRepository, WorkspaceRepository,
+};
+
+/// Shortest pause between passes, whatever `watch_interval_seconds` says.
+const MIN_WATCH_INTERVAL_SECONDS: u64 = 5;
+
+/// Extensions browsers and sync tools use while a download is still being written.
+const PARTIAL_DOWNLOAD_EXTENSIONS: &[&str] = &["crdownload", "part", "partial", "download", "tmp"];
+
+/// Size and modification time (seconds) of an entry.
+type EntryVersion = (i64, i64);
+
+/// Polls the watched folders from `AppConfig`. An entry is imported once it looks the same
+/// on two passes in a row, so files that are still being written are left alone.
+#[derive(Default)]
+pub struct InboxWatcher {
+    last_seen: HashMap<PathBuf, EntryVersion>,
+}
+
+impl InboxWatcher {
+    /// Runs one pass over every enabled folder and returns the number of entries handled.
+    /// `lock` is called for each piece of database work, so the connection is free while
+    /// entries are copied or moved into storage.
+    pub fn run_pass<C>(
+        &mut self,
+        lock: impl Fn() -> Result<C, AppError>,
+        config: &AppConfig,
+    ) -> Result<usize, AppError>
+    where
+        C: DerefMut<Target = Connection>,
+    {
+        let storage_root = PathBuf::from(&config.files_storage_root);
+        let policy = ImportPolicy::from_setting(&config.import_policy);
+        let mut seen = HashMap::new();
+        let mut handled = 0;
+
+        for folder in config
+            .watched_folders
+            .iter()
+            .filter(|folder| folder.enabled && !folder.rules.is_empty())
+        {
+            let Ok(entries) = fs::read_dir(expand_home(&folder.path)) else {
+                continue;
+            };
+            let mut paths: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+            paths.sort();
+
+            for path in paths {
+                let Some(name) = path.file_name().and_then(|value| value.to_str()) else {
+                    continue;
+                };
+                if should_ignore_inbox_file(&path, &config.inbox_ignore_file_names)
+                    || is_partial_download(&path)
+                {
+                    continue;
+                }
+                let Some(rule) = folder
+                    .rules
+                    .iter()
+                    .find(|rule| matches_pattern(&rule.pattern, name))
+                else {
+                    continue;
+                };
+                let Ok(version) = entry_version(&path) else {
+                    continue;
+                };
+                seen.insert(path.clone(), version);
+                if self.last_seen.get(&path) != Some(&version) {
+                    continue;
+                }
+
+                let source_path = path.to_string_lossy().to_string();
+                if InboxActivityRepository::was_processed(
+                    &*lock()?,
+                    &source_path,
+                    version.0,
+                    version.1,
+                )? {
+                    continue;
+                }
+
+                let outcome = apply_rule(&lock, &path, &storage_root, policy, rule);
+                let (status, record, memo_id, message) = match &outcome {
+                    Ok((record, memo_id, messages)) => (
+                        if record
+                            .import_notice
+                            .as_deref()
+                            .is_some_and(|notice| notice.starts_with(ALREADY_IMPORTED_NOTICE))
+                        {
+                            "duplicate"
+                        } else {
+                            "imported"
+                        },
+                        Some(record),
+                        *memo_id,
+                        (!messages.is_empty()).then(|| messages.join(" ")),
+                    ),
+                    Err(err) => ("failed", None, None, Some(err.message.clone())),
+                };
+                if outcome.is_err()
+                    && InboxActivityRepository::has_failed(
+                        &*lock()?,
+                        &source_path,
+                        version.0,
+                        version.1,
+                    )?
+                {
+                    continue;
+                }
+                InboxActivityRepository::record(
+                    &*lock()?,
+                    &NewInboxActivity {
+                        watched_folder: &folder.path,
+                        source_path: &source_path,
+                        source_size: version.0,
+                        source_modified_at: version.1,
+                        rule_pattern: Some(&rule.pattern),
+                        status,
+                        file_id: record.map(|record| record.id.as_str()),
+                        display_name: record
+                            .map(|record| record.display_name.as_str())
+                            .or(Some(name)),
+                        memo_id,
+                        message: message.as_deref(),
+                    },
+                )?;
+                handled += 1;
+            }
+        }
+
+        self.last_seen = seen;
+        Ok(handled)
+    }
+}
+
+/// Starts the background thread. The shared config is read on every pass, so folder
+/// changes apply without a restart.
+pub fn spawn_inbox_watcher(config: SharedConfig) {
+    thread::spawn(move || {
+        let mut watcher = InboxWatcher::default();
+        loop {
+            let config = config.snapshot();
+            if config.setup_complete
+                && !config.files_storage_root.trim().is_empty()
+                && config.watched_folders.iter().any(|folder| folder.enabled)
+            {
+                if let Err(error) = watcher.run_pass(get_conn, &config) {
+                    eprintln!("Inbox watcher pass failed: {}", error);
+                }
+            }
+            thread::sleep(Duration::from_secs(
+                config
+                    .watch_interval_seconds
+                    .max(MIN_WATCH_INTERVAL_SECONDS),
+            ));
+        }
+    });
+}
+
+/// Imports the entry, then applies the rule's note and memo link. Problems after the
+/// import are returned as messages, since the file is already in storage by then.
+fn apply_rule<C>(
+    lock: impl Fn() -> Result<C, AppError>,
+    path: &Path,
+    storage_root: &Path,
+    policy: ImportPolicy,
+    rule: &InboxRule,
+) -> Result<(ManagedFileRecord, Option<i32>, Vec<String>), AppError>
+where
+    C: DerefMut<Target = Connection>,
+{
+    let mut record = FileRepository::import_local_entry(&lock, path, storage_root, policy)?;
+    let mut conn = lock()?;
+    let mut messages: Vec<String> = record.import_notice.iter().cloned().collect();
+
+    if let Some(note) = rule.note.as_deref().filter(|note| !note.trim().is_empty()) {
+        if record.note.is_none() {
+            match FileRepository::update_note(&conn, &record.id, note) {
+                Ok(updated) => record.note = updated.note,
+                Err(err) => messages.push(format!("Failed to set note: {}", err.message)),
+            }
+        }
+    }
+
+    let memo_id = match resolve_link_target(&conn, rule) {
+        Ok(Some(memo_id)) => {
+            let already_linked: bool = conn.query_row(
+                "SELECT EXISTS (SELECT 1 FROM memo_files WHERE memo_id = ? AND file_id = ?)",
+                rusqlite::params![memo_id, &record.id],
+                |row| row.get(0),
+            )?;
+            if already_linked {
+                Some(memo_id)
+            } else {
+                match FileRepository::append_file_link_to_memo(
+                    &mut conn,
+                    memo_id,
+                    &record.id,
+                    &record.display_name,
+                ) {
+                    Ok(()) => Some(memo_id),
+                    Err(err) => {
+                        messages.push(format!("Failed to link memo: {}", err.message));
+                        None
+                    }
+                }
+            }
+        }
+        Ok(None) => None,
+        Err(err) => {
+            messages.push(format!("Failed to link memo: {}", err.message));
+            None
+        }
+    };
+
+    Ok((record, memo_id, messages))
+}
+
+fn resolve_link_target(conn: &Connection, rule: &InboxRule) -> Result<Option<i32>, AppError> {
+    let Some(link_to) = rule
+        .link_to
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    else {
+        return Ok(None);
+    };
+    let workspace_slug_name = rule
+        .workspace_slug_name
+        .as_deref()
+        .ok_or_else(|| AppError::validation("Rules that link memos need a workspace."))?;
+    let workspace =
+        WorkspaceRepository::find_by_slug(conn, workspace_slug_name)?.ok_or_else(|| {
+            AppError::not_found(format!(
+                "Workspace not found for slug: {}",
+                workspace_slug_name
+            ))
+        })?;
+
+    if link_to == "today" {
+        let memo_id: Option<i32> = conn
+            .query_row(
+                "SELECT calendar_day_memo.memo_id
+                FROM calendar_day_memo
+                JOIN calendar_day ON calendar_day.id = calendar_day_memo.calendar_day_id
+                WHERE calendar_day.workspace_id = ? AND calendar_day.date = date('now', 'localtime')
+                ORDER BY calendar_day_memo.created_at, calendar_day_memo.memo_id
+                LIMIT 1",
+                [workspace.id],
+                |row| row.get(0),
+            )
+            .map(Some)
+            .or_else(|err| match err {
+                rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                err => Err(err),
+            })?;
+        return memo_id
+            .map(Some)
+            .ok_or_else(|| AppError::not_found("No memo is attached to today's calendar day."));
+    }
+
+    MemoRepository::find_by_slug(conn, workspace.id, link_to)?
+        .map(|memo| Some(memo.id))
+        .ok_or_else(|| AppError::not_found(format!("Memo not found for slug: {}", link_to)))
+}
+
+fn entry_version(path: &Path) -> std::io::Result<EntryVersion> {
+    let metadata = fs::metadata(path)?;
+    let modified_at = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0);
+    Ok((metadata.len() as i64, modified_at))
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), BaseDirs::new()) {
+        (Some(rest), Some(base_dirs)) => base_dirs.home_dir().join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+fn is_partial_download(path: &Path) -> bool {
+    path.extension()
+        .and_then(|value| value.to_str())
+        .is_some_and(|ext| {
+            PARTIAL_DOWNLOAD_EXTENSIONS
+                .iter()
+                .any(|partial| ext.eq_ignore_ascii_case(partial))
+        })
+}
+
+/// Matches `name` against a pattern where `*` is any run of characters and `?` is one
+/// character, ignoring case.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.trim().to_lowercase().chars().collect();
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&ch) if ch == '?' || ch == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|ch| *ch == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matches_pattern, InboxWatcher};
+    use crate::config::{AppConfig, InboxRule, WatchedFolder};
+    use crate::migrations::apply_migrations;
+    use crate::repositories::{
+        CalendarDayRepository, InboxActivityRepository, MemoRepository, WorkspaceRepository,
+    };
+    use rusqlite::Connection;
+    use std::fs;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    #[test]
+    fn matches_pattern_supports_wildcards_case_insensitively() {
+        assert!(matches_pattern("invoice-*.pdf", "Invoice-2026-10.PDF"));
+        assert!(matches_pattern("*.pdf", "scan.pdf"));
+        assert!(matches_pattern("scan-??.png", "scan-01.png"));
+        assert!(!matches_pattern("invoice-*.pdf", "receipt-2026.pdf"));
+        assert!(!matches_pattern("*.pdf", "scan.pdf.txt"));
+    }
+
+    #[test]
+    fn run_pass_imports_settled_matches_and_links_todays_memo() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let memo = MemoRepository::create(
+            &conn,
+            workspace.id,
+            "daily",
+            "Daily",
+            r#"{"type":"doc","content":[]}"#,
+        )
+        .expect("memo should be created");
+        let today: String = conn
+            .query_row("SELECT date('now', 'localtime')", [], |row| row.get(0))
+            .expect("today should resolve");
+        CalendarDayRepository::add_memo(&conn, workspace.id, &today, memo.id)
+            .expect("memo should be attached to today");
+
+        let temp_root =
+            std::env::temp_dir().join(format!("monobox-watcher-test-{}", Uuid::new_v4()));
+        let scans = temp_root.join("Scans");
+        let storage_root = temp_root.join("Storage");
+        fs::create_dir_all(&scans).expect("watched folder should be created");
+        fs::create_dir_all(&storage_root).expect("storage folder should be created");
+        fs::write(scans.join("invoice-42.pdf"), "invoice").expect("file should be written");
+        fs::write(scans.join("photo.jpg"), "photo").expect("file should be written");
+
+        let config = AppConfig {
+            files_storage_root: storage_root.to_string_lossy().to_string(),
+            watched_folders: vec![WatchedFolder {
+                path: scans.to_string_lossy().to_string(),
+                enabled: true,
+                rules: vec![InboxRule {
+                    pattern: "*.pdf".to_string(),
+                    note: Some("Accounting".to_string()),
+                    workspace_slug_name: Some("team".to_string()),
+                    link_to: Some("today".to_string()),
+                }],
+            }],
+            ..AppConfig::default()
+        };
+
+        let shared = Mutex::new(conn);
+        let lock = || {
+            Ok(shared
+                .lock()
+                .expect("test connection lock should not be poisoned"))
+        };
+        let mut watcher = InboxWatcher::default();
+        assert_eq!(
+            watcher
+                .run_pass(lock, &config)
+                .expect("first pass should run"),
+            0
+        );
+        assert_eq!(
+            watcher
+                .run_pass(lock, &config)
+                .expect("second pass should run"),
+            1
+        );
+        let conn = shared
+            .into_inner()
+            .expect("test connection lock should not be poisoned");
+
+        assert!(!scans.join("invoice-42.pdf").exists());
+        assert!(scans.join("photo.jpg").exists());
+        let activity = InboxActivityRepository::list(&conn, 10, 0).expect("activity should list");
+        assert_eq!(activity.total_count, 1);
+        assert_eq!(activity.items[0].status, "imported");
+        assert_eq!(activity.items[0].memo_id, Some(memo.id));
+        let note: Option<String> = conn
+            .query_row("SELECT note FROM files", [], |row| row.get(0))
+            .expect("file should be stored");
+        assert_eq!(note.as_deref(), Some("Accounting"));
+
+        fs::remove_dir_all(temp_root).expect("temp folders should be removed");
+    }
+
+    #[test]
+    fn run_pass_retries_failed_entries_without_repeating_the_failure() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+
+        let temp_root =
+            std::env::temp_dir().join(format!("monobox-watcher-test-{}", Uuid::new_v4()));
+        let scans = temp_root.join("Scans");
+        let storage_root = temp_root.join("Storage");
+        fs::create_dir_all(&scans).expect("watched folder should be created");
+        fs::write(scans.join("scan.pdf"), "scan").expect("file should be written");
+
+        let config = AppConfig {
+            files_storage_root: storage_root.to_string_lossy().to_string(),
+            watched_folders: vec![WatchedFolder {
+                path: scans.to_string_lossy().to_string(),
+                enabled: true,
+                rules: vec![InboxRule {
+                    pattern: "*.pdf".to_string(),
+                    note: None,
+                    workspace_slug_name: None,
+                    link_to: None,
+                }],
+            }],
+            ..AppConfig::default()
+        };
+
+        let shared = Mutex::new(conn);
+        let lock = || {
+            Ok(shared
+                .lock()
+                .expect("test connection lock should not be poisoned"))
+        };
+        let mut watcher = InboxWatcher::default();
+        let mut run_pass = || watcher.run_pass(lock, &config).expect("pass should run");
+        assert_eq!(run_pass(), 0);
+        // The storage folder is missing, so the import fails once and is not logged again.
+        assert_eq!(run_pass(), 1);
+        assert_eq!(run_pass(), 0);
+
+        fs::create_dir_all(&storage_root).expect("storage folder should be created");
+        assert_eq!(run_pass(), 1);
+        assert!(!scans.join("scan.pdf").exists());
+
+        let conn = shared
+            .into_inner()
+            .expect("test connection lock should not be poisoned");
+        let activity = InboxActivityRepository::list(&conn, 10, 0).expect("activity should list");
+        let statuses: Vec<&str> = activity
+            .items
+            .iter()
+            .map(|item| item.status.as_str())
+            .collect();
+        assert_eq!(statuses, vec!["imported", "failed"]);
+
+        fs::remove_dir_all(temp_root).expect("temp folders should be removed");
+    }
+}
@@ -1,6 +1,8 @@
 pub mod config;
 pub mod database;
 pub mod errors;
+pub mod file_text_index;
+pub mod inbox_watcher;
 pub mod mcp;
 pub mod migrations;
 pub mod models;
@@ -5,7 +5,9 @@ mod commands;
 mod config;
 mod database;
 mod errors;
+mod file_text_index;
 mod global_shortcuts;
+mod inbox_watcher;
 mod mcp;
 mod migrations;
 mod models;
@@ -25,13 +27,17 @@ fn main() {
     let mut mcp_server_info = mcp::build_server_info(&runtime_config);
     let asset_dir_path = runtime_config.asset_dir_path.clone();
 
+    database::configure(&runtime_config.database_path);
     if runtime_config.setup_complete {
         if let Err(error) = database::initialize_database() {
             eprintln!("Failed to initialize database: {}", error);
         }
     }
+    let shared_config = config::SharedConfig::new(runtime_config.clone());
+    inbox_watcher::spawn_inbox_watcher(shared_config.clone());
+    file_text_index::spawn_file_text_indexer(shared_config.clone());
 
-    if let Err(error) = mcp::spawn_http_server(runtime_config.clone()) {
+    if let Err(error) = mcp::spawn_http_server(shared_config.clone()) {
         eprintln!("Failed to start monobox MCP server: {}", error);
         mcp_server_info.enabled = false;
     }
@@ -42,7 +48,7 @@ fn main() {
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_shell::init())
-        .manage(runtime_config.clone())
+        .manage(shared_config)
         .manage(mcp_server_info)
         .manage(global_shortcuts::GlobalShortcutState::from_config(
             &runtime_config,
@@ -112,16 +118,23 @@ fn main() {
             commands::config::set_theme_preference,
             commands::config::set_app_window_opacity,
             commands::config::set_selection_copy_format,
+            commands::config::set_import_policy,
+            commands::config::set_watched_folders,
             commands::config::set_inbox_ignore_file_names,
             commands::config::set_global_shortcuts,
+            // Doctor
+            commands::doctor::run_database_doctor,
             // Files
             commands::file::list_inbox_files,
+            commands::file::list_inbox_activity,
             commands::file::import_inbox_file,
             commands::file::import_inbox_entry,
             commands::file::create_external_file_link,
             commands::file::list_files,
             commands::file::get_file_detail,
             commands::file::resolve_file_open_target,
+            commands::file::verify_file_storage,
+            commands::file::adopt_storage_entry,
             commands::file::open_managed_file,
             commands::file::open_local_path,
             commands::file::delete_file_record,
@@ -140,6 +153,9 @@ fn main() {
             commands::calendar_day::update_calendar_day,
             commands::calendar_day::add_calendar_day_memo,
             commands::calendar_day::remove_calendar_day_memo,
+            commands::calendar_day::export_calendar_ics,
+            commands::calendar_day::import_calendar_ics,
+            commands::calendar_day::get_calendar_feed_url,
             // Milestone
             commands::milestone::list_milestones,
             commands::milestone::create_milestone,
@@ -153,7 +169,13 @@ fn main() {
             commands::workspace::get_workspace,
             commands::workspace::create_workspace,
             commands::workspace::save_workspace,
+            commands::workspace::get_archived_workspaces,
+            commands::workspace::set_workspace_archived,
+            commands::workspace::prepare_workspace_deletion,
             commands::workspace::delete_workspace,
+            commands::workspace::clone_workspace,
+            commands::workspace::export_workspace,
+            commands::workspace::import_workspace,
             // Memo
             commands::memo::get_workspace_memos,
             commands::memo::get_memo,
@@ -168,9 +190,15 @@ fn main() {
             commands::memo_template::get_memo_template,
             commands::memo_template::create_memo_template,
             commands::memo_template::save_memo_template,
+            commands::memo_template::save_memo_template_workflow,
             commands::memo_template::delete_memo_template,
             commands::memo_template::set_default_memo_template,
             commands::memo_template::clear_default_memo_template,
+            commands::memo_template::get_memo_template_prompts,
+            commands::memo_template::render_memo_template,
+            commands::memo_template::copy_memo_template,
+            commands::memo_template::export_memo_template_pack,
+            commands::memo_template::import_memo_template_pack,
             // HTML Export
             commands::html_export::save_html_export,
             commands::html_export::save_text_export,
@@ -181,17 +209,42 @@ fn main() {
             commands::kanban_status::create_kanban_status,
             commands::kanban_status::update_kanban_status,
             commands::kanban_status::update_kanban_status_orders,
+            commands::kanban_status::update_kanban_status_wip_limit,
+            commands::kanban_status::list_kanban_transition_rules,
+            commands::kanban_status::set_kanban_transition_rules,
             commands::kanban_status::delete_kanban_status,
             // Kanban
             commands::kanban::list_kanbans,
             commands::kanban::create_kanban,
             commands::kanban::update_kanban_status_roles,
             commands::kanban::delete_kanban,
+            commands::kanban::export_kanban_board,
+            commands::kanban::import_kanban_csv,
+            commands::kanban::clone_kanban,
+            // Kanban Template
+            commands::kanban_template::list_kanban_templates,
+            commands::kanban_template::create_kanban_template,
+            commands::kanban_template::create_kanban_template_from_board,
+            commands::kanban_template::update_kanban_template,
+            commands::kanban_template::delete_kanban_template,
             // Memo Kanban
             commands::kanban_assignment::list_kanban_assignment_items,
+            commands::kanban_assignment::list_kanban_lanes,
             commands::kanban_assignment::list_kanban_assignment_entries,
             commands::kanban_assignment::upsert_kanban_assignment_status,
+            commands::kanban_assignment::move_kanban_card,
+            commands::kanban_assignment::move_kanban_cards_to_status,
+            commands::kanban_assignment::update_kanban_assignment_dates,
+            commands::kanban_assignment::list_kanban_dated_items,
             commands::kanban_assignment::remove_kanban_assignment,
+            // Kanban Automation
+            commands::kanban_automation::list_kanban_automation_rules,
+            commands::kanban_automation::create_kanban_automation_rule,
+            commands::kanban_automation::update_kanban_automation_rule,
+            commands::kanban_automation::delete_kanban_automation_rule,
+            // Kanban Report
+            commands::kanban_report::get_kanban_report,
+            commands::kanban_report::list_kanban_status_transitions,
             // Link
             commands::link::get_links,
             commands::link::list_workspace_link_counts,
@@ -205,6 +258,8 @@ fn main() {
             // Asset
             commands::asset::save_image,
             commands::asset::read_image_as_data_url,
+            commands::asset::scan_orphaned_assets,
+            commands::asset::clean_orphaned_assets,
         ])
         .run(tauri::generate_context!())
         .expect("error while running Tauri application");
@@ -220,7 +275,7 @@ fn build_runtime_config(mut app_config: config::AppConfig) -> config::AppConfig
 fn load_runtime_config(
     config_dir: &std::path::Path,
     data_dir: &std::path::Path,
-) -> Result<config::AppConfig, String> {
+) -> Result<config::AppConfig, errors::AppError> {
     let config_path = config_dir.join("config.json");
     let mut app_config = config::load_config(config_dir, data_dir)?;
 
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt;
 use std::io::{BufRead, BufReader, Read, Write};
 use std::net::{Shutdown, TcpListener, TcpStream};
@@ -6,9 +7,15 @@ use std::thread;
 use serde::Deserialize;
 use serde_json::{json, Value};
 
-use crate::config::{load_config, AppConfig};
+use crate::config::{AppConfig, SharedConfig};
 use crate::database::get_conn;
-use crate::repositories::{FileRepository, LinkRepository, MemoRepository, WorkspaceRepository};
+use crate::errors::{AppError, ErrorCode};
+use crate::repositories::kanban_assignment_repository::KanbanDateFilter;
+use crate::repositories::memo_template_repository::MemoTemplateVariables;
+use crate::repositories::{
+    CalendarExportRepository, FileRepository, KanbanAssignmentRepository, KanbanReportRepository,
+    KanbanRepository, LinkRepository, MemoRepository, MemoTemplateRepository, WorkspaceRepository,
+};
 
 pub const MCP_PROTOCOL_VERSION: &str = "2024-11-05";
 
@@ -47,6 +54,57 @@ pub struct RpcRequest {
     pub params: Option<Value>,
 }
 
+/// JSON-RPC error object. Application errors keep their stable code and reason in `data`.
+#[derive(Debug)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+    pub data: Option<Value>,
+}
+
+impl RpcError {
+    fn method_not_found(method: &str) -> Self {
+        RpcError {
+            code: -32601,
+            message: format!("Method not found: {}", method),
+            data: None,
+        }
+    }
+
+    fn invalid_params(message: String) -> Self {
+        RpcError {
+            code: -32602,
+            message,
+            data: None,
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        match &self.data {
+            Some(data) => json!({"code": self.code, "message": self.message, "data": data}),
+            None => json!({"code": self.code, "message": self.message}),
+        }
+    }
+}
+
+impl From<AppError> for RpcError {
+    fn from(error: AppError) -> Self {
+        let code = match error.code {
+            ErrorCode::Validation => -32602,
+            ErrorCode::NotFound => -32001,
+            ErrorCode::Conflict => -32002,
+            ErrorCode::Storage => -32003,
+            ErrorCode::Io => -32004,
+            ErrorCode::Config => -32005,
+        };
+        RpcError {
+            code,
+            data: Some(json!({"code": error.code, "reason": error.reason})),
+            message: error.message,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct ToolCallParams {
     name: String,
@@ -82,8 +140,61 @@ pub fn build_server_url(host: &str, port: u16, token: &str) -> String {
     format!("http://{}:{}/mcp/{}", normalize_host(host), port, token)
 }
 
-pub fn spawn_http_server(config: AppConfig) -> Result<(), McpServerError> {
-    let info = build_server_info(&config);
+/// iCalendar subscription URL for a workspace, served by the same listener as MCP.
+pub fn build_calendar_feed_url(
+    host: &str,
+    port: u16,
+    token: &str,
+    workspace_slug_name: &str,
+) -> String {
+    format!(
+        "http://{}:{}/calendar/{}/{}.ics",
+        normalize_host(host),
+        port,
+        token,
+        percent_encode_segment(workspace_slug_name)
+    )
+}
+
+/// Slugs keep non-ASCII characters and may contain `%`, so everything outside the unreserved
+/// set is encoded.
+fn percent_encode_segment(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Returns `None` for a malformed escape or when the decoded bytes are not UTF-8.
+fn percent_decode_segment(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = bytes.get(index + 1..index + 3)?;
+            if !hex.iter().all(u8::is_ascii_hexdigit) {
+                return None;
+            }
+            decoded.push(u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Serves MCP and calendar feed requests. Each connection reads the current `config`, so
+/// completing setup or changing paths in the app applies without a restart.
+pub fn spawn_http_server(config: SharedConfig) -> Result<(), McpServerError> {
+    let info = build_server_info(&config.snapshot());
     let listener = TcpListener::bind((info.bind_host.as_str(), info.port)).map_err(|err| {
         McpServerError::Bind(format!(
             "Failed to bind monobox MCP server at {}:{}: {}",
@@ -96,8 +207,9 @@ pub fn spawn_http_server(config: AppConfig) -> Result<(), McpServerError> {
             match stream {
                 Ok(stream) => {
                     let token = info.token.clone();
+                    let config = config.clone();
                     thread::spawn(move || {
-                        let _ = handle_http_connection(stream, &token);
+                        let _ = handle_http_connection(stream, &token, &config);
                     });
                 }
                 Err(_) => continue,
@@ -108,7 +220,11 @@ pub fn spawn_http_server(config: AppConfig) -> Result<(), McpServerError> {
     Ok(())
 }
 
-pub fn handle_json_rpc_request(request: RpcRequest) -> Result<Option<Value>, String> {
+/// `config` is the caller's current config; tool calls never re-read config.json.
+pub fn handle_json_rpc_request(
+    request: RpcRequest,
+    config: &AppConfig,
+) -> Result<Option<Value>, RpcError> {
     match request.method.as_str() {
         "initialize" => Ok(Some(json!({
             "protocolVersion": MCP_PROTOCOL_VERSION,
@@ -128,26 +244,26 @@ pub fn handle_json_rpc_request(request: RpcRequest) -> Result<Option<Value>, Str
             "tools": tool_definitions()
         }))),
         "tools/call" => {
-            let params_value = request
-                .params
-                .ok_or_else(|| "Missing tools/call params.".to_string())?;
-            let params: ToolCallParams =
-                serde_json::from_value(params_value).map_err(|err| err.to_string())?;
+            let params_value = request.params.ok_or_else(|| {
+                RpcError::invalid_params("Missing tools/call params.".to_string())
+            })?;
+            let params: ToolCallParams = serde_json::from_value(params_value)
+                .map_err(|err| RpcError::invalid_params(err.to_string()))?;
             let arguments = params.arguments.unwrap_or_else(|| json!({}));
-            let structured = call_tool(&params.name, &arguments)?;
+            let structured = call_tool(&params.name, &arguments, config)?;
             Ok(Some(json!({
                 "content": [
                     {
                         "type": "text",
                         "text": serde_json::to_string_pretty(&structured)
-                            .map_err(|err| err.to_string())?
+                            .map_err(AppError::from)?
                     }
                 ],
                 "structuredContent": structured,
                 "isError": false
             })))
         }
-        _ => Err(format!("Method not found: {}", request.method)),
+        _ => Err(RpcError::method_not_found(&request.method)),
     }
 }
 
@@ -163,7 +279,7 @@ pub fn tool_definitions() -> Value {
         },
         {
             "name": "list_workspaces",
-            "description": "List all workspaces in monobox.",
+            "description": "List workspaces in monobox. Archived workspaces are not included.",
             "inputSchema": {
                 "type": "object",
                 "properties": {}
@@ -394,10 +510,14 @@ pub fn tool_definitions() -> Value {
         },
         {
             "name": "list_files",
-            "description": "List managed file records.",
+            "description": "List managed file records. With a query, search file names, notes and the text extracted from plain-text, Markdown, HTML and PDF files, returning snippets and related memos.",
             "inputSchema": {
                 "type": "object",
                 "properties": {
+                    "query": {
+                        "type": "string",
+                        "description": "Optional search query used against file_fts. Matches substrings of at least three characters."
+                    },
                     "limit": {
                         "type": "integer",
                         "description": "Maximum number of files. Defaults to 20."
@@ -462,59 +582,164 @@ pub fn tool_definitions() -> Value {
                 },
                 "required": ["workspace_slug_name", "memo_slug_title"]
             }
+        },
+        {
+            "name": "render_memo_template",
+            "description": "Expand a memo template's variables ({{date}}, {{date:+7d}}, {{title}}, {{workspace}}, {{cursor}}, {{prompt:<label>}}) without creating a memo.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "workspace_slug_name": {
+                        "type": "string",
+                        "description": "Workspace slug."
+                    },
+                    "template_slug_name": {
+                        "type": "string",
+                        "description": "Memo template slug."
+                    },
+                    "title": {
+                        "type": "string",
+                        "description": "Title substituted for {{title}}."
+                    },
+                    "prompt_values": {
+                        "type": "object",
+                        "description": "Values for {{prompt:<label>}} variables, keyed by label.",
+                        "additionalProperties": { "type": "string" }
+                    }
+                },
+                "required": ["workspace_slug_name", "template_slug_name", "title"]
+            }
+        },
+        {
+            "name": "get_kanban_report",
+            "description": "Flow report for a kanban board: completed memos per week, average time in each status, lead and cycle time from the default status to the Done status, and a daily cumulative flow series.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "workspace_slug_name": {
+                        "type": "string",
+                        "description": "Workspace slug."
+                    },
+                    "kanban_id": {
+                        "type": "integer",
+                        "description": "Kanban id. Defaults to the workspace's Status board."
+                    },
+                    "date_from": {
+                        "type": "string",
+                        "description": "First local date (YYYY-MM-DD). Defaults to 29 days before date_to."
+                    },
+                    "date_to": {
+                        "type": "string",
+                        "description": "Last local date (YYYY-MM-DD). Defaults to today."
+                    }
+                },
+                "required": ["workspace_slug_name"]
+            }
+        },
+        {
+            "name": "list_kanban_status_transitions",
+            "description": "List logged kanban status changes, newest first.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "workspace_slug_name": {
+                        "type": "string",
+                        "description": "Workspace slug."
+                    },
+                    "kanban_id": {
+                        "type": "integer",
+                        "description": "Kanban id. Defaults to the workspace's Status board."
+                    },
+                    "memo_slug_title": {
+                        "type": "string",
+                        "description": "Only list the changes of this memo."
+                    },
+                    "limit": {
+                        "type": "integer",
+                        "description": "Maximum number of transitions. Defaults to 50."
+                    },
+                    "offset": {
+                        "type": "integer",
+                        "description": "Pagination offset. Defaults to 0."
+                    }
+                },
+                "required": ["workspace_slug_name"]
+            }
+        },
+        {
+            "name": "list_kanban_dated_items",
+            "description": "List kanban cards with due or scheduled dates across every board of a workspace. Cards in a board's Done status are left out.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "workspace_slug_name": {
+                        "type": "string",
+                        "description": "Workspace slug."
+                    },
+                    "filter": {
+                        "type": "string",
+                        "enum": ["overdue", "due_this_week", "scheduled_today"],
+                        "description": "Which cards to list."
+                    }
+                },
+                "required": ["workspace_slug_name", "filter"]
+            }
         }
     ])
 }
 
-fn call_tool(name: &str, args: &Value) -> Result<Value, String> {
+fn call_tool(name: &str, args: &Value, config: &AppConfig) -> Result<Value, AppError> {
     match name {
-        "get_setup_status" => {
-            let config = load_app_config()?;
-            Ok(json!({
-                "setup_complete": config.setup_complete,
-                "database_path": config.database_path,
-                "asset_dir_path": config.asset_dir_path,
-                "files_storage_root": config.files_storage_root
-            }))
-        }
+        "get_setup_status" => Ok(json!({
+            "setup_complete": config.setup_complete,
+            "database_path": config.database_path,
+            "asset_dir_path": config.asset_dir_path,
+            "files_storage_root": config.files_storage_root
+        })),
         "list_workspaces" => {
-            ensure_setup_complete()?;
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            ensure_setup_complete(config)?;
+            let conn = get_conn()?;
             let workspaces = WorkspaceRepository::list(&conn)?;
             Ok(json!(workspaces))
         }
         "get_workspace" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let workspace_slug_name = required_string(args, "workspace_slug_name")?;
-            let conn = get_conn().map_err(|err| err.to_string())?;
-            let workspace = WorkspaceRepository::find_by_slug(&conn, &workspace_slug_name)
-                .map_err(|err| err.to_string())?
-                .ok_or_else(|| format!("Workspace not found for slug: {}", workspace_slug_name))?;
+            let conn = get_conn()?;
+            let workspace = WorkspaceRepository::find_by_slug(&conn, &workspace_slug_name)?
+                .ok_or_else(|| {
+                    AppError::not_found(format!(
+                        "Workspace not found for slug: {}",
+                        workspace_slug_name
+                    ))
+                })?;
             Ok(json!(workspace))
         }
         "list_memos" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let workspace_slug_name = required_string(args, "workspace_slug_name")?;
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            let conn = get_conn()?;
             let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
             let memos = MemoRepository::list(&conn, workspace.id)?;
             Ok(json!(memos))
         }
         "list_modified_memos" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let workspace_slug_name = required_string(args, "workspace_slug_name")?;
             let modified_from = required_string(args, "modified_from")?;
             let modified_to = required_string(args, "modified_to")?;
             let limit = optional_i32(args, "limit").unwrap_or(100).clamp(1, 500);
             let offset = optional_i32(args, "offset").unwrap_or(0).max(0);
             let include_plain_text = optional_bool(args, "include_plain_text").unwrap_or(true);
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            let conn = get_conn()?;
             let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
             let parsed_modified_from =
                 parse_sqlite_datetime(&conn, "modified_from", &modified_from)?;
             let parsed_modified_to = parse_sqlite_datetime(&conn, "modified_to", &modified_to)?;
             if parsed_modified_from >= parsed_modified_to {
-                return Err("modified_from must be earlier than modified_to.".to_string());
+                return Err(AppError::validation(
+                    "modified_from must be earlier than modified_to.",
+                ));
             }
             let memos = MemoRepository::list_modified_between(
                 &conn,
@@ -540,25 +765,27 @@ fn call_tool(name: &str, args: &Value) -> Result<Value, String> {
             }))
         }
         "get_memo" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let workspace_slug_name = required_string(args, "workspace_slug_name")?;
             let memo_slug_title = required_string(args, "memo_slug_title")?;
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            let conn = get_conn()?;
             let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
-            let memo = MemoRepository::find_by_slug(&conn, workspace.id, &memo_slug_title)
-                .map_err(|err| err.to_string())?
-                .ok_or_else(|| format!("Memo not found for slug: {}", memo_slug_title))?;
+            let memo = MemoRepository::find_by_slug(&conn, workspace.id, &memo_slug_title)?
+                .ok_or_else(|| {
+                    AppError::not_found(format!("Memo not found for slug: {}", memo_slug_title))
+                })?;
             Ok(json!(memo))
         }
         "get_memo_plain_text" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let workspace_slug_name = required_string(args, "workspace_slug_name")?;
             let memo_slug_title = required_string(args, "memo_slug_title")?;
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            let conn = get_conn()?;
             let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
-            let memo = MemoRepository::find_by_slug(&conn, workspace.id, &memo_slug_title)
-                .map_err(|err| err.to_string())?
-                .ok_or_else(|| format!("Memo not found for slug: {}", memo_slug_title))?;
+            let memo = MemoRepository::find_by_slug(&conn, workspace.id, &memo_slug_title)?
+                .ok_or_else(|| {
+                    AppError::not_found(format!("Memo not found for slug: {}", memo_slug_title))
+                })?;
             Ok(json!(build_memo_plain_text_value(
                 &workspace_slug_name,
                 &memo_slug_title,
@@ -566,7 +793,7 @@ fn call_tool(name: &str, args: &Value) -> Result<Value, String> {
             )))
         }
         "get_memo_context" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let workspace_slug_name = required_string(args, "workspace_slug_name")?;
             let memo_slug_title = required_string(args, "memo_slug_title")?;
             let include_content_json = optional_bool(args, "include_content_json").unwrap_or(false);
@@ -578,11 +805,12 @@ fn call_tool(name: &str, args: &Value) -> Result<Value, String> {
                 optional_bool(args, "include_related_memo_plain_text").unwrap_or(false);
             let max_related_memos_per_group =
                 optional_i64(args, "max_related_memos_per_group").unwrap_or(10);
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            let conn = get_conn()?;
             let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
-            let memo = MemoRepository::find_by_slug(&conn, workspace.id, &memo_slug_title)
-                .map_err(|err| err.to_string())?
-                .ok_or_else(|| format!("Memo not found for slug: {}", memo_slug_title))?;
+            let memo = MemoRepository::find_by_slug(&conn, workspace.id, &memo_slug_title)?
+                .ok_or_else(|| {
+                    AppError::not_found(format!("Memo not found for slug: {}", memo_slug_title))
+                })?;
 
             let context = build_memo_context_value(
                 &conn,
@@ -602,19 +830,19 @@ fn call_tool(name: &str, args: &Value) -> Result<Value, String> {
             ))
         }
         "get_current_memo" => {
-            ensure_setup_complete()?;
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            ensure_setup_complete(config)?;
+            let conn = get_conn()?;
             let memo = crate::repositories::MemoViewRepository::get_current_memo(&conn)?;
             Ok(json!(memo))
         }
         "get_current_memo_plain_text" => {
-            ensure_setup_complete()?;
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            ensure_setup_complete(config)?;
+            let conn = get_conn()?;
             let memo = crate::repositories::MemoViewRepository::get_current_memo_plain_text(&conn)?;
             Ok(json!(memo))
         }
         "get_current_memo_context" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let include_content_json = optional_bool(args, "include_content_json").unwrap_or(false);
             let include_plain_text = optional_bool(args, "include_plain_text").unwrap_or(true);
             let include_links = optional_bool(args, "include_links").unwrap_or(true);
@@ -624,7 +852,7 @@ fn call_tool(name: &str, args: &Value) -> Result<Value, String> {
                 optional_bool(args, "include_related_memo_plain_text").unwrap_or(false);
             let max_related_memos_per_group =
                 optional_i64(args, "max_related_memos_per_group").unwrap_or(10);
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            let conn = get_conn()?;
             let context = crate::repositories::MemoViewRepository::get_current_memo_context(
                 &conn,
                 include_related_memo_plain_text,
@@ -640,62 +868,158 @@ fn call_tool(name: &str, args: &Value) -> Result<Value, String> {
             ))
         }
         "search_memos" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let workspace_slug_name = required_string(args, "workspace_slug_name")?;
             let query = required_string(args, "query")?;
             let limit = optional_i32(args, "limit").unwrap_or(20);
             let offset = optional_i32(args, "offset").unwrap_or(0);
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            let conn = get_conn()?;
             let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
             let memos = MemoRepository::search(&conn, workspace.id, &query, limit, offset)?;
             Ok(json!(memos))
         }
         "list_files" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let limit = optional_i64(args, "limit").unwrap_or(20);
             let offset = optional_i64(args, "offset").unwrap_or(0);
             let unlinked_only = optional_bool(args, "unlinked_only").unwrap_or(false);
-            let conn = get_conn().map_err(|err| err.to_string())?;
-            let files = FileRepository::list_files(&conn, limit, offset, unlinked_only)?;
+            let query = optional_string(args, "query");
+            let conn = get_conn()?;
+            let files =
+                FileRepository::list_files(&conn, limit, offset, unlinked_only, query.as_deref())?;
             Ok(json!(files))
         }
         "get_file_detail" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let file_id = required_string(args, "file_id")?;
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            let conn = get_conn()?;
             let file = FileRepository::get_file_detail(&conn, &file_id)?
-                .ok_or_else(|| "File record was not found.".to_string())?;
+                .ok_or_else(|| AppError::not_found("File record was not found."))?;
             Ok(json!(file))
         }
         "list_memo_files" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let workspace_slug_name = required_string(args, "workspace_slug_name")?;
             let memo_slug_title = required_string(args, "memo_slug_title")?;
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            let conn = get_conn()?;
             let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
-            let memo = MemoRepository::find_by_slug(&conn, workspace.id, &memo_slug_title)
-                .map_err(|err| err.to_string())?
-                .ok_or_else(|| format!("Memo not found for slug: {}", memo_slug_title))?;
+            let memo = MemoRepository::find_by_slug(&conn, workspace.id, &memo_slug_title)?
+                .ok_or_else(|| {
+                    AppError::not_found(format!("Memo not found for slug: {}", memo_slug_title))
+                })?;
             let files = FileRepository::list_files_for_memo(&conn, memo.id)?;
             Ok(json!(files))
         }
         "get_memo_links" => {
-            ensure_setup_complete()?;
+            ensure_setup_complete(config)?;
             let workspace_slug_name = required_string(args, "workspace_slug_name")?;
             let memo_slug_title = required_string(args, "memo_slug_title")?;
-            let conn = get_conn().map_err(|err| err.to_string())?;
+            let conn = get_conn()?;
             let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
-            let memo = MemoRepository::find_by_slug(&conn, workspace.id, &memo_slug_title)
-                .map_err(|err| err.to_string())?
-                .ok_or_else(|| format!("Memo not found for slug: {}", memo_slug_title))?;
+            let memo = MemoRepository::find_by_slug(&conn, workspace.id, &memo_slug_title)?
+                .ok_or_else(|| {
+                    AppError::not_found(format!("Memo not found for slug: {}", memo_slug_title))
+                })?;
             let links = LinkRepository::list(&conn, memo.id)?;
             Ok(json!(links))
         }
-        _ => Err(format!("Unknown tool: {}", name)),
+        "get_kanban_report" => {
+            ensure_setup_complete(config)?;
+            let workspace_slug_name = required_string(args, "workspace_slug_name")?;
+            let date_from = optional_string(args, "date_from");
+            let date_to = optional_string(args, "date_to");
+            let conn = get_conn()?;
+            let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
+            let kanban = resolve_kanban(&conn, workspace.id, optional_i32(args, "kanban_id"))?;
+            let report = KanbanReportRepository::build(
+                &conn,
+                &kanban,
+                date_from.as_deref(),
+                date_to.as_deref(),
+            )?;
+            Ok(json!(report))
+        }
+        "list_kanban_status_transitions" => {
+            ensure_setup_complete(config)?;
+            let workspace_slug_name = required_string(args, "workspace_slug_name")?;
+            let memo_slug_title = optional_string(args, "memo_slug_title");
+            let limit = optional_i64(args, "limit").unwrap_or(50);
+            let offset = optional_i64(args, "offset").unwrap_or(0);
+            let conn = get_conn()?;
+            let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
+            let kanban = resolve_kanban(&conn, workspace.id, optional_i32(args, "kanban_id"))?;
+            let memo_id = match memo_slug_title {
+                Some(memo_slug_title) => Some(
+                    MemoRepository::find_by_slug(&conn, workspace.id, &memo_slug_title)?
+                        .ok_or_else(|| {
+                            AppError::not_found(format!(
+                                "Memo not found for slug: {}",
+                                memo_slug_title
+                            ))
+                        })?
+                        .id,
+                ),
+                None => None,
+            };
+            let transitions = KanbanReportRepository::list_transitions(
+                &conn,
+                workspace.id,
+                kanban.id,
+                memo_id,
+                limit,
+                offset,
+            )?;
+            Ok(json!(transitions))
+        }
+        "list_kanban_dated_items" => {
+            ensure_setup_complete(config)?;
+            let workspace_slug_name = required_string(args, "workspace_slug_name")?;
+            let filter = required_string(args, "filter")?;
+            let filter = KanbanDateFilter::parse(&filter).ok_or_else(|| {
+                AppError::validation(format!("Unknown kanban date filter: {}", filter))
+            })?;
+            let conn = get_conn()?;
+            let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
+            let items = KanbanAssignmentRepository::list_dated_items(&conn, workspace.id, filter)?;
+            Ok(json!(items))
+        }
+        "render_memo_template" => {
+            ensure_setup_complete(config)?;
+            let workspace_slug_name = required_string(args, "workspace_slug_name")?;
+            let template_slug_name = required_string(args, "template_slug_name")?;
+            let title = required_string(args, "title")?;
+            let prompt_values = optional_string_map(args, "prompt_values");
+            let conn = get_conn()?;
+            let workspace = resolve_workspace(&conn, &workspace_slug_name)?;
+            let template =
+                MemoTemplateRepository::find_by_slug(&conn, workspace.id, &template_slug_name)?
+                    .ok_or_else(|| {
+                        AppError::not_found(format!(
+                            "Memo template not found for slug: {}",
+                            template_slug_name
+                        ))
+                    })?;
+            let expanded = MemoTemplateRepository::expand_content(
+                &conn,
+                &template.content,
+                &MemoTemplateVariables {
+                    title: &title,
+                    workspace_name: &workspace.name,
+                    prompt_values: &prompt_values,
+                },
+            )?;
+            Ok(json!(expanded))
+        }
+        _ => Err(AppError::validation(format!("Unknown tool: {}", name))),
     }
 }
 
-fn handle_http_connection(mut stream: TcpStream, token: &str) -> std::io::Result<()> {
+fn handle_http_connection(
+    mut stream: TcpStream,
+    token: &str,
+    config: &SharedConfig,
+) -> std::io::Result<()> {
+    let config = &config.snapshot();
     let mut reader = BufReader::new(stream.try_clone()?);
     let mut request_line = String::new();
     if reader.read_line(&mut request_line)? == 0 {
@@ -724,6 +1048,26 @@ fn handle_http_connection(mut stream: TcpStream, token: &str) -> std::io::Result
         }
     }
 
+    if let Some(workspace_slug_name) = calendar_feed_workspace(path, token) {
+        if method != "GET" {
+            write_http_text(&mut stream, 405, "Method Not Allowed")?;
+            return Ok(());
+        }
+        match build_calendar_feed(&workspace_slug_name, config) {
+            Ok(ics) => write_http_response(
+                &mut stream,
+                200,
+                "text/calendar; charset=utf-8",
+                ics.as_bytes(),
+            )?,
+            Err(error) if error.code == ErrorCode::NotFound => {
+                write_http_text(&mut stream, 404, &error.message)?
+            }
+            Err(error) => write_http_text(&mut stream, 500, &error.message)?,
+        }
+        return Ok(());
+    }
+
     if path == format!("/mcp/{}/health", token) && method == "GET" {
         write_http_json(
             &mut stream,
@@ -759,7 +1103,7 @@ fn handle_http_connection(mut stream: TcpStream, token: &str) -> std::io::Result
     };
 
     let request_id = request.id.clone();
-    match handle_json_rpc_request(request) {
+    match handle_json_rpc_request(request, config) {
         Ok(Some(response)) => {
             if let Some(id) = request_id {
                 write_http_json(
@@ -778,21 +1122,15 @@ fn handle_http_connection(mut stream: TcpStream, token: &str) -> std::io::Result
         Ok(None) => {
             write_http_text(&mut stream, 202, "")?;
         }
-        Err(message) => {
+        Err(error) => {
             let payload = match request_id {
                 Some(id) => json!({
                     "jsonrpc": "2.0",
                     "id": id,
-                    "error": {
-                        "code": -32000,
-                        "message": message,
-                    }
+                    "error": error.to_value(),
                 }),
                 None => json!({
-                    "error": {
-                        "code": -32000,
-                        "message": message,
-                    }
+                    "error": error.to_value(),
                 }),
             };
             write_http_json(&mut stream, 200, &payload)?;
@@ -803,6 +1141,29 @@ fn handle_http_connection(mut stream: TcpStream, token: &str) -> std::io::Result
     Ok(())
 }
 
+/// Returns the decoded workspace slug. Calendar apps may append a cache-busting query
+/// string, so it is ignored.
+fn calendar_feed_workspace(path: &str, token: &str) -> Option<String> {
+    let path = path.split('?').next().unwrap_or_default();
+    let slug = path
+        .strip_prefix("/calendar/")?
+        .strip_prefix(token)?
+        .strip_prefix('/')?
+        .strip_suffix(".ics")?;
+    if slug.is_empty() || slug.contains('/') {
+        None
+    } else {
+        percent_decode_segment(slug)
+    }
+}
+
+fn build_calendar_feed(workspace_slug_name: &str, config: &AppConfig) -> Result<String, AppError> {
+    ensure_setup_complete(config)?;
+    let conn = get_conn()?;
+    let workspace = resolve_workspace(&conn, workspace_slug_name)?;
+    CalendarExportRepository::to_ics(&conn, &workspace).map_err(AppError::from)
+}
+
 fn write_http_json(stream: &mut TcpStream, status: u16, body: &Value) -> std::io::Result<()> {
     let payload = serde_json::to_vec(body).unwrap_or_else(|_| b"{}".to_vec());
     write_http_response(stream, status, "application/json", &payload)
@@ -840,35 +1201,46 @@ fn write_http_response(
     Ok(())
 }
 
-fn load_app_config() -> Result<AppConfig, String> {
-    let proj_dirs = directories::ProjectDirs::from("com", "m2tkl", "monobox")
-        .ok_or_else(|| "Failed to determine project directories".to_string())?;
-    load_config(proj_dirs.config_dir(), proj_dirs.data_dir())
-}
-
-fn ensure_setup_complete() -> Result<(), String> {
-    let config = load_app_config()?;
+fn ensure_setup_complete(config: &AppConfig) -> Result<(), AppError> {
     if config.setup_complete {
         Ok(())
     } else {
-        Err("monobox setup is not complete yet.".to_string())
+        Err(AppError::config("monobox setup is not complete yet."))
     }
 }
 
 fn resolve_workspace(
     conn: &rusqlite::Connection,
     workspace_slug_name: &str,
-) -> Result<crate::models::Workspace, String> {
-    WorkspaceRepository::find_by_slug(conn, workspace_slug_name)
-        .map_err(|err| err.to_string())?
-        .ok_or_else(|| format!("Workspace not found for slug: {}", workspace_slug_name))
+) -> Result<crate::models::Workspace, AppError> {
+    WorkspaceRepository::find_by_slug(conn, workspace_slug_name)?.ok_or_else(|| {
+        AppError::not_found(format!(
+            "Workspace not found for slug: {}",
+            workspace_slug_name
+        ))
+    })
 }
 
-fn required_string(args: &Value, key: &str) -> Result<String, String> {
+/// Falls back to the workspace's Status board, which every workspace has.
+fn resolve_kanban(
+    conn: &rusqlite::Connection,
+    workspace_id: i32,
+    kanban_id: Option<i32>,
+) -> Result<crate::models::kanban::Kanban, AppError> {
+    match kanban_id {
+        Some(kanban_id) => KanbanRepository::find_by_id(conn, workspace_id, kanban_id)?
+            .ok_or_else(|| AppError::not_found(format!("Kanban not found: {}", kanban_id))),
+        None => {
+            KanbanRepository::ensure_global_status_board(conn, workspace_id).map_err(AppError::from)
+        }
+    }
+}
+
+fn required_string(args: &Value, key: &str) -> Result<String, AppError> {
     args.get(key)
         .and_then(Value::as_str)
         .map(ToOwned::to_owned)
-        .ok_or_else(|| format!("Missing required string argument: {}", key))
+        .ok_or_else(|| AppError::validation(format!("Missing required string argument: {}", key)))
 }
 
 fn optional_i64(args: &Value, key: &str) -> Option<i64> {
@@ -883,20 +1255,38 @@ fn optional_bool(args: &Value, key: &str) -> Option<bool> {
     args.get(key).and_then(Value::as_bool)
 }
 
+fn optional_string(args: &Value, key: &str) -> Option<String> {
+    args.get(key).and_then(Value::as_str).map(ToOwned::to_owned)
+}
+
+fn optional_string_map(args: &Value, key: &str) -> HashMap<String, String> {
+    args.get(key)
+        .and_then(Value::as_object)
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|(label, value)| {
+                    value
+                        .as_str()
+                        .map(|value| (label.clone(), value.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn parse_sqlite_datetime(
     conn: &rusqlite::Connection,
     key: &str,
     value: &str,
-) -> Result<String, String> {
-    let parsed: Option<String> = conn
-        .query_row("SELECT datetime(?)", [value], |row| row.get(0))
-        .map_err(|err| err.to_string())?;
+) -> Result<String, AppError> {
+    let parsed: Option<String> = conn.query_row("SELECT datetime(?)", [value], |row| row.get(0))?;
 
     parsed.ok_or_else(|| {
-        format!(
+        AppError::validation(format!(
             "Invalid datetime for {}: {}. Use a value accepted by SQLite datetime(), such as YYYY-MM-DD or YYYY-MM-DD HH:MM:SS.",
             key, value
-        )
+        ))
     })
 }
 
@@ -924,7 +1314,7 @@ fn build_memo_context_value(
     memo: &crate::models::memo::MemoDetail,
     include_related_memo_plain_text: bool,
     max_related_memos_per_group: usize,
-) -> Result<Value, String> {
+) -> Result<Value, AppError> {
     let files = FileRepository::list_files_for_memo(conn, memo.id)?;
     let links = LinkRepository::list(conn, memo.id)?;
     let max_related_memos_per_group = max_related_memos_per_group.max(1);
@@ -938,8 +1328,7 @@ fn build_memo_context_value(
 
     for link in links {
         let plain_text = if include_related_memo_plain_text {
-            MemoRepository::find_by_id(conn, memo.workspace_id, link.id)
-                .map_err(|e| e.to_string())?
+            MemoRepository::find_by_id(conn, memo.workspace_id, link.id)?
                 .map(|related_memo| related_memo.plain_text)
         } else {
             None
@@ -1121,12 +1510,55 @@ fn shape_current_memo_context(
 #[cfg(test)]
 mod tests {
     use super::{
-        build_server_info, build_server_url, handle_json_rpc_request, parse_sqlite_datetime,
-        shape_current_memo_context, tool_definitions, RpcRequest,
+        build_calendar_feed_url, build_server_info, build_server_url, calendar_feed_workspace,
+        handle_http_connection, handle_json_rpc_request, parse_sqlite_datetime,
+        shape_current_memo_context, tool_definitions, RpcError, RpcRequest,
     };
-    use crate::config::AppConfig;
+    use crate::config::{AppConfig, SharedConfig};
+    use crate::database;
+    use crate::errors::AppError;
     use rusqlite::Connection;
-    use serde_json::json;
+    use serde_json::{json, Value};
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use uuid::Uuid;
+
+    /// Sends one JSON-RPC request through `handle_http_connection` and returns the JSON body.
+    fn post_json_rpc(config: &SharedConfig, request: Value) -> Value {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("test listener should bind");
+        let mut client =
+            TcpStream::connect(listener.local_addr().expect("listener has an address"))
+                .expect("client should connect");
+        let (stream, _) = listener.accept().expect("connection should be accepted");
+
+        let body = request.to_string();
+        write!(
+            client,
+            "POST /mcp/abc123 HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .expect("request should be written");
+        handle_http_connection(stream, "abc123", config).expect("request should be handled");
+
+        let mut response = String::new();
+        client
+            .read_to_string(&mut response)
+            .expect("response should be readable");
+        let (_, body) = response
+            .split_once("\r\n\r\n")
+            .expect("response should have a body");
+        serde_json::from_str(body).expect("response body should be JSON")
+    }
+
+    fn tool_call(name: &str) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {"name": name, "arguments": {}}
+        })
+    }
 
     #[test]
     fn build_server_url_uses_tokenized_path() {
@@ -1136,6 +1568,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn calendar_feed_path_requires_token_and_ics_suffix() {
+        assert_eq!(
+            build_calendar_feed_url("", 38453, "abc123", "team"),
+            "http://127.0.0.1:38453/calendar/abc123/team.ics"
+        );
+        assert_eq!(
+            calendar_feed_workspace("/calendar/abc123/team.ics?t=1", "abc123").as_deref(),
+            Some("team")
+        );
+        let url = build_calendar_feed_url("", 38453, "abc123", "チーム_100%25");
+        assert_eq!(
+            url,
+            "http://127.0.0.1:38453/calendar/abc123/%E3%83%81%E3%83%BC%E3%83%A0_100%2525.ics"
+        );
+        let path = url
+            .strip_prefix("http://127.0.0.1:38453")
+            .expect("feed URL should start with the server address");
+        assert_eq!(
+            calendar_feed_workspace(path, "abc123").as_deref(),
+            Some("チーム_100%25")
+        );
+        assert_eq!(
+            calendar_feed_workspace("/calendar/abc123/%E3%83.ics", "abc123"),
+            None
+        );
+        assert_eq!(
+            calendar_feed_workspace("/calendar/wrong/team.ics", "abc123"),
+            None
+        );
+        assert_eq!(
+            calendar_feed_workspace("/calendar/abc123/team", "abc123"),
+            None
+        );
+        assert_eq!(calendar_feed_workspace("/mcp/abc123", "abc123"), None);
+    }
+
+    #[test]
+    fn tool_calls_see_setup_completed_after_the_server_started() {
+        let config = SharedConfig::new(AppConfig {
+            setup_complete: false,
+            mcp_token: "abc123".to_string(),
+            ..AppConfig::default()
+        });
+        let before = post_json_rpc(&config, tool_call("list_workspaces"));
+        assert_eq!(
+            before["error"]["message"],
+            "monobox setup is not complete yet."
+        );
+
+        // What `save_app_config` does when the user finishes setup.
+        let dir = std::env::temp_dir().join(format!("monobox-mcp-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("test directory should be creatable");
+        let database_path = dir.join("data.db").to_string_lossy().to_string();
+        database::configure(&database_path);
+        database::initialize_database().expect("database should initialize");
+        config.replace(AppConfig {
+            setup_complete: true,
+            database_path: database_path.clone(),
+            ..config.snapshot()
+        });
+
+        let status = post_json_rpc(&config, tool_call("get_setup_status"));
+        assert_eq!(
+            status["result"]["structuredContent"]["setup_complete"],
+            true
+        );
+        assert_eq!(
+            status["result"]["structuredContent"]["database_path"],
+            database_path.as_str()
+        );
+        let after = post_json_rpc(&config, tool_call("list_workspaces"));
+        assert_eq!(after["result"]["structuredContent"], json!([]));
+
+        std::fs::remove_dir_all(&dir).expect("test directory should be removable");
+    }
+
     #[test]
     fn build_server_info_uses_configured_hosts() {
         let config = AppConfig {
@@ -1155,12 +1664,15 @@ mod tests {
 
     #[test]
     fn initialize_returns_server_info() {
-        let response = handle_json_rpc_request(RpcRequest {
-            jsonrpc: "2.0".to_string(),
-            id: Some(json!(1)),
-            method: "initialize".to_string(),
-            params: Some(json!({})),
-        })
+        let response = handle_json_rpc_request(
+            RpcRequest {
+                jsonrpc: "2.0".to_string(),
+                id: Some(json!(1)),
+                method: "initialize".to_string(),
+                params: Some(json!({})),
+            },
+            &AppConfig::default(),
+        )
         .expect("initialize should succeed")
         .expect("initialize should return a result");
 
@@ -1168,6 +1680,48 @@ mod tests {
         assert_eq!(response["serverInfo"]["name"], "monobox-mcp");
     }
 
+    #[test]
+    fn errors_map_to_json_rpc_codes() {
+        let unknown_method = handle_json_rpc_request(
+            RpcRequest {
+                jsonrpc: "2.0".to_string(),
+                id: Some(json!(1)),
+                method: "resources/list".to_string(),
+                params: None,
+            },
+            &AppConfig::default(),
+        )
+        .expect_err("unknown methods should fail");
+        assert_eq!(unknown_method.code, -32601);
+
+        let missing_params = handle_json_rpc_request(
+            RpcRequest {
+                jsonrpc: "2.0".to_string(),
+                id: Some(json!(2)),
+                method: "tools/call".to_string(),
+                params: None,
+            },
+            &AppConfig::default(),
+        )
+        .expect_err("tools/call without params should fail");
+        assert_eq!(missing_params.code, -32602);
+
+        let not_found = RpcError::from(AppError::not_found("Memo not found for slug: x"));
+        assert_eq!(not_found.code, -32001);
+        assert_eq!(
+            not_found.to_value(),
+            json!({
+                "code": -32001,
+                "message": "Memo not found for slug: x",
+                "data": {"code": "NOT_FOUND", "reason": null}
+            })
+        );
+        assert_eq!(
+            RpcError::from(AppError::storage("disk I/O error")).code,
+            -32003
+        );
+    }
+
     #[test]
     fn tool_definitions_include_core_tools() {
         let tools = tool_definitions();
@@ -1,6 +1,10 @@
 use rusqlite::Connection;
 
-pub const MIGRATIONS: &[(&str, &str)] = &[
+use crate::errors::AppError;
+
+/// Each entry is `(version, up SQL, down SQL)`. Only migrations with down SQL can be
+/// rolled back by `rollback_to`.
+pub const MIGRATIONS: &[(&str, &str, Option<&str>)] = &[
     (
         "20250101_create_workspace_table",
         "CREATE TABLE if not exists workspace (
@@ -16,6 +20,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
                 UPDATE workspace SET updated_at = CURRENT_TIMESTAMP WHERE NEW.id;
             END;
             ",
+        None,
     ),
     (
         "20250101_create_memo_table",
@@ -38,6 +43,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
                 UPDATE memo SET updated_at = CURRENT_TIMESTAMP WHERE id == NEW.id;
             END;
             ",
+        None,
     ),
     (
         "20250101_create_link_table",
@@ -51,6 +57,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
                 UNIQUE (from_memo_id, to_memo_id)
             );
             ",
+        None,
     ),
     (
         "20250110_add_modified_at_to_memo",
@@ -58,12 +65,14 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
         ALTER TABLE memo ADD COLUMN modified_at TEXT;
         UPDATE memo SET modified_at = updated_at;
         ",
+        None,
     ),
     (
         "20250112_add_thumbnail_to_memo",
         "
         ALTER TABLE memo ADD COLUMN thumbnail_image TEXT;
         ",
+        None,
     ),
     (
         "20250523_create_bookmark_table",
@@ -76,12 +85,14 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
             FOREIGN KEY (memo_id) REFERENCES memo(id) ON DELETE CASCADE,
             FOREIGN KEY (workspace_id) REFERENCES workspace(id) ON DELETE CASCADE
         );",
+        None,
     ),
     (
         "20250530_add_body_text_to_memo",
         "
         ALTER TABLE memo ADD COLUMN body_text TEXT;
         ",
+        None,
     ),
     (
         "20250530_create_memo_fts",
@@ -94,6 +105,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
             slug_title UNINDEXED,
             tokenize = 'trigram'
         );",
+        None,
     ),
     (
         "20250601_create_kanban_table",
@@ -115,6 +127,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
             UPDATE kanban SET updated_at = CURRENT_TIMESTAMP WHERE id = NEW.id;
         END;
         ",
+        None,
     ),
     (
         "20250602_create_kanban_status_table",
@@ -140,6 +153,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
             UPDATE kanban_status SET updated_at = CURRENT_TIMESTAMP WHERE id = NEW.id;
         END;
         ",
+        None,
     ),
     (
         "20250603_create_kanban_assignment_table",
@@ -169,6 +183,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
             UPDATE kanban_assignment SET updated_at = CURRENT_TIMESTAMP WHERE id = NEW.id;
         END;
         ",
+        None,
     ),
     (
         "20260426_add_order_index_to_bookmark",
@@ -178,6 +193,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
         SET order_index = id
         WHERE order_index IS NULL;
         ",
+        None,
     ),
     (
         "20260427_create_memo_template_table",
@@ -200,6 +216,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
             UPDATE memo_template SET updated_at = CURRENT_TIMESTAMP WHERE id = NEW.id;
         END;
         ",
+        None,
     ),
     (
         "20260427_add_is_default_to_memo_template",
@@ -209,6 +226,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
         ON memo_template(workspace_id)
         WHERE is_default = 1;
         ",
+        None,
     ),
     (
         "20260518_create_files_table",
@@ -223,6 +241,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
 
         CREATE INDEX IF NOT EXISTS idx_files_imported_at ON files(imported_at DESC);
         ",
+        None,
     ),
     (
         "20260518_create_note_files_table",
@@ -237,18 +256,21 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
 
         CREATE INDEX IF NOT EXISTS idx_note_files_file_id ON note_files(file_id);
         ",
+        None,
     ),
     (
         "20260523_add_note_to_files",
         "
         ALTER TABLE files ADD COLUMN note TEXT;
         ",
+        None,
     ),
     (
         "20260531_rename_note_files_to_memo_files",
         "
         ALTER TABLE note_files RENAME TO memo_files;
         ",
+        None,
     ),
     (
         "20260616_allow_local_directory_files",
@@ -276,6 +298,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
 
         PRAGMA foreign_keys = ON;
         ",
+        None,
     ),
     (
         "20260529_create_memo_view_event_table",
@@ -291,6 +314,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
         CREATE INDEX IF NOT EXISTS idx_memo_view_event_memo_id ON memo_view_event(memo_id);
         CREATE INDEX IF NOT EXISTS idx_memo_view_event_viewed_at ON memo_view_event(viewed_at DESC);
         ",
+        None,
     ),
     (
         "20260529_create_memo_view_state_table",
@@ -303,6 +327,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
             FOREIGN KEY (memo_id) REFERENCES memo(id) ON DELETE CASCADE
         );
         ",
+        None,
     ),
     (
         "20260531_create_focus_memo_table",
@@ -327,12 +352,14 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
             UPDATE focus_memo SET updated_at = CURRENT_TIMESTAMP WHERE id = NEW.id;
         END;
         ",
+        None,
     ),
     (
         "20260601_rename_memo_files_note_id_to_memo_id",
         "
         ALTER TABLE memo_files RENAME COLUMN note_id TO memo_id;
         ",
+        None,
     ),
     (
         "20260602_add_status_roles_to_kanban",
@@ -360,6 +387,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
         )
         WHERE focus_status_id IS NULL;
         ",
+        None,
     ),
     (
         "20260603_create_calendar_tables",
@@ -395,6 +423,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
         CREATE INDEX IF NOT EXISTS idx_calendar_day_memo_memo_id
         ON calendar_day_memo(memo_id);
         ",
+        None,
     ),
     (
         "20260604_create_milestone_tables",
@@ -429,6 +458,7 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
         CREATE INDEX IF NOT EXISTS idx_milestone_memo_memo_id
         ON milestone_memo(memo_id);
         ",
+        None,
     ),
     (
         "20260802_replace_focus_memo_with_focus_daily_state",
@@ -460,29 +490,612 @@ pub const MIGRATIONS: &[(&str, &str)] = &[
         DROP TRIGGER IF EXISTS trigger_focus_memo_updated_at;
         DROP TABLE IF EXISTS focus_memo;
         ",
+        None,
+    ),
+    (
+        "20261019_add_workflow_to_memo_template",
+        "
+        ALTER TABLE memo_template ADD COLUMN kanban_id INTEGER REFERENCES kanban(id) ON DELETE SET NULL;
+        ALTER TABLE memo_template ADD COLUMN kanban_status_id INTEGER REFERENCES kanban_status(id) ON DELETE SET NULL;
+        ALTER TABLE memo_template ADD COLUMN milestone_id INTEGER REFERENCES milestone(id) ON DELETE SET NULL;
+        ALTER TABLE memo_template ADD COLUMN attach_to_today INTEGER NOT NULL DEFAULT 0 CHECK(attach_to_today IN (0, 1));
+        ",
+        None,
+    ),
+    (
+        "20261019_add_archived_at_to_workspace",
+        "
+        ALTER TABLE workspace ADD COLUMN archived_at TEXT;
+        ",
+        None,
+    ),
+    (
+        "20261020_add_content_hashes",
+        "
+        ALTER TABLE files ADD COLUMN content_hash TEXT;
+        CREATE INDEX IF NOT EXISTS idx_files_content_hash ON files(content_hash);
+
+        CREATE TABLE IF NOT EXISTS asset_hash (
+            content_hash TEXT PRIMARY KEY,
+            file_name TEXT NOT NULL,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+        ",
+        None,
+    ),
+    (
+        "20261021_create_inbox_activity_table",
+        "
+        CREATE TABLE IF NOT EXISTS inbox_activity (
+            id INTEGER PRIMARY KEY,
+            watched_folder TEXT NOT NULL,
+            source_path TEXT NOT NULL,
+            source_size INTEGER NOT NULL,
+            source_modified_at INTEGER NOT NULL,
+            rule_pattern TEXT,
+            status TEXT NOT NULL CHECK(status IN ('imported', 'duplicate', 'failed')),
+            file_id TEXT REFERENCES files(id) ON DELETE SET NULL,
+            display_name TEXT,
+            memo_id INTEGER REFERENCES memo(id) ON DELETE SET NULL,
+            message TEXT,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_inbox_activity_source
+        ON inbox_activity(source_path, source_size, source_modified_at);
+        CREATE INDEX IF NOT EXISTS idx_inbox_activity_created_at
+        ON inbox_activity(created_at DESC);
+        ",
+        None,
+    ),
+    (
+        "20261022_create_file_fts",
+        "
+        ALTER TABLE files ADD COLUMN text_extracted_at TEXT;
+
+        CREATE VIRTUAL TABLE IF NOT EXISTS file_fts USING fts5(
+            display_name,
+            note,
+            body_text,
+            file_id UNINDEXED,
+            tokenize = 'trigram'
+        );
+
+        INSERT INTO file_fts (display_name, note, body_text, file_id)
+        SELECT display_name, COALESCE(note, ''), '', id FROM files;
+
+        CREATE TRIGGER IF NOT EXISTS trigger_files_fts_insert AFTER INSERT ON files
+        BEGIN
+            INSERT INTO file_fts (display_name, note, body_text, file_id)
+            VALUES (NEW.display_name, COALESCE(NEW.note, ''), '', NEW.id);
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS trigger_files_fts_update AFTER UPDATE OF display_name, note ON files
+        BEGIN
+            UPDATE file_fts
+            SET display_name = NEW.display_name, note = COALESCE(NEW.note, '')
+            WHERE file_id = NEW.id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS trigger_files_fts_delete AFTER DELETE ON files
+        BEGIN
+            DELETE FROM file_fts WHERE file_id = OLD.id;
+        END;
+        ",
+        None,
+    ),
+    (
+        "20261023_create_kanban_status_transition_table",
+        "
+        ALTER TABLE kanban ADD COLUMN done_status_id INTEGER;
+
+        UPDATE kanban
+        SET done_status_id = (
+            SELECT kanban_status.id
+            FROM kanban_status
+            WHERE kanban_status.kanban_id = kanban.id
+              AND kanban_status.name = 'Done'
+            LIMIT 1
+        )
+        WHERE done_status_id IS NULL;
+
+        CREATE TABLE IF NOT EXISTS kanban_status_transition (
+            id INTEGER PRIMARY KEY,
+            workspace_id INTEGER NOT NULL,
+            kanban_id INTEGER NOT NULL,
+            memo_id INTEGER NOT NULL,
+            from_status_id INTEGER,
+            to_status_id INTEGER,
+            transitioned_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (workspace_id) REFERENCES workspace(id) ON DELETE CASCADE,
+            FOREIGN KEY (kanban_id) REFERENCES kanban(id) ON DELETE CASCADE,
+            FOREIGN KEY (memo_id) REFERENCES memo(id) ON DELETE CASCADE
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_kanban_status_transition_kanban
+        ON kanban_status_transition(kanban_id, transitioned_at);
+        CREATE INDEX IF NOT EXISTS idx_kanban_status_transition_memo
+        ON kanban_status_transition(memo_id, kanban_id, transitioned_at);
+
+        INSERT INTO kanban_status_transition
+            (workspace_id, kanban_id, memo_id, from_status_id, to_status_id, transitioned_at)
+        SELECT workspace_id, kanban_id, memo_id, NULL, kanban_status_id,
+            COALESCE(updated_at, created_at, CURRENT_TIMESTAMP)
+        FROM kanban_assignment;
+
+        CREATE TRIGGER IF NOT EXISTS trigger_kanban_assignment_transition_insert
+        AFTER INSERT ON kanban_assignment
+        BEGIN
+            INSERT INTO kanban_status_transition
+                (workspace_id, kanban_id, memo_id, from_status_id, to_status_id)
+            VALUES (NEW.workspace_id, NEW.kanban_id, NEW.memo_id, NULL, NEW.kanban_status_id);
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS trigger_kanban_assignment_transition_update
+        AFTER UPDATE OF kanban_status_id ON kanban_assignment
+        WHEN OLD.kanban_status_id IS NOT NEW.kanban_status_id
+        BEGIN
+            INSERT INTO kanban_status_transition
+                (workspace_id, kanban_id, memo_id, from_status_id, to_status_id)
+            VALUES (
+                NEW.workspace_id, NEW.kanban_id, NEW.memo_id,
+                OLD.kanban_status_id, NEW.kanban_status_id
+            );
+        END;
+
+        -- Removing a memo from a board ends its last stay. Deletes cascading from the
+        -- board or the memo take their history with them, so they are not logged.
+        CREATE TRIGGER IF NOT EXISTS trigger_kanban_assignment_transition_delete
+        AFTER DELETE ON kanban_assignment
+        WHEN EXISTS (SELECT 1 FROM kanban WHERE id = OLD.kanban_id)
+          AND EXISTS (SELECT 1 FROM memo WHERE id = OLD.memo_id)
+        BEGIN
+            INSERT INTO kanban_status_transition
+                (workspace_id, kanban_id, memo_id, from_status_id, to_status_id)
+            VALUES (OLD.workspace_id, OLD.kanban_id, OLD.memo_id, OLD.kanban_status_id, NULL);
+        END;
+        ",
+        None,
+    ),
+    (
+        "20261024_add_wip_limits_and_transition_rules",
+        "
+        ALTER TABLE kanban_status ADD COLUMN wip_limit INTEGER CHECK (wip_limit IS NULL OR wip_limit > 0);
+
+        -- A status with no outgoing rules can move anywhere. Once it has at least one,
+        -- only the listed destinations are allowed.
+        CREATE TABLE IF NOT EXISTS kanban_status_transition_rule (
+            id INTEGER PRIMARY KEY,
+            workspace_id INTEGER NOT NULL,
+            kanban_id INTEGER NOT NULL,
+            from_status_id INTEGER NOT NULL,
+            to_status_id INTEGER NOT NULL,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE (kanban_id, from_status_id, to_status_id),
+            FOREIGN KEY (workspace_id) REFERENCES workspace(id) ON DELETE CASCADE,
+            FOREIGN KEY (kanban_id) REFERENCES kanban(id) ON DELETE CASCADE,
+            FOREIGN KEY (from_status_id) REFERENCES kanban_status(id) ON DELETE CASCADE,
+            FOREIGN KEY (to_status_id) REFERENCES kanban_status(id) ON DELETE CASCADE
+        );
+        ",
+        Some(
+            "
+            DROP TABLE IF EXISTS kanban_status_transition_rule;
+            ALTER TABLE kanban_status DROP COLUMN wip_limit;
+            ",
+        ),
+    ),
+    (
+        "20261025_create_kanban_automation_rule_table",
+        "
+        CREATE TABLE IF NOT EXISTS kanban_automation_rule (
+            id INTEGER PRIMARY KEY,
+            workspace_id INTEGER NOT NULL,
+            kanban_id INTEGER NOT NULL,
+            trigger_type TEXT NOT NULL
+                CHECK (trigger_type IN ('enter_status', 'enter_done_status', 'leave_status')),
+            trigger_status_id INTEGER,
+            condition_type TEXT NOT NULL DEFAULT 'always'
+                CHECK (condition_type IN ('always', 'from_status', 'has_milestone')),
+            condition_status_id INTEGER,
+            action_type TEXT NOT NULL
+                CHECK (action_type IN (
+                    'complete_milestones', 'mark_focus_done_today', 'add_to_today', 'add_bookmark'
+                )),
+            enabled INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            updated_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (workspace_id) REFERENCES workspace(id) ON DELETE CASCADE,
+            FOREIGN KEY (kanban_id) REFERENCES kanban(id) ON DELETE CASCADE,
+            FOREIGN KEY (trigger_status_id) REFERENCES kanban_status(id) ON DELETE CASCADE,
+            FOREIGN KEY (condition_status_id) REFERENCES kanban_status(id) ON DELETE CASCADE
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_kanban_automation_rule_kanban
+        ON kanban_automation_rule(kanban_id);
+
+        CREATE TRIGGER IF NOT EXISTS trigger_kanban_automation_rule_updated_at
+        AFTER UPDATE ON kanban_automation_rule
+        BEGIN
+            UPDATE kanban_automation_rule SET updated_at = CURRENT_TIMESTAMP WHERE id = NEW.id;
+        END;
+        ",
+        Some(
+            "
+            DROP TRIGGER IF EXISTS trigger_kanban_automation_rule_updated_at;
+            DROP INDEX IF EXISTS idx_kanban_automation_rule_kanban;
+            DROP TABLE IF EXISTS kanban_automation_rule;
+            ",
+        ),
+    ),
+    (
+        "20261026_add_dates_to_kanban_assignment",
+        "
+        ALTER TABLE kanban_assignment ADD COLUMN due_on TEXT
+            CHECK (due_on IS NULL OR due_on GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]');
+        ALTER TABLE kanban_assignment ADD COLUMN scheduled_on TEXT
+            CHECK (scheduled_on IS NULL OR scheduled_on GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]');
+
+        CREATE INDEX IF NOT EXISTS idx_kanban_assignment_due_on
+        ON kanban_assignment(workspace_id, due_on) WHERE due_on IS NOT NULL;
+        CREATE INDEX IF NOT EXISTS idx_kanban_assignment_scheduled_on
+        ON kanban_assignment(workspace_id, scheduled_on) WHERE scheduled_on IS NOT NULL;
+        ",
+        Some(
+            "
+            DROP INDEX IF EXISTS idx_kanban_assignment_scheduled_on;
+            DROP INDEX IF EXISTS idx_kanban_assignment_due_on;
+            ALTER TABLE kanban_assignment DROP COLUMN scheduled_on;
+            ALTER TABLE kanban_assignment DROP COLUMN due_on;
+            ",
+        ),
+    ),
+    (
+        "20261027_create_kanban_template_tables",
+        "
+        CREATE TABLE IF NOT EXISTS kanban_template (
+            id INTEGER PRIMARY KEY,
+            workspace_id INTEGER NOT NULL,
+            name VARCHAR(128) NOT NULL,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            updated_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE (workspace_id, name),
+            FOREIGN KEY (workspace_id) REFERENCES workspace(id) ON DELETE CASCADE
+        );
+
+        CREATE TRIGGER IF NOT EXISTS trigger_kanban_template_updated_at
+        AFTER UPDATE ON kanban_template
+        BEGIN
+            UPDATE kanban_template SET updated_at = CURRENT_TIMESTAMP WHERE id = NEW.id;
+        END;
+
+        -- The role flags become the board's default, focus and done statuses.
+        CREATE TABLE IF NOT EXISTS kanban_template_status (
+            id INTEGER PRIMARY KEY,
+            template_id INTEGER NOT NULL,
+            name VARCHAR(128) NOT NULL,
+            color VARCHAR(32),
+            order_index INTEGER NOT NULL DEFAULT 0,
+            wip_limit INTEGER CHECK (wip_limit IS NULL OR wip_limit > 0),
+            is_default INTEGER NOT NULL DEFAULT 0,
+            is_focus INTEGER NOT NULL DEFAULT 0,
+            is_done INTEGER NOT NULL DEFAULT 0,
+            UNIQUE (template_id, name),
+            FOREIGN KEY (template_id) REFERENCES kanban_template(id) ON DELETE CASCADE
+        );
+        ",
+        Some(
+            "
+            DROP TABLE IF EXISTS kanban_template_status;
+            DROP TRIGGER IF EXISTS trigger_kanban_template_updated_at;
+            DROP TABLE IF EXISTS kanban_template;
+            ",
+        ),
     ),
 ];
 
-pub fn apply_migrations(conn: &Connection) -> Result<(), String> {
-    let applied_versions: Vec<String> = conn
-        .prepare("SELECT version FROM schema_migrations")
-        .map_err(|e| e.to_string())?
-        .query_map([], |row| row.get(0))
-        .map_err(|e| e.to_string())?
-        .filter_map(Result::ok)
-        .collect();
-
-    for (version, sql) in MIGRATIONS {
-        if !applied_versions.contains(&version.to_string()) {
-            println!("Applying migration: {}", version);
-            conn.execute_batch(sql)
-                .map_err(|e| format!("Failed to apply migration {}: {}", version, e))?;
-            conn.execute(
-                "INSERT INTO schema_migrations (version) VALUES (?)",
-                &[version],
-            )
-            .map_err(|e| format!("Failed to record migration {}: {}", version, e))?;
+/// Adds the `checksum` column to `schema_migrations` tables created before it existed.
+fn ensure_checksum_column(conn: &Connection) -> Result<(), AppError> {
+    let has_checksum = conn
+        .prepare("PRAGMA table_info(schema_migrations)")?
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<Result<Vec<_>, _>>()?
+        .iter()
+        .any(|column| column == "checksum");
+    if !has_checksum {
+        conn.execute("ALTER TABLE schema_migrations ADD COLUMN checksum TEXT", [])?;
+    }
+    Ok(())
+}
+
+/// FNV-1a over the migration SQL. It only needs to notice edits, not resist tampering.
+pub fn migration_checksum(sql: &str) -> String {
+    let hash = sql.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Fails when the database records a migration this build does not know, which means a
+/// newer build has migrated it. Databases without `schema_migrations` pass.
+pub fn ensure_known_schema(conn: &Connection) -> Result<(), AppError> {
+    let has_table: bool = conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'schema_migrations')",
+        [],
+        |row| row.get(0),
+    )?;
+    if !has_table {
+        return Ok(());
+    }
+
+    let versions = conn
+        .prepare("SELECT version FROM schema_migrations ORDER BY version")?
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    match versions
+        .iter()
+        .find(|version| !MIGRATIONS.iter().any(|(known, _, _)| known == version))
+    {
+        Some(version) => Err(AppError::conflict(format!(
+            "Database schema includes migration {} which this version of monobox does not know. Update the app before opening this database.",
+            version
+        ))
+        .with_reason("SCHEMA_NEWER_THAN_APP")),
+        None => Ok(()),
+    }
+}
+
+/// Checks the applied migrations against `MIGRATIONS` and returns the versions that still
+/// need to run. Fails when the database was migrated by a newer build or when the SQL of an
+/// applied migration has been edited since. Migrations recorded before checksums existed
+/// get the current checksum.
+pub fn pending_migrations(conn: &Connection) -> Result<Vec<&'static str>, AppError> {
+    ensure_checksum_column(conn)?;
+    ensure_known_schema(conn)?;
+
+    let applied: Vec<(String, Option<String>)> = conn
+        .prepare("SELECT version, checksum FROM schema_migrations ORDER BY version")?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (version, checksum) in &applied {
+        let Some((_, sql, _)) = MIGRATIONS.iter().find(|(known, _, _)| known == version) else {
+            continue;
+        };
+
+        let expected = migration_checksum(sql);
+        match checksum {
+            Some(checksum) if *checksum != expected => {
+                return Err(AppError::conflict(format!(
+                    "Migration {} was changed after it was applied (checksum {} but recorded {}).",
+                    version, expected, checksum
+                ))
+                .with_reason("MIGRATION_CHECKSUM_MISMATCH"));
+            }
+            Some(_) => {}
+            None => {
+                conn.execute(
+                    "UPDATE schema_migrations SET checksum = ? WHERE version = ?",
+                    [&expected, version],
+                )?;
+            }
         }
     }
+
+    Ok(MIGRATIONS
+        .iter()
+        .map(|(version, _, _)| *version)
+        .filter(|version| !applied.iter().any(|(applied, _)| applied == version))
+        .collect())
+}
+
+/// Applies pending migrations, each in its own transaction together with its
+/// `schema_migrations` row, so a failing migration leaves no partial schema behind.
+/// Foreign keys are switched off for the run because `PRAGMA foreign_keys` is ignored
+/// inside a transaction and table rebuilds would otherwise cascade deletes.
+pub fn apply_migrations(conn: &Connection) -> Result<(), AppError> {
+    let pending = pending_migrations(conn)?;
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let foreign_keys: bool = conn.query_row("PRAGMA foreign_keys", [], |row| row.get(0))?;
+    conn.execute_batch("PRAGMA foreign_keys = OFF;")?;
+    let result = MIGRATIONS
+        .iter()
+        .filter(|(version, _, _)| pending.contains(version))
+        .try_for_each(|(version, sql, _)| apply_migration(conn, version, sql));
+    conn.execute_batch(if foreign_keys {
+        "PRAGMA foreign_keys = ON;"
+    } else {
+        "PRAGMA foreign_keys = OFF;"
+    })?;
+    result
+}
+
+/// Reverts every applied migration newer than `version`, newest first, and returns the
+/// reverted versions. Each runs its down SQL in its own transaction together with the removal
+/// of its `schema_migrations` row. Nothing is reverted when one of them has no down SQL.
+pub fn rollback_to(conn: &Connection, version: &str) -> Result<Vec<&'static str>, AppError> {
+    if !MIGRATIONS.iter().any(|(known, _, _)| *known == version) {
+        return Err(AppError::not_found(format!(
+            "Migration {} does not exist.",
+            version
+        )));
+    }
+    ensure_known_schema(conn)?;
+
+    let applied = conn
+        .prepare("SELECT version FROM schema_migrations WHERE version > ?")?
+        .query_map([version], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    let targets: Vec<(&'static str, &'static str)> = MIGRATIONS
+        .iter()
+        .rev()
+        .filter(|(known, _, _)| applied.iter().any(|applied| applied == known))
+        .map(|(known, _, down)| {
+            down.map(|down| (*known, down)).ok_or_else(|| {
+                AppError::conflict(format!("Migration {} cannot be rolled back.", known))
+                    .with_reason("MIGRATION_NOT_REVERSIBLE")
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    if targets.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let foreign_keys: bool = conn.query_row("PRAGMA foreign_keys", [], |row| row.get(0))?;
+    conn.execute_batch("PRAGMA foreign_keys = OFF;")?;
+    let result = targets
+        .iter()
+        .try_for_each(|(version, down)| revert_migration(conn, version, down));
+    conn.execute_batch(if foreign_keys {
+        "PRAGMA foreign_keys = ON;"
+    } else {
+        "PRAGMA foreign_keys = OFF;"
+    })?;
+    result.map(|()| targets.into_iter().map(|(version, _)| version).collect())
+}
+
+fn apply_migration(conn: &Connection, version: &str, sql: &str) -> Result<(), AppError> {
+    println!("Applying migration: {}", version);
+    let tx = conn.unchecked_transaction()?;
+    tx.execute_batch(sql)
+        .map_err(|e| AppError::storage(format!("Failed to apply migration {}: {}", version, e)))?;
+    tx.execute(
+        "INSERT INTO schema_migrations (version, checksum) VALUES (?, ?)",
+        [version, migration_checksum(sql).as_str()],
+    )
+    .map_err(|e| AppError::storage(format!("Failed to record migration {}: {}", version, e)))?;
+    tx.commit()?;
     Ok(())
 }
+
+fn revert_migration(conn: &Connection, version: &str, sql: &str) -> Result<(), AppError> {
+    println!("Reverting migration: {}", version);
+    let tx = conn.unchecked_transaction()?;
+    tx.execute_batch(sql)
+        .map_err(|e| AppError::storage(format!("Failed to revert migration {}: {}", version, e)))?;
+    tx.execute("DELETE FROM schema_migrations WHERE version = ?", [version])
+        .map_err(|e| {
+            AppError::storage(format!("Failed to unrecord migration {}: {}", version, e))
+        })?;
+    tx.commit()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_migration, apply_migrations, pending_migrations, rollback_to, MIGRATIONS};
+    use crate::errors::ErrorCode;
+    use rusqlite::Connection;
+
+    fn setup_conn() -> Connection {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        conn
+    }
+
+    #[test]
+    fn apply_migrations_records_checksums_and_rejects_edits_and_newer_schemas() {
+        let conn = setup_conn();
+        apply_migrations(&conn).expect("migrations should apply");
+        assert!(pending_migrations(&conn)
+            .expect("applied migrations should verify")
+            .is_empty());
+
+        let (version, _, _) = MIGRATIONS[0];
+        conn.execute(
+            "UPDATE schema_migrations SET checksum = 'edited' WHERE version = ?",
+            [version],
+        )
+        .expect("checksum should be updatable");
+        let error = apply_migrations(&conn).expect_err("edited migration should be rejected");
+        assert_eq!(error.code, ErrorCode::Conflict);
+        assert_eq!(error.reason.as_deref(), Some("MIGRATION_CHECKSUM_MISMATCH"));
+
+        conn.execute(
+            "UPDATE schema_migrations SET checksum = NULL WHERE version = ?",
+            [version],
+        )
+        .expect("checksum should be clearable");
+        conn.execute(
+            "INSERT INTO schema_migrations (version) VALUES ('99991231_from_the_future')",
+            [],
+        )
+        .expect("future migration should be recordable");
+        let error = apply_migrations(&conn).expect_err("newer schema should be rejected");
+        assert_eq!(error.reason.as_deref(), Some("SCHEMA_NEWER_THAN_APP"));
+    }
+
+    #[test]
+    fn rollback_to_reverts_newer_migrations_and_reapplies_cleanly() {
+        let conn = setup_conn();
+        apply_migrations(&conn).expect("migrations should apply");
+
+        let reverted = rollback_to(&conn, "20261023_create_kanban_status_transition_table")
+            .expect("newest migrations should roll back");
+        assert_eq!(
+            reverted,
+            vec![
+                "20261027_create_kanban_template_tables",
+                "20261026_add_dates_to_kanban_assignment",
+                "20261025_create_kanban_automation_rule_table",
+                "20261024_add_wip_limits_and_transition_rules",
+            ]
+        );
+        let remaining: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master
+                 WHERE name IN ('kanban_template', 'kanban_automation_rule', 'kanban_status_transition_rule')",
+                [],
+                |row| row.get(0),
+            )
+            .expect("tables should be countable");
+        assert_eq!(remaining, 0);
+        assert_eq!(
+            pending_migrations(&conn).expect("pending migrations should load"),
+            reverted.iter().rev().copied().collect::<Vec<_>>()
+        );
+
+        apply_migrations(&conn).expect("reverted migrations should apply again");
+        assert!(pending_migrations(&conn)
+            .expect("applied migrations should verify")
+            .is_empty());
+
+        let error = rollback_to(&conn, "20250101_create_workspace_table")
+            .expect_err("irreversible migrations should block the rollback");
+        assert_eq!(error.reason.as_deref(), Some("MIGRATION_NOT_REVERSIBLE"));
+        assert!(pending_migrations(&conn)
+            .expect("applied migrations should verify")
+            .is_empty());
+    }
+
+    #[test]
+    fn failed_migration_leaves_no_partial_schema() {
+        let conn = setup_conn();
+        super::ensure_checksum_column(&conn).expect("checksum column should be added");
+
+        apply_migration(
+            &conn,
+            "20990101_broken",
+            "CREATE TABLE half_applied (id INTEGER PRIMARY KEY);
+            CREATE TABLE half_applied (id INTEGER PRIMARY KEY);",
+        )
+        .expect_err("duplicate table should fail the migration");
+
+        let leftovers: i64 = conn
+            .query_row(
+                "SELECT (SELECT COUNT(*) FROM schema_migrations)
+                    + (SELECT COUNT(*) FROM sqlite_master WHERE name = 'half_applied')",
+                [],
+                |row| row.get(0),
+            )
+            .expect("leftovers should be countable");
+        assert_eq!(leftovers, 0);
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrphanedAsset {
+    pub file_name: String,
+    pub size: u64,
+}
+
+/// Files in the asset directory that no memo, thumbnail or template references. Pass
+/// `confirmation_token` to `clean_orphaned_assets`; it stops matching when the set of
+/// orphaned files changes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrphanedAssetScan {
+    pub files: Vec<OrphanedAsset>,
+    pub total_size: u64,
+    pub confirmation_token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrphanedAssetCleanup {
+    pub removed_count: usize,
+    pub freed_size: u64,
+    /// Folder the files were moved to; `None` when they were deleted.
+    pub quarantine_path: Option<String>,
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::kanban_assignment::KanbanDatedItem;
+
 #[derive(Serialize, Deserialize)]
 pub struct CalendarDayMemo {
     pub id: i32,
@@ -7,6 +9,8 @@ pub struct CalendarDayMemo {
     pub title: String,
 }
 
+/// A date with a note, linked memos or dated kanban assignments. Dates that only have
+/// kanban assignments are not stored and come back with `id` 0.
 #[derive(Serialize, Deserialize)]
 pub struct CalendarDay {
     pub id: i32,
@@ -14,4 +18,49 @@ pub struct CalendarDay {
     pub note: Option<String>,
     pub is_non_working: bool,
     pub memos: Vec<CalendarDayMemo>,
+    /// Assignments due or scheduled on this date.
+    #[serde(default)]
+    pub kanban_items: Vec<KanbanDatedItem>,
+}
+
+/// One VEVENT read from an ICS file, reduced to the dates it covers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalendarImportEvent {
+    /// Line of `BEGIN:VEVENT` in the unfolded file, starting at 1.
+    pub line: u64,
+    pub summary: Option<String>,
+    /// `YYYY-MM-DD`, or `YYYY-MM-DDTHH:MM:SSZ` for a UTC start time, which the import
+    /// moves to the local date. `None` when `DTSTART` is missing or unreadable.
+    pub start: Option<String>,
+    /// Exclusive `YYYY-MM-DD` end of an all-day event. `None` covers only `start`.
+    pub end: Option<String>,
+    pub cancelled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CalendarImportDay {
+    pub date: String,
+    pub previous_note: Option<String>,
+    pub note: Option<String>,
+    pub was_non_working: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CalendarImportSkippedEvent {
+    pub line: u64,
+    pub summary: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct CalendarImportSummary {
+    /// Nothing was written; the summary shows what the import would do.
+    pub dry_run: bool,
+    /// Dates that had no calendar day yet.
+    pub created: Vec<CalendarImportDay>,
+    /// Dates whose note or non-working flag changed.
+    pub updated: Vec<CalendarImportDay>,
+    /// Dates that were already non-working with the resulting note.
+    pub unchanged: Vec<String>,
+    pub skipped: Vec<CalendarImportSkippedEvent>,
 }
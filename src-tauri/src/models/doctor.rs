@@ -0,0 +1,66 @@
+use serde::Serialize;
+
+/// Result of a database health check. Each list holds the problems found by one check.
+#[derive(Debug, Default, Serialize)]
+pub struct DoctorReport {
+    pub healthy: bool,
+    /// Messages from `PRAGMA integrity_check`; empty when the database file is sound.
+    pub integrity_errors: Vec<String>,
+    pub foreign_key_violations: Vec<ForeignKeyViolation>,
+    pub search_index_issues: Vec<SearchIndexIssue>,
+    pub link_issues: Vec<LinkIssue>,
+    pub memo_file_issues: Vec<MemoFileIssue>,
+    pub missing_files: Vec<MissingFile>,
+    pub kanban_issues: Vec<KanbanIssue>,
+    /// What repair mode changed; empty for a plain check.
+    pub repaired: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ForeignKeyViolation {
+    pub table: String,
+    pub rowid: Option<i64>,
+    pub parent: String,
+}
+
+/// `issue` is one of `missing_index_row`, `duplicate_index_row`, `stale_index_row`,
+/// `orphaned_index_row` or `stale_body_text`.
+#[derive(Debug, Serialize)]
+pub struct SearchIndexIssue {
+    pub memo_id: i64,
+    pub issue: String,
+}
+
+/// `issue` is `missing_row` when the memo content links to the memo but no `link` row exists,
+/// or `not_in_content` for a `link` row the content no longer backs.
+#[derive(Debug, Serialize)]
+pub struct LinkIssue {
+    pub from_memo_id: i32,
+    pub to_memo_id: i32,
+    pub issue: String,
+}
+
+/// Same `issue` values as `LinkIssue`, comparing `memo_files` with `fileLink` marks.
+#[derive(Debug, Serialize)]
+pub struct MemoFileIssue {
+    pub memo_id: i32,
+    pub file_id: String,
+    pub issue: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MissingFile {
+    pub file_id: String,
+    pub display_name: String,
+    pub relative_path: Option<String>,
+}
+
+/// `issue` is one of `status_from_other_kanban`, `workspace_mismatch`,
+/// `invalid_default_status`, `invalid_focus_status` or `invalid_done_status`.
+#[derive(Debug, Serialize)]
+pub struct KanbanIssue {
+    pub kanban_id: i32,
+    pub assignment_id: Option<i32>,
+    pub memo_id: Option<i32>,
+    pub issue: String,
+}
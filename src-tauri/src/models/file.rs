@@ -1,5 +1,37 @@
 use serde::{Deserialize, Serialize};
 
+/// What importing does with the source entry, read from `AppConfig::import_policy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImportPolicy {
+    Move,
+    Copy,
+    CopyThenTrash,
+}
+
+impl ImportPolicy {
+    /// Unknown values fall back to `Move`, the behaviour before the setting existed.
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "copy" => ImportPolicy::Copy,
+            "copy_then_trash" => ImportPolicy::CopyThenTrash,
+            _ => ImportPolicy::Move,
+        }
+    }
+
+    /// Moving to the trash is only implemented on Unix.
+    pub fn is_supported(self) -> bool {
+        self != ImportPolicy::CopyThenTrash || cfg!(unix)
+    }
+
+    pub fn as_setting(self) -> &'static str {
+        match self {
+            ImportPolicy::Move => "move",
+            ImportPolicy::Copy => "copy",
+            ImportPolicy::CopyThenTrash => "copy_then_trash",
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ManagedFileListItem {
     pub id: String,
@@ -8,6 +40,12 @@ pub struct ManagedFileListItem {
     pub display_name: String,
     pub imported_at: String,
     pub related_memo_count: i64,
+    /// Matching text around the search query; only set for search results.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+    /// Only filled for search results.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_memos: Vec<RelatedMemoSummary>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -74,6 +112,9 @@ pub struct ManagedFileRecord {
     pub relative_path: Option<String>,
     pub url: Option<String>,
     pub imported_at: String,
+    /// Set when an import matched an existing file by content and returned it instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import_notice: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -81,3 +122,41 @@ pub struct ResolvedFileOpenTarget {
     pub open_kind: String,
     pub value: String,
 }
+
+/// An entry in the files storage folder that no record points at. `embedded_file_id` is the
+/// id in a `__mb_<id>` name whose record no longer exists.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StorageEntry {
+    pub relative_path: String,
+    pub entry_type: String,
+    pub embedded_file_id: Option<String>,
+}
+
+/// A record whose stored path is gone. `candidates` lists the entries that carry its id when
+/// there are several, so it could not be relinked automatically.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MissingManagedFile {
+    pub file_id: String,
+    pub display_name: String,
+    pub relative_path: Option<String>,
+    pub candidates: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RelinkedManagedFile {
+    pub file_id: String,
+    pub display_name: String,
+    pub previous_relative_path: Option<String>,
+    pub relative_path: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StorageVerificationReport {
+    pub checked_count: i64,
+    pub missing: Vec<MissingManagedFile>,
+    pub relinked: Vec<RelinkedManagedFile>,
+    /// Entries named with a file id that has no record.
+    pub unknown: Vec<StorageEntry>,
+    /// Entries without a file id, which can be adopted as records.
+    pub unmanaged: Vec<StorageEntry>,
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// One entry the inbox watcher handled. `status` is `imported`, `duplicate` or `failed`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct InboxActivity {
+    pub id: i64,
+    pub watched_folder: String,
+    pub source_path: String,
+    pub rule_pattern: Option<String>,
+    pub status: String,
+    pub file_id: Option<String>,
+    pub display_name: Option<String>,
+    pub memo_id: Option<i32>,
+    pub message: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct InboxActivityPage {
+    pub items: Vec<InboxActivity>,
+    pub total_count: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
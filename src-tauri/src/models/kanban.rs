@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct Kanban {
     pub id: i32,
     pub workspace_id: i32,
@@ -8,6 +8,7 @@ pub struct Kanban {
     pub order_index: i32,
     pub default_status_id: Option<i32>,
     pub focus_status_id: Option<i32>,
+    pub done_status_id: Option<i32>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize)]
+use crate::models::kanban_automation::KanbanAutomationOutcome;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct KanbanAssignmentItem {
     pub memo_id: i32,
     pub slug_title: String,
@@ -10,6 +12,8 @@ pub struct KanbanAssignmentItem {
     pub position: Option<i64>,
     pub modified_at: String,
     pub kanban_id: i32,
+    pub due_on: Option<String>,
+    pub scheduled_on: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -20,4 +24,43 @@ pub struct KanbanAssignmentEntry {
     pub kanban_status_name: Option<String>,
     pub kanban_status_color: Option<String>,
     pub position: Option<i64>,
+    pub due_on: Option<String>,
+    pub scheduled_on: Option<String>,
+}
+
+/// Assignment with a due or scheduled date, listed across every kanban of a workspace.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanDatedItem {
+    pub memo_id: i32,
+    pub slug_title: String,
+    pub title: String,
+    pub kanban_id: i32,
+    pub kanban_name: String,
+    pub kanban_status_id: Option<i32>,
+    pub kanban_status_name: Option<String>,
+    pub due_on: Option<String>,
+    pub scheduled_on: Option<String>,
+    /// Whether the memo sits in the board's Done status.
+    pub is_done: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KanbanCardPosition {
+    pub memo_id: i32,
+    pub position: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanCardMoveResult {
+    /// Every position that changed, the moved card first. Holds the whole column when it
+    /// had to be renumbered.
+    pub positions: Vec<KanbanCardPosition>,
+    pub outcomes: Vec<KanbanAutomationOutcome>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanBulkMoveResult {
+    /// Memos that changed status. Memos already in the target status are left in place.
+    pub moved_count: usize,
+    pub outcomes: Vec<KanbanAutomationOutcome>,
 }
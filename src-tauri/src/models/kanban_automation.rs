@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Action run when a memo's status changes on a kanban.
+///
+/// - `trigger_type`: `enter_status` or `leave_status` (both use `trigger_status_id`), or
+///   `enter_done_status` for the board's Done role.
+/// - `condition_type`: `always`, `from_status` (the previous status is
+///   `condition_status_id`) or `has_milestone` (the memo is on an open milestone).
+/// - `action_type`: `complete_milestones`, `mark_focus_done_today`, `add_to_today` or
+///   `add_bookmark`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanAutomationRule {
+    pub id: i32,
+    pub workspace_id: i32,
+    pub kanban_id: i32,
+    pub trigger_type: String,
+    pub trigger_status_id: Option<i32>,
+    pub condition_type: String,
+    pub condition_status_id: Option<i32>,
+    pub action_type: String,
+    pub enabled: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanAutomationRuleInput {
+    pub trigger_type: String,
+    pub trigger_status_id: Option<i32>,
+    #[serde(default = "default_condition_type")]
+    pub condition_type: String,
+    pub condition_status_id: Option<i32>,
+    pub action_type: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+/// A rule whose action changed something during a status update.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KanbanAutomationOutcome {
+    pub rule_id: i32,
+    pub action_type: String,
+}
+
+fn default_condition_type() -> String {
+    "always".to_string()
+}
+
+fn default_enabled() -> bool {
+    true
+}
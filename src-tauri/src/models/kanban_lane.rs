@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::kanban_assignment::KanbanAssignmentItem;
+
+/// Cards of a board that share one value of the grouping dimension, split by the board's
+/// statuses.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanLane {
+    /// `milestone:<id>`, `bookmarked`, `status:<id>` or `no_status`, and `none` for the lane
+    /// of cards without a value.
+    pub key: String,
+    pub label: String,
+    pub total_count: usize,
+    /// One bucket per board status in board order, the same for every lane, so lanes line
+    /// up as columns.
+    pub buckets: Vec<KanbanLaneBucket>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanLaneBucket {
+    /// `None` holds the cards on the board without a status. That bucket is only present
+    /// when the board has such cards.
+    pub kanban_status_id: Option<i32>,
+    pub count: usize,
+    pub items: Vec<KanbanAssignmentItem>,
+}
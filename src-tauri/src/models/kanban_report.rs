@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+/// One logged status change. A `None` status means the memo was not on the board, or had
+/// no status there.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanStatusTransition {
+    pub id: i64,
+    pub kanban_id: i32,
+    pub memo_id: i32,
+    pub memo_slug_title: String,
+    pub from_status_id: Option<i32>,
+    pub from_status_name: Option<String>,
+    pub to_status_id: Option<i32>,
+    pub to_status_name: Option<String>,
+    pub transitioned_at: String,
+}
+
+/// Memos that reached the Done status during the week starting on Monday `week_start`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanThroughputWeek {
+    pub week_start: String,
+    pub completed_count: i64,
+}
+
+/// Average stay in one status, over the stays that overlap the report range. Stays that
+/// have not ended yet count up to now.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanStatusDuration {
+    pub status_id: i32,
+    pub status_name: String,
+    pub stay_count: i64,
+    pub average_hours: Option<f64>,
+}
+
+/// Lead time runs from entering the default status to reaching Done; cycle time from the
+/// first move past the default status to reaching Done.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanFlowTimes {
+    pub completed_count: i64,
+    pub average_lead_time_hours: Option<f64>,
+    pub median_lead_time_hours: Option<f64>,
+    pub average_cycle_time_hours: Option<f64>,
+    pub median_cycle_time_hours: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanStatusCount {
+    pub status_id: i32,
+    pub count: i64,
+}
+
+/// Memos per status at the end of `date`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanCumulativeFlowDay {
+    pub date: String,
+    pub counts: Vec<KanbanStatusCount>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanReportStatus {
+    pub id: i32,
+    pub name: String,
+    pub color: Option<String>,
+}
+
+/// Flow metrics for one board between `date_from` and `date_to`, both local dates.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanReport {
+    pub kanban_id: i32,
+    pub date_from: String,
+    pub date_to: String,
+    pub default_status_id: Option<i32>,
+    pub done_status_id: Option<i32>,
+    pub statuses: Vec<KanbanReportStatus>,
+    pub throughput: Vec<KanbanThroughputWeek>,
+    pub time_in_status: Vec<KanbanStatusDuration>,
+    pub flow_times: KanbanFlowTimes,
+    pub cumulative_flow: Vec<KanbanCumulativeFlowDay>,
+}
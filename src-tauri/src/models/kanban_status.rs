@@ -8,6 +8,16 @@ pub struct KanbanStatus {
     pub name: String,
     pub color: Option<String>,
     pub order_index: i32,
+    /// Maximum number of memos allowed in this status, or `None` for no limit.
+    pub wip_limit: Option<i32>,
     pub created_at: String,
     pub updated_at: String,
 }
+
+/// Allowed move between two statuses of the same kanban. A status without outgoing rules
+/// accepts any move.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct KanbanStatusTransitionRule {
+    pub from_status_id: i32,
+    pub to_status_id: i32,
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::kanban::Kanban;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KanbanTemplateStatus {
+    pub name: String,
+    pub color: Option<String>,
+    pub wip_limit: Option<i32>,
+    /// Becomes the board's `default_status_id`.
+    #[serde(default)]
+    pub is_default: bool,
+    /// Becomes the board's `focus_status_id`.
+    #[serde(default)]
+    pub is_focus: bool,
+    /// Becomes the board's `done_status_id`.
+    #[serde(default)]
+    pub is_done: bool,
+}
+
+/// Named set of statuses a new board can start from.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KanbanTemplate {
+    pub id: i32,
+    pub workspace_id: i32,
+    pub name: String,
+    /// In board order.
+    pub statuses: Vec<KanbanTemplateStatus>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct KanbanCloneResult {
+    pub kanban: Kanban,
+    pub copied_assignment_count: usize,
+    /// Memos on the source board that have no memo with the same slug in the target
+    /// workspace.
+    pub skipped_memo_slugs: Vec<String>,
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+pub const KANBAN_EXPORT_FORMAT: &str = "monobox-kanban-export";
+pub const KANBAN_EXPORT_VERSION: u32 = 1;
+
+/// Snapshot of a kanban board for people who don't use monobox. Items follow the board
+/// order: status by status, then by position.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct KanbanExport {
+    pub format: String,
+    pub version: u32,
+    pub kanban_name: String,
+    pub statuses: Vec<String>,
+    pub items: Vec<KanbanExportItem>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KanbanExportItem {
+    pub title: String,
+    pub slug: String,
+    pub status: Option<String>,
+    pub position: Option<i64>,
+    pub modified_at: String,
+    pub description: Option<String>,
+}
+
+/// One CSV record to import. Blank cells are `None`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KanbanImportRow {
+    /// Line of the record in the file, counting the header as line 1.
+    pub line: u64,
+    pub title: Option<String>,
+    pub slug: Option<String>,
+    pub status: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KanbanImportSkippedRow {
+    pub line: u64,
+    pub title: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct KanbanImportSummary {
+    /// Slugs of memos created by the import.
+    pub created: Vec<String>,
+    /// Slugs of existing memos that were added to the board or changed status.
+    pub updated: Vec<String>,
+    /// Slugs of existing memos that were already in the requested status.
+    pub unchanged: Vec<String>,
+    pub created_statuses: Vec<String>,
+    pub skipped: Vec<KanbanImportSkippedRow>,
+}
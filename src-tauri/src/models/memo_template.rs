@@ -8,6 +8,10 @@ pub struct MemoTemplateDetail {
     pub content: String,
     pub is_default: bool,
     pub workspace_id: i32,
+    pub kanban_id: Option<i32>,
+    pub kanban_status_id: Option<i32>,
+    pub milestone_id: Option<i32>,
+    pub attach_to_today: bool,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -21,3 +25,56 @@ pub struct MemoTemplateIndexItem {
     pub created_at: String,
     pub updated_at: String,
 }
+
+/// Where a memo created from the template lands, besides its content.
+#[derive(Serialize, Deserialize, Default)]
+pub struct MemoTemplateWorkflow {
+    pub kanban_id: Option<i32>,
+    /// Falls back to the kanban's default status when unset.
+    pub kanban_status_id: Option<i32>,
+    pub milestone_id: Option<i32>,
+    #[serde(default)]
+    pub attach_to_today: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExpandedMemoTemplate {
+    pub content: String,
+    /// ProseMirror position where `{{cursor}}` was placed, if the template had one.
+    pub cursor_position: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MemoTemplateCopyResult {
+    pub template: MemoTemplateDetail,
+    /// Internal links left pointing at the source workspace because no memo with that slug exists in the target.
+    pub unresolved_hrefs: Vec<String>,
+}
+
+pub const MEMO_TEMPLATE_PACK_FORMAT: &str = "monobox-template-pack";
+pub const MEMO_TEMPLATE_PACK_VERSION: u32 = 1;
+
+/// Portable file format for sharing templates between databases.
+#[derive(Serialize, Deserialize)]
+pub struct MemoTemplatePack {
+    pub format: String,
+    pub version: u32,
+    /// Workspace the templates were exported from; hrefs under it are rewritten on import.
+    pub source_workspace_slug_name: String,
+    pub templates: Vec<MemoTemplatePackItem>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MemoTemplatePackItem {
+    pub slug_name: String,
+    pub name: String,
+    pub content: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct MemoTemplatePackImportSummary {
+    pub imported: Vec<String>,
+    pub overwritten: Vec<String>,
+    pub skipped: Vec<String>,
+    pub unresolved_hrefs: Vec<String>,
+}
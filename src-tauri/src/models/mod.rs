@@ -1,15 +1,24 @@
+pub mod asset;
 pub mod bookmark;
 pub mod calendar_day;
+pub mod doctor;
 pub mod file;
 pub mod focus_daily_state;
+pub mod inbox_activity;
 pub mod kanban;
 pub mod kanban_assignment;
+pub mod kanban_automation;
+pub mod kanban_lane;
+pub mod kanban_report;
 pub mod kanban_status;
+pub mod kanban_template;
+pub mod kanban_transfer;
 pub mod link;
 pub mod memo;
 pub mod memo_template;
 pub mod milestone;
 pub mod workspace;
+pub mod workspace_transfer;
 
 pub use link::{Link, LinkId, MemoLinkCount};
 pub use memo::MemoIndexItem;
@@ -5,6 +5,20 @@ pub struct Workspace {
     pub id: i32,
     pub slug_name: String,
     pub name: String,
+    pub archived_at: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
+
+/// What `delete_workspace` would remove. Pass `confirmation_token` back to confirm; it
+/// stops matching as soon as the workspace changes.
+#[derive(Serialize, Deserialize)]
+pub struct WorkspaceDeletionSummary {
+    pub workspace_slug_name: String,
+    pub memo_count: i64,
+    pub file_link_count: i64,
+    pub kanban_count: i64,
+    /// Assets referenced only by this workspace, which become unreferenced after deletion.
+    pub asset_count: i64,
+    pub confirmation_token: String,
+}
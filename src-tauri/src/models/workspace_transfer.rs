@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const WORKSPACE_EXPORT_FORMAT: &str = "monobox-workspace-export";
+pub const WORKSPACE_EXPORT_VERSION: u32 = 1;
+
+/// Single-file representation of a workspace. Rows reference each other by slug or name
+/// instead of database ids so the file can be imported into any database.
+#[derive(Serialize, Deserialize)]
+pub struct WorkspaceExport {
+    pub format: String,
+    pub version: u32,
+    pub workspace: ExportedWorkspace,
+    pub memos: Vec<ExportedMemo>,
+    pub links: Vec<ExportedLink>,
+    pub templates: Vec<ExportedMemoTemplate>,
+    pub kanbans: Vec<ExportedKanban>,
+    pub calendar_days: Vec<ExportedCalendarDay>,
+    pub milestones: Vec<ExportedMilestone>,
+    pub bookmarks: Vec<ExportedBookmark>,
+    #[serde(default)]
+    pub files: Vec<ExportedFile>,
+    /// Images referenced through asset URLs; empty for clones.
+    #[serde(default)]
+    pub assets: Vec<ExportedAsset>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedWorkspace {
+    pub slug_name: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedMemo {
+    pub slug_title: String,
+    pub title: String,
+    pub content: Value,
+    pub description: Option<String>,
+    pub thumbnail_image: Option<String>,
+    pub created_at: Option<String>,
+    pub modified_at: Option<String>,
+    #[serde(default)]
+    pub file_ids: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedLink {
+    pub from_slug_title: String,
+    pub to_slug_title: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedMemoTemplate {
+    pub slug_name: String,
+    pub name: String,
+    pub content: Value,
+    pub is_default: bool,
+    pub kanban_name: Option<String>,
+    pub kanban_status_name: Option<String>,
+    /// Index into `WorkspaceExport::milestones`, since milestone titles are not unique.
+    pub milestone_index: Option<usize>,
+    pub attach_to_today: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedKanban {
+    pub name: String,
+    pub order_index: i64,
+    pub default_status_name: Option<String>,
+    pub focus_status_name: Option<String>,
+    #[serde(default)]
+    pub done_status_name: Option<String>,
+    pub statuses: Vec<ExportedKanbanStatus>,
+    pub assignments: Vec<ExportedKanbanAssignment>,
+    #[serde(default)]
+    pub transition_rules: Vec<ExportedKanbanTransitionRule>,
+    #[serde(default)]
+    pub automation_rules: Vec<ExportedKanbanAutomationRule>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedKanbanStatus {
+    pub name: String,
+    pub color: Option<String>,
+    pub order_index: i64,
+    #[serde(default)]
+    pub wip_limit: Option<i32>,
+}
+
+/// Allowed move between two statuses of the same board, by status name.
+#[derive(Serialize, Deserialize)]
+pub struct ExportedKanbanTransitionRule {
+    pub from_status_name: String,
+    pub to_status_name: String,
+}
+
+/// Automation rule of a board, with its statuses by name. See `KanbanAutomationRule`.
+#[derive(Serialize, Deserialize)]
+pub struct ExportedKanbanAutomationRule {
+    pub trigger_type: String,
+    pub trigger_status_name: Option<String>,
+    pub condition_type: String,
+    pub condition_status_name: Option<String>,
+    pub action_type: String,
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedKanbanAssignment {
+    pub memo_slug_title: String,
+    pub status_name: Option<String>,
+    pub position: Option<i64>,
+    #[serde(default)]
+    pub due_on: Option<String>,
+    #[serde(default)]
+    pub scheduled_on: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedCalendarDay {
+    pub date: String,
+    pub note: Option<String>,
+    pub is_non_working: bool,
+    pub memo_slug_titles: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedMilestone {
+    pub date: String,
+    pub title: String,
+    pub completed_at: Option<String>,
+    pub memo_slug_titles: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedBookmark {
+    pub memo_slug_title: String,
+    pub order_index: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedFile {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub file_type: String,
+    pub display_name: String,
+    pub note: Option<String>,
+    pub relative_path: Option<String>,
+    pub url: Option<String>,
+    pub imported_at: String,
+    /// File contents; a single entry with an empty path for local files, one per file for
+    /// local directories. Empty when the file was missing from storage at export time.
+    #[serde(default)]
+    pub entries: Vec<ExportedFileEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedFileEntry {
+    pub path: String,
+    /// Base64 encoded contents.
+    pub data: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedAsset {
+    pub file_name: String,
+    /// Base64 encoded contents.
+    pub data: String,
+}
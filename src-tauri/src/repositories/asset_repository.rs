@@ -0,0 +1,261 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+use rusqlite::{Connection, OptionalExtension};
+
+use crate::errors::AppError;
+use crate::models::asset::{OrphanedAsset, OrphanedAssetCleanup, OrphanedAssetScan};
+
+/// Folder inside the asset directory that receives quarantined assets.
+pub const ASSET_QUARANTINE_DIR: &str = ".quarantine";
+
+/// Prefixes the webview uses for files in the asset directory: `asset://localhost` on macOS
+/// and Linux, `http://asset.localhost` on Windows.
+const ASSET_URL_PREFIXES: &[&str] = &[
+    "asset://localhost/monobox/",
+    "http://asset.localhost/monobox/",
+];
+
+pub struct AssetRepository;
+
+impl AssetRepository {
+    /// Lists regular files directly in `asset_dir` that no memo content, memo thumbnail
+    /// or template content references. Hidden files such as the quarantine folder are
+    /// skipped.
+    pub fn scan_orphans(
+        conn: &Connection,
+        asset_dir: &Path,
+    ) -> Result<OrphanedAssetScan, AppError> {
+        let referenced = referenced_asset_file_names(conn)?;
+
+        let mut files = Vec::new();
+        if asset_dir.is_dir() {
+            for entry in fs::read_dir(asset_dir)? {
+                let entry = entry?;
+                let file_name = entry.file_name().to_string_lossy().into_owned();
+                let metadata = entry.metadata()?;
+                if !metadata.is_file()
+                    || file_name.starts_with('.')
+                    || referenced.contains(&file_name)
+                {
+                    continue;
+                }
+                files.push(OrphanedAsset {
+                    file_name,
+                    size: metadata.len(),
+                });
+            }
+        }
+        files.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+
+        let mut hasher = DefaultHasher::new();
+        for file in &files {
+            (&file.file_name, file.size).hash(&mut hasher);
+        }
+
+        Ok(OrphanedAssetScan {
+            total_size: files.iter().map(|file| file.size).sum(),
+            files,
+            confirmation_token: format!("{:016x}", hasher.finish()),
+        })
+    }
+
+    /// Returns the asset file name previously saved with `content_hash`.
+    pub fn find_by_hash(conn: &Connection, content_hash: &str) -> Result<Option<String>, AppError> {
+        conn.query_row(
+            "SELECT file_name FROM asset_hash WHERE content_hash = ?",
+            [content_hash],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(AppError::from)
+    }
+
+    pub fn record_hash(
+        conn: &Connection,
+        content_hash: &str,
+        file_name: &str,
+    ) -> Result<(), AppError> {
+        conn.execute(
+            "INSERT OR REPLACE INTO asset_hash (content_hash, file_name) VALUES (?, ?)",
+            [content_hash, file_name],
+        )?;
+        Ok(())
+    }
+
+    /// Moves the orphaned assets into `asset_dir/.quarantine`, or deletes them when
+    /// `permanently_delete` is set. The scan is repeated first and must still match
+    /// `confirmation_token`.
+    pub fn clean_orphans(
+        conn: &Connection,
+        asset_dir: &Path,
+        confirmation_token: &str,
+        permanently_delete: bool,
+    ) -> Result<OrphanedAssetCleanup, AppError> {
+        let scan = Self::scan_orphans(conn, asset_dir)?;
+        if scan.confirmation_token != confirmation_token {
+            return Err(AppError::conflict(
+                "Orphaned assets changed since the scan. Review them again before cleaning up.",
+            ));
+        }
+
+        let quarantine_dir = asset_dir.join(ASSET_QUARANTINE_DIR);
+        if !permanently_delete && !scan.files.is_empty() {
+            fs::create_dir_all(&quarantine_dir)
+                .map_err(|e| AppError::io(format!("Failed to create directory: {}", e)))?;
+        }
+
+        let mut cleanup = OrphanedAssetCleanup {
+            removed_count: 0,
+            freed_size: 0,
+            quarantine_path: (!permanently_delete)
+                .then(|| quarantine_dir.to_string_lossy().into_owned()),
+        };
+        for file in &scan.files {
+            let path = asset_dir.join(&file.file_name);
+            let result = if permanently_delete {
+                fs::remove_file(&path)
+            } else {
+                fs::rename(&path, quarantine_dir.join(&file.file_name))
+            };
+            result.map_err(|e| {
+                AppError::io(format!("Failed to remove asset {}: {}", file.file_name, e))
+            })?;
+            conn.execute(
+                "DELETE FROM asset_hash WHERE file_name = ?",
+                [&file.file_name],
+            )?;
+            cleanup.removed_count += 1;
+            cleanup.freed_size += file.size;
+        }
+
+        Ok(cleanup)
+    }
+}
+
+fn referenced_asset_file_names(conn: &Connection) -> Result<BTreeSet<String>, AppError> {
+    let mut referenced = BTreeSet::new();
+    let mut stmt = conn.prepare(
+        "SELECT content, thumbnail_image FROM memo
+        UNION ALL
+        SELECT content, NULL FROM memo_template",
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        for index in [0, 1] {
+            if let Some(text) = row.get::<_, Option<String>>(index)? {
+                collect_asset_file_names(&text, &mut referenced);
+            }
+        }
+    }
+    Ok(referenced)
+}
+
+/// Returns what follows the asset URL prefix in `src`, or `None` for other URLs.
+pub(crate) fn strip_asset_url_prefix(src: &str) -> Option<&str> {
+    ASSET_URL_PREFIXES
+        .iter()
+        .find_map(|prefix| src.strip_prefix(prefix))
+}
+
+/// Collects `<file_name>` from every asset URL in `text`, whichever prefix it uses.
+pub(crate) fn collect_asset_file_names(text: &str, out: &mut BTreeSet<String>) {
+    for prefix in ASSET_URL_PREFIXES {
+        for (start, _) in text.match_indices(prefix) {
+            let rest = &text[start + prefix.len()..];
+            let file_name: String = rest
+                .chars()
+                .take_while(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | '_'))
+                .collect();
+            if !file_name.is_empty() {
+                out.insert(file_name);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect_asset_file_names, AssetRepository, ASSET_QUARANTINE_DIR};
+    use crate::migrations::apply_migrations;
+    use crate::repositories::{MemoRepository, WorkspaceRepository};
+    use rusqlite::Connection;
+    use std::collections::BTreeSet;
+    use std::fs;
+    use uuid::Uuid;
+
+    #[test]
+    fn clean_orphans_quarantines_only_unreferenced_assets() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        MemoRepository::create(
+            &conn,
+            workspace.id,
+            "photo",
+            "Photo",
+            r#"{"type":"doc","content":[{"type":"image","attrs":{"src":"asset://localhost/monobox/kept.png"}}]}"#,
+        )
+        .expect("memo should be created");
+        MemoRepository::create(
+            &conn,
+            workspace.id,
+            "windows-photo",
+            "Windows photo",
+            r#"{"type":"doc","content":[{"type":"image","attrs":{"src":"http://asset.localhost/monobox/kept-too.png"}}]}"#,
+        )
+        .expect("memo should be created");
+
+        let asset_dir = std::env::temp_dir().join(format!("monobox-asset-test-{}", Uuid::new_v4()));
+        fs::create_dir_all(&asset_dir).expect("asset folder should be created");
+        fs::write(asset_dir.join("kept.png"), "kept").expect("asset should be written");
+        fs::write(asset_dir.join("kept-too.png"), "kept").expect("asset should be written");
+        fs::write(asset_dir.join("orphan.png"), "orphan").expect("asset should be written");
+
+        let scan = AssetRepository::scan_orphans(&conn, &asset_dir).expect("scan should run");
+        assert_eq!(scan.files.len(), 1);
+        assert_eq!(scan.files[0].file_name, "orphan.png");
+        assert_eq!(scan.total_size, 6);
+
+        assert!(AssetRepository::clean_orphans(&conn, &asset_dir, "stale", false).is_err());
+        let cleanup =
+            AssetRepository::clean_orphans(&conn, &asset_dir, &scan.confirmation_token, false)
+                .expect("cleanup should run");
+        assert_eq!((cleanup.removed_count, cleanup.freed_size), (1, 6));
+        assert!(asset_dir.join("kept.png").is_file());
+        assert!(asset_dir.join("kept-too.png").is_file());
+        assert!(asset_dir
+            .join(ASSET_QUARANTINE_DIR)
+            .join("orphan.png")
+            .is_file());
+        assert!(AssetRepository::scan_orphans(&conn, &asset_dir)
+            .expect("scan should run")
+            .files
+            .is_empty());
+
+        fs::remove_dir_all(asset_dir).expect("temp folders should be removed");
+    }
+
+    #[test]
+    fn collect_asset_file_names_reads_both_asset_url_forms() {
+        let mut names = BTreeSet::new();
+        collect_asset_file_names(
+            r#"{"src":"asset://localhost/monobox/a1.png"} http://asset.localhost/monobox/b-2.jpg""#,
+            &mut names,
+        );
+
+        assert_eq!(
+            names.into_iter().collect::<Vec<_>>(),
+            vec!["a1.png".to_string(), "b-2.jpg".to_string()]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::errors::AppError;
 use crate::models::bookmark::Bookmark;
 use rusqlite::{Connection, Result};
 
@@ -7,15 +8,13 @@ impl BookmarkRepository {
     pub fn list_by_workspace(
         conn: &Connection,
         workspace_id: i32,
-    ) -> Result<Vec<Bookmark>, String> {
-        let mut stmt = conn
-            .prepare(
-                "SELECT id, workspace_id, memo_id, order_index, created_at
+    ) -> Result<Vec<Bookmark>, AppError> {
+        let mut stmt = conn.prepare(
+            "SELECT id, workspace_id, memo_id, order_index, created_at
                  FROM bookmark
                  WHERE workspace_id = ?
                  ORDER BY order_index ASC, created_at ASC, id ASC",
-            )
-            .map_err(|e| e.to_string())?;
+        )?;
 
         let bookmarks = stmt
             .query_map([workspace_id], |row| {
@@ -26,10 +25,8 @@ impl BookmarkRepository {
                     order_index: row.get(3)?,
                     created_at: row.get(4)?,
                 })
-            })
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(bookmarks)
     }
@@ -62,7 +59,7 @@ impl BookmarkRepository {
         memo_id: i32,
         target_memo_id: i32,
         position: &str,
-    ) -> Result<(), String> {
+    ) -> Result<(), AppError> {
         if memo_id == target_memo_id {
             return Ok(());
         }
@@ -72,22 +69,29 @@ impl BookmarkRepository {
         let current_index = bookmarks
             .iter()
             .position(|bookmark| bookmark.memo_id == memo_id)
-            .ok_or_else(|| format!("Bookmark not found for memo_id: {}", memo_id))?;
+            .ok_or_else(|| {
+                AppError::not_found(format!("Bookmark not found for memo_id: {}", memo_id))
+            })?;
         let current = bookmarks.remove(current_index);
 
         let target_index = bookmarks
             .iter()
             .position(|bookmark| bookmark.memo_id == target_memo_id)
-            .ok_or_else(|| format!("Target bookmark not found for memo_id: {}", target_memo_id))?;
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "Target bookmark not found for memo_id: {}",
+                    target_memo_id
+                ))
+            })?;
 
         let insert_index = match position {
             "before" => target_index,
             "after" => target_index + 1,
             _ => {
-                return Err(format!(
+                return Err(AppError::validation(format!(
                     "Unsupported bookmark reorder position: {}",
                     position
-                ))
+                )))
             }
         };
 
@@ -97,8 +101,7 @@ impl BookmarkRepository {
             conn.execute(
                 "UPDATE bookmark SET order_index = ? WHERE id = ?",
                 (index as i32, bookmark.id),
-            )
-            .map_err(|e| e.to_string())?;
+            )?;
         }
 
         Ok(())
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use crate::models::calendar_day::{CalendarDay, CalendarDayMemo};
+use crate::repositories::KanbanAssignmentRepository;
 use rusqlite::{Connection, OptionalExtension, Result};
 
 pub struct CalendarDayRepository;
@@ -29,6 +30,7 @@ impl CalendarDayRepository {
                     note: row.get(2)?,
                     is_non_working: row.get(3)?,
                     memos: Vec::new(),
+                    kanban_items: Vec::new(),
                 })
             })?
             .collect::<Result<Vec<_>>>()?;
@@ -65,6 +67,42 @@ impl CalendarDayRepository {
             }
         }
 
+        let dated_items = KanbanAssignmentRepository::list_dated_items_between(
+            conn,
+            workspace_id,
+            &start_date,
+            &end_date,
+        )?;
+        if !dated_items.is_empty() {
+            let mut date_indexes: HashMap<String, usize> = days
+                .iter()
+                .enumerate()
+                .map(|(index, day)| (day.date.clone(), index))
+                .collect();
+            for item in dated_items {
+                let mut dates = vec![item.due_on.clone(), item.scheduled_on.clone()];
+                dates.dedup();
+                for date in dates.into_iter().flatten() {
+                    if date < start_date || date >= end_date {
+                        continue;
+                    }
+                    let index = *date_indexes.entry(date.clone()).or_insert_with(|| {
+                        days.push(CalendarDay {
+                            id: 0,
+                            date,
+                            note: None,
+                            is_non_working: false,
+                            memos: Vec::new(),
+                            kanban_items: Vec::new(),
+                        });
+                        days.len() - 1
+                    });
+                    days[index].kanban_items.push(item.clone());
+                }
+            }
+            days.sort_by(|a, b| a.date.cmp(&b.date));
+        }
+
         Ok(days)
     }
 
@@ -177,7 +215,9 @@ mod tests {
 
     use super::CalendarDayRepository;
     use crate::migrations::apply_migrations;
-    use crate::repositories::{MemoRepository, WorkspaceRepository};
+    use crate::repositories::{
+        KanbanAssignmentRepository, KanbanRepository, MemoRepository, WorkspaceRepository,
+    };
 
     fn setup_conn() -> Connection {
         let conn = Connection::open_in_memory().expect("in-memory DB should open");
@@ -246,4 +286,48 @@ mod tests {
             .expect("dates should load");
         assert_eq!(dates, vec!["2026-06-03", "2027-01-04"]);
     }
+
+    #[test]
+    fn dated_kanban_assignments_appear_on_their_days() {
+        let conn = setup_conn();
+        let workspace = WorkspaceRepository::create(&conn, "test", "Test")
+            .expect("workspace should be created");
+        let memo = MemoRepository::create(&conn, workspace.id, "memo", "Memo", r#"{"type":"doc"}"#)
+            .expect("memo should be created");
+        let kanban = KanbanRepository::ensure_global_status_board(&conn, workspace.id)
+            .expect("board should exist");
+        KanbanAssignmentRepository::upsert_status(
+            &conn,
+            workspace.id,
+            memo.id,
+            kanban.id,
+            kanban.default_status_id,
+            None,
+        )
+        .expect("memo should be assigned");
+        KanbanAssignmentRepository::update_dates(
+            &conn,
+            workspace.id,
+            memo.id,
+            kanban.id,
+            Some("2026-06-05"),
+            Some("2026-06-03"),
+        )
+        .expect("dates should be set");
+        CalendarDayRepository::add_memo(&conn, workspace.id, "2026-06-03", memo.id)
+            .expect("memo should link");
+
+        let days = CalendarDayRepository::list_by_year(&conn, workspace.id, 2026)
+            .expect("calendar days should load");
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].date, "2026-06-03");
+        assert_eq!(days[0].memos.len(), 1);
+        assert_eq!(days[0].kanban_items.len(), 1);
+        assert_eq!(days[1].date, "2026-06-05");
+        assert_eq!(days[1].id, 0);
+        assert_eq!(
+            days[1].kanban_items[0].due_on.as_deref(),
+            Some("2026-06-05")
+        );
+    }
 }
@@ -0,0 +1,353 @@
+use std::collections::HashMap;
+
+use rusqlite::{Connection, Result};
+
+use crate::models::Workspace;
+
+pub struct CalendarExportRepository;
+
+struct ExportEvent {
+    uid: String,
+    start: String,
+    end: String,
+    stamp: String,
+    summary: String,
+    description: String,
+    busy: bool,
+    completed: Option<bool>,
+}
+
+struct LinkedMemo {
+    slug_title: String,
+    title: String,
+}
+
+impl CalendarExportRepository {
+    /// Builds an iCalendar feed with every milestone and non-working day in the workspace.
+    /// Milestones are free all-day events; non-working days block the day as busy.
+    pub fn to_ics(conn: &Connection, workspace: &Workspace) -> Result<String> {
+        let mut events = Self::milestone_events(conn, workspace)?;
+        events.extend(Self::non_working_day_events(conn, workspace)?);
+        events.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.uid.cmp(&b.uid)));
+
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//monobox//Calendar Export//EN".to_string(),
+            "CALSCALE:GREGORIAN".to_string(),
+            "METHOD:PUBLISH".to_string(),
+            format!("X-WR-CALNAME:{}", escape_text(&workspace.name)),
+        ];
+        for event in events {
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:{}", event.uid));
+            lines.push(format!("DTSTAMP:{}", event.stamp));
+            lines.push(format!("DTSTART;VALUE=DATE:{}", event.start));
+            lines.push(format!("DTEND;VALUE=DATE:{}", event.end));
+            lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+            if !event.description.is_empty() {
+                lines.push(format!("DESCRIPTION:{}", escape_text(&event.description)));
+            }
+            lines.push(format!(
+                "TRANSP:{}",
+                if event.busy { "OPAQUE" } else { "TRANSPARENT" }
+            ));
+            if let Some(completed) = event.completed {
+                lines.push("CATEGORIES:Milestone".to_string());
+                lines.push(format!(
+                    "X-MONOBOX-COMPLETED:{}",
+                    if completed { "TRUE" } else { "FALSE" }
+                ));
+            }
+            lines.push("END:VEVENT".to_string());
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        let mut ics = String::new();
+        for line in lines {
+            ics.push_str(&fold_line(&line));
+            ics.push_str("\r\n");
+        }
+        Ok(ics)
+    }
+
+    fn milestone_events(conn: &Connection, workspace: &Workspace) -> Result<Vec<ExportEvent>> {
+        let memos = Self::linked_memos(
+            conn,
+            "SELECT milestone_memo.milestone_id, memo.slug_title, memo.title
+             FROM milestone_memo
+             JOIN milestone ON milestone_memo.milestone_id = milestone.id
+             JOIN memo ON milestone_memo.memo_id = memo.id
+             WHERE milestone.workspace_id = ?
+             ORDER BY milestone_memo.created_at ASC, memo.id ASC",
+            workspace.id,
+        )?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, date, date(date, '+1 day'), title, completed_at, updated_at
+             FROM milestone
+             WHERE workspace_id = ?
+             ORDER BY date ASC, id ASC",
+        )?;
+        let rows = stmt.query_map([workspace.id], |row| {
+            Ok((
+                row.get::<_, i32>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, Option<String>>(4)?,
+                row.get::<_, String>(5)?,
+            ))
+        })?;
+
+        let mut events = Vec::new();
+        for row in rows {
+            let (id, date, next_date, title, completed_at, updated_at) = row?;
+            let mut description = vec![match &completed_at {
+                Some(completed_at) => format!("Completed on {}", date_part(completed_at)),
+                None => "Not completed".to_string(),
+            }];
+            description.extend(memo_lines(
+                &workspace.slug_name,
+                memos.get(&id).map(Vec::as_slice).unwrap_or_default(),
+            ));
+            events.push(ExportEvent {
+                uid: format!("milestone-{}@{}.monobox", id, workspace.slug_name),
+                start: compact_date(&date),
+                end: compact_date(&next_date),
+                stamp: compact_timestamp(&updated_at),
+                summary: if completed_at.is_some() {
+                    format!("✓ {}", title)
+                } else {
+                    title
+                },
+                description: description.join("\n"),
+                busy: false,
+                completed: Some(completed_at.is_some()),
+            });
+        }
+        Ok(events)
+    }
+
+    fn non_working_day_events(
+        conn: &Connection,
+        workspace: &Workspace,
+    ) -> Result<Vec<ExportEvent>> {
+        let memos = Self::linked_memos(
+            conn,
+            "SELECT calendar_day_memo.calendar_day_id, memo.slug_title, memo.title
+             FROM calendar_day_memo
+             JOIN calendar_day ON calendar_day_memo.calendar_day_id = calendar_day.id
+             JOIN memo ON calendar_day_memo.memo_id = memo.id
+             WHERE calendar_day.workspace_id = ?
+             ORDER BY calendar_day_memo.created_at ASC, memo.id ASC",
+            workspace.id,
+        )?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, date, date(date, '+1 day'), note, updated_at
+             FROM calendar_day
+             WHERE workspace_id = ? AND is_non_working = 1
+             ORDER BY date ASC",
+        )?;
+        let rows = stmt.query_map([workspace.id], |row| {
+            Ok((
+                row.get::<_, i32>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, String>(4)?,
+            ))
+        })?;
+
+        let mut events = Vec::new();
+        for row in rows {
+            let (id, date, next_date, note, updated_at) = row?;
+            let mut description: Vec<String> = note.into_iter().collect();
+            description.extend(memo_lines(
+                &workspace.slug_name,
+                memos.get(&id).map(Vec::as_slice).unwrap_or_default(),
+            ));
+            events.push(ExportEvent {
+                uid: format!("day-{}@{}.monobox", date, workspace.slug_name),
+                start: compact_date(&date),
+                end: compact_date(&next_date),
+                stamp: compact_timestamp(&updated_at),
+                summary: "Non-working day".to_string(),
+                description: description.join("\n"),
+                busy: true,
+                completed: None,
+            });
+        }
+        Ok(events)
+    }
+
+    fn linked_memos(
+        conn: &Connection,
+        sql: &str,
+        workspace_id: i32,
+    ) -> Result<HashMap<i32, Vec<LinkedMemo>>> {
+        let mut stmt = conn.prepare(sql)?;
+        let rows = stmt.query_map([workspace_id], |row| {
+            Ok((
+                row.get::<_, i32>(0)?,
+                LinkedMemo {
+                    slug_title: row.get(1)?,
+                    title: row.get(2)?,
+                },
+            ))
+        })?;
+
+        let mut memos: HashMap<i32, Vec<LinkedMemo>> = HashMap::new();
+        for row in rows {
+            let (owner_id, memo) = row?;
+            memos.entry(owner_id).or_default().push(memo);
+        }
+        Ok(memos)
+    }
+}
+
+/// Memo links mirror the app's `/:workspace/:memo` route. Slugs are already path-safe.
+fn memo_url(workspace_slug_name: &str, slug_title: &str) -> String {
+    format!("monobox://{}/{}", workspace_slug_name, slug_title)
+}
+
+fn memo_lines(workspace_slug_name: &str, memos: &[LinkedMemo]) -> Vec<String> {
+    memos
+        .iter()
+        .map(|memo| {
+            format!(
+                "{}: {}",
+                memo.title,
+                memo_url(workspace_slug_name, &memo.slug_title)
+            )
+        })
+        .collect()
+}
+
+fn date_part(timestamp: &str) -> &str {
+    timestamp.get(..10).unwrap_or(timestamp)
+}
+
+fn compact_date(date: &str) -> String {
+    date.replace('-', "")
+}
+
+/// SQLite's `CURRENT_TIMESTAMP` is UTC, so `YYYY-MM-DD HH:MM:SS` maps straight to the
+/// iCalendar UTC form.
+fn compact_timestamp(timestamp: &str) -> String {
+    let compact: String = timestamp
+        .chars()
+        .filter(|ch| *ch != '-' && *ch != ':')
+        .map(|ch| if ch == ' ' { 'T' } else { ch })
+        .collect();
+    format!("{}Z", compact)
+}
+
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Folds content lines at 75 octets without splitting a UTF-8 character.
+fn fold_line(line: &str) -> String {
+    const LIMIT: usize = 75;
+    let mut folded = String::with_capacity(line.len() + line.len() / LIMIT * 3);
+    let mut width = 0;
+    for ch in line.chars() {
+        let len = ch.len_utf8();
+        if width + len > LIMIT {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(ch);
+        width += len;
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use super::{fold_line, CalendarExportRepository};
+    use crate::migrations::apply_migrations;
+    use crate::repositories::{
+        CalendarDayRepository, MemoRepository, MilestoneRepository, WorkspaceRepository,
+    };
+
+    #[test]
+    fn exports_milestones_and_non_working_days_as_all_day_events() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let memo = MemoRepository::create(
+            &conn,
+            workspace.id,
+            "Release_plan",
+            "Release plan",
+            r#"{"type":"doc"}"#,
+        )
+        .expect("memo should be created");
+
+        let milestone_id = MilestoneRepository::create(&conn, workspace.id, "2026-12-31", "Ship")
+            .expect("milestone should be created");
+        MilestoneRepository::add_memo(&conn, workspace.id, milestone_id, memo.id)
+            .expect("memo should be linked");
+        MilestoneRepository::set_completed(&conn, workspace.id, milestone_id, true)
+            .expect("milestone should be completed");
+        CalendarDayRepository::update_day(
+            &conn,
+            workspace.id,
+            "2026-11-03",
+            Some("Culture Day; office closed, no deploys"),
+            true,
+        )
+        .expect("day should be saved");
+        CalendarDayRepository::update_day(&conn, workspace.id, "2026-11-04", Some("Note"), false)
+            .expect("working day should be saved");
+
+        let ics = CalendarExportRepository::to_ics(&conn, &workspace).expect("ICS should build");
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("UID:day-2026-11-03@team.monobox\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20261103\r\nDTEND;VALUE=DATE:20261104\r\n"));
+        assert!(ics.contains("DESCRIPTION:Culture Day\\; office closed\\, no deploys\r\n"));
+        assert!(ics.contains("TRANSP:OPAQUE\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20261231\r\nDTEND;VALUE=DATE:20270101\r\n"));
+        assert!(ics.contains("SUMMARY:✓ Ship\r\n"));
+        assert!(ics.contains("X-MONOBOX-COMPLETED:TRUE\r\n"));
+        let unfolded = ics.replace("\r\n ", "");
+        assert!(unfolded.contains("Release plan: monobox://team/Release_plan"));
+        assert!(ics.find("20261103").unwrap() < ics.find("20261231").unwrap());
+    }
+
+    #[test]
+    fn fold_line_keeps_multibyte_characters_whole() {
+        let line = format!("SUMMARY:{}", "締".repeat(40));
+        let folded = fold_line(&line);
+
+        for part in folded.split("\r\n") {
+            assert!(part.len() <= 75);
+        }
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+}
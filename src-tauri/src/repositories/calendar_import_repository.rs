@@ -0,0 +1,528 @@
+use std::collections::BTreeMap;
+
+use rusqlite::{Connection, OptionalExtension};
+
+use crate::errors::AppError;
+use crate::models::calendar_day::{
+    CalendarImportDay, CalendarImportEvent, CalendarImportSkippedEvent, CalendarImportSummary,
+};
+use crate::repositories::CalendarDayRepository;
+
+/// Longest all-day event the importer expands into single dates.
+const MAX_EVENT_DAYS: usize = 366;
+
+pub struct CalendarImportRepository;
+
+/// How imported event titles combine with a note the date already has.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CalendarImportNoteMode {
+    /// Replaces the note with the imported titles.
+    Overwrite,
+    /// Appends imported titles the note doesn't already have as a line.
+    Merge,
+}
+
+impl CalendarImportNoteMode {
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        match value {
+            "overwrite" => Ok(Self::Overwrite),
+            "merge" => Ok(Self::Merge),
+            other => Err(AppError::validation(format!(
+                "Unsupported calendar import note mode: {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl CalendarImportRepository {
+    /// Reads VEVENTs from an ICS file. Recurrence rules are ignored, so a recurring
+    /// event only marks its first date.
+    pub fn parse_ics(text: &str) -> Result<Vec<CalendarImportEvent>, AppError> {
+        let lines = unfold_lines(text.trim_start_matches('\u{feff}'));
+        if !lines
+            .iter()
+            .any(|(_, line)| line.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+        {
+            return Err(AppError::validation("Not an iCalendar file.").with_reason("INVALID_ICS"));
+        }
+
+        let mut events = Vec::new();
+        let mut current: Option<CalendarImportEvent> = None;
+        // Alarms and other components nested in an event carry their own properties.
+        let mut nested_depth = 0usize;
+        for (line_number, line) in lines {
+            let Some((name, params, value)) = split_property(&line) else {
+                continue;
+            };
+
+            if current.is_none() {
+                if name == "BEGIN" && value.eq_ignore_ascii_case("VEVENT") {
+                    current = Some(CalendarImportEvent {
+                        line: line_number,
+                        summary: None,
+                        start: None,
+                        end: None,
+                        cancelled: false,
+                    });
+                    nested_depth = 0;
+                }
+                continue;
+            }
+
+            match name.as_str() {
+                "BEGIN" => nested_depth += 1,
+                "END" if nested_depth > 0 => nested_depth -= 1,
+                "END" if value.eq_ignore_ascii_case("VEVENT") => events.extend(current.take()),
+                _ if nested_depth > 0 => {}
+                "SUMMARY" => {
+                    if let Some(event) = current.as_mut() {
+                        let summary = unescape_text(value);
+                        let summary = summary.trim();
+                        event.summary = (!summary.is_empty()).then(|| summary.to_string());
+                    }
+                }
+                "DTSTART" => {
+                    if let Some(event) = current.as_mut() {
+                        event.start = parse_date(value);
+                    }
+                }
+                "DTEND" => {
+                    // Only all-day ends are exclusive dates; timed events stay on their
+                    // start date.
+                    if let Some(event) = current.as_mut() {
+                        let is_all_day = params
+                            .split(';')
+                            .any(|param| param.eq_ignore_ascii_case("VALUE=DATE"));
+                        if is_all_day || value.len() == 8 {
+                            event.end = parse_date(value);
+                        }
+                    }
+                }
+                "STATUS" => {
+                    if let Some(event) = current.as_mut() {
+                        event.cancelled = value.eq_ignore_ascii_case("CANCELLED");
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Marks every date the events cover as non-working and sets its note from the event
+    /// titles. With `dry_run` nothing is written.
+    pub fn import_events(
+        conn: &Connection,
+        workspace_id: i32,
+        events: &[CalendarImportEvent],
+        note_mode: CalendarImportNoteMode,
+        dry_run: bool,
+    ) -> Result<CalendarImportSummary, AppError> {
+        let mut summary = CalendarImportSummary {
+            dry_run,
+            ..CalendarImportSummary::default()
+        };
+
+        let mut titles_by_date: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for event in events {
+            let skip = |reason: &str| CalendarImportSkippedEvent {
+                line: event.line,
+                summary: event.summary.clone(),
+                reason: reason.to_string(),
+            };
+            if event.cancelled {
+                summary.skipped.push(skip("Event is cancelled."));
+                continue;
+            }
+            let Some(start) = event.start.as_deref() else {
+                summary.skipped.push(skip("Missing or unreadable DTSTART."));
+                continue;
+            };
+            let Some(start) = to_local_date(conn, start)? else {
+                summary.skipped.push(skip("Invalid date."));
+                continue;
+            };
+            let Some(dates) = expand_dates(conn, &start, event.end.as_deref())? else {
+                summary.skipped.push(skip("Invalid date."));
+                continue;
+            };
+            if dates.len() > MAX_EVENT_DAYS {
+                summary.skipped.push(skip("Event spans more than a year."));
+                continue;
+            }
+
+            for date in dates {
+                let titles = titles_by_date.entry(date).or_default();
+                if let Some(title) = &event.summary {
+                    if !titles.contains(title) {
+                        titles.push(title.clone());
+                    }
+                }
+            }
+        }
+
+        for (date, titles) in titles_by_date {
+            let existing: Option<(Option<String>, bool)> = conn
+                .query_row(
+                    "SELECT note, is_non_working FROM calendar_day
+                     WHERE workspace_id = ? AND date = ?",
+                    (workspace_id, &date),
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .optional()?;
+            let (previous_note, was_non_working) = existing.clone().unwrap_or((None, false));
+            let note = resolve_note(previous_note.as_deref(), &titles, note_mode);
+
+            if was_non_working && note == previous_note {
+                summary.unchanged.push(date);
+                continue;
+            }
+
+            if !dry_run {
+                CalendarDayRepository::update_day(
+                    conn,
+                    workspace_id,
+                    &date,
+                    note.as_deref(),
+                    true,
+                )?;
+            }
+
+            let day = CalendarImportDay {
+                date,
+                previous_note,
+                note,
+                was_non_working,
+            };
+            if existing.is_some() {
+                summary.updated.push(day);
+            } else {
+                summary.created.push(day);
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+/// Joins folded continuation lines and numbers the results by their first physical line.
+fn unfold_lines(text: &str) -> Vec<(u64, String)> {
+    let mut lines: Vec<(u64, String)> = Vec::new();
+    for (index, raw) in text.split('\n').enumerate() {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        if let Some(continuation) = raw.strip_prefix([' ', '\t']) {
+            if let Some((_, last)) = lines.last_mut() {
+                last.push_str(continuation);
+                continue;
+            }
+        }
+        if !raw.is_empty() {
+            lines.push((index as u64 + 1, raw.to_string()));
+        }
+    }
+    lines
+}
+
+/// Splits `NAME;PARAM=x:value` into an upper-cased name, its parameters and the value.
+/// Colons inside quoted parameter values don't end the name.
+fn split_property(line: &str) -> Option<(String, &str, &str)> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(index, ch)| match ch {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(index),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let (name, params) = head.split_once(';').unwrap_or((head, ""));
+    Some((name.trim().to_ascii_uppercase(), params, value.trim()))
+}
+
+/// Accepts `YYYYMMDD` and the date part of a floating `YYYYMMDDTHHMMSS`. A UTC
+/// `YYYYMMDDTHHMMSSZ` keeps its time, as `YYYY-MM-DDTHH:MM:SSZ`, so `import_events` can
+/// move it to the local date.
+fn parse_date(value: &str) -> Option<String> {
+    let digits = value.get(..8)?;
+    if !digits.chars().all(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+    let date = format!("{}-{}-{}", &digits[..4], &digits[4..6], &digits[6..8]);
+    if value.len() == 8 {
+        return Some(date);
+    }
+
+    let time = value[8..].strip_prefix('T')?;
+    match time.strip_suffix('Z') {
+        Some(utc) if utc.len() == 6 && utc.chars().all(|ch| ch.is_ascii_digit()) => Some(format!(
+            "{}T{}:{}:{}Z",
+            date,
+            &utc[..2],
+            &utc[2..4],
+            &utc[4..6]
+        )),
+        _ => Some(date),
+    }
+}
+
+/// Turns a UTC time from `parse_date` into the local date; plain dates pass through.
+fn to_local_date(conn: &Connection, value: &str) -> Result<Option<String>, AppError> {
+    if !value.ends_with('Z') {
+        return Ok(Some(value.to_string()));
+    }
+    conn.query_row("SELECT date(?, 'localtime')", [value], |row| row.get(0))
+        .map_err(AppError::from)
+}
+
+/// `None` when a date doesn't exist in the calendar. Stops one past the cap so callers can
+/// tell an oversized event apart.
+fn expand_dates(
+    conn: &Connection,
+    start: &str,
+    end: Option<&str>,
+) -> Result<Option<Vec<String>>, AppError> {
+    let end = end.unwrap_or(start);
+    let is_valid: bool = conn.query_row(
+        "SELECT date(?1, '+0 days') = ?1 AND date(?2, '+0 days') = ?2",
+        (start, end),
+        |row| row.get(0),
+    )?;
+    if !is_valid {
+        return Ok(None);
+    }
+
+    let mut stmt = conn.prepare(
+        "WITH RECURSIVE span(day) AS (
+             SELECT ?1
+             UNION ALL
+             SELECT date(day, '+1 day') FROM span WHERE date(day, '+1 day') < ?2
+         )
+         SELECT day FROM span LIMIT ?3",
+    )?;
+    let dates = stmt
+        .query_map((start, end, MAX_EVENT_DAYS as i64 + 1), |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<String>>>()?;
+    Ok(Some(dates))
+}
+
+fn resolve_note(
+    previous: Option<&str>,
+    titles: &[String],
+    note_mode: CalendarImportNoteMode,
+) -> Option<String> {
+    let previous = previous.map(str::trim).filter(|note| !note.is_empty());
+    if titles.is_empty() {
+        return previous.map(str::to_string);
+    }
+
+    let note = match (note_mode, previous) {
+        (CalendarImportNoteMode::Merge, Some(previous)) => {
+            let mut lines: Vec<&str> = previous.lines().collect();
+            for title in titles {
+                if !lines.iter().any(|line| line.trim() == title) {
+                    lines.push(title);
+                }
+            }
+            lines.join("\n")
+        }
+        _ => titles.join("\n"),
+    };
+    Some(note)
+}
+
+fn unescape_text(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            unescaped.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use super::{CalendarImportNoteMode, CalendarImportRepository};
+    use crate::migrations::apply_migrations;
+    use crate::repositories::{CalendarDayRepository, WorkspaceRepository};
+
+    const HOLIDAYS: &str = "BEGIN:VCALENDAR\r\n\
+        VERSION:2.0\r\n\
+        BEGIN:VEVENT\r\n\
+        DTSTART;VALUE=DATE:20261103\r\n\
+        DTEND;VALUE=DATE:20261104\r\n\
+        SUMMARY:Culture Day\r\n\
+        BEGIN:VALARM\r\n\
+        SUMMARY:Reminder\r\n\
+        END:VALARM\r\n\
+        END:VEVENT\r\n\
+        BEGIN:VEVENT\r\n\
+        DTSTART;VALUE=DATE:20261229\r\n\
+        DTEND;VALUE=DATE:20270101\r\n\
+        SUMMARY:Year-end\r\n \
+        \\, office closed\r\n\
+        END:VEVENT\r\n\
+        BEGIN:VEVENT\r\n\
+        SUMMARY:No date\r\n\
+        END:VEVENT\r\n\
+        BEGIN:VEVENT\r\n\
+        DTSTART:20261123T000000Z\r\n\
+        SUMMARY:Labor Thanksgiving Day\r\n\
+        STATUS:CANCELLED\r\n\
+        END:VEVENT\r\n\
+        END:VCALENDAR\r\n";
+
+    fn setup() -> (Connection, i32) {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        (conn, workspace.id)
+    }
+
+    #[test]
+    fn dry_run_previews_without_writing_and_merge_keeps_existing_notes() {
+        let (conn, workspace_id) = setup();
+        CalendarDayRepository::update_day(
+            &conn,
+            workspace_id,
+            "2026-11-03",
+            Some("Team offsite"),
+            false,
+        )
+        .expect("day should be saved");
+
+        let events = CalendarImportRepository::parse_ics(HOLIDAYS).expect("ICS should parse");
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[0].summary.as_deref(), Some("Culture Day"));
+        assert_eq!(
+            events[1].summary.as_deref(),
+            Some("Year-end, office closed")
+        );
+
+        let preview = CalendarImportRepository::import_events(
+            &conn,
+            workspace_id,
+            &events,
+            CalendarImportNoteMode::Merge,
+            true,
+        )
+        .expect("dry run should succeed");
+        assert!(preview.dry_run);
+        assert_eq!(preview.updated.len(), 1);
+        assert_eq!(
+            preview.updated[0].note.as_deref(),
+            Some("Team offsite\nCulture Day")
+        );
+        let created: Vec<&str> = preview
+            .created
+            .iter()
+            .map(|day| day.date.as_str())
+            .collect();
+        assert_eq!(created, vec!["2026-12-29", "2026-12-30", "2026-12-31"]);
+        let reasons: Vec<&str> = preview.skipped.iter().map(|s| s.reason.as_str()).collect();
+        assert_eq!(
+            reasons,
+            vec!["Missing or unreadable DTSTART.", "Event is cancelled."]
+        );
+        let days = CalendarDayRepository::list_by_year(&conn, workspace_id, 2026)
+            .expect("days should list");
+        assert_eq!(days.len(), 1);
+        assert!(!days[0].is_non_working);
+
+        let summary = CalendarImportRepository::import_events(
+            &conn,
+            workspace_id,
+            &events,
+            CalendarImportNoteMode::Merge,
+            false,
+        )
+        .expect("import should succeed");
+        assert_eq!(summary.created.len(), 3);
+        let again = CalendarImportRepository::import_events(
+            &conn,
+            workspace_id,
+            &events,
+            CalendarImportNoteMode::Merge,
+            false,
+        )
+        .expect("re-import should succeed");
+        assert_eq!(again.unchanged.len(), 4);
+        assert!(again.created.is_empty() && again.updated.is_empty());
+    }
+
+    #[test]
+    fn overwrite_replaces_existing_notes() {
+        let (conn, workspace_id) = setup();
+        CalendarDayRepository::update_day(&conn, workspace_id, "2026-11-03", Some("Old"), true)
+            .expect("day should be saved");
+
+        let events = CalendarImportRepository::parse_ics(HOLIDAYS).expect("ICS should parse");
+        let summary = CalendarImportRepository::import_events(
+            &conn,
+            workspace_id,
+            &events[..1],
+            CalendarImportNoteMode::Overwrite,
+            false,
+        )
+        .expect("import should succeed");
+
+        assert_eq!(summary.updated[0].previous_note.as_deref(), Some("Old"));
+        let days = CalendarDayRepository::list_by_year(&conn, workspace_id, 2026)
+            .expect("days should list");
+        assert_eq!(days[0].note.as_deref(), Some("Culture Day"));
+        assert!(CalendarImportRepository::parse_ics("not a calendar").is_err());
+    }
+
+    #[test]
+    fn utc_start_times_land_on_the_local_date() {
+        let (conn, workspace_id) = setup();
+        let events = CalendarImportRepository::parse_ics(
+            "BEGIN:VCALENDAR\r\n\
+             BEGIN:VEVENT\r\n\
+             DTSTART:20261123T233000Z\r\n\
+             DTEND:20261124T003000Z\r\n\
+             SUMMARY:Late call\r\n\
+             END:VEVENT\r\n\
+             END:VCALENDAR\r\n",
+        )
+        .expect("ICS should parse");
+        assert_eq!(events[0].start.as_deref(), Some("2026-11-23T23:30:00Z"));
+        assert_eq!(events[0].end, None);
+
+        let summary = CalendarImportRepository::import_events(
+            &conn,
+            workspace_id,
+            &events,
+            CalendarImportNoteMode::Merge,
+            true,
+        )
+        .expect("dry run should succeed");
+        let local_date: String = conn
+            .query_row(
+                "SELECT date('2026-11-23 23:30:00', 'localtime')",
+                [],
+                |row| row.get(0),
+            )
+            .expect("local date should resolve");
+        assert_eq!(summary.created.len(), 1);
+        assert_eq!(summary.created[0].date, local_date);
+    }
+}
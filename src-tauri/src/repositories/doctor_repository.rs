@@ -0,0 +1,671 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
+use rusqlite::{params, Connection};
+use serde_json::Value;
+
+use crate::errors::AppError;
+use crate::models::doctor::{
+    DoctorReport, ForeignKeyViolation, KanbanIssue, LinkIssue, MemoFileIssue, MissingFile,
+    SearchIndexIssue,
+};
+use crate::repositories::file_repository::collect_file_ids_from_content;
+use crate::repositories::memo_repository::extract_plain_text_from_json_str;
+use crate::repositories::MemoRepository;
+
+/// Tables whose rows only connect other rows, so rows that fail `foreign_key_check` can be
+/// deleted without losing user content.
+const REPAIRABLE_FOREIGN_KEY_TABLES: &[&str] = &[
+    "link",
+    "memo_files",
+    "bookmark",
+    "calendar_day_memo",
+    "milestone_memo",
+    "kanban_assignment",
+    "memo_view_event",
+    "memo_view_state",
+    "focus_daily_state",
+];
+
+pub struct DoctorRepository;
+
+impl DoctorRepository {
+    /// Runs every health check without changing anything. Managed files are only checked
+    /// when `storage_root` is an existing directory.
+    pub fn check(conn: &Connection, storage_root: Option<&Path>) -> Result<DoctorReport, AppError> {
+        let mut report = DoctorReport {
+            integrity_errors: check_integrity(conn)?,
+            foreign_key_violations: check_foreign_keys(conn)?,
+            search_index_issues: check_search_index(conn)?,
+            link_issues: check_links(conn)?,
+            memo_file_issues: check_memo_files(conn)?,
+            missing_files: match storage_root.filter(|root| root.is_dir()) {
+                Some(root) => check_missing_files(conn, root)?,
+                None => Vec::new(),
+            },
+            kanban_issues: check_kanbans(conn)?,
+            ..DoctorReport::default()
+        };
+        report.healthy = is_healthy(&report);
+        Ok(report)
+    }
+
+    /// Fixes what can be derived from other data: association rows that fail foreign key
+    /// checks, `link` and `memo_files` rows, kanban statuses from another board and the
+    /// search index. Integrity errors and missing files are only reported. Returns the
+    /// report of a fresh check with `repaired` describing the changes.
+    pub fn repair(
+        conn: &mut Connection,
+        storage_root: Option<&Path>,
+    ) -> Result<DoctorReport, AppError> {
+        let before = Self::check(conn, storage_root)?;
+        let mut repaired = Vec::new();
+
+        let tx = conn.transaction()?;
+
+        let mut removed_by_table: HashMap<&str, usize> = HashMap::new();
+        for violation in &before.foreign_key_violations {
+            let (Some(table), Some(rowid)) = (
+                REPAIRABLE_FOREIGN_KEY_TABLES
+                    .iter()
+                    .find(|table| **table == violation.table),
+                violation.rowid,
+            ) else {
+                continue;
+            };
+            let removed = tx.execute(&format!("DELETE FROM {} WHERE rowid = ?", table), [rowid])?;
+            *removed_by_table.entry(table).or_default() += removed;
+        }
+        let mut removed_tables: Vec<_> = removed_by_table.into_iter().collect();
+        removed_tables.sort();
+        for (table, removed) in removed_tables {
+            repaired.push(format!(
+                "Removed {} {} row(s) that failed foreign key checks.",
+                removed, table
+            ));
+        }
+
+        let (mut added_links, mut removed_links) = (0, 0);
+        for issue in &before.link_issues {
+            if issue.issue == "missing_row" {
+                added_links += tx.execute(
+                    "INSERT OR IGNORE INTO link (from_memo_id, to_memo_id)
+                    SELECT ?1, ?2
+                    WHERE EXISTS (SELECT 1 FROM memo WHERE id = ?1)
+                      AND EXISTS (SELECT 1 FROM memo WHERE id = ?2)",
+                    params![issue.from_memo_id, issue.to_memo_id],
+                )?;
+            } else {
+                removed_links += tx.execute(
+                    "DELETE FROM link WHERE from_memo_id = ? AND to_memo_id = ?",
+                    params![issue.from_memo_id, issue.to_memo_id],
+                )?;
+            }
+        }
+        if added_links + removed_links > 0 {
+            repaired.push(format!(
+                "Added {} and removed {} link row(s) to match memo content.",
+                added_links, removed_links
+            ));
+        }
+
+        let (mut added_memo_files, mut removed_memo_files) = (0, 0);
+        for issue in &before.memo_file_issues {
+            if issue.issue == "missing_row" {
+                added_memo_files += tx.execute(
+                    "INSERT OR IGNORE INTO memo_files (memo_id, file_id)
+                    SELECT ?1, id FROM files WHERE id = ?2",
+                    params![issue.memo_id, issue.file_id],
+                )?;
+            } else {
+                removed_memo_files += tx.execute(
+                    "DELETE FROM memo_files WHERE memo_id = ? AND file_id = ?",
+                    params![issue.memo_id, issue.file_id],
+                )?;
+            }
+        }
+        if added_memo_files + removed_memo_files > 0 {
+            repaired.push(format!(
+                "Added {} and removed {} memo_files row(s) to match memo content.",
+                added_memo_files, removed_memo_files
+            ));
+        }
+
+        let mut fixed_kanban_rows = 0;
+        for issue in &before.kanban_issues {
+            fixed_kanban_rows += match (issue.issue.as_str(), issue.assignment_id) {
+                // Fall back to the board's default status when it belongs to the board.
+                ("status_from_other_kanban", Some(assignment_id)) => tx.execute(
+                    "UPDATE kanban_assignment
+                    SET kanban_status_id = (
+                        SELECT kanban.default_status_id
+                        FROM kanban
+                        JOIN kanban_status
+                          ON kanban_status.id = kanban.default_status_id
+                         AND kanban_status.kanban_id = kanban.id
+                        WHERE kanban.id = kanban_assignment.kanban_id
+                    )
+                    WHERE id = ?",
+                    [assignment_id],
+                )?,
+                ("invalid_default_status", _) => tx.execute(
+                    "UPDATE kanban SET default_status_id = NULL WHERE id = ?",
+                    [issue.kanban_id],
+                )?,
+                ("invalid_focus_status", _) => tx.execute(
+                    "UPDATE kanban SET focus_status_id = NULL WHERE id = ?",
+                    [issue.kanban_id],
+                )?,
+                ("invalid_done_status", _) => tx.execute(
+                    "UPDATE kanban SET done_status_id = NULL WHERE id = ?",
+                    [issue.kanban_id],
+                )?,
+                _ => 0,
+            };
+        }
+        if fixed_kanban_rows > 0 {
+            repaired.push(format!(
+                "Reset {} kanban status reference(s) that pointed outside their board.",
+                fixed_kanban_rows
+            ));
+        }
+
+        tx.commit()?;
+
+        if !before.search_index_issues.is_empty() {
+            MemoRepository::rebuild_search_index(conn)?;
+            repaired.push("Rebuilt the memo search index.".to_string());
+        }
+
+        let mut after = Self::check(conn, storage_root)?;
+        after.repaired = repaired;
+        Ok(after)
+    }
+}
+
+fn is_healthy(report: &DoctorReport) -> bool {
+    report.integrity_errors.is_empty()
+        && report.foreign_key_violations.is_empty()
+        && report.search_index_issues.is_empty()
+        && report.link_issues.is_empty()
+        && report.memo_file_issues.is_empty()
+        && report.missing_files.is_empty()
+        && report.kanban_issues.is_empty()
+}
+
+fn check_integrity(conn: &Connection) -> Result<Vec<String>, AppError> {
+    let messages = conn
+        .prepare("PRAGMA integrity_check")?
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(messages
+        .into_iter()
+        .filter(|message| message != "ok")
+        .collect())
+}
+
+fn check_foreign_keys(conn: &Connection) -> Result<Vec<ForeignKeyViolation>, AppError> {
+    let violations = conn
+        .prepare("PRAGMA foreign_key_check")?
+        .query_map([], |row| {
+            Ok(ForeignKeyViolation {
+                table: row.get(0)?,
+                rowid: row.get(1)?,
+                parent: row.get(2)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(violations)
+}
+
+struct IndexedMemo {
+    title: String,
+    description: Option<String>,
+    body_text: Option<String>,
+    workspace_id: i64,
+    slug_title: String,
+}
+
+fn check_search_index(conn: &Connection) -> Result<Vec<SearchIndexIssue>, AppError> {
+    let mut index_rows: HashMap<i64, Vec<IndexedMemo>> = HashMap::new();
+    {
+        let mut stmt = conn.prepare(
+            "SELECT memo_id, title, description, body_text, workspace_id, slug_title FROM memo_fts",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                IndexedMemo {
+                    title: row.get(1)?,
+                    description: row.get(2)?,
+                    body_text: row.get(3)?,
+                    workspace_id: row.get(4)?,
+                    slug_title: row.get(5)?,
+                },
+            ))
+        })?;
+        for row in rows {
+            let (memo_id, indexed) = row?;
+            index_rows.entry(memo_id).or_default().push(indexed);
+        }
+    }
+
+    let mut issues = Vec::new();
+    let mut stmt = conn.prepare(
+        "SELECT id, title, description, content, body_text, workspace_id, slug_title
+        FROM memo
+        ORDER BY id",
+    )?;
+    let memos = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            IndexedMemo {
+                title: row.get(1)?,
+                description: row.get(2)?,
+                body_text: row.get(4)?,
+                workspace_id: row.get(5)?,
+                slug_title: row.get(6)?,
+            },
+            row.get::<_, String>(3)?,
+        ))
+    })?;
+
+    for memo in memos {
+        let (memo_id, memo, content) = memo?;
+        let expected_body_text = extract_plain_text_from_json_str(&content);
+        let mut push = |issue: &str| {
+            issues.push(SearchIndexIssue {
+                memo_id,
+                issue: issue.to_string(),
+            })
+        };
+
+        if memo.body_text.as_deref() != Some(expected_body_text.as_str()) {
+            push("stale_body_text");
+        }
+
+        match index_rows.remove(&memo_id).as_deref() {
+            None | Some([]) => push("missing_index_row"),
+            Some([indexed]) => {
+                if indexed.title != memo.title
+                    || indexed.description != memo.description
+                    || indexed.body_text.as_deref() != Some(expected_body_text.as_str())
+                    || indexed.workspace_id != memo.workspace_id
+                    || indexed.slug_title != memo.slug_title
+                {
+                    push("stale_index_row");
+                }
+            }
+            Some(_) => push("duplicate_index_row"),
+        }
+    }
+
+    let mut orphaned: Vec<i64> = index_rows.into_keys().collect();
+    orphaned.sort();
+    issues.extend(orphaned.into_iter().map(|memo_id| SearchIndexIssue {
+        memo_id,
+        issue: "orphaned_index_row".to_string(),
+    }));
+
+    Ok(issues)
+}
+
+fn check_links(conn: &Connection) -> Result<Vec<LinkIssue>, AppError> {
+    let mut memo_ids_by_slug: HashMap<(i32, String), i32> = HashMap::new();
+    let mut memos = Vec::new();
+    {
+        let mut stmt = conn.prepare("SELECT id, workspace_id, slug_title, content FROM memo")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, i32>(0)?,
+                row.get::<_, i32>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+        for row in rows {
+            let (memo_id, workspace_id, slug_title, content) = row?;
+            memo_ids_by_slug.insert((workspace_id, slug_title), memo_id);
+            memos.push((memo_id, workspace_id, content));
+        }
+    }
+
+    // The editor creates a link row in the memo's own workspace for every `/{ws}/{slug}`
+    // href, so the expected rows are derived the same way.
+    let mut expected = BTreeSet::new();
+    for (memo_id, workspace_id, content) in memos {
+        let Ok(doc) = serde_json::from_str::<Value>(&content) else {
+            continue;
+        };
+        let mut slugs = BTreeSet::new();
+        collect_linked_slugs(&doc, &mut slugs);
+        for slug in slugs {
+            if let Some(to_memo_id) = memo_ids_by_slug.get(&(workspace_id, slug)) {
+                if *to_memo_id != memo_id {
+                    expected.insert((memo_id, *to_memo_id));
+                }
+            }
+        }
+    }
+
+    let actual: BTreeSet<(i32, i32)> = conn
+        .prepare("SELECT from_memo_id, to_memo_id FROM link")?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<_, _>>()?;
+
+    Ok(diff_pairs(&expected, &actual)
+        .into_iter()
+        .map(|((from_memo_id, to_memo_id), issue)| LinkIssue {
+            from_memo_id,
+            to_memo_id,
+            issue,
+        })
+        .collect())
+}
+
+fn check_memo_files(conn: &Connection) -> Result<Vec<MemoFileIssue>, AppError> {
+    let file_ids: HashSet<String> = conn
+        .prepare("SELECT id FROM files")?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+
+    let mut expected = BTreeSet::new();
+    {
+        let mut stmt = conn.prepare("SELECT id, content FROM memo")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, i32>(0)?, row.get::<_, String>(1)?))
+        })?;
+        for row in rows {
+            let (memo_id, content) = row?;
+            for file_id in collect_file_ids_from_content(&content) {
+                if file_ids.contains(&file_id) {
+                    expected.insert((memo_id, file_id));
+                }
+            }
+        }
+    }
+
+    let actual: BTreeSet<(i32, String)> = conn
+        .prepare("SELECT memo_id, file_id FROM memo_files")?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<_, _>>()?;
+
+    Ok(diff_pairs(&expected, &actual)
+        .into_iter()
+        .map(|((memo_id, file_id), issue)| MemoFileIssue {
+            memo_id,
+            file_id,
+            issue,
+        })
+        .collect())
+}
+
+fn check_missing_files(
+    conn: &Connection,
+    storage_root: &Path,
+) -> Result<Vec<MissingFile>, AppError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, display_name, relative_path
+        FROM files
+        WHERE type IN ('local_file', 'local_directory')
+        ORDER BY imported_at, id",
+    )?;
+    let files = stmt
+        .query_map([], |row| {
+            Ok(MissingFile {
+                file_id: row.get(0)?,
+                display_name: row.get(1)?,
+                relative_path: row.get(2)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(files
+        .into_iter()
+        .filter(|file| {
+            !file
+                .relative_path
+                .as_deref()
+                .is_some_and(|relative_path| storage_root.join(relative_path).exists())
+        })
+        .collect())
+}
+
+fn check_kanbans(conn: &Connection) -> Result<Vec<KanbanIssue>, AppError> {
+    let mut issues = Vec::new();
+
+    let mut stmt = conn.prepare(
+        "SELECT kanban_assignment.kanban_id, kanban_assignment.id, kanban_assignment.memo_id,
+            kanban_status.kanban_id IS NOT NULL
+                AND kanban_status.kanban_id != kanban_assignment.kanban_id,
+            kanban.workspace_id != kanban_assignment.workspace_id
+                OR memo.workspace_id != kanban_assignment.workspace_id
+        FROM kanban_assignment
+        JOIN kanban ON kanban.id = kanban_assignment.kanban_id
+        JOIN memo ON memo.id = kanban_assignment.memo_id
+        LEFT JOIN kanban_status ON kanban_status.id = kanban_assignment.kanban_status_id
+        ORDER BY kanban_assignment.id",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, i32>(0)?,
+            row.get::<_, i32>(1)?,
+            row.get::<_, i32>(2)?,
+            row.get::<_, bool>(3)?,
+            row.get::<_, bool>(4)?,
+        ))
+    })?;
+    for row in rows {
+        let (kanban_id, assignment_id, memo_id, foreign_status, workspace_mismatch) = row?;
+        for (found, issue) in [
+            (foreign_status, "status_from_other_kanban"),
+            (workspace_mismatch, "workspace_mismatch"),
+        ] {
+            if found {
+                issues.push(KanbanIssue {
+                    kanban_id,
+                    assignment_id: Some(assignment_id),
+                    memo_id: Some(memo_id),
+                    issue: issue.to_string(),
+                });
+            }
+        }
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT kanban.id,
+            kanban.default_status_id IS NOT NULL AND NOT EXISTS (
+                SELECT 1 FROM kanban_status
+                WHERE kanban_status.id = kanban.default_status_id
+                  AND kanban_status.kanban_id = kanban.id
+            ),
+            kanban.focus_status_id IS NOT NULL AND NOT EXISTS (
+                SELECT 1 FROM kanban_status
+                WHERE kanban_status.id = kanban.focus_status_id
+                  AND kanban_status.kanban_id = kanban.id
+            ),
+            kanban.done_status_id IS NOT NULL AND NOT EXISTS (
+                SELECT 1 FROM kanban_status
+                WHERE kanban_status.id = kanban.done_status_id
+                  AND kanban_status.kanban_id = kanban.id
+            )
+        FROM kanban
+        ORDER BY kanban.id",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, i32>(0)?,
+            row.get::<_, bool>(1)?,
+            row.get::<_, bool>(2)?,
+            row.get::<_, bool>(3)?,
+        ))
+    })?;
+    for row in rows {
+        let (kanban_id, invalid_default, invalid_focus, invalid_done) = row?;
+        for (found, issue) in [
+            (invalid_default, "invalid_default_status"),
+            (invalid_focus, "invalid_focus_status"),
+            (invalid_done, "invalid_done_status"),
+        ] {
+            if found {
+                issues.push(KanbanIssue {
+                    kanban_id,
+                    assignment_id: None,
+                    memo_id: None,
+                    issue: issue.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(issues)
+}
+
+fn diff_pairs<T: Ord + Clone>(expected: &BTreeSet<T>, actual: &BTreeSet<T>) -> Vec<(T, String)> {
+    expected
+        .difference(actual)
+        .map(|pair| (pair.clone(), "missing_row".to_string()))
+        .chain(
+            actual
+                .difference(expected)
+                .map(|pair| (pair.clone(), "not_in_content".to_string())),
+        )
+        .collect()
+}
+
+/// Collects the memo slug of every `/{workspace}/{slug}` link href, without any `#heading`.
+fn collect_linked_slugs(node: &Value, out: &mut BTreeSet<String>) {
+    if let Some(marks) = node.get("marks").and_then(Value::as_array) {
+        for mark in marks {
+            if mark.get("type").and_then(Value::as_str) != Some("link") {
+                continue;
+            }
+            let Some(href) = mark
+                .get("attrs")
+                .and_then(|attrs| attrs.get("href"))
+                .and_then(Value::as_str)
+            else {
+                continue;
+            };
+            let mut parts = href.splitn(3, '/');
+            if let (Some(""), Some(workspace_slug), Some(memo_slug)) =
+                (parts.next(), parts.next(), parts.next())
+            {
+                let memo_slug = memo_slug.split(['#', '?']).next().unwrap_or_default();
+                if !workspace_slug.is_empty() && !memo_slug.is_empty() && !memo_slug.contains('/') {
+                    out.insert(memo_slug.to_string());
+                }
+            }
+        }
+    }
+
+    if let Some(children) = node.get("content").and_then(Value::as_array) {
+        for child in children {
+            collect_linked_slugs(child, out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DoctorRepository;
+    use crate::migrations::apply_migrations;
+    use crate::repositories::{
+        KanbanRepository, KanbanStatusRepository, MemoRepository, WorkspaceRepository,
+    };
+    use rusqlite::Connection;
+    use serde_json::json;
+
+    fn setup_conn() -> Connection {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        conn
+    }
+
+    #[test]
+    fn repair_fixes_drifted_index_links_and_kanban_statuses() {
+        let mut conn = setup_conn();
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let content = json!({
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [{
+                    "type": "text",
+                    "text": "notes",
+                    "marks": [{ "type": "link", "attrs": { "href": "/team/notes#todo" } }]
+                }]
+            }]
+        })
+        .to_string();
+        let index = MemoRepository::create(&conn, workspace.id, "index", "Index", &content)
+            .expect("memo should be created");
+        let notes = MemoRepository::create(&conn, workspace.id, "notes", "Notes", "\"\"")
+            .expect("memo should be created");
+        let global = KanbanRepository::ensure_global_status_board(&conn, workspace.id)
+            .expect("board should exist");
+        let other = KanbanRepository::create(&conn, workspace.id, "Other")
+            .expect("kanban should be created");
+        let other_status =
+            KanbanStatusRepository::create(&conn, workspace.id, other.id, "Doing", None)
+                .expect("status should be created");
+
+        conn.execute_batch(&format!(
+            "PRAGMA foreign_keys = OFF;
+            DELETE FROM memo_fts WHERE memo_id = {notes};
+            INSERT INTO link (from_memo_id, to_memo_id) VALUES ({notes}, {index});
+            INSERT INTO memo_files (memo_id, file_id) VALUES ({index}, 'GONE');
+            INSERT INTO kanban_assignment (workspace_id, memo_id, kanban_id, kanban_status_id)
+            VALUES ({workspace}, {index}, {global}, {other_status});
+            PRAGMA foreign_keys = ON;",
+            notes = notes.id,
+            index = index.id,
+            workspace = workspace.id,
+            global = global.id,
+            other_status = other_status.id,
+        ))
+        .expect("drift should be seeded");
+
+        let report = DoctorRepository::check(&conn, None).expect("check should run");
+        assert!(!report.healthy);
+        assert_eq!(report.foreign_key_violations.len(), 1);
+        assert_eq!(report.foreign_key_violations[0].table, "memo_files");
+        assert!(report
+            .search_index_issues
+            .iter()
+            .any(|issue| issue.memo_id == notes.id as i64 && issue.issue == "missing_index_row"));
+        let mut link_issues: Vec<_> = report
+            .link_issues
+            .iter()
+            .map(|issue| (issue.from_memo_id, issue.to_memo_id, issue.issue.as_str()))
+            .collect();
+        link_issues.sort();
+        assert_eq!(
+            link_issues,
+            vec![
+                (index.id, notes.id, "missing_row"),
+                (notes.id, index.id, "not_in_content"),
+            ]
+        );
+        assert_eq!(report.kanban_issues.len(), 1);
+        assert_eq!(report.kanban_issues[0].issue, "status_from_other_kanban");
+
+        let repaired = DoctorRepository::repair(&mut conn, None).expect("repair should run");
+        assert!(repaired.healthy, "{:?}", repaired);
+        assert_eq!(repaired.repaired.len(), 4);
+        let status_id: Option<i32> = conn
+            .query_row(
+                "SELECT kanban_status_id FROM kanban_assignment WHERE memo_id = ?",
+                [index.id],
+                |row| row.get(0),
+            )
+            .expect("assignment should exist");
+        assert_eq!(status_id, global.default_status_id);
+    }
+}
@@ -1,17 +1,25 @@
 use std::collections::BTreeSet;
 use std::fs;
+use std::ops::DerefMut;
 use std::path::{Path, PathBuf};
 use std::time::UNIX_EPOCH;
 
 use rusqlite::{params, Connection, OptionalExtension};
 use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
+use crate::errors::AppError;
 use crate::models::file::{
-    InboxFileItem, InboxFilePage, ManagedFileDetail, ManagedFileListItem, ManagedFileListPage,
-    ManagedFileRecord, MemoLinkedFileItem, RelatedMemoSummary, ResolvedFileOpenTarget,
+    ImportPolicy, InboxFileItem, InboxFilePage, ManagedFileDetail, ManagedFileListItem,
+    ManagedFileListPage, ManagedFileRecord, MemoLinkedFileItem, MissingManagedFile,
+    RelatedMemoSummary, RelinkedManagedFile, ResolvedFileOpenTarget, StorageEntry,
+    StorageVerificationReport,
 };
 
+/// Start of `import_notice` when an import matched an existing file by content.
+pub(crate) const ALREADY_IMPORTED_NOTICE: &str = "Already imported as";
+
 pub struct FileRepository;
 
 impl FileRepository {
@@ -20,7 +28,7 @@ impl FileRepository {
         limit: i64,
         offset: i64,
         ignored_file_names: &[String],
-    ) -> Result<InboxFilePage, String> {
+    ) -> Result<InboxFilePage, AppError> {
         if !downloads_dir.exists() {
             return Ok(InboxFilePage {
                 items: Vec::new(),
@@ -32,8 +40,8 @@ impl FileRepository {
 
         let mut items = Vec::new();
 
-        for entry in fs::read_dir(downloads_dir).map_err(|e| e.to_string())? {
-            let entry = entry.map_err(|e| e.to_string())?;
+        for entry in fs::read_dir(downloads_dir)? {
+            let entry = entry?;
             let path = entry.path();
             if !path.is_file() && !path.is_dir() {
                 continue;
@@ -42,7 +50,7 @@ impl FileRepository {
                 continue;
             }
 
-            let metadata = entry.metadata().map_err(|e| e.to_string())?;
+            let metadata = entry.metadata()?;
             let acquired_at = metadata
                 .created()
                 .or_else(|_| metadata.modified())
@@ -93,146 +101,146 @@ impl FileRepository {
         })
     }
 
-    pub fn import_local_file(
-        conn: &mut Connection,
+    /// Imports one file. `lock` is called only for the duplicate lookup and the insert, so the
+    /// connection is not held while files are hashed or the source is placed into storage.
+    pub fn import_local_file<C>(
+        lock: impl Fn() -> Result<C, AppError>,
         source_path: &Path,
         storage_root: &Path,
-    ) -> Result<ManagedFileRecord, String> {
+        policy: ImportPolicy,
+    ) -> Result<ManagedFileRecord, AppError>
+    where
+        C: DerefMut<Target = Connection>,
+    {
+        ensure_import_policy_supported(policy)?;
         if !source_path.exists() {
-            return Err("Source file does not exist.".to_string());
+            return Err(AppError::not_found("Source file does not exist."));
         }
         if !source_path.is_file() {
-            return Err("Source path is not a file.".to_string());
+            return Err(AppError::validation("Source path is not a file."));
         }
         if !storage_root.exists() {
-            return Err("Files storage folder does not exist.".to_string());
+            return Err(AppError::not_found("Files storage folder does not exist."));
         }
         if !storage_root.is_dir() {
-            return Err("Files storage folder is not a directory.".to_string());
+            return Err(AppError::validation(
+                "Files storage folder is not a directory.",
+            ));
         }
 
         let display_name = source_path
             .file_name()
             .map(|name| name.to_string_lossy().to_string())
-            .ok_or_else(|| "Failed to resolve source file name.".to_string())?;
+            .ok_or_else(|| AppError::io("Failed to resolve source file name."))?;
+        let content_hash = hash_file(source_path)
+            .map_err(|e| AppError::io(format!("Failed to read source file: {}", e)))?;
+
+        let existing = find_local_file_by_hash(&lock, storage_root, source_path, &content_hash)?;
+        if let Some(mut existing) = existing {
+            // The source is byte-for-byte the stored file, so the policy applies to it as if
+            // it had just been copied.
+            let mut notice = format!("{} {}.", ALREADY_IMPORTED_NOTICE, existing.display_name);
+            if let Some(source_notice) = finish_source(source_path, policy, Placement::Copied) {
+                notice = format!("{} {}", notice, source_notice);
+            }
+            existing.import_notice = Some(notice);
+            return Ok(existing);
+        }
+
         let file_id = generate_file_id();
         let physical_name = build_physical_file_name(&display_name, &file_id);
         let destination_path = storage_root.join(&physical_name);
         let relative_path = destination_path
             .strip_prefix(storage_root)
-            .map_err(|e| e.to_string())?
+            .map_err(|e| AppError::io(e.to_string()))?
             .to_string_lossy()
             .to_string();
 
-        fs::rename(source_path, &destination_path)
-            .map_err(|e| format!("Failed to move file into storage: {}", e))?;
-
-        let tx = conn.transaction().map_err(|e| e.to_string())?;
-        let insert_result = tx.execute(
-            "INSERT INTO files (id, type, display_name, note, relative_path, url, imported_at)
-             VALUES (?, 'local_file', ?, NULL, ?, NULL, CURRENT_TIMESTAMP)",
-            params![&file_id, &display_name, &relative_path],
-        );
-
-        if let Err(err) = insert_result {
-            let _ = fs::rename(&destination_path, source_path);
-            return Err(err.to_string());
-        }
-
-        let record = tx
-            .query_row(
-                "SELECT id, type, display_name, note, relative_path, url, imported_at
-                 FROM files
-                 WHERE id = ?",
-                [&file_id],
-                |row| {
-                    Ok(ManagedFileRecord {
-                        id: row.get(0)?,
-                        file_type: row.get(1)?,
-                        display_name: row.get(2)?,
-                        note: row.get(3)?,
-                        relative_path: row.get(4)?,
-                        url: row.get(5)?,
-                        imported_at: row.get(6)?,
-                    })
-                },
+        let placement = place_into_storage(source_path, &destination_path, policy)?;
+        let inserted = lock().and_then(|mut conn| {
+            insert_local_record(
+                &mut conn,
+                &file_id,
+                "local_file",
+                &display_name,
+                &relative_path,
+                Some(&content_hash),
             )
-            .map_err(|e| e.to_string())?;
-
-        tx.commit().map_err(|e| e.to_string())?;
+        });
+        let mut record = match inserted {
+            Ok(record) => record,
+            Err(err) => {
+                undo_placement(source_path, &destination_path, placement);
+                return Err(err);
+            }
+        };
+        record.import_notice = finish_source(source_path, policy, placement);
         Ok(record)
     }
 
-    pub fn import_local_entry(
-        conn: &mut Connection,
+    /// Imports a file or a whole folder, holding `lock` only around the database work.
+    pub fn import_local_entry<C>(
+        lock: impl Fn() -> Result<C, AppError>,
         source_path: &Path,
         storage_root: &Path,
-    ) -> Result<ManagedFileRecord, String> {
+        policy: ImportPolicy,
+    ) -> Result<ManagedFileRecord, AppError>
+    where
+        C: DerefMut<Target = Connection>,
+    {
+        ensure_import_policy_supported(policy)?;
         if source_path.is_file() {
-            return Self::import_local_file(conn, source_path, storage_root);
+            return Self::import_local_file(lock, source_path, storage_root, policy);
         }
         if !source_path.exists() {
-            return Err("Source path does not exist.".to_string());
+            return Err(AppError::not_found("Source path does not exist."));
         }
         if !source_path.is_dir() {
-            return Err("Source path is not a file or directory.".to_string());
+            return Err(AppError::validation(
+                "Source path is not a file or directory.",
+            ));
         }
         if !storage_root.exists() {
-            return Err("Files storage folder does not exist.".to_string());
+            return Err(AppError::not_found("Files storage folder does not exist."));
         }
         if !storage_root.is_dir() {
-            return Err("Files storage folder is not a directory.".to_string());
+            return Err(AppError::validation(
+                "Files storage folder is not a directory.",
+            ));
         }
 
         let source_display_name = source_path
             .file_name()
             .map(|name| name.to_string_lossy().to_string())
-            .ok_or_else(|| "Failed to resolve source folder name.".to_string())?;
+            .ok_or_else(|| AppError::io("Failed to resolve source folder name."))?;
         let file_id = generate_file_id();
         let physical_name = build_physical_file_name(&source_display_name, &file_id);
         let destination_path = storage_root.join(&physical_name);
         let relative_path = destination_path
             .strip_prefix(storage_root)
-            .map_err(|e| e.to_string())?
+            .map_err(|e| AppError::io(e.to_string()))?
             .to_string_lossy()
             .to_string();
 
-        fs::rename(source_path, &destination_path)
-            .map_err(|e| format!("Failed to move folder into storage: {}", e))?;
-
-        let tx = conn.transaction().map_err(|e| e.to_string())?;
-        let insert_result = tx.execute(
-            "INSERT INTO files (id, type, display_name, note, relative_path, url, imported_at)
-             VALUES (?, 'local_directory', ?, NULL, ?, NULL, CURRENT_TIMESTAMP)",
-            params![&file_id, &source_display_name, &relative_path],
-        );
-
-        if let Err(err) = insert_result {
-            let _ = fs::rename(&destination_path, source_path);
-            return Err(err.to_string());
-        }
-
-        let record = tx
-            .query_row(
-                "SELECT id, type, display_name, note, relative_path, url, imported_at
-                 FROM files
-                 WHERE id = ?",
-                [&file_id],
-                |row| {
-                    Ok(ManagedFileRecord {
-                        id: row.get(0)?,
-                        file_type: row.get(1)?,
-                        display_name: row.get(2)?,
-                        note: row.get(3)?,
-                        relative_path: row.get(4)?,
-                        url: row.get(5)?,
-                        imported_at: row.get(6)?,
-                    })
-                },
+        let placement = place_into_storage(source_path, &destination_path, policy)?;
+        let inserted = lock().and_then(|mut conn| {
+            insert_local_record(
+                &mut conn,
+                &file_id,
+                "local_directory",
+                &source_display_name,
+                &relative_path,
+                None,
             )
-            .map_err(|e| e.to_string())?;
-
-        tx.commit().map_err(|e| e.to_string())?;
+        });
+        let mut record = match inserted {
+            Ok(record) => record,
+            Err(err) => {
+                undo_placement(source_path, &destination_path, placement);
+                return Err(err);
+            }
+        };
+        record.import_notice = finish_source(source_path, policy, placement);
         Ok(record)
     }
 
@@ -240,14 +248,13 @@ impl FileRepository {
         conn: &Connection,
         display_name: &str,
         url: &str,
-    ) -> Result<ManagedFileRecord, String> {
+    ) -> Result<ManagedFileRecord, AppError> {
         let file_id = generate_file_id();
         conn.execute(
             "INSERT INTO files (id, type, display_name, note, relative_path, url, imported_at)
              VALUES (?, 'external_link', ?, NULL, NULL, ?, CURRENT_TIMESTAMP)",
             params![&file_id, display_name, url],
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
         conn.query_row(
             "SELECT id, type, display_name, note, relative_path, url, imported_at
@@ -263,20 +270,27 @@ impl FileRepository {
                     relative_path: row.get(4)?,
                     url: row.get(5)?,
                     imported_at: row.get(6)?,
+                    import_notice: None,
                 })
             },
         )
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
     }
 
+    /// Lists file records, newest first. With a non-empty `query` the records are matched
+    /// against `file_fts` instead, ranked by relevance, with a snippet and related memos.
     pub fn list_files(
         conn: &Connection,
         limit: i64,
         offset: i64,
         unlinked_only: bool,
-    ) -> Result<ManagedFileListPage, String> {
+        query: Option<&str>,
+    ) -> Result<ManagedFileListPage, AppError> {
         let limit = limit.max(1);
         let offset = offset.max(0);
+        if let Some(query) = query.map(str::trim).filter(|query| !query.is_empty()) {
+            return search_files(conn, query, limit, offset, unlinked_only);
+        }
         let total_count_sql = if unlinked_only {
             "SELECT COUNT(*) FROM files
              WHERE NOT EXISTS (
@@ -285,9 +299,7 @@ impl FileRepository {
         } else {
             "SELECT COUNT(*) FROM files"
         };
-        let total_count: i64 = conn
-            .query_row(total_count_sql, [], |row| row.get(0))
-            .map_err(|e| e.to_string())?;
+        let total_count: i64 = conn.query_row(total_count_sql, [], |row| row.get(0))?;
 
         let list_sql = if unlinked_only {
             "SELECT
@@ -318,23 +330,21 @@ impl FileRepository {
                  LIMIT ? OFFSET ?"
         };
 
-        let mut stmt = conn.prepare(list_sql).map_err(|e| e.to_string())?;
-
-        let rows = stmt
-            .query_map(params![limit, offset], |row| {
-                Ok(ManagedFileListItem {
-                    id: row.get(0)?,
-                    file_type: row.get(1)?,
-                    display_name: row.get(2)?,
-                    imported_at: row.get(3)?,
-                    related_memo_count: row.get(4)?,
-                })
+        let mut stmt = conn.prepare(list_sql)?;
+
+        let rows = stmt.query_map(params![limit, offset], |row| {
+            Ok(ManagedFileListItem {
+                id: row.get(0)?,
+                file_type: row.get(1)?,
+                display_name: row.get(2)?,
+                imported_at: row.get(3)?,
+                related_memo_count: row.get(4)?,
+                snippet: None,
+                related_memos: Vec::new(),
             })
-            .map_err(|e| e.to_string())?;
+        })?;
 
-        let items = rows
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+        let items = rows.collect::<Result<Vec<_>, _>>()?;
 
         Ok(ManagedFileListPage {
             items,
@@ -347,7 +357,7 @@ impl FileRepository {
     pub fn find_record(
         conn: &Connection,
         file_id: &str,
-    ) -> Result<Option<ManagedFileRecord>, String> {
+    ) -> Result<Option<ManagedFileRecord>, AppError> {
         conn.query_row(
             "SELECT id, type, display_name, note, relative_path, url, imported_at
              FROM files
@@ -362,65 +372,60 @@ impl FileRepository {
                     relative_path: row.get(4)?,
                     url: row.get(5)?,
                     imported_at: row.get(6)?,
+                    import_notice: None,
                 })
             },
         )
         .optional()
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
     }
 
     pub fn list_files_for_memo(
         conn: &Connection,
         memo_id: i32,
-    ) -> Result<Vec<MemoLinkedFileItem>, String> {
-        let mut stmt = conn
-            .prepare(
-                "SELECT files.id, files.type, files.display_name
+    ) -> Result<Vec<MemoLinkedFileItem>, AppError> {
+        let mut stmt = conn.prepare(
+            "SELECT files.id, files.type, files.display_name
                  FROM memo_files
                  JOIN files ON files.id = memo_files.file_id
                  WHERE memo_files.memo_id = ?
                  ORDER BY files.imported_at DESC, files.display_name ASC",
-            )
-            .map_err(|e| e.to_string())?;
+        )?;
 
-        let rows = stmt
-            .query_map([memo_id], |row| {
-                Ok(MemoLinkedFileItem {
-                    id: row.get(0)?,
-                    file_type: row.get(1)?,
-                    display_name: row.get(2)?,
-                })
+        let rows = stmt.query_map([memo_id], |row| {
+            Ok(MemoLinkedFileItem {
+                id: row.get(0)?,
+                file_type: row.get(1)?,
+                display_name: row.get(2)?,
             })
-            .map_err(|e| e.to_string())?;
+        })?;
 
-        rows.collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())
+        rows.collect::<Result<Vec<_>, _>>().map_err(AppError::from)
     }
 
     pub fn update_display_name(
         conn: &Connection,
         file_id: &str,
         display_name: &str,
-    ) -> Result<ManagedFileRecord, String> {
+    ) -> Result<ManagedFileRecord, AppError> {
         let normalized_display_name = display_name.trim();
         if normalized_display_name.is_empty() {
-            return Err("Display name is required.".to_string());
+            return Err(AppError::validation("Display name is required."));
         }
 
-        let updated = conn
-            .execute(
-                "UPDATE files
+        let updated = conn.execute(
+            "UPDATE files
                  SET display_name = ?
                  WHERE id = ?",
-                params![normalized_display_name, file_id],
-            )
-            .map_err(|e| e.to_string())?;
+            params![normalized_display_name, file_id],
+        )?;
 
         if updated == 0 {
-            return Err("File record was not found.".to_string());
+            return Err(AppError::not_found("File record was not found."));
         }
 
-        Self::find_record(conn, file_id)?.ok_or_else(|| "File record was not found.".to_string())
+        Self::find_record(conn, file_id)?
+            .ok_or_else(|| AppError::not_found("File record was not found."))
     }
 
     pub fn update_external_link(
@@ -428,38 +433,37 @@ impl FileRepository {
         file_id: &str,
         display_name: &str,
         url: &str,
-    ) -> Result<ManagedFileRecord, String> {
+    ) -> Result<ManagedFileRecord, AppError> {
         let normalized_display_name = display_name.trim();
         if normalized_display_name.is_empty() {
-            return Err("Display name is required.".to_string());
+            return Err(AppError::validation("Display name is required."));
         }
 
         let normalized_url = url.trim();
         if normalized_url.is_empty() {
-            return Err("URL is required.".to_string());
+            return Err(AppError::validation("URL is required."));
         }
 
-        let updated = conn
-            .execute(
-                "UPDATE files
+        let updated = conn.execute(
+            "UPDATE files
                  SET display_name = ?, url = ?
                  WHERE id = ? AND type = 'external_link'",
-                params![normalized_display_name, normalized_url, file_id],
-            )
-            .map_err(|e| e.to_string())?;
+            params![normalized_display_name, normalized_url, file_id],
+        )?;
 
         if updated == 0 {
-            return Err("External link record was not found.".to_string());
+            return Err(AppError::not_found("External link record was not found."));
         }
 
-        Self::find_record(conn, file_id)?.ok_or_else(|| "File record was not found.".to_string())
+        Self::find_record(conn, file_id)?
+            .ok_or_else(|| AppError::not_found("File record was not found."))
     }
 
     pub fn update_note(
         conn: &Connection,
         file_id: &str,
         note: &str,
-    ) -> Result<ManagedFileRecord, String> {
+    ) -> Result<ManagedFileRecord, AppError> {
         let normalized_note = note.trim();
         let stored_note = if normalized_note.is_empty() {
             None
@@ -467,53 +471,30 @@ impl FileRepository {
             Some(normalized_note)
         };
 
-        let updated = conn
-            .execute(
-                "UPDATE files
+        let updated = conn.execute(
+            "UPDATE files
                  SET note = ?
                  WHERE id = ?",
-                params![stored_note, file_id],
-            )
-            .map_err(|e| e.to_string())?;
+            params![stored_note, file_id],
+        )?;
 
         if updated == 0 {
-            return Err("File record was not found.".to_string());
+            return Err(AppError::not_found("File record was not found."));
         }
 
-        Self::find_record(conn, file_id)?.ok_or_else(|| "File record was not found.".to_string())
+        Self::find_record(conn, file_id)?
+            .ok_or_else(|| AppError::not_found("File record was not found."))
     }
 
     pub fn get_file_detail(
         conn: &Connection,
         file_id: &str,
-    ) -> Result<Option<ManagedFileDetail>, String> {
+    ) -> Result<Option<ManagedFileDetail>, AppError> {
         let Some(record) = Self::find_record(conn, file_id)? else {
             return Ok(None);
         };
 
-        let mut stmt = conn
-            .prepare(
-                "SELECT memo.id, workspace.slug_name, memo.slug_title, memo.title
-                 FROM memo_files
-                 JOIN memo ON memo.id = memo_files.memo_id
-                 JOIN workspace ON workspace.id = memo.workspace_id
-                 WHERE memo_files.file_id = ?
-                 ORDER BY memo.modified_at DESC, memo.id DESC",
-            )
-            .map_err(|e| e.to_string())?;
-
-        let related_memos = stmt
-            .query_map([file_id], |row| {
-                Ok(RelatedMemoSummary {
-                    memo_id: row.get(0)?,
-                    workspace_slug_name: row.get(1)?,
-                    memo_slug_title: row.get(2)?,
-                    title: row.get(3)?,
-                })
-            })
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+        let related_memos = list_related_memos(conn, file_id)?;
 
         Ok(Some(ManagedFileDetail {
             id: record.id,
@@ -531,15 +512,15 @@ impl FileRepository {
         conn: &Connection,
         storage_root: &Path,
         file_id: &str,
-    ) -> Result<ResolvedFileOpenTarget, String> {
+    ) -> Result<ResolvedFileOpenTarget, AppError> {
         let record = Self::find_record(conn, file_id)?
-            .ok_or_else(|| "File record was not found.".to_string())?;
+            .ok_or_else(|| AppError::not_found("File record was not found."))?;
 
         match record.file_type.as_str() {
             "external_link" => {
                 let url = record
                     .url
-                    .ok_or_else(|| "External link URL is missing.".to_string())?;
+                    .ok_or_else(|| AppError::validation("External link URL is missing."))?;
                 Ok(ResolvedFileOpenTarget {
                     open_kind: "url".to_string(),
                     value: url,
@@ -549,7 +530,7 @@ impl FileRepository {
                 let relative_path = record
                     .relative_path
                     .clone()
-                    .ok_or_else(|| "Local file path is missing.".to_string())?;
+                    .ok_or_else(|| AppError::validation("Local file path is missing."))?;
                 let direct_path = storage_root.join(&relative_path);
                 if direct_path.exists() {
                     return Ok(ResolvedFileOpenTarget {
@@ -560,50 +541,254 @@ impl FileRepository {
 
                 let matches = find_files_by_file_id(storage_root, file_id)?;
                 if matches.is_empty() {
-                    return Err("The file could not be found in storage.".to_string());
+                    return Err(AppError::not_found(
+                        "The file could not be found in storage.",
+                    ));
                 }
                 if matches.len() > 1 {
-                    return Err("Multiple matching files were found in storage.".to_string());
+                    return Err(AppError::conflict(
+                        "Multiple matching files were found in storage.",
+                    ));
                 }
 
                 let resolved_path = matches[0].clone();
                 let updated_relative_path = resolved_path
                     .strip_prefix(storage_root)
-                    .map_err(|e| e.to_string())?
+                    .map_err(|e| AppError::io(e.to_string()))?
                     .to_string_lossy()
                     .to_string();
                 conn.execute(
-                    "UPDATE files SET relative_path = ? WHERE id = ?",
+                    "UPDATE files SET relative_path = ?, text_extracted_at = NULL WHERE id = ?",
                     params![&updated_relative_path, file_id],
-                )
-                .map_err(|e| e.to_string())?;
+                )?;
 
                 Ok(ResolvedFileOpenTarget {
                     open_kind: "path".to_string(),
                     value: resolved_path.to_string_lossy().to_string(),
                 })
             }
-            _ => Err("Unsupported file type.".to_string()),
+            _ => Err(AppError::validation("Unsupported file type.")),
+        }
+    }
+
+    /// Compares local file records with the storage folder. Records whose path is gone
+    /// are relinked when exactly one entry carries their id, and reported as missing
+    /// otherwise. Entries no record points at are listed as unknown or unmanaged.
+    pub fn verify_storage(
+        conn: &Connection,
+        storage_root: &Path,
+    ) -> Result<StorageVerificationReport, AppError> {
+        if !storage_root.is_dir() {
+            return Err(AppError::not_found("Files storage folder does not exist."));
+        }
+
+        let mut entries = Vec::new();
+        scan_storage(storage_root, storage_root, &mut entries)?;
+
+        let records: Vec<(String, String, Option<String>)> = conn
+            .prepare(
+                "SELECT id, display_name, relative_path
+                FROM files
+                WHERE type IN ('local_file', 'local_directory')
+                ORDER BY imported_at, id",
+            )?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect::<Result<_, _>>()?;
+
+        let mut report = StorageVerificationReport {
+            checked_count: records.len() as i64,
+            missing: Vec::new(),
+            relinked: Vec::new(),
+            unknown: Vec::new(),
+            unmanaged: Vec::new(),
+        };
+        let mut known_ids = BTreeSet::new();
+        for (file_id, display_name, relative_path) in records {
+            known_ids.insert(file_id.clone());
+            if relative_path
+                .as_deref()
+                .is_some_and(|relative_path| storage_root.join(relative_path).exists())
+            {
+                continue;
+            }
+
+            let candidates: Vec<String> = entries
+                .iter()
+                .filter(|entry| entry.embedded_file_id.as_deref() == Some(file_id.as_str()))
+                .map(|entry| entry.relative_path.clone())
+                .collect();
+            if let [new_relative_path] = candidates.as_slice() {
+                conn.execute(
+                    "UPDATE files SET relative_path = ?, text_extracted_at = NULL WHERE id = ?",
+                    params![new_relative_path, &file_id],
+                )?;
+                report.relinked.push(RelinkedManagedFile {
+                    file_id,
+                    display_name,
+                    previous_relative_path: relative_path,
+                    relative_path: new_relative_path.clone(),
+                });
+            } else {
+                report.missing.push(MissingManagedFile {
+                    file_id,
+                    display_name,
+                    relative_path,
+                    candidates,
+                });
+            }
         }
+
+        for entry in entries {
+            match entry.embedded_file_id.as_deref() {
+                Some(file_id) if known_ids.contains(file_id) => {}
+                Some(_) => report.unknown.push(entry),
+                None => report.unmanaged.push(entry),
+            }
+        }
+
+        Ok(report)
     }
 
-    pub fn delete_file_record(conn: &Connection, file_id: &str) -> Result<(), String> {
-        let related_count: i64 = conn
-            .query_row(
-                "SELECT COUNT(*) FROM memo_files WHERE file_id = ?",
-                [file_id],
-                |row| row.get(0),
+    /// Creates a record for an entry already in the storage folder. Entries named with an
+    /// unknown file id keep it; others are renamed to carry a new one so they can be relinked
+    /// after later moves.
+    pub fn adopt_storage_entry(
+        conn: &Connection,
+        storage_root: &Path,
+        relative_path: &str,
+    ) -> Result<ManagedFileRecord, AppError> {
+        let relative = Path::new(relative_path);
+        if relative_path.trim().is_empty()
+            || relative
+                .components()
+                .any(|component| !matches!(component, std::path::Component::Normal(_)))
+        {
+            return Err(AppError::validation(
+                "Path must be relative to the files storage folder.",
+            ));
+        }
+        let source_path = storage_root.join(relative);
+        let file_type = if source_path.is_dir() {
+            "local_directory"
+        } else if source_path.is_file() {
+            "local_file"
+        } else {
+            return Err(AppError::not_found(
+                "The entry could not be found in storage.",
+            ));
+        };
+        let name = source_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .ok_or_else(|| AppError::io("Failed to resolve entry name."))?;
+
+        let (file_id, display_name, destination_path) = match parse_embedded_file_id(&name) {
+            Some(file_id) => {
+                if Self::find_record(conn, &file_id)?.is_some() {
+                    return Err(AppError::conflict(format!(
+                        "The entry already belongs to file record {}.",
+                        file_id
+                    )));
+                }
+                let display_name = name.replacen(&format!("__mb_{}", file_id), "", 1);
+                (file_id, display_name, source_path.clone())
+            }
+            None => {
+                let file_id = generate_file_id();
+                let destination_path =
+                    source_path.with_file_name(build_physical_file_name(&name, &file_id));
+                (file_id, name, destination_path)
+            }
+        };
+        let new_relative_path = destination_path
+            .strip_prefix(storage_root)
+            .map_err(|e| AppError::io(e.to_string()))?
+            .to_string_lossy()
+            .to_string();
+        let content_hash = if file_type == "local_file" {
+            Some(
+                hash_file(&source_path)
+                    .map_err(|e| AppError::io(format!("Failed to read file: {}", e)))?,
             )
-            .map_err(|e| e.to_string())?;
+        } else {
+            None
+        };
+
+        if destination_path != source_path {
+            fs::rename(&source_path, &destination_path)
+                .map_err(|e| AppError::io(format!("Failed to rename entry: {}", e)))?;
+        }
+        let insert_result = conn.execute(
+            "INSERT INTO files (id, type, display_name, note, relative_path, url, imported_at, content_hash)
+             VALUES (?, ?, ?, NULL, ?, NULL, CURRENT_TIMESTAMP, ?)",
+            params![&file_id, file_type, &display_name, &new_relative_path, &content_hash],
+        );
+        if let Err(err) = insert_result {
+            if destination_path != source_path {
+                let _ = fs::rename(&destination_path, &source_path);
+            }
+            return Err(err.into());
+        }
+
+        Self::find_record(conn, &file_id)?
+            .ok_or_else(|| AppError::not_found("File record was not found."))
+    }
+
+    /// Local files whose text has not been extracted yet, as `(id, relative_path)`.
+    pub fn list_pending_text_extractions(
+        conn: &Connection,
+        limit: i64,
+    ) -> Result<Vec<(String, String)>, AppError> {
+        let pending = conn
+            .prepare(
+                "SELECT id, relative_path
+                FROM files
+                WHERE type = 'local_file'
+                  AND relative_path IS NOT NULL
+                  AND text_extracted_at IS NULL
+                ORDER BY imported_at DESC, id
+                LIMIT ?",
+            )?
+            .query_map([limit.max(1)], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(pending)
+    }
+
+    /// Stores the extracted text in `file_fts`. Files without extractable text are stored
+    /// with an empty body so they are not picked up again.
+    pub fn store_extracted_text(
+        conn: &mut Connection,
+        file_id: &str,
+        body_text: &str,
+    ) -> Result<(), AppError> {
+        let tx = conn.transaction()?;
+        tx.execute(
+            "UPDATE file_fts SET body_text = ? WHERE file_id = ?",
+            params![body_text, file_id],
+        )?;
+        tx.execute(
+            "UPDATE files SET text_extracted_at = CURRENT_TIMESTAMP WHERE id = ?",
+            [file_id],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub fn delete_file_record(conn: &Connection, file_id: &str) -> Result<(), AppError> {
+        let related_count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM memo_files WHERE file_id = ?",
+            [file_id],
+            |row| row.get(0),
+        )?;
         if related_count > 0 {
-            return Err("Cannot delete a file record that is still linked from memos.".to_string());
+            return Err(AppError::conflict(
+                "Cannot delete a file record that is still linked from memos.",
+            ));
         }
 
-        let deleted = conn
-            .execute("DELETE FROM files WHERE id = ?", [file_id])
-            .map_err(|e| e.to_string())?;
+        let deleted = conn.execute("DELETE FROM files WHERE id = ?", [file_id])?;
         if deleted == 0 {
-            return Err("File record was not found.".to_string());
+            return Err(AppError::not_found("File record was not found."));
         }
         Ok(())
     }
@@ -613,13 +798,12 @@ impl FileRepository {
         memo_id: i32,
         file_id: &str,
         display_name: &str,
-    ) -> Result<(), String> {
-        let tx = conn.transaction().map_err(|e| e.to_string())?;
-        let current_content: String = tx
-            .query_row("SELECT content FROM memo WHERE id = ?", [memo_id], |row| {
+    ) -> Result<(), AppError> {
+        let tx = conn.transaction()?;
+        let current_content: String =
+            tx.query_row("SELECT content FROM memo WHERE id = ?", [memo_id], |row| {
                 row.get(0)
-            })
-            .map_err(|e| e.to_string())?;
+            })?;
 
         let updated_content = append_file_link_block(&current_content, file_id, display_name)?;
         let body_text = extract_plain_text_from_value_str(&updated_content);
@@ -629,11 +813,9 @@ impl FileRepository {
              SET content = ?, body_text = ?, modified_at = CURRENT_TIMESTAMP
              WHERE id = ?",
             params![&updated_content, &body_text, memo_id],
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
-        tx.execute("DELETE FROM memo_fts WHERE memo_id = ?", [memo_id])
-            .map_err(|e| e.to_string())?;
+        tx.execute("DELETE FROM memo_fts WHERE memo_id = ?", [memo_id])?;
 
         tx.execute(
             "INSERT INTO memo_fts (title, description, body_text, memo_id, workspace_id, slug_title)
@@ -641,35 +823,121 @@ impl FileRepository {
              FROM memo
              WHERE id = ?",
             [memo_id],
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
         sync_memo_files_in_tx(&tx, memo_id, &updated_content)?;
-        tx.commit().map_err(|e| e.to_string())
+        tx.commit().map_err(AppError::from)
     }
 
-    pub fn sync_memo_files(conn: &Connection, memo_id: i32, content: &str) -> Result<(), String> {
+    pub fn sync_memo_files(conn: &Connection, memo_id: i32, content: &str) -> Result<(), AppError> {
         sync_memo_files_in_tx(conn, memo_id, content)
     }
 }
 
-fn sync_memo_files_in_tx(conn: &Connection, memo_id: i32, content: &str) -> Result<(), String> {
+fn search_files(
+    conn: &Connection,
+    query: &str,
+    limit: i64,
+    offset: i64,
+    unlinked_only: bool,
+) -> Result<ManagedFileListPage, AppError> {
+    // The trigram tokenizer matches a quoted phrase as a substring, so user input never has
+    // to be valid FTS5 query syntax.
+    let phrase = format!("\"{}\"", query.replace('"', "\"\""));
+    let total_count: i64 = conn.query_row(
+        "SELECT COUNT(*)
+        FROM file_fts
+        JOIN files ON files.id = file_fts.file_id
+        WHERE file_fts MATCH ?1
+          AND (?2 = 0 OR NOT EXISTS (
+            SELECT 1 FROM memo_files WHERE memo_files.file_id = files.id
+          ))",
+        params![&phrase, unlinked_only],
+        |row| row.get(0),
+    )?;
+
+    let mut items = conn
+        .prepare(
+            "SELECT
+                files.id,
+                files.type,
+                files.display_name,
+                files.imported_at,
+                (SELECT COUNT(*) FROM memo_files WHERE memo_files.file_id = files.id),
+                snippet(file_fts, -1, '', '', '…', 20)
+            FROM file_fts
+            JOIN files ON files.id = file_fts.file_id
+            WHERE file_fts MATCH ?1
+              AND (?2 = 0 OR NOT EXISTS (
+                SELECT 1 FROM memo_files WHERE memo_files.file_id = files.id
+              ))
+            ORDER BY bm25(file_fts) ASC, files.imported_at DESC
+            LIMIT ?3 OFFSET ?4",
+        )?
+        .query_map(params![&phrase, unlinked_only, limit, offset], |row| {
+            Ok(ManagedFileListItem {
+                id: row.get(0)?,
+                file_type: row.get(1)?,
+                display_name: row.get(2)?,
+                imported_at: row.get(3)?,
+                related_memo_count: row.get(4)?,
+                snippet: row.get(5)?,
+                related_memos: Vec::new(),
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    for item in &mut items {
+        item.related_memos = list_related_memos(conn, &item.id)?;
+    }
+
+    Ok(ManagedFileListPage {
+        items,
+        total_count,
+        limit,
+        offset,
+    })
+}
+
+fn list_related_memos(
+    conn: &Connection,
+    file_id: &str,
+) -> Result<Vec<RelatedMemoSummary>, AppError> {
+    let related_memos = conn
+        .prepare(
+            "SELECT memo.id, workspace.slug_name, memo.slug_title, memo.title
+                 FROM memo_files
+                 JOIN memo ON memo.id = memo_files.memo_id
+                 JOIN workspace ON workspace.id = memo.workspace_id
+                 WHERE memo_files.file_id = ?
+                 ORDER BY memo.modified_at DESC, memo.id DESC",
+        )?
+        .query_map([file_id], |row| {
+            Ok(RelatedMemoSummary {
+                memo_id: row.get(0)?,
+                workspace_slug_name: row.get(1)?,
+                memo_slug_title: row.get(2)?,
+                title: row.get(3)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(related_memos)
+}
+
+fn sync_memo_files_in_tx(conn: &Connection, memo_id: i32, content: &str) -> Result<(), AppError> {
     let file_ids = collect_file_ids_from_content(content);
-    conn.execute("DELETE FROM memo_files WHERE memo_id = ?", [memo_id])
-        .map_err(|e| e.to_string())?;
+    conn.execute("DELETE FROM memo_files WHERE memo_id = ?", [memo_id])?;
 
     for file_id in file_ids {
         conn.execute(
             "INSERT INTO memo_files (memo_id, file_id) VALUES (?, ?)",
             params![memo_id, file_id],
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
     }
 
     Ok(())
 }
 
-fn collect_file_ids_from_content(content: &str) -> Vec<String> {
+pub(crate) fn collect_file_ids_from_content(content: &str) -> Vec<String> {
     let Ok(doc) = serde_json::from_str::<Value>(content) else {
         return Vec::new();
     };
@@ -706,7 +974,7 @@ fn append_file_link_block(
     content: &str,
     file_id: &str,
     display_name: &str,
-) -> Result<String, String> {
+) -> Result<String, AppError> {
     let mut doc = match serde_json::from_str::<Value>(content) {
         Ok(Value::Object(mut object)) => {
             if object.get("type").and_then(Value::as_str) != Some("doc") {
@@ -726,7 +994,7 @@ fn append_file_link_block(
     let content_array = doc
         .get_mut("content")
         .and_then(Value::as_array_mut)
-        .ok_or_else(|| "Memo content does not have a valid content array.".to_string())?;
+        .ok_or_else(|| AppError::validation("Memo content does not have a valid content array."))?;
 
     content_array.push(json!({
         "type": "paragraph",
@@ -747,7 +1015,7 @@ fn append_file_link_block(
         ]
     }));
 
-    serde_json::to_string(&doc).map_err(|e| e.to_string())
+    serde_json::to_string(&doc).map_err(AppError::from)
 }
 
 fn extract_plain_text_from_value_str(content: &str) -> String {
@@ -773,15 +1041,15 @@ fn collect_plain_text(node: &Value, out: &mut Vec<String>) {
     }
 }
 
-fn find_files_by_file_id(root: &Path, file_id: &str) -> Result<Vec<PathBuf>, String> {
+fn find_files_by_file_id(root: &Path, file_id: &str) -> Result<Vec<PathBuf>, AppError> {
     let mut matches = Vec::new();
     walk_storage(root, file_id, &mut matches)?;
     Ok(matches)
 }
 
-fn walk_storage(dir: &Path, file_id: &str, matches: &mut Vec<PathBuf>) -> Result<(), String> {
-    for entry in fs::read_dir(dir).map_err(|e| e.to_string())? {
-        let entry = entry.map_err(|e| e.to_string())?;
+fn walk_storage(dir: &Path, file_id: &str, matches: &mut Vec<PathBuf>) -> Result<(), AppError> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
         let path = entry.path();
         if path.is_dir() {
             walk_storage(&path, file_id, matches)?;
@@ -799,6 +1067,384 @@ fn walk_storage(dir: &Path, file_id: &str, matches: &mut Vec<PathBuf>) -> Result
     Ok(())
 }
 
+/// How an imported entry reached storage, so a failed insert can be undone.
+#[derive(Clone, Copy)]
+enum Placement {
+    Moved,
+    Copied,
+}
+
+/// Fails for a policy this platform cannot carry out, before anything is placed.
+pub(crate) fn ensure_import_policy_supported(policy: ImportPolicy) -> Result<(), AppError> {
+    if policy.is_supported() {
+        Ok(())
+    } else {
+        Err(
+            AppError::validation("Moving files to the trash is not supported on this platform.")
+                .with_reason("TRASH_UNSUPPORTED"),
+        )
+    }
+}
+
+/// Moves or copies `source_path` to `destination_path` per `policy`. A move across
+/// filesystems falls back to a copy; the source is removed once the record exists.
+fn place_into_storage(
+    source_path: &Path,
+    destination_path: &Path,
+    policy: ImportPolicy,
+) -> Result<Placement, AppError> {
+    if policy == ImportPolicy::Move {
+        match fs::rename(source_path, destination_path) {
+            Ok(()) => return Ok(Placement::Moved),
+            Err(err) if is_cross_device_error(&err) => {}
+            Err(err) => {
+                return Err(AppError::io(format!(
+                    "Failed to move item into storage: {}",
+                    err
+                )))
+            }
+        }
+    }
+
+    copy_entry(source_path, destination_path).map_err(|err| {
+        let _ = remove_entry(destination_path);
+        AppError::io(format!("Failed to copy item into storage: {}", err))
+    })?;
+    Ok(Placement::Copied)
+}
+
+fn undo_placement(source_path: &Path, destination_path: &Path, placement: Placement) {
+    match placement {
+        Placement::Moved => {
+            let _ = fs::rename(destination_path, source_path);
+        }
+        Placement::Copied => {
+            let _ = remove_entry(destination_path);
+        }
+    }
+}
+
+/// Applies the policy to the source once the record is stored. Failures here do not undo
+/// the import; they are returned as a notice instead.
+fn finish_source(source_path: &Path, policy: ImportPolicy, placement: Placement) -> Option<String> {
+    let result = match (policy, placement) {
+        (ImportPolicy::Move, Placement::Copied) => remove_entry(source_path),
+        (ImportPolicy::CopyThenTrash, _) => move_to_trash(source_path),
+        _ => return None,
+    };
+    result
+        .err()
+        .map(|err| format!("The source could not be removed: {}", err))
+}
+
+fn insert_local_record(
+    conn: &mut Connection,
+    file_id: &str,
+    file_type: &str,
+    display_name: &str,
+    relative_path: &str,
+    content_hash: Option<&str>,
+) -> Result<ManagedFileRecord, AppError> {
+    let tx = conn.transaction()?;
+    tx.execute(
+        "INSERT INTO files (id, type, display_name, note, relative_path, url, imported_at, content_hash)
+         VALUES (?, ?, ?, NULL, ?, NULL, CURRENT_TIMESTAMP, ?)",
+        params![file_id, file_type, display_name, relative_path, content_hash],
+    )?;
+    let record = FileRepository::find_record(&tx, file_id)?
+        .ok_or_else(|| AppError::not_found("File record was not found."))?;
+    tx.commit()?;
+    Ok(record)
+}
+
+fn is_cross_device_error(err: &std::io::Error) -> bool {
+    // EXDEV on Unix, ERROR_NOT_SAME_DEVICE on Windows.
+    let code = if cfg!(windows) { 17 } else { 18 };
+    err.raw_os_error() == Some(code)
+}
+
+fn copy_entry(source_path: &Path, destination_path: &Path) -> std::io::Result<()> {
+    if !source_path.is_dir() {
+        return fs::copy(source_path, destination_path).map(|_| ());
+    }
+    fs::create_dir(destination_path)?;
+    for entry in fs::read_dir(source_path)? {
+        let entry = entry?;
+        copy_entry(&entry.path(), &destination_path.join(entry.file_name()))?;
+    }
+    Ok(())
+}
+
+fn remove_entry(path: &Path) -> std::io::Result<()> {
+    if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// Moves `path` to the user's trash: `~/.Trash` on macOS and the freedesktop.org trash
+/// elsewhere on Unix.
+fn move_to_trash(path: &Path) -> std::io::Result<()> {
+    let unsupported = || {
+        std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "the trash is not supported on this platform",
+        )
+    };
+    let base_dirs = directories::BaseDirs::new().ok_or_else(unsupported)?;
+
+    if cfg!(target_os = "macos") {
+        let name = entry_name(path)?;
+        let target = unique_path(&base_dirs.home_dir().join(".Trash"), &name, "");
+        return rename_or_copy(path, &target);
+    }
+    if !cfg!(unix) {
+        return Err(unsupported());
+    }
+    move_to_freedesktop_trash(path, &base_dirs.data_dir().join("Trash"))
+}
+
+/// Moves `path` into the freedesktop.org trash at `trash_dir`, writing its `.trashinfo`.
+fn move_to_freedesktop_trash(path: &Path, trash_dir: &Path) -> std::io::Result<()> {
+    let name = entry_name(path)?;
+    let files_dir = trash_dir.join("files");
+    let info_dir = trash_dir.join("info");
+    fs::create_dir_all(&files_dir)?;
+    fs::create_dir_all(&info_dir)?;
+
+    let target = unique_path(&files_dir, &name, ".trashinfo");
+    let trashed_name = target
+        .file_name()
+        .map(|value| value.to_string_lossy().to_string())
+        .unwrap_or(name);
+    let info_path = info_dir.join(format!("{}.trashinfo", trashed_name));
+    let absolute_path = fs::canonicalize(path)?;
+    fs::write(
+        &info_path,
+        format!(
+            "[Trash Info]\nPath={}\nDeletionDate={}\n",
+            percent_encode_path(&absolute_path.to_string_lossy()),
+            format_utc_timestamp(std::time::SystemTime::now())
+        ),
+    )?;
+    rename_or_copy(path, &target).inspect_err(|_| {
+        let _ = fs::remove_file(&info_path);
+    })
+}
+
+fn entry_name(path: &Path) -> std::io::Result<String> {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "no file name"))
+}
+
+/// Renames `source` to `target`. When they are on different filesystems, e.g. a USB stick
+/// and the home trash, the entry is copied and the source removed instead, as the
+/// freedesktop.org trash spec allows.
+fn rename_or_copy(source: &Path, target: &Path) -> std::io::Result<()> {
+    match fs::rename(source, target) {
+        Err(err) if is_cross_device_error(&err) => {
+            copy_entry(source, target).inspect_err(|_| {
+                let _ = remove_entry(target);
+            })?;
+            remove_entry(source)
+        }
+        result => result,
+    }
+}
+
+/// Picks `<dir>/<name>`, adding ` (n)` before the extension while it or its trash info exists.
+fn unique_path(dir: &Path, name: &str, info_suffix: &str) -> PathBuf {
+    let original = Path::new(name);
+    let stem = original
+        .file_stem()
+        .map(|value| value.to_string_lossy().to_string())
+        .unwrap_or_else(|| name.to_string());
+    let ext = original
+        .extension()
+        .map(|value| format!(".{}", value.to_string_lossy()))
+        .unwrap_or_default();
+    let info_dir = dir.parent().map(|parent| parent.join("info"));
+    let taken = |candidate: &str| {
+        dir.join(candidate).exists()
+            || (!info_suffix.is_empty()
+                && info_dir.as_ref().is_some_and(|info_dir| {
+                    info_dir
+                        .join(format!("{}{}", candidate, info_suffix))
+                        .exists()
+                }))
+    };
+
+    let mut candidate = name.to_string();
+    let mut counter = 1;
+    while taken(&candidate) {
+        candidate = format!("{} ({}){}", stem, counter, ext);
+        counter += 1;
+    }
+    dir.join(candidate)
+}
+
+fn percent_encode_path(path: &str) -> String {
+    path.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Formats `time` as `YYYY-MM-DDThh:mm:ss` in UTC.
+fn format_utc_timestamp(time: std::time::SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0);
+    let (days, seconds_of_day) = (seconds.div_euclid(86_400), seconds.rem_euclid(86_400));
+
+    // Civil-from-days conversion for the proleptic Gregorian calendar.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds_of_day / 3_600,
+        seconds_of_day % 3_600 / 60,
+        seconds_of_day % 60
+    )
+}
+
+/// Lists storage entries below `dir`. Entries carrying a file id are not descended into,
+/// since a managed folder belongs to its record as a whole. Hidden entries are skipped.
+fn scan_storage(root: &Path, dir: &Path, entries: &mut Vec<StorageEntry>) -> Result<(), AppError> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    paths.sort();
+
+    for path in paths {
+        let Some(name) = path.file_name().and_then(|value| value.to_str()) else {
+            continue;
+        };
+        if name.starts_with('.') {
+            continue;
+        }
+        let embedded_file_id = parse_embedded_file_id(name);
+        if embedded_file_id.is_none() && path.is_dir() {
+            scan_storage(root, &path, entries)?;
+            continue;
+        }
+
+        entries.push(StorageEntry {
+            relative_path: path
+                .strip_prefix(root)
+                .map_err(|e| AppError::io(e.to_string()))?
+                .to_string_lossy()
+                .to_string(),
+            entry_type: if path.is_dir() {
+                "local_directory".to_string()
+            } else {
+                "local_file".to_string()
+            },
+            embedded_file_id,
+        });
+    }
+
+    Ok(())
+}
+
+/// Reads `<id>` from a `<stem>__mb_<id>[.ext]` name built by `build_physical_file_name`.
+fn parse_embedded_file_id(name: &str) -> Option<String> {
+    let (_, rest) = name.rsplit_once("__mb_")?;
+    let file_id = rest.split('.').next()?;
+    (file_id.len() == 32
+        && file_id
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || ch.is_ascii_uppercase()))
+    .then(|| file_id.to_string())
+}
+
+/// Hex SHA-256 of the file contents.
+pub(crate) fn hash_file(path: &Path) -> std::io::Result<String> {
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut fs::File::open(path)?, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Finds a managed file with the given content hash whose stored copy still exists.
+/// Records imported before hashes were kept are hashed on demand when their size matches.
+/// `lock` is called once to list those records and once to store their hashes and look up
+/// the match, so the connection is not held while stored files are read.
+fn find_local_file_by_hash<C>(
+    lock: impl Fn() -> Result<C, AppError>,
+    storage_root: &Path,
+    source_path: &Path,
+    content_hash: &str,
+) -> Result<Option<ManagedFileRecord>, AppError>
+where
+    C: DerefMut<Target = Connection>,
+{
+    let source_size = fs::metadata(source_path)?.len();
+    let unhashed: Vec<(String, String)> = lock()?
+        .prepare(
+            "SELECT id, relative_path FROM files
+            WHERE type = 'local_file' AND content_hash IS NULL AND relative_path IS NOT NULL",
+        )?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<_, _>>()?;
+    let stored_hashes: Vec<(String, String)> = unhashed
+        .into_iter()
+        .filter_map(|(file_id, relative_path)| {
+            let stored_path = storage_root.join(relative_path);
+            let size = fs::metadata(&stored_path).ok()?.len();
+            if size != source_size {
+                return None;
+            }
+            hash_file(&stored_path).ok().map(|hash| (file_id, hash))
+        })
+        .collect();
+
+    let conn = lock()?;
+    for (file_id, stored_hash) in &stored_hashes {
+        conn.execute(
+            "UPDATE files SET content_hash = ? WHERE id = ? AND content_hash IS NULL",
+            params![stored_hash, file_id],
+        )?;
+    }
+
+    let candidates: Vec<(String, String)> = conn
+        .prepare(
+            "SELECT id, relative_path FROM files
+            WHERE type = 'local_file' AND content_hash = ? AND relative_path IS NOT NULL
+            ORDER BY imported_at, id",
+        )?
+        .query_map([content_hash], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<_, _>>()?;
+    for (file_id, relative_path) in candidates {
+        if storage_root.join(relative_path).is_file() {
+            return FileRepository::find_record(&conn, &file_id);
+        }
+    }
+    Ok(None)
+}
+
 fn generate_file_id() -> String {
     Uuid::new_v4().simple().to_string().to_uppercase()
 }
@@ -821,7 +1467,7 @@ fn build_physical_file_name(original_name: &str, file_id: &str) -> String {
     }
 }
 
-fn should_ignore_inbox_file(path: &Path, ignored_file_names: &[String]) -> bool {
+pub(crate) fn should_ignore_inbox_file(path: &Path, ignored_file_names: &[String]) -> bool {
     let Some(name) = path.file_name().and_then(|value| value.to_str()) else {
         return false;
     };
@@ -838,12 +1484,17 @@ fn should_ignore_inbox_file(path: &Path, ignored_file_names: &[String]) -> bool
 #[cfg(test)]
 mod tests {
     use std::fs;
+    use std::path::Path;
+    use std::sync::Mutex;
 
     use rusqlite::{params, Connection};
 
+    use crate::models::file::ImportPolicy;
+
     use super::{
         append_file_link_block, build_physical_file_name, collect_file_ids_from_content,
-        generate_file_id, should_ignore_inbox_file, FileRepository,
+        format_utc_timestamp, generate_file_id, hash_file, move_to_freedesktop_trash,
+        should_ignore_inbox_file, FileRepository,
     };
     use crate::migrations::apply_migrations;
 
@@ -973,7 +1624,12 @@ mod tests {
 
     #[test]
     fn import_local_entry_moves_directory_as_single_record() {
-        let mut conn = setup_conn();
+        let shared = Mutex::new(setup_conn());
+        let lock = || {
+            Ok(shared
+                .lock()
+                .expect("test connection lock should not be poisoned"))
+        };
         let temp_root =
             std::env::temp_dir().join(format!("monobox-file-test-{}", generate_file_id()));
         let source_dir = temp_root.join("Downloads").join("Project");
@@ -983,8 +1639,13 @@ mod tests {
         fs::create_dir_all(&storage_root).expect("storage folder should be created");
         fs::write(nested_dir.join("plan.md"), "# Plan").expect("nested file should be written");
 
-        let record = FileRepository::import_local_entry(&mut conn, &source_dir, &storage_root)
-            .expect("directory should import");
+        let record = FileRepository::import_local_entry(
+            lock,
+            &source_dir,
+            &storage_root,
+            ImportPolicy::Move,
+        )
+        .expect("directory should import");
 
         assert_eq!(record.file_type, "local_directory");
         assert_eq!(record.display_name, "Project");
@@ -997,4 +1658,213 @@ mod tests {
 
         fs::remove_dir_all(temp_root).expect("temp folders should be removed");
     }
+
+    #[test]
+    fn import_local_file_reuses_record_for_identical_content() {
+        let shared = Mutex::new(setup_conn());
+        let lock = || {
+            Ok(shared
+                .lock()
+                .expect("test connection lock should not be poisoned"))
+        };
+        let temp_root =
+            std::env::temp_dir().join(format!("monobox-file-test-{}", generate_file_id()));
+        let downloads = temp_root.join("Downloads");
+        let storage_root = temp_root.join("Storage");
+        fs::create_dir_all(&downloads).expect("downloads folder should be created");
+        fs::create_dir_all(&storage_root).expect("storage folder should be created");
+        fs::write(downloads.join("report.pdf"), "same bytes").expect("file should be written");
+        fs::write(downloads.join("report (1).pdf"), "same bytes").expect("file should be written");
+
+        let first = FileRepository::import_local_file(
+            lock,
+            &downloads.join("report.pdf"),
+            &storage_root,
+            ImportPolicy::Move,
+        )
+        .expect("first import should succeed");
+        assert_eq!(first.import_notice, None);
+        // Records imported before hashes were kept are hashed when a duplicate arrives.
+        shared
+            .lock()
+            .expect("test connection lock should not be poisoned")
+            .execute("UPDATE files SET content_hash = NULL", [])
+            .expect("hash should be clearable");
+
+        let second = FileRepository::import_local_file(
+            lock,
+            &downloads.join("report (1).pdf"),
+            &storage_root,
+            ImportPolicy::Move,
+        )
+        .expect("duplicate import should succeed");
+        assert_eq!(second.id, first.id);
+        assert_eq!(
+            second.import_notice.as_deref(),
+            Some("Already imported as report.pdf.")
+        );
+        assert!(!downloads.join("report (1).pdf").exists());
+        let conn = shared
+            .into_inner()
+            .expect("test connection lock should not be poisoned");
+        let file_count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM files", [], |row| row.get(0))
+            .expect("files should be countable");
+        assert_eq!(file_count, 1);
+        let stored_hash: Option<String> = conn
+            .query_row("SELECT content_hash FROM files", [], |row| row.get(0))
+            .expect("hash should be readable");
+        assert_eq!(
+            stored_hash,
+            Some(
+                hash_file(&storage_root.join(first.relative_path.expect("local file has a path")))
+                    .expect("stored file should hash")
+            )
+        );
+
+        fs::remove_dir_all(temp_root).expect("temp folders should be removed");
+    }
+
+    #[test]
+    fn verify_storage_relinks_moved_files_and_lists_unmanaged_entries() {
+        let shared = Mutex::new(setup_conn());
+        let lock = || {
+            Ok(shared
+                .lock()
+                .expect("test connection lock should not be poisoned"))
+        };
+        let temp_root =
+            std::env::temp_dir().join(format!("monobox-file-test-{}", generate_file_id()));
+        let downloads = temp_root.join("Downloads");
+        let storage_root = temp_root.join("Storage");
+        fs::create_dir_all(&downloads).expect("downloads folder should be created");
+        fs::create_dir_all(storage_root.join("archive")).expect("storage folder should be created");
+        fs::write(downloads.join("plan.md"), "# Plan").expect("file should be written");
+        fs::write(storage_root.join("loose.txt"), "loose").expect("file should be written");
+
+        let record = FileRepository::import_local_file(
+            lock,
+            &downloads.join("plan.md"),
+            &storage_root,
+            ImportPolicy::Move,
+        )
+        .expect("import should succeed");
+        let stored_name = record
+            .relative_path
+            .clone()
+            .expect("relative path should exist");
+        fs::rename(
+            storage_root.join(&stored_name),
+            storage_root.join("archive").join(&stored_name),
+        )
+        .expect("stored file should move");
+
+        let conn = shared
+            .into_inner()
+            .expect("test connection lock should not be poisoned");
+        let report =
+            FileRepository::verify_storage(&conn, &storage_root).expect("verification should run");
+        assert!(report.missing.is_empty());
+        assert_eq!(report.relinked.len(), 1);
+        assert_eq!(
+            report.relinked[0].relative_path,
+            Path::new("archive").join(&stored_name).to_string_lossy()
+        );
+        assert_eq!(report.unmanaged.len(), 1);
+        assert_eq!(report.unmanaged[0].relative_path, "loose.txt");
+
+        let adopted = FileRepository::adopt_storage_entry(&conn, &storage_root, "loose.txt")
+            .expect("entry should be adopted");
+        assert_eq!(adopted.display_name, "loose.txt");
+        let report =
+            FileRepository::verify_storage(&conn, &storage_root).expect("verification should run");
+        assert!(report.relinked.is_empty() && report.unmanaged.is_empty());
+
+        fs::remove_dir_all(temp_root).expect("temp folders should be removed");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn move_to_freedesktop_trash_copies_across_filesystems() {
+        use std::os::unix::fs::MetadataExt;
+
+        let test_id = generate_file_id();
+        let temp_root = std::env::temp_dir().join(format!("monobox-file-test-{}", test_id));
+        let source_root = Path::new("/dev/shm").join(format!("monobox-file-test-{}", test_id));
+        fs::create_dir_all(&temp_root).expect("temp folder should be created");
+        if fs::create_dir_all(&source_root).is_err() {
+            fs::remove_dir_all(temp_root).expect("temp folder should be removed");
+            return;
+        }
+        let device = |path: &Path| fs::metadata(path).expect("folder should exist").dev();
+        if device(&source_root) == device(&temp_root) {
+            fs::remove_dir_all(source_root).expect("source folder should be removed");
+            fs::remove_dir_all(temp_root).expect("temp folder should be removed");
+            return;
+        }
+        let source = source_root.join("scan.pdf");
+        fs::write(&source, "scan").expect("file should be written");
+        let trash_dir = temp_root.join("Trash");
+
+        move_to_freedesktop_trash(&source, &trash_dir)
+            .expect("a source on another filesystem should be trashed");
+
+        assert!(!source.exists());
+        assert_eq!(
+            fs::read_to_string(trash_dir.join("files").join("scan.pdf"))
+                .expect("trashed file should be readable"),
+            "scan"
+        );
+        let info = fs::read_to_string(trash_dir.join("info").join("scan.pdf.trashinfo"))
+            .expect("trash info should be written");
+        assert!(info.contains(&format!(
+            "Path=/dev/shm/monobox-file-test-{}/scan.pdf",
+            test_id
+        )));
+
+        fs::remove_dir_all(source_root).expect("source folder should be removed");
+        fs::remove_dir_all(temp_root).expect("temp folder should be removed");
+    }
+
+    #[test]
+    fn import_local_file_copy_policy_keeps_source() {
+        let shared = Mutex::new(setup_conn());
+        let lock = || {
+            Ok(shared
+                .lock()
+                .expect("test connection lock should not be poisoned"))
+        };
+        let temp_root =
+            std::env::temp_dir().join(format!("monobox-file-test-{}", generate_file_id()));
+        let downloads = temp_root.join("Downloads");
+        let storage_root = temp_root.join("Storage");
+        fs::create_dir_all(&downloads).expect("downloads folder should be created");
+        fs::create_dir_all(&storage_root).expect("storage folder should be created");
+        fs::write(downloads.join("notes.txt"), "notes").expect("file should be written");
+
+        let record = FileRepository::import_local_file(
+            lock,
+            &downloads.join("notes.txt"),
+            &storage_root,
+            ImportPolicy::Copy,
+        )
+        .expect("copy import should succeed");
+
+        assert_eq!(record.import_notice, None);
+        assert!(downloads.join("notes.txt").is_file());
+        let stored_path =
+            storage_root.join(record.relative_path.expect("relative path should exist"));
+        assert_eq!(
+            fs::read_to_string(stored_path).expect("stored copy should be readable"),
+            "notes"
+        );
+
+        fs::remove_dir_all(temp_root).expect("temp folders should be removed");
+    }
+
+    #[test]
+    fn format_utc_timestamp_uses_civil_dates() {
+        let time = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_709_210_096);
+        assert_eq!(format_utc_timestamp(time), "2024-02-29T12:34:56");
+    }
 }
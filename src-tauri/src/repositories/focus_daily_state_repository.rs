@@ -1,3 +1,4 @@
+use crate::errors::AppError;
 use crate::models::focus_daily_state::FocusDailyState;
 use rusqlite::{Connection, Result};
 
@@ -7,15 +8,13 @@ impl FocusDailyStateRepository {
     pub fn list_by_workspace(
         conn: &Connection,
         workspace_id: i32,
-    ) -> Result<Vec<FocusDailyState>, String> {
-        let mut stmt = conn
-            .prepare(
-                "SELECT id, workspace_id, memo_id, done_on, created_at, updated_at
+    ) -> Result<Vec<FocusDailyState>, AppError> {
+        let mut stmt = conn.prepare(
+            "SELECT id, workspace_id, memo_id, done_on, created_at, updated_at
                  FROM focus_daily_state
                  WHERE workspace_id = ?
                  ORDER BY done_on DESC, updated_at DESC, id DESC",
-            )
-            .map_err(|e| e.to_string())?;
+        )?;
 
         let states = stmt
             .query_map([workspace_id], |row| {
@@ -27,10 +26,8 @@ impl FocusDailyStateRepository {
                     created_at: row.get(4)?,
                     updated_at: row.get(5)?,
                 })
-            })
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(states)
     }
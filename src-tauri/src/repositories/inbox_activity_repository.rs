@@ -0,0 +1,138 @@
+use rusqlite::{params, Connection};
+
+use crate::errors::AppError;
+use crate::models::inbox_activity::{InboxActivity, InboxActivityPage};
+
+/// What the watcher did with one source entry, identified by path, size and mtime.
+pub struct NewInboxActivity<'a> {
+    pub watched_folder: &'a str,
+    pub source_path: &'a str,
+    pub source_size: i64,
+    pub source_modified_at: i64,
+    pub rule_pattern: Option<&'a str>,
+    pub status: &'a str,
+    pub file_id: Option<&'a str>,
+    pub display_name: Option<&'a str>,
+    pub memo_id: Option<i32>,
+    pub message: Option<&'a str>,
+}
+
+pub struct InboxActivityRepository;
+
+impl InboxActivityRepository {
+    pub fn record(conn: &Connection, activity: &NewInboxActivity) -> Result<i64, AppError> {
+        conn.execute(
+            "INSERT INTO inbox_activity (
+                watched_folder, source_path, source_size, source_modified_at, rule_pattern,
+                status, file_id, display_name, memo_id, message
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            params![
+                activity.watched_folder,
+                activity.source_path,
+                activity.source_size,
+                activity.source_modified_at,
+                activity.rule_pattern,
+                activity.status,
+                activity.file_id,
+                activity.display_name,
+                activity.memo_id,
+                activity.message,
+            ],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Whether this exact version of the entry was imported before, so sources left in place
+    /// by the copy policy are not imported again. Failed attempts do not count.
+    pub fn was_processed(
+        conn: &Connection,
+        source_path: &str,
+        source_size: i64,
+        source_modified_at: i64,
+    ) -> Result<bool, AppError> {
+        Self::exists_with_status(
+            conn,
+            source_path,
+            source_size,
+            source_modified_at,
+            "status <> 'failed'",
+        )
+    }
+
+    /// Whether a failure was already recorded for this version of the entry, so retries that
+    /// keep failing do not add a row on every pass.
+    pub fn has_failed(
+        conn: &Connection,
+        source_path: &str,
+        source_size: i64,
+        source_modified_at: i64,
+    ) -> Result<bool, AppError> {
+        Self::exists_with_status(
+            conn,
+            source_path,
+            source_size,
+            source_modified_at,
+            "status = 'failed'",
+        )
+    }
+
+    fn exists_with_status(
+        conn: &Connection,
+        source_path: &str,
+        source_size: i64,
+        source_modified_at: i64,
+        status_filter: &str,
+    ) -> Result<bool, AppError> {
+        conn.query_row(
+            &format!(
+                "SELECT EXISTS (
+                    SELECT 1 FROM inbox_activity
+                    WHERE source_path = ? AND source_size = ? AND source_modified_at = ?
+                        AND {}
+                )",
+                status_filter
+            ),
+            params![source_path, source_size, source_modified_at],
+            |row| row.get(0),
+        )
+        .map_err(AppError::from)
+    }
+
+    pub fn list(conn: &Connection, limit: i64, offset: i64) -> Result<InboxActivityPage, AppError> {
+        let limit = limit.clamp(1, 200);
+        let offset = offset.max(0);
+        let total_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM inbox_activity", [], |row| row.get(0))?;
+        let items = conn
+            .prepare(
+                "SELECT id, watched_folder, source_path, rule_pattern, status, file_id,
+                    display_name, memo_id, message, created_at
+                FROM inbox_activity
+                ORDER BY id DESC
+                LIMIT ? OFFSET ?",
+            )?
+            .query_map([limit, offset], |row| {
+                Ok(InboxActivity {
+                    id: row.get(0)?,
+                    watched_folder: row.get(1)?,
+                    source_path: row.get(2)?,
+                    rule_pattern: row.get(3)?,
+                    status: row.get(4)?,
+                    file_id: row.get(5)?,
+                    display_name: row.get(6)?,
+                    memo_id: row.get(7)?,
+                    message: row.get(8)?,
+                    created_at: row.get(9)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(InboxActivityPage {
+            items,
+            total_count,
+            limit,
+            offset,
+        })
+    }
+}
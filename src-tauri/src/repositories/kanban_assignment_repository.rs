@@ -1,5 +1,45 @@
-use crate::models::kanban_assignment::{KanbanAssignmentEntry, KanbanAssignmentItem};
-use rusqlite::{Connection, Result};
+use crate::errors::AppError;
+use crate::models::kanban_assignment::{
+    KanbanAssignmentEntry, KanbanAssignmentItem, KanbanCardPosition, KanbanDatedItem,
+};
+use rusqlite::{Connection, OptionalExtension, Result};
+
+/// Spacing between card positions. A move takes the midpoint of its neighbours, and the
+/// column is renumbered once two neighbours sit next to each other.
+pub const POSITION_GAP: i64 = 1000;
+
+const SELECT_DATED_ITEM: &str = "SELECT memo.id, memo.slug_title, memo.title,
+        kanban.id, kanban.name, kanban_assignment.kanban_status_id, kanban_status.name,
+        kanban_assignment.due_on, kanban_assignment.scheduled_on,
+        kanban.done_status_id IS NOT NULL
+            AND kanban_assignment.kanban_status_id IS kanban.done_status_id
+    FROM kanban_assignment
+    JOIN memo ON kanban_assignment.memo_id = memo.id
+    JOIN kanban ON kanban_assignment.kanban_id = kanban.id
+    LEFT JOIN kanban_status ON kanban_assignment.kanban_status_id = kanban_status.id";
+
+/// Open work lists built from assignment dates. Memos in their board's Done status are left
+/// out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KanbanDateFilter {
+    /// Due before today.
+    Overdue,
+    /// Due from today through Sunday.
+    DueThisWeek,
+    /// Scheduled for today.
+    ScheduledToday,
+}
+
+impl KanbanDateFilter {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "overdue" => Some(Self::Overdue),
+            "due_this_week" => Some(Self::DueThisWeek),
+            "scheduled_today" => Some(Self::ScheduledToday),
+            _ => None,
+        }
+    }
+}
 
 pub struct KanbanAssignmentRepository;
 
@@ -11,7 +51,8 @@ impl KanbanAssignmentRepository {
     ) -> Result<Vec<KanbanAssignmentItem>> {
         let mut stmt = conn.prepare(
             "SELECT memo.id, memo.slug_title, memo.title, memo.description,
-                    kanban_assignment.kanban_status_id, kanban_assignment.position, memo.modified_at, kanban_assignment.kanban_id
+                    kanban_assignment.kanban_status_id, kanban_assignment.position, memo.modified_at, kanban_assignment.kanban_id,
+                    kanban_assignment.due_on, kanban_assignment.scheduled_on
             FROM kanban_assignment
             JOIN memo ON kanban_assignment.memo_id = memo.id
             WHERE kanban_assignment.workspace_id = ? AND kanban_assignment.kanban_id = ?
@@ -29,6 +70,8 @@ impl KanbanAssignmentRepository {
                     position: row.get(5)?,
                     modified_at: row.get(6)?,
                     kanban_id: row.get(7)?,
+                    due_on: row.get(8)?,
+                    scheduled_on: row.get(9)?,
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -44,7 +87,7 @@ impl KanbanAssignmentRepository {
         let mut stmt = conn.prepare(
             "SELECT kanban.id, kanban.name,
                     kanban_assignment.kanban_status_id, kanban_status.name, kanban_status.color,
-                    kanban_assignment.position
+                    kanban_assignment.position, kanban_assignment.due_on, kanban_assignment.scheduled_on
             FROM kanban_assignment
             JOIN kanban ON kanban_assignment.kanban_id = kanban.id
             LEFT JOIN kanban_status ON kanban_assignment.kanban_status_id = kanban_status.id
@@ -61,6 +104,8 @@ impl KanbanAssignmentRepository {
                     kanban_status_name: row.get(3)?,
                     kanban_status_color: row.get(4)?,
                     position: row.get(5)?,
+                    due_on: row.get(6)?,
+                    scheduled_on: row.get(7)?,
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -68,6 +113,103 @@ impl KanbanAssignmentRepository {
         Ok(items)
     }
 
+    /// Current status of the memo on the kanban: `None` when the memo is not on the board,
+    /// `Some(None)` when it is on the board without a status.
+    pub fn find_status(
+        conn: &Connection,
+        workspace_id: i32,
+        memo_id: i32,
+        kanban_id: i32,
+    ) -> Result<Option<Option<i32>>> {
+        conn.query_row(
+            "SELECT kanban_status_id
+            FROM kanban_assignment
+            WHERE workspace_id = ? AND memo_id = ? AND kanban_id = ?",
+            (workspace_id, memo_id, kanban_id),
+            |row| row.get(0),
+        )
+        .optional()
+    }
+
+    /// Checks a status change against the target status's WIP limit and the kanban's
+    /// transition rules. Reordering within the same status and clearing the status are
+    /// always allowed, as is the first status of a memo that is new to the kanban.
+    pub fn ensure_status_move_allowed(
+        conn: &Connection,
+        workspace_id: i32,
+        memo_id: i32,
+        kanban_id: i32,
+        kanban_status_id: Option<i32>,
+    ) -> Result<(), AppError> {
+        let Some(to_status_id) = kanban_status_id else {
+            return Ok(());
+        };
+
+        let from_status_id = Self::find_status(conn, workspace_id, memo_id, kanban_id)?.flatten();
+
+        if from_status_id == Some(to_status_id) {
+            return Ok(());
+        }
+
+        let (to_name, wip_limit): (String, Option<i32>) = conn
+            .query_row(
+                "SELECT name, wip_limit
+                FROM kanban_status
+                WHERE id = ? AND workspace_id = ? AND kanban_id = ?",
+                (to_status_id, workspace_id, kanban_id),
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?
+            .ok_or_else(|| {
+                AppError::not_found(format!("Kanban status not found: {}", to_status_id))
+            })?;
+
+        if let Some(from_status_id) = from_status_id {
+            let (restricted, allowed): (bool, bool) = conn.query_row(
+                "SELECT
+                    EXISTS(SELECT 1 FROM kanban_status_transition_rule
+                           WHERE kanban_id = ?1 AND from_status_id = ?2),
+                    EXISTS(SELECT 1 FROM kanban_status_transition_rule
+                           WHERE kanban_id = ?1 AND from_status_id = ?2 AND to_status_id = ?3)",
+                (kanban_id, from_status_id, to_status_id),
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )?;
+
+            if restricted && !allowed {
+                let from_name: String = conn.query_row(
+                    "SELECT name FROM kanban_status WHERE id = ?",
+                    [from_status_id],
+                    |row| row.get(0),
+                )?;
+                return Err(AppError::conflict(format!(
+                    "Moving from \"{}\" to \"{}\" is not allowed on this board.",
+                    from_name, to_name
+                ))
+                .with_reason("TRANSITION_NOT_ALLOWED"));
+            }
+        }
+
+        if let Some(wip_limit) = wip_limit {
+            let count: i32 = conn.query_row(
+                "SELECT COUNT(*)
+                FROM kanban_assignment
+                WHERE kanban_id = ? AND kanban_status_id = ? AND memo_id != ?",
+                (kanban_id, to_status_id, memo_id),
+                |row| row.get(0),
+            )?;
+
+            if count >= wip_limit {
+                return Err(AppError::conflict(format!(
+                    "\"{}\" already holds {} of {} allowed memos.",
+                    to_name, count, wip_limit
+                ))
+                .with_reason("WIP_LIMIT_EXCEEDED"));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn upsert_status(
         conn: &Connection,
         workspace_id: i32,
@@ -89,6 +231,215 @@ impl KanbanAssignmentRepository {
         Ok(())
     }
 
+    /// Places the memo in a status column between two cards of that column.
+    /// `after_memo_id` ends up directly above the moved card and `before_memo_id` directly
+    /// below it; with neither, the card goes to the end. Neighbours that are not in the
+    /// column, or no longer adjacent, fail with `STALE_NEIGHBOURS`.
+    ///
+    /// Returns every position that changed, the moved card first. The column is renumbered
+    /// with [`POSITION_GAP`] spacing when it holds cards without a position or the
+    /// neighbours leave no room.
+    pub fn move_card(
+        conn: &Connection,
+        workspace_id: i32,
+        memo_id: i32,
+        kanban_id: i32,
+        kanban_status_id: i32,
+        after_memo_id: Option<i32>,
+        before_memo_id: Option<i32>,
+    ) -> Result<Vec<KanbanCardPosition>, AppError> {
+        ensure_status_on_kanban(conn, workspace_id, kanban_id, kanban_status_id)?;
+
+        let column =
+            list_column_positions(conn, workspace_id, kanban_id, kanban_status_id, memo_id)?;
+        let index_of = |neighbour_id: i32| {
+            column
+                .iter()
+                .position(|(id, _)| *id == neighbour_id)
+                .ok_or_else(|| {
+                    AppError::conflict("A neighbouring card is no longer in the target status.")
+                        .with_reason("STALE_NEIGHBOURS")
+                })
+        };
+        let index = match (after_memo_id, before_memo_id) {
+            (Some(after_id), Some(before_id)) => {
+                let after_index = index_of(after_id)?;
+                let before_index = index_of(before_id)?;
+                if after_index + 1 != before_index {
+                    return Err(AppError::conflict(
+                        "The neighbouring cards are no longer next to each other.",
+                    )
+                    .with_reason("STALE_NEIGHBOURS"));
+                }
+                before_index
+            }
+            (Some(after_id), None) => index_of(after_id)? + 1,
+            (None, Some(before_id)) => index_of(before_id)?,
+            (None, None) => column.len(),
+        };
+
+        let has_missing = column.iter().any(|(_, position)| position.is_none());
+        let previous = index.checked_sub(1).and_then(|i| column[i].1);
+        let next = column.get(index).and_then(|(_, position)| *position);
+        let position = match (previous, next) {
+            _ if has_missing => None,
+            (None, None) => Some(POSITION_GAP),
+            (Some(previous), None) => Some(previous + POSITION_GAP),
+            (None, Some(next)) => Some(next - POSITION_GAP),
+            (Some(previous), Some(next)) if next - previous > 1 => {
+                Some(previous + (next - previous) / 2)
+            }
+            _ => None,
+        };
+
+        if let Some(position) = position {
+            Self::upsert_status(
+                conn,
+                workspace_id,
+                memo_id,
+                kanban_id,
+                Some(kanban_status_id),
+                Some(position),
+            )?;
+            return Ok(vec![KanbanCardPosition { memo_id, position }]);
+        }
+
+        let mut ordered = column;
+        ordered.insert(index, (memo_id, None));
+        let mut moved = Vec::with_capacity(1);
+        let mut renumbered = Vec::new();
+        for (offset, (id, current)) in ordered.into_iter().enumerate() {
+            let position = (offset as i64 + 1) * POSITION_GAP;
+            if id == memo_id {
+                Self::upsert_status(
+                    conn,
+                    workspace_id,
+                    memo_id,
+                    kanban_id,
+                    Some(kanban_status_id),
+                    Some(position),
+                )?;
+                moved.push(KanbanCardPosition { memo_id, position });
+            } else if current != Some(position) {
+                conn.execute(
+                    "UPDATE kanban_assignment
+                    SET position = ?, updated_at = CURRENT_TIMESTAMP
+                    WHERE workspace_id = ? AND memo_id = ? AND kanban_id = ?",
+                    (position, workspace_id, id, kanban_id),
+                )?;
+                renumbered.push(KanbanCardPosition {
+                    memo_id: id,
+                    position,
+                });
+            }
+        }
+        moved.extend(renumbered);
+        Ok(moved)
+    }
+
+    /// Puts the memo at the end of a status column and returns its position.
+    pub fn append_to_status(
+        conn: &Connection,
+        workspace_id: i32,
+        memo_id: i32,
+        kanban_id: i32,
+        kanban_status_id: i32,
+    ) -> Result<i64, AppError> {
+        ensure_status_on_kanban(conn, workspace_id, kanban_id, kanban_status_id)?;
+
+        let last: Option<i64> = conn.query_row(
+            "SELECT MAX(position)
+            FROM kanban_assignment
+            WHERE workspace_id = ? AND kanban_id = ? AND kanban_status_id = ? AND memo_id != ?",
+            (workspace_id, kanban_id, kanban_status_id, memo_id),
+            |row| row.get(0),
+        )?;
+        let position = last.unwrap_or(0) + POSITION_GAP;
+
+        Self::upsert_status(
+            conn,
+            workspace_id,
+            memo_id,
+            kanban_id,
+            Some(kanban_status_id),
+            Some(position),
+        )?;
+        Ok(position)
+    }
+
+    pub fn update_dates(
+        conn: &Connection,
+        workspace_id: i32,
+        memo_id: i32,
+        kanban_id: i32,
+        due_on: Option<&str>,
+        scheduled_on: Option<&str>,
+    ) -> Result<bool> {
+        let updated = conn.execute(
+            "UPDATE kanban_assignment
+            SET due_on = ?, scheduled_on = ?, updated_at = CURRENT_TIMESTAMP
+            WHERE workspace_id = ? AND memo_id = ? AND kanban_id = ?",
+            (due_on, scheduled_on, workspace_id, memo_id, kanban_id),
+        )?;
+        Ok(updated > 0)
+    }
+
+    pub fn list_dated_items(
+        conn: &Connection,
+        workspace_id: i32,
+        filter: KanbanDateFilter,
+    ) -> Result<Vec<KanbanDatedItem>> {
+        let condition = match filter {
+            KanbanDateFilter::Overdue => "kanban_assignment.due_on < date('now', 'localtime')",
+            KanbanDateFilter::DueThisWeek => {
+                "kanban_assignment.due_on BETWEEN date('now', 'localtime')
+                    AND date('now', 'localtime', 'weekday 0')"
+            }
+            KanbanDateFilter::ScheduledToday => {
+                "kanban_assignment.scheduled_on = date('now', 'localtime')"
+            }
+        };
+        let order = match filter {
+            KanbanDateFilter::ScheduledToday => "kanban.order_index ASC, memo.title ASC",
+            _ => "kanban_assignment.due_on ASC, memo.title ASC",
+        };
+        let mut stmt = conn.prepare(&format!(
+            "{}
+            WHERE kanban_assignment.workspace_id = ? AND {}
+              AND (kanban.done_status_id IS NULL
+                   OR kanban_assignment.kanban_status_id IS NOT kanban.done_status_id)
+            ORDER BY {}",
+            SELECT_DATED_ITEM, condition, order
+        ))?;
+
+        let items = stmt
+            .query_map([workspace_id], map_dated_item)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(items)
+    }
+
+    /// Assignments due or scheduled within `[start_date, end_date)`, done or not.
+    pub fn list_dated_items_between(
+        conn: &Connection,
+        workspace_id: i32,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<KanbanDatedItem>> {
+        let mut stmt = conn.prepare(&format!(
+            "{}
+            WHERE kanban_assignment.workspace_id = ?1
+              AND ((kanban_assignment.due_on >= ?2 AND kanban_assignment.due_on < ?3)
+                   OR (kanban_assignment.scheduled_on >= ?2 AND kanban_assignment.scheduled_on < ?3))
+            ORDER BY kanban.order_index ASC, memo.title ASC",
+            SELECT_DATED_ITEM
+        ))?;
+
+        let items = stmt
+            .query_map((workspace_id, start_date, end_date), map_dated_item)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(items)
+    }
+
     pub fn delete_entry(
         conn: &Connection,
         workspace_id: i32,
@@ -103,3 +454,282 @@ impl KanbanAssignmentRepository {
         Ok(deleted > 0)
     }
 }
+
+fn ensure_status_on_kanban(
+    conn: &Connection,
+    workspace_id: i32,
+    kanban_id: i32,
+    kanban_status_id: i32,
+) -> Result<(), AppError> {
+    let exists: bool = conn.query_row(
+        "SELECT EXISTS(
+            SELECT 1 FROM kanban_status WHERE id = ? AND workspace_id = ? AND kanban_id = ?
+        )",
+        (kanban_status_id, workspace_id, kanban_id),
+        |row| row.get(0),
+    )?;
+    if !exists {
+        return Err(AppError::not_found(format!(
+            "Kanban status not found: {}",
+            kanban_status_id
+        )));
+    }
+    Ok(())
+}
+
+/// Cards of a status column other than `excluded_memo_id`, in board order: by position
+/// with unpositioned cards last, newest first, the same way the board sorts them.
+fn list_column_positions(
+    conn: &Connection,
+    workspace_id: i32,
+    kanban_id: i32,
+    kanban_status_id: i32,
+    excluded_memo_id: i32,
+) -> Result<Vec<(i32, Option<i64>)>> {
+    let mut stmt = conn.prepare(
+        "SELECT kanban_assignment.memo_id, kanban_assignment.position
+        FROM kanban_assignment
+        JOIN memo ON kanban_assignment.memo_id = memo.id
+        WHERE kanban_assignment.workspace_id = ? AND kanban_assignment.kanban_id = ?
+          AND kanban_assignment.kanban_status_id = ? AND kanban_assignment.memo_id != ?
+        ORDER BY kanban_assignment.position IS NULL, kanban_assignment.position ASC,
+                 memo.id DESC",
+    )?;
+    let column = stmt
+        .query_map(
+            (workspace_id, kanban_id, kanban_status_id, excluded_memo_id),
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(column)
+}
+
+fn map_dated_item(row: &rusqlite::Row<'_>) -> Result<KanbanDatedItem> {
+    Ok(KanbanDatedItem {
+        memo_id: row.get(0)?,
+        slug_title: row.get(1)?,
+        title: row.get(2)?,
+        kanban_id: row.get(3)?,
+        kanban_name: row.get(4)?,
+        kanban_status_id: row.get(5)?,
+        kanban_status_name: row.get(6)?,
+        due_on: row.get(7)?,
+        scheduled_on: row.get(8)?,
+        is_done: row.get(9)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use super::{KanbanAssignmentRepository, POSITION_GAP};
+    use crate::migrations::apply_migrations;
+    use crate::models::kanban_assignment::KanbanCardPosition;
+    use crate::models::kanban_status::KanbanStatusTransitionRule;
+    use crate::repositories::{
+        KanbanRepository, KanbanStatusRepository, MemoRepository, WorkspaceRepository,
+    };
+
+    #[test]
+    fn status_moves_respect_wip_limits_and_transition_rules() {
+        let mut conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let kanban = KanbanRepository::ensure_global_status_board(&conn, workspace.id)
+            .expect("board should exist");
+        let statuses = KanbanStatusRepository::list_by_kanban(&conn, workspace.id, kanban.id)
+            .expect("statuses should load");
+        let status_id = |name: &str| {
+            statuses
+                .iter()
+                .find(|status| status.name == name)
+                .map(|status| status.id)
+                .expect("default status should exist")
+        };
+        let (inbox, now, done) = (status_id("Inbox"), status_id("Now"), status_id("Done"));
+        let first = MemoRepository::create(&conn, workspace.id, "first", "First", "\"\"")
+            .expect("memo should be created");
+        let second = MemoRepository::create(&conn, workspace.id, "second", "Second", "\"\"")
+            .expect("memo should be created");
+
+        KanbanStatusRepository::update_wip_limit(&conn, workspace.id, now, Some(1))
+            .expect("WIP limit should be set");
+        KanbanStatusRepository::replace_transition_rules(
+            &mut conn,
+            workspace.id,
+            kanban.id,
+            &[KanbanStatusTransitionRule {
+                from_status_id: inbox,
+                to_status_id: now,
+            }],
+        )
+        .expect("rules should be stored");
+
+        for memo_id in [first.id, second.id] {
+            KanbanAssignmentRepository::upsert_status(
+                &conn,
+                workspace.id,
+                memo_id,
+                kanban.id,
+                Some(inbox),
+                None,
+            )
+            .expect("memo should be assigned");
+        }
+
+        let error = KanbanAssignmentRepository::ensure_status_move_allowed(
+            &conn,
+            workspace.id,
+            first.id,
+            kanban.id,
+            Some(done),
+        )
+        .expect_err("Inbox may only move to Now");
+        assert_eq!(error.reason.as_deref(), Some("TRANSITION_NOT_ALLOWED"));
+
+        KanbanAssignmentRepository::ensure_status_move_allowed(
+            &conn,
+            workspace.id,
+            first.id,
+            kanban.id,
+            Some(now),
+        )
+        .expect("Now has room");
+        KanbanAssignmentRepository::upsert_status(
+            &conn,
+            workspace.id,
+            first.id,
+            kanban.id,
+            Some(now),
+            None,
+        )
+        .expect("memo should move");
+
+        let error = KanbanAssignmentRepository::ensure_status_move_allowed(
+            &conn,
+            workspace.id,
+            second.id,
+            kanban.id,
+            Some(now),
+        )
+        .expect_err("Now is full");
+        assert_eq!(error.reason.as_deref(), Some("WIP_LIMIT_EXCEEDED"));
+
+        KanbanAssignmentRepository::ensure_status_move_allowed(
+            &conn,
+            workspace.id,
+            first.id,
+            kanban.id,
+            Some(now),
+        )
+        .expect("reordering within a full status is allowed");
+    }
+
+    #[test]
+    fn move_card_takes_the_gap_between_neighbours_and_rebalances_when_full() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let kanban = KanbanRepository::ensure_global_status_board(&conn, workspace.id)
+            .expect("board should exist");
+        let status_id = KanbanStatusRepository::list_by_kanban(&conn, workspace.id, kanban.id)
+            .expect("statuses should load")
+            .into_iter()
+            .find(|status| status.name == "Now")
+            .map(|status| status.id)
+            .expect("Now status should exist");
+        let memo_ids = ["a", "b", "c", "d"].map(|slug| {
+            MemoRepository::create(&conn, workspace.id, slug, slug, "\"\"")
+                .expect("memo should be created")
+                .id
+        });
+        let [a, b, c, d] = memo_ids;
+
+        for memo_id in [a, b] {
+            KanbanAssignmentRepository::append_to_status(
+                &conn,
+                workspace.id,
+                memo_id,
+                kanban.id,
+                status_id,
+            )
+            .expect("memo should be appended");
+        }
+
+        let moved = KanbanAssignmentRepository::move_card(
+            &conn,
+            workspace.id,
+            c,
+            kanban.id,
+            status_id,
+            Some(a),
+            Some(b),
+        )
+        .expect("card should fit between neighbours");
+        assert_eq!(
+            moved,
+            vec![KanbanCardPosition {
+                memo_id: c,
+                position: POSITION_GAP + POSITION_GAP / 2,
+            }]
+        );
+
+        conn.execute(
+            "UPDATE kanban_assignment SET position = position - 499 WHERE memo_id = ?",
+            [b],
+        )
+        .expect("positions should be squeezed");
+        let moved = KanbanAssignmentRepository::move_card(
+            &conn,
+            workspace.id,
+            d,
+            kanban.id,
+            status_id,
+            Some(c),
+            Some(b),
+        )
+        .expect("column should be renumbered");
+        assert_eq!(
+            moved,
+            vec![
+                KanbanCardPosition {
+                    memo_id: d,
+                    position: 3 * POSITION_GAP,
+                },
+                KanbanCardPosition {
+                    memo_id: c,
+                    position: 2 * POSITION_GAP,
+                },
+                KanbanCardPosition {
+                    memo_id: b,
+                    position: 4 * POSITION_GAP,
+                },
+            ]
+        );
+
+        let error = KanbanAssignmentRepository::move_card(
+            &conn,
+            workspace.id,
+            a,
+            kanban.id,
+            status_id,
+            Some(c),
+            Some(b),
+        )
+        .expect_err("c and b are no longer adjacent");
+        assert_eq!(error.reason.as_deref(), Some("STALE_NEIGHBOURS"));
+    }
+}
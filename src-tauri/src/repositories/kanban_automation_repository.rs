@@ -0,0 +1,494 @@
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::errors::AppError;
+use crate::models::kanban_automation::{
+    KanbanAutomationOutcome, KanbanAutomationRule, KanbanAutomationRuleInput,
+};
+use crate::repositories::{BookmarkRepository, CalendarDayRepository, FocusDailyStateRepository};
+
+const TRIGGER_TYPES: [&str; 3] = ["enter_status", "enter_done_status", "leave_status"];
+const CONDITION_TYPES: [&str; 3] = ["always", "from_status", "has_milestone"];
+const ACTION_TYPES: [&str; 4] = [
+    "complete_milestones",
+    "mark_focus_done_today",
+    "add_to_today",
+    "add_bookmark",
+];
+
+const SELECT_RULE: &str = "SELECT id, workspace_id, kanban_id, trigger_type, trigger_status_id,
+        condition_type, condition_status_id, action_type, enabled, created_at, updated_at
+    FROM kanban_automation_rule";
+
+pub struct KanbanAutomationRepository;
+
+impl KanbanAutomationRepository {
+    pub fn list_by_kanban(
+        conn: &Connection,
+        workspace_id: i32,
+        kanban_id: i32,
+    ) -> Result<Vec<KanbanAutomationRule>, AppError> {
+        let mut stmt = conn.prepare(&format!(
+            "{} WHERE workspace_id = ? AND kanban_id = ? ORDER BY id ASC",
+            SELECT_RULE
+        ))?;
+        let rules = stmt
+            .query_map((workspace_id, kanban_id), map_rule)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rules)
+    }
+
+    pub fn find_by_id(
+        conn: &Connection,
+        workspace_id: i32,
+        id: i32,
+    ) -> Result<Option<KanbanAutomationRule>, AppError> {
+        let rule = conn
+            .query_row(
+                &format!("{} WHERE id = ? AND workspace_id = ?", SELECT_RULE),
+                (id, workspace_id),
+                map_rule,
+            )
+            .optional()?;
+        Ok(rule)
+    }
+
+    pub fn create(
+        conn: &Connection,
+        workspace_id: i32,
+        kanban_id: i32,
+        input: &KanbanAutomationRuleInput,
+    ) -> Result<KanbanAutomationRule, AppError> {
+        validate_input(conn, workspace_id, kanban_id, input)?;
+
+        conn.execute(
+            "INSERT INTO kanban_automation_rule (
+                workspace_id, kanban_id, trigger_type, trigger_status_id,
+                condition_type, condition_status_id, action_type, enabled
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            params![
+                workspace_id,
+                kanban_id,
+                input.trigger_type,
+                input.trigger_status_id,
+                input.condition_type,
+                input.condition_status_id,
+                input.action_type,
+                input.enabled,
+            ],
+        )?;
+
+        let id = conn.last_insert_rowid() as i32;
+        Self::find_by_id(conn, workspace_id, id)?
+            .ok_or_else(|| AppError::not_found(format!("Automation rule not found: {}", id)))
+    }
+
+    pub fn update(
+        conn: &Connection,
+        workspace_id: i32,
+        id: i32,
+        input: &KanbanAutomationRuleInput,
+    ) -> Result<KanbanAutomationRule, AppError> {
+        let existing = Self::find_by_id(conn, workspace_id, id)?
+            .ok_or_else(|| AppError::not_found(format!("Automation rule not found: {}", id)))?;
+        validate_input(conn, workspace_id, existing.kanban_id, input)?;
+
+        conn.execute(
+            "UPDATE kanban_automation_rule
+            SET trigger_type = ?, trigger_status_id = ?, condition_type = ?,
+                condition_status_id = ?, action_type = ?, enabled = ?
+            WHERE id = ? AND workspace_id = ?",
+            params![
+                input.trigger_type,
+                input.trigger_status_id,
+                input.condition_type,
+                input.condition_status_id,
+                input.action_type,
+                input.enabled,
+                id,
+                workspace_id,
+            ],
+        )?;
+
+        Self::find_by_id(conn, workspace_id, id)?
+            .ok_or_else(|| AppError::not_found(format!("Automation rule not found: {}", id)))
+    }
+
+    pub fn delete(conn: &Connection, workspace_id: i32, id: i32) -> Result<bool, AppError> {
+        let deleted = conn.execute(
+            "DELETE FROM kanban_automation_rule WHERE id = ? AND workspace_id = ?",
+            (id, workspace_id),
+        )?;
+        Ok(deleted > 0)
+    }
+
+    /// Runs the enabled rules of the kanban that match a status change, in creation order.
+    /// Callers pass the connection of the transaction that changed the status, so a failing
+    /// action rolls the move back with it. Nothing runs when the status did not change.
+    pub fn run_for_status_change(
+        conn: &Connection,
+        workspace_id: i32,
+        kanban_id: i32,
+        memo_id: i32,
+        from_status_id: Option<i32>,
+        to_status_id: Option<i32>,
+    ) -> Result<Vec<KanbanAutomationOutcome>, AppError> {
+        if from_status_id == to_status_id {
+            return Ok(Vec::new());
+        }
+
+        let done_status_id: Option<i32> = conn.query_row(
+            "SELECT done_status_id FROM kanban WHERE id = ? AND workspace_id = ?",
+            (kanban_id, workspace_id),
+            |row| row.get(0),
+        )?;
+
+        let mut outcomes = Vec::new();
+        for rule in Self::list_by_kanban(conn, workspace_id, kanban_id)? {
+            if !rule.enabled {
+                continue;
+            }
+
+            let triggered = match rule.trigger_type.as_str() {
+                "enter_status" => to_status_id.is_some() && rule.trigger_status_id == to_status_id,
+                "enter_done_status" => done_status_id.is_some() && done_status_id == to_status_id,
+                "leave_status" => {
+                    from_status_id.is_some() && rule.trigger_status_id == from_status_id
+                }
+                _ => false,
+            };
+            if !triggered {
+                continue;
+            }
+
+            let satisfied = match rule.condition_type.as_str() {
+                "always" => true,
+                "from_status" => rule.condition_status_id == from_status_id,
+                "has_milestone" => has_open_milestone(conn, workspace_id, memo_id)?,
+                _ => false,
+            };
+            if !satisfied {
+                continue;
+            }
+
+            let changed = match rule.action_type.as_str() {
+                "complete_milestones" => {
+                    complete_finished_milestones(conn, workspace_id, kanban_id, memo_id)? > 0
+                }
+                "mark_focus_done_today" => {
+                    FocusDailyStateRepository::mark_done_for_today(conn, workspace_id, memo_id)?;
+                    true
+                }
+                "add_to_today" => {
+                    let today: String =
+                        conn.query_row("SELECT date('now', 'localtime')", [], |row| row.get(0))?;
+                    CalendarDayRepository::add_memo(conn, workspace_id, &today, memo_id)?;
+                    true
+                }
+                "add_bookmark" => add_bookmark_if_missing(conn, workspace_id, memo_id)?,
+                _ => false,
+            };
+            if changed {
+                outcomes.push(KanbanAutomationOutcome {
+                    rule_id: rule.id,
+                    action_type: rule.action_type,
+                });
+            }
+        }
+
+        Ok(outcomes)
+    }
+}
+
+fn map_rule(row: &rusqlite::Row<'_>) -> rusqlite::Result<KanbanAutomationRule> {
+    Ok(KanbanAutomationRule {
+        id: row.get(0)?,
+        workspace_id: row.get(1)?,
+        kanban_id: row.get(2)?,
+        trigger_type: row.get(3)?,
+        trigger_status_id: row.get(4)?,
+        condition_type: row.get(5)?,
+        condition_status_id: row.get(6)?,
+        action_type: row.get(7)?,
+        enabled: row.get(8)?,
+        created_at: row.get(9)?,
+        updated_at: row.get(10)?,
+    })
+}
+
+fn validate_input(
+    conn: &Connection,
+    workspace_id: i32,
+    kanban_id: i32,
+    input: &KanbanAutomationRuleInput,
+) -> Result<(), AppError> {
+    if !TRIGGER_TYPES.contains(&input.trigger_type.as_str()) {
+        return Err(AppError::validation(format!(
+            "Unknown automation trigger: {}",
+            input.trigger_type
+        )));
+    }
+    if !CONDITION_TYPES.contains(&input.condition_type.as_str()) {
+        return Err(AppError::validation(format!(
+            "Unknown automation condition: {}",
+            input.condition_type
+        )));
+    }
+    if !ACTION_TYPES.contains(&input.action_type.as_str()) {
+        return Err(AppError::validation(format!(
+            "Unknown automation action: {}",
+            input.action_type
+        )));
+    }
+
+    let needs_trigger_status = input.trigger_type != "enter_done_status";
+    check_status_reference(
+        conn,
+        workspace_id,
+        kanban_id,
+        input.trigger_status_id,
+        needs_trigger_status,
+        "trigger",
+    )?;
+    check_status_reference(
+        conn,
+        workspace_id,
+        kanban_id,
+        input.condition_status_id,
+        input.condition_type == "from_status",
+        "condition",
+    )?;
+
+    Ok(())
+}
+
+fn check_status_reference(
+    conn: &Connection,
+    workspace_id: i32,
+    kanban_id: i32,
+    status_id: Option<i32>,
+    required: bool,
+    role: &str,
+) -> Result<(), AppError> {
+    match (status_id, required) {
+        (None, true) => Err(AppError::validation(format!(
+            "This automation {} needs a status.",
+            role
+        ))),
+        (Some(_), false) => Err(AppError::validation(format!(
+            "This automation {} does not take a status.",
+            role
+        ))),
+        (Some(status_id), true) => {
+            let exists: bool = conn.query_row(
+                "SELECT EXISTS(
+                    SELECT 1 FROM kanban_status
+                    WHERE id = ? AND workspace_id = ? AND kanban_id = ?
+                )",
+                (status_id, workspace_id, kanban_id),
+                |row| row.get(0),
+            )?;
+            if exists {
+                Ok(())
+            } else {
+                Err(AppError::validation(format!(
+                    "Kanban status {} does not belong to kanban {}.",
+                    status_id, kanban_id
+                )))
+            }
+        }
+        (None, false) => Ok(()),
+    }
+}
+
+fn has_open_milestone(
+    conn: &Connection,
+    workspace_id: i32,
+    memo_id: i32,
+) -> Result<bool, AppError> {
+    let exists = conn.query_row(
+        "SELECT EXISTS(
+            SELECT 1
+            FROM milestone_memo
+            JOIN milestone ON milestone.id = milestone_memo.milestone_id
+            WHERE milestone.workspace_id = ? AND milestone_memo.memo_id = ?
+              AND milestone.completed_at IS NULL
+        )",
+        (workspace_id, memo_id),
+        |row| row.get(0),
+    )?;
+    Ok(exists)
+}
+
+/// Completes the open milestones of the memo whose memos are all in the kanban's Done
+/// status. Does nothing on boards without a Done role.
+fn complete_finished_milestones(
+    conn: &Connection,
+    workspace_id: i32,
+    kanban_id: i32,
+    memo_id: i32,
+) -> Result<usize, AppError> {
+    let completed = conn.execute(
+        "UPDATE milestone
+        SET completed_at = CURRENT_TIMESTAMP
+        WHERE workspace_id = ?1
+          AND completed_at IS NULL
+          AND id IN (SELECT milestone_id FROM milestone_memo WHERE memo_id = ?3)
+          AND NOT EXISTS (
+              SELECT 1
+              FROM milestone_memo
+              JOIN kanban ON kanban.id = ?2
+              LEFT JOIN kanban_assignment
+                ON kanban_assignment.memo_id = milestone_memo.memo_id
+               AND kanban_assignment.kanban_id = kanban.id
+              WHERE milestone_memo.milestone_id = milestone.id
+                AND (kanban.done_status_id IS NULL
+                     OR kanban_assignment.kanban_status_id IS NOT kanban.done_status_id)
+          )",
+        (workspace_id, kanban_id, memo_id),
+    )?;
+    Ok(completed)
+}
+
+fn add_bookmark_if_missing(
+    conn: &Connection,
+    workspace_id: i32,
+    memo_id: i32,
+) -> Result<bool, AppError> {
+    let exists: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM bookmark WHERE workspace_id = ? AND memo_id = ?)",
+        (workspace_id, memo_id),
+        |row| row.get(0),
+    )?;
+    if exists {
+        return Ok(false);
+    }
+    BookmarkRepository::create(conn, workspace_id, memo_id)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use super::KanbanAutomationRepository;
+    use crate::migrations::apply_migrations;
+    use crate::models::kanban_automation::KanbanAutomationRuleInput;
+    use crate::repositories::{
+        KanbanAssignmentRepository, KanbanRepository, KanbanStatusRepository, MemoRepository,
+        MilestoneRepository, WorkspaceRepository,
+    };
+
+    fn rule(trigger: &str, status: Option<i32>, action: &str) -> KanbanAutomationRuleInput {
+        KanbanAutomationRuleInput {
+            trigger_type: trigger.to_string(),
+            trigger_status_id: status,
+            condition_type: "always".to_string(),
+            condition_status_id: None,
+            action_type: action.to_string(),
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn moving_to_done_completes_milestones_once_every_memo_is_done() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let kanban = KanbanRepository::ensure_global_status_board(&conn, workspace.id)
+            .expect("board should exist");
+        let statuses = KanbanStatusRepository::list_by_kanban(&conn, workspace.id, kanban.id)
+            .expect("statuses should load");
+        let status_id = |name: &str| {
+            statuses
+                .iter()
+                .find(|status| status.name == name)
+                .map(|status| status.id)
+        };
+        let first = MemoRepository::create(&conn, workspace.id, "first", "First", "\"\"")
+            .expect("memo should be created");
+        let second = MemoRepository::create(&conn, workspace.id, "second", "Second", "\"\"")
+            .expect("memo should be created");
+        let milestone = MilestoneRepository::create(&conn, workspace.id, "2026-12-01", "Ship")
+            .expect("milestone should be created");
+        for memo_id in [first.id, second.id] {
+            MilestoneRepository::add_memo(&conn, workspace.id, milestone, memo_id)
+                .expect("memo should join the milestone");
+        }
+
+        KanbanAutomationRepository::create(
+            &conn,
+            workspace.id,
+            kanban.id,
+            &rule("enter_done_status", None, "complete_milestones"),
+        )
+        .expect("rule should be created");
+        KanbanAutomationRepository::create(
+            &conn,
+            workspace.id,
+            kanban.id,
+            &rule("enter_status", status_id("Now"), "add_bookmark"),
+        )
+        .expect("rule should be created");
+        let invalid = KanbanAutomationRepository::create(
+            &conn,
+            workspace.id,
+            kanban.id,
+            &rule("enter_status", None, "add_bookmark"),
+        )
+        .expect_err("enter_status needs a status");
+        assert_eq!(invalid.code, crate::errors::ErrorCode::Validation);
+
+        let move_to = |memo_id: i32, name: &str| {
+            let from =
+                KanbanAssignmentRepository::find_status(&conn, workspace.id, memo_id, kanban.id)
+                    .expect("status should load")
+                    .flatten();
+            KanbanAssignmentRepository::upsert_status(
+                &conn,
+                workspace.id,
+                memo_id,
+                kanban.id,
+                status_id(name),
+                None,
+            )
+            .expect("status should change");
+            KanbanAutomationRepository::run_for_status_change(
+                &conn,
+                workspace.id,
+                kanban.id,
+                memo_id,
+                from,
+                status_id(name),
+            )
+            .expect("automations should run")
+        };
+        let completed_at = || -> Option<String> {
+            conn.query_row(
+                "SELECT completed_at FROM milestone WHERE id = ?",
+                [milestone],
+                |row| row.get(0),
+            )
+            .expect("milestone should load")
+        };
+
+        let outcomes = move_to(first.id, "Now");
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].action_type, "add_bookmark");
+        assert!(move_to(first.id, "Inbox").is_empty());
+        assert!(move_to(first.id, "Now").is_empty());
+
+        assert!(move_to(first.id, "Done").is_empty());
+        assert!(completed_at().is_none());
+        let outcomes = move_to(second.id, "Done");
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].action_type, "complete_milestones");
+        assert!(completed_at().is_some());
+    }
+}
@@ -0,0 +1,346 @@
+use std::collections::HashMap;
+
+use rusqlite::Connection;
+
+use crate::errors::AppError;
+use crate::models::kanban::Kanban;
+use crate::models::kanban_assignment::KanbanAssignmentItem;
+use crate::models::kanban_lane::{KanbanLane, KanbanLaneBucket};
+use crate::repositories::{KanbanAssignmentRepository, KanbanRepository, KanbanStatusRepository};
+
+/// Second dimension a board can be split by into lanes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KanbanLaneDimension {
+    /// One lane per milestone the cards belong to. A card on several milestones shows up
+    /// in each of their lanes.
+    Milestone,
+    Bookmark,
+    /// One lane per status of another kanban of the same workspace.
+    KanbanStatus(i32),
+}
+
+impl KanbanLaneDimension {
+    pub fn parse(value: &str, group_kanban_id: Option<i32>) -> Option<Self> {
+        match (value, group_kanban_id) {
+            ("milestone", _) => Some(Self::Milestone),
+            ("bookmark", _) => Some(Self::Bookmark),
+            ("kanban_status", Some(kanban_id)) => Some(Self::KanbanStatus(kanban_id)),
+            _ => None,
+        }
+    }
+}
+
+/// Lanes in display order, keyed by `KanbanLane::key`.
+struct LaneLayout {
+    lanes: Vec<(String, String)>,
+    /// Indexes into `lanes` for every memo with a value. Other memos go to the last lane.
+    memberships: HashMap<i32, Vec<usize>>,
+}
+
+pub struct KanbanLaneRepository;
+
+impl KanbanLaneRepository {
+    /// Cards of the board grouped into lanes. Lanes without cards are left out; the lane for
+    /// cards without a value comes last.
+    pub fn list_lanes(
+        conn: &Connection,
+        kanban: &Kanban,
+        dimension: KanbanLaneDimension,
+    ) -> Result<Vec<KanbanLane>, AppError> {
+        let layout = match dimension {
+            KanbanLaneDimension::Milestone => milestone_layout(conn, kanban)?,
+            KanbanLaneDimension::Bookmark => bookmark_layout(conn, kanban)?,
+            KanbanLaneDimension::KanbanStatus(group_kanban_id) => {
+                if group_kanban_id == kanban.id {
+                    return Err(AppError::validation(
+                        "Cannot group a kanban by its own statuses",
+                    ));
+                }
+                let group_kanban =
+                    KanbanRepository::find_by_id(conn, kanban.workspace_id, group_kanban_id)?
+                        .ok_or_else(|| {
+                            AppError::not_found(format!("Kanban not found: {}", group_kanban_id))
+                        })?;
+                kanban_status_layout(conn, &group_kanban)?
+            }
+        };
+
+        let status_ids: Vec<i32> =
+            KanbanStatusRepository::list_by_kanban(conn, kanban.workspace_id, kanban.id)?
+                .into_iter()
+                .map(|status| status.id)
+                .collect();
+        let items =
+            KanbanAssignmentRepository::list_items_by_kanban(conn, kanban.workspace_id, kanban.id)?;
+
+        Ok(group_into_lanes(items, &status_ids, layout))
+    }
+}
+
+fn milestone_layout(conn: &Connection, kanban: &Kanban) -> Result<LaneLayout, AppError> {
+    let mut stmt = conn.prepare(
+        "SELECT milestone_memo.memo_id, milestone.id, milestone.title
+        FROM milestone_memo
+        JOIN milestone ON milestone.id = milestone_memo.milestone_id
+        JOIN kanban_assignment ON kanban_assignment.memo_id = milestone_memo.memo_id
+        WHERE milestone.workspace_id = ?1 AND kanban_assignment.kanban_id = ?2
+        ORDER BY milestone.date ASC, milestone.id ASC",
+    )?;
+    let rows = stmt
+        .query_map((kanban.workspace_id, kanban.id), |row| {
+            Ok((
+                row.get::<_, i32>(0)?,
+                row.get::<_, i32>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut lanes = Vec::new();
+    let mut lane_indexes = HashMap::new();
+    let mut memberships: HashMap<i32, Vec<usize>> = HashMap::new();
+    for (memo_id, milestone_id, title) in rows {
+        let index = *lane_indexes.entry(milestone_id).or_insert_with(|| {
+            lanes.push((format!("milestone:{}", milestone_id), title));
+            lanes.len() - 1
+        });
+        memberships.entry(memo_id).or_default().push(index);
+    }
+    lanes.push(("none".to_string(), "No milestone".to_string()));
+
+    Ok(LaneLayout { lanes, memberships })
+}
+
+fn bookmark_layout(conn: &Connection, kanban: &Kanban) -> Result<LaneLayout, AppError> {
+    let memberships = conn
+        .prepare("SELECT memo_id FROM bookmark WHERE workspace_id = ?")?
+        .query_map([kanban.workspace_id], |row| row.get::<_, i32>(0))?
+        .map(|memo_id| memo_id.map(|memo_id| (memo_id, vec![0])))
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+    Ok(LaneLayout {
+        lanes: vec![
+            ("bookmarked".to_string(), "Bookmarked".to_string()),
+            ("none".to_string(), "Not bookmarked".to_string()),
+        ],
+        memberships,
+    })
+}
+
+fn kanban_status_layout(conn: &Connection, group_kanban: &Kanban) -> Result<LaneLayout, AppError> {
+    let statuses =
+        KanbanStatusRepository::list_by_kanban(conn, group_kanban.workspace_id, group_kanban.id)?;
+    let lane_indexes: HashMap<i32, usize> = statuses
+        .iter()
+        .enumerate()
+        .map(|(index, status)| (status.id, index))
+        .collect();
+    let no_status_index = statuses.len();
+    let mut lanes: Vec<(String, String)> = statuses
+        .into_iter()
+        .map(|status| (format!("status:{}", status.id), status.name))
+        .collect();
+    lanes.push(("no_status".to_string(), "No status".to_string()));
+    lanes.push(("none".to_string(), format!("Not on {}", group_kanban.name)));
+
+    let memberships = KanbanAssignmentRepository::list_items_by_kanban(
+        conn,
+        group_kanban.workspace_id,
+        group_kanban.id,
+    )?
+    .into_iter()
+    .map(|item| {
+        let index = item
+            .kanban_status_id
+            .and_then(|status_id| lane_indexes.get(&status_id).copied())
+            .unwrap_or(no_status_index);
+        (item.memo_id, vec![index])
+    })
+    .collect();
+
+    Ok(LaneLayout { lanes, memberships })
+}
+
+fn group_into_lanes(
+    items: Vec<KanbanAssignmentItem>,
+    status_ids: &[i32],
+    layout: LaneLayout,
+) -> Vec<KanbanLane> {
+    let mut bucket_ids: Vec<Option<i32>> = status_ids.iter().copied().map(Some).collect();
+    if items.iter().any(|item| item.kanban_status_id.is_none()) {
+        bucket_ids.push(None);
+    }
+
+    let mut lanes: Vec<KanbanLane> = layout
+        .lanes
+        .into_iter()
+        .map(|(key, label)| KanbanLane {
+            key,
+            label,
+            total_count: 0,
+            buckets: bucket_ids
+                .iter()
+                .map(|&kanban_status_id| KanbanLaneBucket {
+                    kanban_status_id,
+                    count: 0,
+                    items: Vec::new(),
+                })
+                .collect(),
+        })
+        .collect();
+    let fallback = [lanes.len() - 1];
+
+    for item in items {
+        let Some(bucket_index) = bucket_ids
+            .iter()
+            .position(|&status_id| status_id == item.kanban_status_id)
+        else {
+            continue;
+        };
+        let lane_indexes = layout
+            .memberships
+            .get(&item.memo_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&fallback);
+        for &lane_index in lane_indexes {
+            let lane = &mut lanes[lane_index];
+            lane.total_count += 1;
+            lane.buckets[bucket_index].count += 1;
+            lane.buckets[bucket_index].items.push(item.clone());
+        }
+    }
+
+    lanes.retain(|lane| lane.total_count > 0);
+    lanes
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use super::{KanbanLaneDimension, KanbanLaneRepository};
+    use crate::migrations::apply_migrations;
+    use crate::models::kanban_lane::KanbanLane;
+    use crate::repositories::{
+        BookmarkRepository, KanbanAssignmentRepository, KanbanRepository, KanbanStatusRepository,
+        MemoRepository, MilestoneRepository, WorkspaceRepository,
+    };
+
+    fn lane_summary(lanes: &[KanbanLane]) -> Vec<(String, Vec<usize>)> {
+        lanes
+            .iter()
+            .map(|lane| {
+                (
+                    lane.label.clone(),
+                    lane.buckets.iter().map(|bucket| bucket.count).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lanes_split_each_status_by_milestone_bookmark_and_other_board() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let board = KanbanRepository::ensure_global_status_board(&conn, workspace.id)
+            .expect("board should exist");
+        let statuses = KanbanStatusRepository::list_by_kanban(&conn, workspace.id, board.id)
+            .expect("statuses should load");
+        let status_count = statuses.len();
+        let [first_status, second_status] = [statuses[0].id, statuses[1].id];
+        let [a, b, c] = ["a", "b", "c"].map(|slug| {
+            MemoRepository::create(&conn, workspace.id, slug, slug, "\"\"")
+                .expect("memo should be created")
+                .id
+        });
+        for (memo_id, status_id) in [(a, first_status), (b, first_status), (c, second_status)] {
+            KanbanAssignmentRepository::append_to_status(
+                &conn,
+                workspace.id,
+                memo_id,
+                board.id,
+                status_id,
+            )
+            .expect("memo should be placed");
+        }
+
+        let late = MilestoneRepository::create(&conn, workspace.id, "2026-12-01", "Launch")
+            .expect("milestone should be created");
+        let early = MilestoneRepository::create(&conn, workspace.id, "2026-11-01", "Beta")
+            .expect("milestone should be created");
+        for (milestone_id, memo_id) in [(late, a), (early, a), (early, c)] {
+            MilestoneRepository::add_memo(&conn, workspace.id, milestone_id, memo_id)
+                .expect("memo should join the milestone");
+        }
+        let expected_counts = |counts: &[usize]| {
+            let mut row = vec![0; status_count];
+            row[..counts.len()].copy_from_slice(counts);
+            row
+        };
+
+        let lanes = KanbanLaneRepository::list_lanes(&conn, &board, KanbanLaneDimension::Milestone)
+            .expect("lanes should load");
+        assert_eq!(
+            lane_summary(&lanes),
+            vec![
+                ("Beta".to_string(), expected_counts(&[1, 1])),
+                ("Launch".to_string(), expected_counts(&[1])),
+                ("No milestone".to_string(), expected_counts(&[1])),
+            ]
+        );
+        assert_eq!(lanes[2].buckets[0].items[0].memo_id, b);
+
+        BookmarkRepository::create(&conn, workspace.id, c).expect("bookmark should be created");
+        let lanes = KanbanLaneRepository::list_lanes(&conn, &board, KanbanLaneDimension::Bookmark)
+            .expect("lanes should load");
+        assert_eq!(
+            lane_summary(&lanes),
+            vec![
+                ("Bookmarked".to_string(), expected_counts(&[0, 1])),
+                ("Not bookmarked".to_string(), expected_counts(&[2])),
+            ]
+        );
+
+        let release = KanbanRepository::create(&conn, workspace.id, "Release")
+            .expect("kanban should be created");
+        let shipped =
+            KanbanStatusRepository::create(&conn, workspace.id, release.id, "Shipped", None)
+                .expect("status should be created");
+        KanbanAssignmentRepository::append_to_status(
+            &conn,
+            workspace.id,
+            b,
+            release.id,
+            shipped.id,
+        )
+        .expect("memo should be placed");
+        let lanes = KanbanLaneRepository::list_lanes(
+            &conn,
+            &board,
+            KanbanLaneDimension::KanbanStatus(release.id),
+        )
+        .expect("lanes should load");
+        assert_eq!(
+            lane_summary(&lanes),
+            vec![
+                ("Shipped".to_string(), expected_counts(&[1])),
+                ("Not on Release".to_string(), expected_counts(&[1, 1])),
+            ]
+        );
+
+        let error = KanbanLaneRepository::list_lanes(
+            &conn,
+            &board,
+            KanbanLaneDimension::KanbanStatus(board.id),
+        )
+        .expect_err("a board cannot be its own lane dimension");
+        assert_eq!(error.code, crate::errors::ErrorCode::Validation);
+    }
+}
@@ -0,0 +1,532 @@
+use std::collections::{BTreeMap, HashMap};
+
+use rusqlite::{params, Connection};
+
+use crate::errors::AppError;
+use crate::models::kanban::Kanban;
+use crate::models::kanban_report::{
+    KanbanCumulativeFlowDay, KanbanFlowTimes, KanbanReport, KanbanReportStatus, KanbanStatusCount,
+    KanbanStatusDuration, KanbanStatusTransition, KanbanThroughputWeek,
+};
+use crate::repositories::KanbanStatusRepository;
+
+/// Reports cover at most this many days, which bounds the cumulative flow series.
+const MAX_REPORT_DAYS: i64 = 366;
+/// Days covered when no start date is given, counting the end date.
+const DEFAULT_REPORT_DAYS: i64 = 30;
+
+/// A transition with its time as Unix seconds, ordered per memo.
+struct TimedTransition {
+    memo_id: i32,
+    to_status_id: Option<i32>,
+    at: i64,
+    week_start: String,
+}
+
+pub struct KanbanReportRepository;
+
+impl KanbanReportRepository {
+    /// Newest transitions first, optionally for a single memo.
+    pub fn list_transitions(
+        conn: &Connection,
+        workspace_id: i32,
+        kanban_id: i32,
+        memo_id: Option<i32>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<KanbanStatusTransition>, AppError> {
+        let transitions = conn
+            .prepare(
+                "SELECT transition.id, transition.kanban_id, transition.memo_id, memo.slug_title,
+                    transition.from_status_id, from_status.name,
+                    transition.to_status_id, to_status.name,
+                    transition.transitioned_at
+                FROM kanban_status_transition AS transition
+                JOIN memo ON memo.id = transition.memo_id
+                LEFT JOIN kanban_status AS from_status ON from_status.id = transition.from_status_id
+                LEFT JOIN kanban_status AS to_status ON to_status.id = transition.to_status_id
+                WHERE transition.workspace_id = ?1
+                  AND transition.kanban_id = ?2
+                  AND (?3 IS NULL OR transition.memo_id = ?3)
+                ORDER BY transition.transitioned_at DESC, transition.id DESC
+                LIMIT ?4 OFFSET ?5",
+            )?
+            .query_map(
+                params![
+                    workspace_id,
+                    kanban_id,
+                    memo_id,
+                    limit.max(1),
+                    offset.max(0)
+                ],
+                |row| {
+                    Ok(KanbanStatusTransition {
+                        id: row.get(0)?,
+                        kanban_id: row.get(1)?,
+                        memo_id: row.get(2)?,
+                        memo_slug_title: row.get(3)?,
+                        from_status_id: row.get(4)?,
+                        from_status_name: row.get(5)?,
+                        to_status_id: row.get(6)?,
+                        to_status_name: row.get(7)?,
+                        transitioned_at: row.get(8)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(transitions)
+    }
+
+    /// Builds the flow report for `kanban` between two local dates. The range defaults to
+    /// the last 30 days up to today.
+    pub fn build(
+        conn: &Connection,
+        kanban: &Kanban,
+        date_from: Option<&str>,
+        date_to: Option<&str>,
+    ) -> Result<KanbanReport, AppError> {
+        let date_to = match date_to {
+            Some(date) => validate_date(conn, date)?,
+            None => conn.query_row("SELECT date('now', 'localtime')", [], |row| row.get(0))?,
+        };
+        let date_from = match date_from {
+            Some(date) => validate_date(conn, date)?,
+            None => conn.query_row(
+                "SELECT date(?, ?)",
+                params![&date_to, format!("-{} days", DEFAULT_REPORT_DAYS - 1)],
+                |row| row.get(0),
+            )?,
+        };
+        let day_count: i64 = conn.query_row(
+            "SELECT CAST(julianday(?) - julianday(?) AS INTEGER) + 1",
+            params![&date_to, &date_from],
+            |row| row.get(0),
+        )?;
+        if day_count < 1 {
+            return Err(AppError::validation(
+                "Report start date must not be after its end date.",
+            ));
+        }
+        if day_count > MAX_REPORT_DAYS {
+            return Err(AppError::validation(format!(
+                "Reports cover at most {} days.",
+                MAX_REPORT_DAYS
+            )));
+        }
+
+        let (range_start, range_end, now): (i64, i64, i64) = conn.query_row(
+            "SELECT CAST(strftime('%s', ?1, 'utc') AS INTEGER),
+                CAST(strftime('%s', ?2, '+1 day', 'utc') AS INTEGER),
+                CAST(strftime('%s', 'now') AS INTEGER)",
+            params![&date_from, &date_to],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )?;
+
+        let statuses: Vec<KanbanReportStatus> =
+            KanbanStatusRepository::list_by_kanban(conn, kanban.workspace_id, kanban.id)?
+                .into_iter()
+                .map(|status| KanbanReportStatus {
+                    id: status.id,
+                    name: status.name,
+                    color: status.color,
+                })
+                .collect();
+        let transitions = load_transitions(conn, kanban)?;
+        let mut by_memo: BTreeMap<i32, Vec<&TimedTransition>> = BTreeMap::new();
+        for transition in &transitions {
+            by_memo
+                .entry(transition.memo_id)
+                .or_default()
+                .push(transition);
+        }
+
+        let throughput = build_throughput(
+            conn,
+            &by_memo,
+            kanban.done_status_id,
+            &date_from,
+            &date_to,
+            (range_start, range_end),
+        )?;
+        let time_in_status =
+            build_time_in_status(&by_memo, &statuses, (range_start, range_end), now);
+        let flow_times = build_flow_times(
+            &by_memo,
+            kanban.default_status_id,
+            kanban.done_status_id,
+            (range_start, range_end),
+        );
+        let cumulative_flow =
+            build_cumulative_flow(conn, &by_memo, &statuses, &date_from, &date_to)?;
+
+        Ok(KanbanReport {
+            kanban_id: kanban.id,
+            date_from,
+            date_to,
+            default_status_id: kanban.default_status_id,
+            done_status_id: kanban.done_status_id,
+            statuses,
+            throughput,
+            time_in_status,
+            flow_times,
+            cumulative_flow,
+        })
+    }
+}
+
+fn validate_date(conn: &Connection, date: &str) -> Result<String, AppError> {
+    let is_valid: bool = conn.query_row("SELECT date(?, '+0 days') = ?", (date, date), |row| {
+        row.get(0)
+    })?;
+    if !is_valid {
+        return Err(AppError::validation(format!(
+            "Invalid report date: {}",
+            date
+        )));
+    }
+    Ok(date.to_string())
+}
+
+fn load_transitions(conn: &Connection, kanban: &Kanban) -> Result<Vec<TimedTransition>, AppError> {
+    let transitions = conn
+        .prepare(
+            "SELECT memo_id, to_status_id,
+                CAST(strftime('%s', transitioned_at) AS INTEGER),
+                date(transitioned_at, 'localtime', 'weekday 0', '-6 days')
+            FROM kanban_status_transition
+            WHERE workspace_id = ? AND kanban_id = ?
+            ORDER BY memo_id, transitioned_at, id",
+        )?
+        .query_map((kanban.workspace_id, kanban.id), |row| {
+            Ok(TimedTransition {
+                memo_id: row.get(0)?,
+                to_status_id: row.get(1)?,
+                at: row.get(2)?,
+                week_start: row.get(3)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(transitions)
+}
+
+/// Counts each memo once per week, in the week it first reached Done.
+fn build_throughput(
+    conn: &Connection,
+    by_memo: &BTreeMap<i32, Vec<&TimedTransition>>,
+    done_status_id: Option<i32>,
+    date_from: &str,
+    date_to: &str,
+    (range_start, range_end): (i64, i64),
+) -> Result<Vec<KanbanThroughputWeek>, AppError> {
+    let mut counts: HashMap<String, i64> = HashMap::new();
+    if let Some(done_status_id) = done_status_id {
+        for transitions in by_memo.values() {
+            let mut weeks: Vec<&str> = transitions
+                .iter()
+                .filter(|transition| {
+                    transition.to_status_id == Some(done_status_id)
+                        && (range_start..range_end).contains(&transition.at)
+                })
+                .map(|transition| transition.week_start.as_str())
+                .collect();
+            weeks.dedup();
+            for week in weeks {
+                *counts.entry(week.to_string()).or_default() += 1;
+            }
+        }
+    }
+
+    let weeks = conn
+        .prepare(
+            "WITH RECURSIVE weeks(week_start) AS (
+                SELECT date(?1, 'weekday 0', '-6 days')
+                UNION ALL
+                SELECT date(week_start, '+7 days') FROM weeks
+                WHERE date(week_start, '+7 days') <= ?2
+            )
+            SELECT week_start FROM weeks",
+        )?
+        .query_map([date_from, date_to], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(weeks
+        .into_iter()
+        .map(|week_start| KanbanThroughputWeek {
+            completed_count: counts.get(&week_start).copied().unwrap_or(0),
+            week_start,
+        })
+        .collect())
+}
+
+fn build_time_in_status(
+    by_memo: &BTreeMap<i32, Vec<&TimedTransition>>,
+    statuses: &[KanbanReportStatus],
+    (range_start, range_end): (i64, i64),
+    now: i64,
+) -> Vec<KanbanStatusDuration> {
+    let mut stays: HashMap<i32, (i64, i64)> = HashMap::new();
+    for transitions in by_memo.values() {
+        for (index, transition) in transitions.iter().enumerate() {
+            let Some(status_id) = transition.to_status_id else {
+                continue;
+            };
+            let ended_at = transitions
+                .get(index + 1)
+                .map(|next| next.at)
+                .unwrap_or(now);
+            if transition.at >= range_end || ended_at < range_start {
+                continue;
+            }
+            let entry = stays.entry(status_id).or_default();
+            entry.0 += 1;
+            entry.1 += ended_at - transition.at;
+        }
+    }
+
+    statuses
+        .iter()
+        .map(|status| {
+            let (stay_count, total_seconds) = stays.get(&status.id).copied().unwrap_or((0, 0));
+            KanbanStatusDuration {
+                status_id: status.id,
+                status_name: status.name.clone(),
+                stay_count,
+                average_hours: (stay_count > 0)
+                    .then(|| to_hours(total_seconds as f64 / stay_count as f64)),
+            }
+        })
+        .collect()
+}
+
+fn build_flow_times(
+    by_memo: &BTreeMap<i32, Vec<&TimedTransition>>,
+    default_status_id: Option<i32>,
+    done_status_id: Option<i32>,
+    (range_start, range_end): (i64, i64),
+) -> KanbanFlowTimes {
+    let mut lead_times = Vec::new();
+    let mut cycle_times = Vec::new();
+
+    if let Some(done_status_id) = done_status_id {
+        for transitions in by_memo.values() {
+            // Memos that skipped the default status are measured from joining the board.
+            let Some(lead_start) = transitions
+                .iter()
+                .position(|transition| {
+                    default_status_id.is_some() && transition.to_status_id == default_status_id
+                })
+                .or_else(|| (!transitions.is_empty()).then_some(0))
+            else {
+                continue;
+            };
+            let Some(done_index) = transitions[lead_start..]
+                .iter()
+                .position(|transition| transition.to_status_id == Some(done_status_id))
+                .map(|offset| lead_start + offset)
+            else {
+                continue;
+            };
+            let done_at = transitions[done_index].at;
+            if !(range_start..range_end).contains(&done_at) {
+                continue;
+            }
+            let cycle_start = transitions[lead_start..=done_index]
+                .iter()
+                .find(|transition| {
+                    transition.to_status_id.is_some()
+                        && transition.to_status_id != default_status_id
+                })
+                .map(|transition| transition.at)
+                .unwrap_or(done_at);
+
+            lead_times.push((done_at - transitions[lead_start].at) as f64);
+            cycle_times.push((done_at - cycle_start) as f64);
+        }
+    }
+
+    KanbanFlowTimes {
+        completed_count: lead_times.len() as i64,
+        average_lead_time_hours: average(&lead_times).map(to_hours),
+        median_lead_time_hours: median(&mut lead_times).map(to_hours),
+        average_cycle_time_hours: average(&cycle_times).map(to_hours),
+        median_cycle_time_hours: median(&mut cycle_times).map(to_hours),
+    }
+}
+
+fn build_cumulative_flow(
+    conn: &Connection,
+    by_memo: &BTreeMap<i32, Vec<&TimedTransition>>,
+    statuses: &[KanbanReportStatus],
+    date_from: &str,
+    date_to: &str,
+) -> Result<Vec<KanbanCumulativeFlowDay>, AppError> {
+    let days = conn
+        .prepare(
+            "WITH RECURSIVE days(day) AS (
+                SELECT ?1
+                UNION ALL
+                SELECT date(day, '+1 day') FROM days WHERE day < ?2
+            )
+            SELECT day, CAST(strftime('%s', day, '+1 day', 'utc') AS INTEGER) FROM days",
+        )?
+        .query_map([date_from, date_to], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(days
+        .into_iter()
+        .map(|(date, day_end)| {
+            let mut counts: HashMap<i32, i64> = HashMap::new();
+            for transitions in by_memo.values() {
+                let current = transitions
+                    .iter()
+                    .take_while(|transition| transition.at < day_end)
+                    .last()
+                    .and_then(|transition| transition.to_status_id);
+                if let Some(status_id) = current {
+                    *counts.entry(status_id).or_default() += 1;
+                }
+            }
+            KanbanCumulativeFlowDay {
+                date,
+                counts: statuses
+                    .iter()
+                    .map(|status| KanbanStatusCount {
+                        status_id: status.id,
+                        count: counts.get(&status.id).copied().unwrap_or(0),
+                    })
+                    .collect(),
+            }
+        })
+        .collect())
+}
+
+fn average(values: &[f64]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let middle = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    })
+}
+
+fn to_hours(seconds: f64) -> f64 {
+    (seconds / 36.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use super::KanbanReportRepository;
+    use crate::migrations::apply_migrations;
+    use crate::repositories::{
+        KanbanAssignmentRepository, KanbanRepository, KanbanStatusRepository, MemoRepository,
+        WorkspaceRepository,
+    };
+
+    #[test]
+    fn build_reports_throughput_flow_times_and_cumulative_flow() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let kanban = KanbanRepository::ensure_global_status_board(&conn, workspace.id)
+            .expect("board should exist");
+        let statuses = KanbanStatusRepository::list_by_kanban(&conn, workspace.id, kanban.id)
+            .expect("statuses should load");
+        let status_id = |name: &str| {
+            statuses
+                .iter()
+                .find(|status| status.name == name)
+                .map(|status| status.id)
+        };
+        assert_eq!(kanban.done_status_id, status_id("Done"));
+        let memo = MemoRepository::create(&conn, workspace.id, "task", "Task", "\"\"")
+            .expect("memo should be created");
+
+        for (name, at) in [
+            ("Inbox", "2026-10-05 09:00:00"),
+            ("Now", "2026-10-06 09:00:00"),
+            ("Done", "2026-10-07 21:00:00"),
+        ] {
+            KanbanAssignmentRepository::upsert_status(
+                &conn,
+                workspace.id,
+                memo.id,
+                kanban.id,
+                status_id(name),
+                None,
+            )
+            .expect("status should change");
+            conn.execute(
+                "UPDATE kanban_status_transition SET transitioned_at = ?
+                WHERE id = (SELECT MAX(id) FROM kanban_status_transition)",
+                [at],
+            )
+            .expect("transition time should be set");
+        }
+
+        let history = KanbanReportRepository::list_transitions(
+            &conn,
+            workspace.id,
+            kanban.id,
+            Some(memo.id),
+            50,
+            0,
+        )
+        .expect("history should load");
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].from_status_name.as_deref(), Some("Now"));
+        assert_eq!(history[0].to_status_name.as_deref(), Some("Done"));
+
+        let report =
+            KanbanReportRepository::build(&conn, &kanban, Some("2026-10-01"), Some("2026-10-20"))
+                .expect("report should build");
+        let completed: i64 = report
+            .throughput
+            .iter()
+            .map(|week| week.completed_count)
+            .sum();
+        assert_eq!(completed, 1);
+        assert_eq!(report.flow_times.completed_count, 1);
+        assert_eq!(report.flow_times.average_lead_time_hours, Some(60.0));
+        assert_eq!(report.flow_times.average_cycle_time_hours, Some(36.0));
+        let now = report
+            .time_in_status
+            .iter()
+            .find(|duration| Some(duration.status_id) == status_id("Now"))
+            .expect("Now should be reported");
+        assert_eq!(now.average_hours, Some(36.0));
+
+        assert_eq!(report.cumulative_flow.len(), 20);
+        let last_day = report.cumulative_flow.last().expect("days should exist");
+        let done_count = last_day
+            .counts
+            .iter()
+            .find(|count| Some(count.status_id) == status_id("Done"))
+            .map(|count| count.count);
+        assert_eq!(done_count, Some(1));
+
+        assert!(KanbanReportRepository::build(
+            &conn,
+            &kanban,
+            Some("2026-10-20"),
+            Some("2026-10-01")
+        )
+        .is_err());
+    }
+}
@@ -35,7 +35,10 @@ impl KanbanRepository {
                 )?;
             }
             Self::set_status_roles_from_default_names(conn, workspace_id, kanban.id)?;
-        } else if kanban.default_status_id.is_none() || kanban.focus_status_id.is_none() {
+        } else if kanban.default_status_id.is_none()
+            || kanban.focus_status_id.is_none()
+            || kanban.done_status_id.is_none()
+        {
             Self::set_status_roles_from_default_names(conn, workspace_id, kanban.id)?;
         }
 
@@ -79,7 +82,7 @@ impl KanbanRepository {
         kanban_id: i32,
     ) -> Result<Option<Kanban>> {
         let mut stmt = conn.prepare(
-            "SELECT id, workspace_id, name, order_index, default_status_id, focus_status_id, created_at, updated_at
+            "SELECT id, workspace_id, name, order_index, default_status_id, focus_status_id, created_at, updated_at, done_status_id
             FROM kanban
             WHERE id = ? AND workspace_id = ?",
         )?;
@@ -93,6 +96,7 @@ impl KanbanRepository {
                     order_index: row.get(3)?,
                     default_status_id: row.get(4)?,
                     focus_status_id: row.get(5)?,
+                    done_status_id: row.get(8)?,
                     created_at: row.get(6)?,
                     updated_at: row.get(7)?,
                 })
@@ -104,7 +108,7 @@ impl KanbanRepository {
 
     fn find_by_name(conn: &Connection, workspace_id: i32, name: &str) -> Result<Option<Kanban>> {
         let mut stmt = conn.prepare(
-            "SELECT id, workspace_id, name, order_index, default_status_id, focus_status_id, created_at, updated_at
+            "SELECT id, workspace_id, name, order_index, default_status_id, focus_status_id, created_at, updated_at, done_status_id
             FROM kanban
             WHERE workspace_id = ? AND name = ?",
         )?;
@@ -118,6 +122,7 @@ impl KanbanRepository {
                     order_index: row.get(3)?,
                     default_status_id: row.get(4)?,
                     focus_status_id: row.get(5)?,
+                    done_status_id: row.get(8)?,
                     created_at: row.get(6)?,
                     updated_at: row.get(7)?,
                 })
@@ -133,19 +138,25 @@ impl KanbanRepository {
         kanban_id: i32,
         default_status_id: Option<i32>,
         focus_status_id: Option<i32>,
+        done_status_id: Option<i32>,
     ) -> Result<bool> {
-        if !Self::status_belongs_to_kanban(conn, workspace_id, kanban_id, default_status_id)? {
-            return Err(rusqlite::Error::QueryReturnedNoRows);
-        }
-        if !Self::status_belongs_to_kanban(conn, workspace_id, kanban_id, focus_status_id)? {
-            return Err(rusqlite::Error::QueryReturnedNoRows);
+        for status_id in [default_status_id, focus_status_id, done_status_id] {
+            if !Self::status_belongs_to_kanban(conn, workspace_id, kanban_id, status_id)? {
+                return Err(rusqlite::Error::QueryReturnedNoRows);
+            }
         }
 
         let updated = conn.execute(
             "UPDATE kanban
-            SET default_status_id = ?, focus_status_id = ?
+            SET default_status_id = ?, focus_status_id = ?, done_status_id = ?
             WHERE id = ? AND workspace_id = ?",
-            (default_status_id, focus_status_id, kanban_id, workspace_id),
+            (
+                default_status_id,
+                focus_status_id,
+                done_status_id,
+                kanban_id,
+                workspace_id,
+            ),
         )?;
 
         Ok(updated > 0)
@@ -170,18 +181,27 @@ impl KanbanRepository {
                 (SELECT id FROM kanban_status
                  WHERE workspace_id = ? AND kanban_id = ? AND name = ?
                  LIMIT 1)
+              ),
+              done_status_id = COALESCE(
+                done_status_id,
+                (SELECT id FROM kanban_status
+                 WHERE workspace_id = ? AND kanban_id = ? AND name = ?
+                 LIMIT 1)
               )
             WHERE id = ? AND workspace_id = ?",
-            (
+            rusqlite::params![
                 workspace_id,
                 kanban_id,
                 Self::GLOBAL_STATUS_NAMES[0],
                 workspace_id,
                 kanban_id,
                 Self::GLOBAL_STATUS_NAMES[1],
+                workspace_id,
+                kanban_id,
+                Self::GLOBAL_STATUS_NAMES[5],
                 kanban_id,
                 workspace_id,
-            ),
+            ],
         )?;
 
         Ok(())
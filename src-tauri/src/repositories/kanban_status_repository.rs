@@ -1,4 +1,4 @@
-use crate::models::kanban_status::KanbanStatus;
+use crate::models::kanban_status::{KanbanStatus, KanbanStatusTransitionRule};
 use rusqlite::{Connection, Result};
 
 pub struct KanbanStatusRepository;
@@ -10,7 +10,7 @@ impl KanbanStatusRepository {
         kanban_id: i32,
     ) -> Result<Vec<KanbanStatus>> {
         let mut stmt = conn.prepare(
-            "SELECT id, workspace_id, kanban_id, name, color, order_index, created_at, updated_at
+            "SELECT id, workspace_id, kanban_id, name, color, order_index, wip_limit, created_at, updated_at
             FROM kanban_status
             WHERE workspace_id = ? AND kanban_id = ?
             ORDER BY order_index ASC, created_at ASC",
@@ -25,8 +25,9 @@ impl KanbanStatusRepository {
                     name: row.get(3)?,
                     color: row.get(4)?,
                     order_index: row.get(5)?,
-                    created_at: row.get(6)?,
-                    updated_at: row.get(7)?,
+                    wip_limit: row.get(6)?,
+                    created_at: row.get(7)?,
+                    updated_at: row.get(8)?,
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -58,7 +59,7 @@ impl KanbanStatusRepository {
         let status_id = conn.last_insert_rowid() as i32;
 
         let mut stmt = conn.prepare(
-            "SELECT id, workspace_id, kanban_id, name, color, order_index, created_at, updated_at
+            "SELECT id, workspace_id, kanban_id, name, color, order_index, wip_limit, created_at, updated_at
             FROM kanban_status
             WHERE id = ?",
         )?;
@@ -71,8 +72,9 @@ impl KanbanStatusRepository {
                 name: row.get(3)?,
                 color: row.get(4)?,
                 order_index: row.get(5)?,
-                created_at: row.get(6)?,
-                updated_at: row.get(7)?,
+                wip_limit: row.get(6)?,
+                created_at: row.get(7)?,
+                updated_at: row.get(8)?,
             })
         })?;
 
@@ -96,14 +98,89 @@ impl KanbanStatusRepository {
         Ok(updated > 0)
     }
 
+    pub fn update_wip_limit(
+        conn: &Connection,
+        workspace_id: i32,
+        status_id: i32,
+        wip_limit: Option<i32>,
+    ) -> Result<bool> {
+        let updated = conn.execute(
+            "UPDATE kanban_status
+            SET wip_limit = ?, updated_at = CURRENT_TIMESTAMP
+            WHERE id = ? AND workspace_id = ?",
+            (wip_limit, status_id, workspace_id),
+        )?;
+
+        Ok(updated > 0)
+    }
+
+    pub fn list_transition_rules(
+        conn: &Connection,
+        workspace_id: i32,
+        kanban_id: i32,
+    ) -> Result<Vec<KanbanStatusTransitionRule>> {
+        let mut stmt = conn.prepare(
+            "SELECT from_status_id, to_status_id
+            FROM kanban_status_transition_rule
+            WHERE workspace_id = ? AND kanban_id = ?
+            ORDER BY from_status_id ASC, to_status_id ASC",
+        )?;
+
+        let rules = stmt
+            .query_map((workspace_id, kanban_id), |row| {
+                Ok(KanbanStatusTransitionRule {
+                    from_status_id: row.get(0)?,
+                    to_status_id: row.get(1)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(rules)
+    }
+
+    /// Replaces every transition rule of the kanban. Callers check that both ends of each
+    /// rule are statuses of that kanban.
+    pub fn replace_transition_rules(
+        conn: &mut Connection,
+        workspace_id: i32,
+        kanban_id: i32,
+        rules: &[KanbanStatusTransitionRule],
+    ) -> Result<()> {
+        let tx = conn.transaction()?;
+
+        tx.execute(
+            "DELETE FROM kanban_status_transition_rule
+            WHERE workspace_id = ? AND kanban_id = ?",
+            (workspace_id, kanban_id),
+        )?;
+
+        for rule in rules {
+            tx.execute(
+                "INSERT OR IGNORE INTO kanban_status_transition_rule
+                    (workspace_id, kanban_id, from_status_id, to_status_id)
+                VALUES (?, ?, ?, ?)",
+                (
+                    workspace_id,
+                    kanban_id,
+                    rule.from_status_id,
+                    rule.to_status_id,
+                ),
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
     pub fn delete(conn: &Connection, workspace_id: i32, status_id: i32) -> Result<bool> {
         conn.execute(
             "UPDATE kanban
             SET
               default_status_id = CASE WHEN default_status_id = ? THEN NULL ELSE default_status_id END,
-              focus_status_id = CASE WHEN focus_status_id = ? THEN NULL ELSE focus_status_id END
+              focus_status_id = CASE WHEN focus_status_id = ? THEN NULL ELSE focus_status_id END,
+              done_status_id = CASE WHEN done_status_id = ? THEN NULL ELSE done_status_id END
             WHERE workspace_id = ?",
-            (status_id, status_id, workspace_id),
+            (status_id, status_id, status_id, workspace_id),
         )?;
 
         let deleted = conn.execute(
@@ -0,0 +1,658 @@
+use std::collections::{HashMap, HashSet};
+
+use rusqlite::{Connection, OptionalExtension};
+
+use crate::errors::AppError;
+use crate::models::kanban::Kanban;
+use crate::models::kanban_automation::KanbanAutomationRuleInput;
+use crate::models::kanban_template::{KanbanCloneResult, KanbanTemplate, KanbanTemplateStatus};
+use crate::repositories::{
+    KanbanAutomationRepository, KanbanRepository, KanbanStatusRepository, MemoRepository,
+};
+
+/// A card of the board being cloned.
+struct SourceAssignment {
+    memo_id: i32,
+    slug_title: String,
+    kanban_status_id: Option<i32>,
+    position: Option<i64>,
+    due_on: Option<String>,
+    scheduled_on: Option<String>,
+}
+
+pub struct KanbanTemplateRepository;
+
+impl KanbanTemplateRepository {
+    pub fn list(conn: &Connection, workspace_id: i32) -> Result<Vec<KanbanTemplate>, AppError> {
+        let ids = conn
+            .prepare("SELECT id FROM kanban_template WHERE workspace_id = ? ORDER BY name ASC")?
+            .query_map([workspace_id], |row| row.get::<_, i32>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut templates = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(template) = Self::find_by_id(conn, workspace_id, id)? {
+                templates.push(template);
+            }
+        }
+        Ok(templates)
+    }
+
+    pub fn find_by_id(
+        conn: &Connection,
+        workspace_id: i32,
+        id: i32,
+    ) -> Result<Option<KanbanTemplate>, AppError> {
+        let Some((name, created_at, updated_at)) = conn
+            .query_row(
+                "SELECT name, created_at, updated_at
+                FROM kanban_template
+                WHERE id = ? AND workspace_id = ?",
+                (id, workspace_id),
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?
+        else {
+            return Ok(None);
+        };
+
+        let statuses = conn
+            .prepare(
+                "SELECT name, color, wip_limit, is_default, is_focus, is_done
+                FROM kanban_template_status
+                WHERE template_id = ?
+                ORDER BY order_index ASC, id ASC",
+            )?
+            .query_map([id], |row| {
+                Ok(KanbanTemplateStatus {
+                    name: row.get(0)?,
+                    color: row.get(1)?,
+                    wip_limit: row.get(2)?,
+                    is_default: row.get(3)?,
+                    is_focus: row.get(4)?,
+                    is_done: row.get(5)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some(KanbanTemplate {
+            id,
+            workspace_id,
+            name,
+            statuses,
+            created_at,
+            updated_at,
+        }))
+    }
+
+    /// Callers run this in a transaction so a rejected status leaves no template behind.
+    pub fn create(
+        conn: &Connection,
+        workspace_id: i32,
+        name: &str,
+        statuses: &[KanbanTemplateStatus],
+    ) -> Result<KanbanTemplate, AppError> {
+        let name = validate_template(name, statuses)?;
+        ensure_template_name_free(conn, workspace_id, name, None)?;
+
+        conn.execute(
+            "INSERT INTO kanban_template (workspace_id, name) VALUES (?, ?)",
+            (workspace_id, name),
+        )?;
+        let id = conn.last_insert_rowid() as i32;
+        insert_template_statuses(conn, id, statuses)?;
+
+        Self::find_by_id(conn, workspace_id, id)?
+            .ok_or_else(|| AppError::not_found(format!("Kanban template not found: {}", id)))
+    }
+
+    /// Renames the template and replaces its statuses.
+    pub fn update(
+        conn: &Connection,
+        workspace_id: i32,
+        id: i32,
+        name: &str,
+        statuses: &[KanbanTemplateStatus],
+    ) -> Result<KanbanTemplate, AppError> {
+        let name = validate_template(name, statuses)?;
+        ensure_template_name_free(conn, workspace_id, name, Some(id))?;
+
+        let updated = conn.execute(
+            "UPDATE kanban_template SET name = ? WHERE id = ? AND workspace_id = ?",
+            (name, id, workspace_id),
+        )?;
+        if updated == 0 {
+            return Err(AppError::not_found(format!(
+                "Kanban template not found: {}",
+                id
+            )));
+        }
+        conn.execute(
+            "DELETE FROM kanban_template_status WHERE template_id = ?",
+            [id],
+        )?;
+        insert_template_statuses(conn, id, statuses)?;
+
+        Self::find_by_id(conn, workspace_id, id)?
+            .ok_or_else(|| AppError::not_found(format!("Kanban template not found: {}", id)))
+    }
+
+    pub fn delete(conn: &Connection, workspace_id: i32, id: i32) -> Result<bool, AppError> {
+        let deleted = conn.execute(
+            "DELETE FROM kanban_template WHERE id = ? AND workspace_id = ?",
+            (id, workspace_id),
+        )?;
+        Ok(deleted > 0)
+    }
+
+    /// The board's statuses in board order, with its roles and WIP limits.
+    pub fn statuses_of_kanban(
+        conn: &Connection,
+        kanban: &Kanban,
+    ) -> Result<Vec<KanbanTemplateStatus>, AppError> {
+        let statuses =
+            KanbanStatusRepository::list_by_kanban(conn, kanban.workspace_id, kanban.id)?
+                .into_iter()
+                .map(|status| KanbanTemplateStatus {
+                    is_default: kanban.default_status_id == Some(status.id),
+                    is_focus: kanban.focus_status_id == Some(status.id),
+                    is_done: kanban.done_status_id == Some(status.id),
+                    name: status.name,
+                    color: status.color,
+                    wip_limit: status.wip_limit,
+                })
+                .collect();
+        Ok(statuses)
+    }
+
+    /// Creates a board with the given statuses and roles. Returns the board and the ids of
+    /// the new statuses, in the order of `statuses`. Callers run this in a transaction.
+    pub fn create_kanban(
+        conn: &Connection,
+        workspace_id: i32,
+        name: &str,
+        statuses: &[KanbanTemplateStatus],
+    ) -> Result<(Kanban, Vec<i32>), AppError> {
+        let name = validate_template(name, statuses)?;
+        let exists: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM kanban WHERE workspace_id = ? AND name = ?)",
+            (workspace_id, name),
+            |row| row.get(0),
+        )?;
+        if exists {
+            return Err(
+                AppError::conflict(format!("Kanban already exists: {}", name))
+                    .with_reason("DUPLICATE_KANBAN_NAME"),
+            );
+        }
+
+        let kanban = KanbanRepository::create(conn, workspace_id, name)?;
+        let mut status_ids = Vec::with_capacity(statuses.len());
+        let (mut default_status_id, mut focus_status_id, mut done_status_id) = (None, None, None);
+        for status in statuses {
+            let created = KanbanStatusRepository::create(
+                conn,
+                workspace_id,
+                kanban.id,
+                status.name.trim(),
+                status.color.as_deref(),
+            )?;
+            if status.wip_limit.is_some() {
+                KanbanStatusRepository::update_wip_limit(
+                    conn,
+                    workspace_id,
+                    created.id,
+                    status.wip_limit,
+                )?;
+            }
+            if status.is_default {
+                default_status_id = Some(created.id);
+            }
+            if status.is_focus {
+                focus_status_id = Some(created.id);
+            }
+            if status.is_done {
+                done_status_id = Some(created.id);
+            }
+            status_ids.push(created.id);
+        }
+        KanbanRepository::update_status_roles(
+            conn,
+            workspace_id,
+            kanban.id,
+            default_status_id,
+            focus_status_id,
+            done_status_id,
+        )?;
+
+        let kanban = KanbanRepository::find_by_id(conn, workspace_id, kanban.id)?
+            .ok_or_else(|| AppError::not_found(format!("Kanban not found: {}", kanban.id)))?;
+        Ok((kanban, status_ids))
+    }
+
+    /// Copies the board's statuses, roles, WIP limits, transition rules and automation rules
+    /// into a new board, in the same workspace or another one. With `include_assignments`,
+    /// cards keep their status, position and dates; in another workspace they are matched to
+    /// memos by slug. Callers run this in a transaction.
+    pub fn clone_kanban(
+        conn: &Connection,
+        source: &Kanban,
+        target_workspace_id: i32,
+        name: &str,
+        include_assignments: bool,
+    ) -> Result<KanbanCloneResult, AppError> {
+        let source_statuses =
+            KanbanStatusRepository::list_by_kanban(conn, source.workspace_id, source.id)?;
+        let statuses = Self::statuses_of_kanban(conn, source)?;
+        let (kanban, new_status_ids) =
+            Self::create_kanban(conn, target_workspace_id, name, &statuses)?;
+        let status_map: HashMap<i32, i32> = source_statuses
+            .iter()
+            .map(|status| status.id)
+            .zip(new_status_ids)
+            .collect();
+
+        for rule in
+            KanbanStatusRepository::list_transition_rules(conn, source.workspace_id, source.id)?
+        {
+            if let (Some(from), Some(to)) = (
+                status_map.get(&rule.from_status_id),
+                status_map.get(&rule.to_status_id),
+            ) {
+                conn.execute(
+                    "INSERT OR IGNORE INTO kanban_status_transition_rule
+                        (workspace_id, kanban_id, from_status_id, to_status_id)
+                    VALUES (?, ?, ?, ?)",
+                    (target_workspace_id, kanban.id, from, to),
+                )?;
+            }
+        }
+
+        for rule in
+            KanbanAutomationRepository::list_by_kanban(conn, source.workspace_id, source.id)?
+        {
+            let remap = |status_id: Option<i32>| match status_id {
+                Some(status_id) => status_map.get(&status_id).copied().map(Some),
+                None => Some(None),
+            };
+            let (Some(trigger_status_id), Some(condition_status_id)) = (
+                remap(rule.trigger_status_id),
+                remap(rule.condition_status_id),
+            ) else {
+                continue;
+            };
+            KanbanAutomationRepository::create(
+                conn,
+                target_workspace_id,
+                kanban.id,
+                &KanbanAutomationRuleInput {
+                    trigger_type: rule.trigger_type,
+                    trigger_status_id,
+                    condition_type: rule.condition_type,
+                    condition_status_id,
+                    action_type: rule.action_type,
+                    enabled: rule.enabled,
+                },
+            )?;
+        }
+
+        let mut copied_assignment_count = 0;
+        let mut skipped_memo_slugs = Vec::new();
+        if include_assignments {
+            let assignments = conn
+                .prepare(
+                    "SELECT kanban_assignment.memo_id, memo.slug_title,
+                        kanban_assignment.kanban_status_id, kanban_assignment.position,
+                        kanban_assignment.due_on, kanban_assignment.scheduled_on
+                    FROM kanban_assignment
+                    JOIN memo ON memo.id = kanban_assignment.memo_id
+                    WHERE kanban_assignment.workspace_id = ? AND kanban_assignment.kanban_id = ?
+                    ORDER BY kanban_assignment.id ASC",
+                )?
+                .query_map((source.workspace_id, source.id), |row| {
+                    Ok(SourceAssignment {
+                        memo_id: row.get(0)?,
+                        slug_title: row.get(1)?,
+                        kanban_status_id: row.get(2)?,
+                        position: row.get(3)?,
+                        due_on: row.get(4)?,
+                        scheduled_on: row.get(5)?,
+                    })
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for assignment in assignments {
+                let memo_id = if target_workspace_id == source.workspace_id {
+                    assignment.memo_id
+                } else {
+                    match MemoRepository::find_by_slug(
+                        conn,
+                        target_workspace_id,
+                        &assignment.slug_title,
+                    )? {
+                        Some(memo) => memo.id,
+                        None => {
+                            skipped_memo_slugs.push(assignment.slug_title);
+                            continue;
+                        }
+                    }
+                };
+                conn.execute(
+                    "INSERT INTO kanban_assignment
+                        (workspace_id, memo_id, kanban_id, kanban_status_id, position, due_on, scheduled_on)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)",
+                    (
+                        target_workspace_id,
+                        memo_id,
+                        kanban.id,
+                        assignment
+                            .kanban_status_id
+                            .and_then(|id| status_map.get(&id).copied()),
+                        assignment.position,
+                        assignment.due_on,
+                        assignment.scheduled_on,
+                    ),
+                )?;
+                copied_assignment_count += 1;
+            }
+        }
+
+        Ok(KanbanCloneResult {
+            kanban,
+            copied_assignment_count,
+            skipped_memo_slugs,
+        })
+    }
+}
+
+/// Checks the name and statuses shared by templates and new boards. Returns the trimmed
+/// name.
+fn validate_template<'a>(
+    name: &'a str,
+    statuses: &[KanbanTemplateStatus],
+) -> Result<&'a str, AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::validation("Name is required"));
+    }
+
+    let mut names = HashSet::new();
+    for status in statuses {
+        let status_name = status.name.trim();
+        if status_name.is_empty() {
+            return Err(AppError::validation("Status name is required"));
+        }
+        if !names.insert(status_name) {
+            return Err(
+                AppError::validation(format!("Duplicate status name: {}", status_name))
+                    .with_reason("DUPLICATE_STATUS_NAME"),
+            );
+        }
+        if status.wip_limit.is_some_and(|limit| limit <= 0) {
+            return Err(AppError::validation(format!(
+                "WIP limit must be positive: {}",
+                status_name
+            )));
+        }
+    }
+
+    let role_counts = [
+        (
+            "default",
+            statuses.iter().filter(|status| status.is_default).count(),
+        ),
+        (
+            "focus",
+            statuses.iter().filter(|status| status.is_focus).count(),
+        ),
+        (
+            "done",
+            statuses.iter().filter(|status| status.is_done).count(),
+        ),
+    ];
+    if let Some((role, _)) = role_counts.iter().find(|(_, count)| *count > 1) {
+        return Err(AppError::validation(format!(
+            "Only one status can be the {} status",
+            role
+        )));
+    }
+
+    Ok(name)
+}
+
+fn ensure_template_name_free(
+    conn: &Connection,
+    workspace_id: i32,
+    name: &str,
+    excluded_id: Option<i32>,
+) -> Result<(), AppError> {
+    let exists: bool = conn.query_row(
+        "SELECT EXISTS(
+            SELECT 1 FROM kanban_template
+            WHERE workspace_id = ?1 AND name = ?2 AND (?3 IS NULL OR id != ?3)
+        )",
+        (workspace_id, name, excluded_id),
+        |row| row.get(0),
+    )?;
+    if exists {
+        return Err(
+            AppError::conflict(format!("Kanban template already exists: {}", name))
+                .with_reason("DUPLICATE_TEMPLATE_NAME"),
+        );
+    }
+    Ok(())
+}
+
+fn insert_template_statuses(
+    conn: &Connection,
+    template_id: i32,
+    statuses: &[KanbanTemplateStatus],
+) -> Result<(), AppError> {
+    for (order_index, status) in statuses.iter().enumerate() {
+        conn.execute(
+            "INSERT INTO kanban_template_status
+                (template_id, name, color, order_index, wip_limit, is_default, is_focus, is_done)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            rusqlite::params![
+                template_id,
+                status.name.trim(),
+                status.color,
+                order_index as i32,
+                status.wip_limit,
+                status.is_default,
+                status.is_focus,
+                status.is_done,
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use super::KanbanTemplateRepository;
+    use crate::migrations::apply_migrations;
+    use crate::models::kanban_automation::KanbanAutomationRuleInput;
+    use crate::models::kanban_template::KanbanTemplateStatus;
+    use crate::repositories::{
+        KanbanAssignmentRepository, KanbanAutomationRepository, KanbanRepository,
+        KanbanStatusRepository, MemoRepository, WorkspaceRepository,
+    };
+
+    fn open_db() -> Connection {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        conn
+    }
+
+    #[test]
+    fn board_saved_as_template_seeds_new_boards_with_roles_and_wip_limits() {
+        let conn = open_db();
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let board = KanbanRepository::ensure_global_status_board(&conn, workspace.id)
+            .expect("board should exist");
+        let now = KanbanStatusRepository::list_by_kanban(&conn, workspace.id, board.id)
+            .expect("statuses should load")
+            .into_iter()
+            .find(|status| status.name == "Now")
+            .expect("Now status should exist");
+        KanbanStatusRepository::update_wip_limit(&conn, workspace.id, now.id, Some(3))
+            .expect("WIP limit should be set");
+
+        let statuses =
+            KanbanTemplateRepository::statuses_of_kanban(&conn, &board).expect("statuses load");
+        let template = KanbanTemplateRepository::create(&conn, workspace.id, "Weekly", &statuses)
+            .expect("template should be created");
+        assert_eq!(template.statuses, statuses);
+
+        let (kanban, status_ids) = KanbanTemplateRepository::create_kanban(
+            &conn,
+            workspace.id,
+            "Sprint",
+            &template.statuses,
+        )
+        .expect("board should be created from the template");
+        let created = KanbanStatusRepository::list_by_kanban(&conn, workspace.id, kanban.id)
+            .expect("statuses should load");
+        assert_eq!(
+            created.iter().map(|status| status.id).collect::<Vec<_>>(),
+            status_ids
+        );
+        let created_now = created
+            .iter()
+            .find(|status| status.name == "Now")
+            .expect("Now status should be copied");
+        assert_eq!(created_now.wip_limit, Some(3));
+        assert_eq!(kanban.focus_status_id, Some(created_now.id));
+        assert_eq!(
+            KanbanTemplateRepository::statuses_of_kanban(&conn, &kanban).expect("statuses load"),
+            statuses
+        );
+
+        let duplicate = KanbanTemplateRepository::create(
+            &conn,
+            workspace.id,
+            "Other",
+            &[
+                KanbanTemplateStatus {
+                    name: "Todo".to_string(),
+                    color: None,
+                    wip_limit: None,
+                    is_default: true,
+                    is_focus: false,
+                    is_done: false,
+                },
+                KanbanTemplateStatus {
+                    name: "Todo ".to_string(),
+                    color: None,
+                    wip_limit: None,
+                    is_default: false,
+                    is_focus: false,
+                    is_done: false,
+                },
+            ],
+        )
+        .expect_err("status names must be unique");
+        assert_eq!(duplicate.reason.as_deref(), Some("DUPLICATE_STATUS_NAME"));
+    }
+
+    #[test]
+    fn cloning_into_another_workspace_matches_cards_by_slug() {
+        let conn = open_db();
+        let source_workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let target_workspace = WorkspaceRepository::create(&conn, "home", "Home")
+            .expect("workspace should be created");
+        let board = KanbanRepository::ensure_global_status_board(&conn, source_workspace.id)
+            .expect("board should exist");
+        let statuses = KanbanStatusRepository::list_by_kanban(&conn, source_workspace.id, board.id)
+            .expect("statuses should load");
+        let next = statuses
+            .iter()
+            .find(|status| status.name == "Next")
+            .expect("Next status should exist");
+        for slug in ["shared", "only-here"] {
+            let memo = MemoRepository::create(&conn, source_workspace.id, slug, slug, "\"\"")
+                .expect("memo should be created");
+            KanbanAssignmentRepository::append_to_status(
+                &conn,
+                source_workspace.id,
+                memo.id,
+                board.id,
+                next.id,
+            )
+            .expect("memo should be placed");
+        }
+        let shared = MemoRepository::create(&conn, target_workspace.id, "shared", "shared", "\"\"")
+            .expect("memo should be created");
+        KanbanAutomationRepository::create(
+            &conn,
+            source_workspace.id,
+            board.id,
+            &KanbanAutomationRuleInput {
+                trigger_type: "enter_status".to_string(),
+                trigger_status_id: Some(next.id),
+                condition_type: "always".to_string(),
+                condition_status_id: None,
+                action_type: "add_bookmark".to_string(),
+                enabled: true,
+            },
+        )
+        .expect("automation rule should be created");
+
+        let result = KanbanTemplateRepository::clone_kanban(
+            &conn,
+            &board,
+            target_workspace.id,
+            "Team status",
+            true,
+        )
+        .expect("board should be cloned");
+        assert_eq!(result.kanban.workspace_id, target_workspace.id);
+        assert_eq!(result.copied_assignment_count, 1);
+        assert_eq!(result.skipped_memo_slugs, vec!["only-here".to_string()]);
+
+        let items = KanbanAssignmentRepository::list_items_by_kanban(
+            &conn,
+            target_workspace.id,
+            result.kanban.id,
+        )
+        .expect("items should load");
+        let cloned_next =
+            KanbanStatusRepository::list_by_kanban(&conn, target_workspace.id, result.kanban.id)
+                .expect("statuses should load")
+                .into_iter()
+                .find(|status| status.name == "Next")
+                .expect("Next status should be copied");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].memo_id, shared.id);
+        assert_eq!(items[0].kanban_status_id, Some(cloned_next.id));
+        let rules = KanbanAutomationRepository::list_by_kanban(
+            &conn,
+            target_workspace.id,
+            result.kanban.id,
+        )
+        .expect("automation rules should load");
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].trigger_status_id, Some(cloned_next.id));
+        assert_eq!(rules[0].action_type, "add_bookmark");
+
+        let error = KanbanTemplateRepository::clone_kanban(
+            &conn,
+            &board,
+            source_workspace.id,
+            "Status",
+            false,
+        )
+        .expect_err("board names are unique per workspace");
+        assert_eq!(error.reason.as_deref(), Some("DUPLICATE_KANBAN_NAME"));
+    }
+}
@@ -0,0 +1,437 @@
+use crate::errors::AppError;
+use crate::models::kanban::Kanban;
+use crate::models::kanban_transfer::{
+    KanbanExport, KanbanExportItem, KanbanImportRow, KanbanImportSkippedRow, KanbanImportSummary,
+    KANBAN_EXPORT_FORMAT, KANBAN_EXPORT_VERSION,
+};
+use crate::repositories::{
+    KanbanAssignmentRepository, KanbanRepository, KanbanStatusRepository, MemoRepository,
+};
+use rusqlite::{Connection, Result};
+use serde_json::{json, Value};
+
+const CSV_HEADERS: [&str; 6] = [
+    "title",
+    "slug",
+    "status",
+    "position",
+    "modified_at",
+    "description",
+];
+
+// Header names accepted on import, compared case-insensitively. Besides our own export
+// this covers Trello ("Card Name", "List Name", "Card Description") and GitHub Projects
+// ("Title", "Status", "Body") exports.
+const TITLE_HEADERS: &[&str] = &["title", "name", "card name"];
+const SLUG_HEADERS: &[&str] = &["slug", "slug_title"];
+const STATUS_HEADERS: &[&str] = &["status", "list", "list name", "column"];
+const DESCRIPTION_HEADERS: &[&str] = &["description", "card description", "desc", "body"];
+
+pub struct KanbanTransferRepository;
+
+impl KanbanTransferRepository {
+    pub fn export_board(
+        conn: &Connection,
+        workspace_id: i32,
+        kanban: &Kanban,
+    ) -> Result<KanbanExport> {
+        let statuses = KanbanStatusRepository::list_by_kanban(conn, workspace_id, kanban.id)?
+            .into_iter()
+            .map(|status| status.name)
+            .collect();
+
+        let mut stmt = conn.prepare(
+            "SELECT memo.title, memo.slug_title, kanban_status.name, kanban_assignment.position,
+                    memo.modified_at, memo.description
+            FROM kanban_assignment
+            JOIN memo ON kanban_assignment.memo_id = memo.id
+            LEFT JOIN kanban_status ON kanban_assignment.kanban_status_id = kanban_status.id
+            WHERE kanban_assignment.workspace_id = ? AND kanban_assignment.kanban_id = ?
+            ORDER BY kanban_status.id IS NULL, kanban_status.order_index ASC,
+                     kanban_assignment.position IS NULL, kanban_assignment.position ASC,
+                     memo.id DESC",
+        )?;
+        let items = stmt
+            .query_map((workspace_id, kanban.id), |row| {
+                Ok(KanbanExportItem {
+                    title: row.get(0)?,
+                    slug: row.get(1)?,
+                    status: row.get(2)?,
+                    position: row.get(3)?,
+                    modified_at: row.get(4)?,
+                    description: row.get(5)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(KanbanExport {
+            format: KANBAN_EXPORT_FORMAT.to_string(),
+            version: KANBAN_EXPORT_VERSION,
+            kanban_name: kanban.name.clone(),
+            statuses,
+            items,
+        })
+    }
+
+    pub fn to_csv(export: &KanbanExport) -> Result<String, AppError> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record(CSV_HEADERS).map_err(csv_error)?;
+        for item in &export.items {
+            let position = item
+                .position
+                .map(|position| position.to_string())
+                .unwrap_or_default();
+            writer
+                .write_record([
+                    item.title.as_str(),
+                    item.slug.as_str(),
+                    item.status.as_deref().unwrap_or(""),
+                    position.as_str(),
+                    item.modified_at.as_str(),
+                    item.description.as_deref().unwrap_or(""),
+                ])
+                .map_err(csv_error)?;
+        }
+        let bytes = writer
+            .into_inner()
+            .map_err(|e| AppError::io(format!("Failed to write CSV: {}", e)))?;
+        String::from_utf8(bytes).map_err(|e| AppError::io(format!("Failed to write CSV: {}", e)))
+    }
+
+    /// Reads titles, statuses and optional slugs and descriptions from a CSV with a header
+    /// row. Only the title column is required.
+    pub fn parse_csv(text: &str) -> Result<Vec<KanbanImportRow>, AppError> {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let mut reader = csv::ReaderBuilder::new()
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(text.as_bytes());
+
+        let headers = reader.headers().map_err(csv_error)?.clone();
+        let column = |aliases: &[&str]| {
+            headers.iter().position(|header| {
+                aliases
+                    .iter()
+                    .any(|alias| header.eq_ignore_ascii_case(alias))
+            })
+        };
+        let title_column = column(TITLE_HEADERS).ok_or_else(|| {
+            AppError::validation("The CSV needs a title column (title, name or card name).")
+        })?;
+        let slug_column = column(SLUG_HEADERS);
+        let status_column = column(STATUS_HEADERS);
+        let description_column = column(DESCRIPTION_HEADERS);
+
+        let mut rows = Vec::new();
+        for (index, record) in reader.records().enumerate() {
+            let record = record.map_err(csv_error)?;
+            let cell = |column: Option<usize>| {
+                column
+                    .and_then(|column| record.get(column))
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string)
+            };
+            rows.push(KanbanImportRow {
+                line: record
+                    .position()
+                    .map(|position| position.line())
+                    .unwrap_or(index as u64 + 2),
+                title: cell(Some(title_column)),
+                slug: cell(slug_column),
+                status: cell(status_column),
+                description: cell(description_column),
+            });
+        }
+
+        Ok(rows)
+    }
+
+    /// Creates missing memos and puts every row on the kanban in its status, appended in
+    /// file order. Memos are matched by slug, or by the slug derived from the title. Rows
+    /// without a status use the board's default status, and leave memos that are already
+    /// on the board where they are. A row's description replaces the memo description, for
+    /// matched memos too. Board rules and automations don't apply to imports.
+    pub fn import_rows(
+        conn: &Connection,
+        workspace_id: i32,
+        kanban: &Kanban,
+        rows: &[KanbanImportRow],
+        create_missing_statuses: bool,
+    ) -> Result<KanbanImportSummary, AppError> {
+        let mut statuses = KanbanStatusRepository::list_by_kanban(conn, workspace_id, kanban.id)?;
+        let global_kanban = KanbanRepository::ensure_global_status_board(conn, workspace_id)?;
+        let mut summary = KanbanImportSummary::default();
+
+        for row in rows {
+            let skip = |reason: String| KanbanImportSkippedRow {
+                line: row.line,
+                title: row.title.clone(),
+                reason,
+            };
+
+            let Some(title) = row.title.as_deref() else {
+                summary.skipped.push(skip("Missing title.".to_string()));
+                continue;
+            };
+
+            let status_id = match row.status.as_deref() {
+                None => kanban.default_status_id,
+                Some(name) => {
+                    let existing = statuses
+                        .iter()
+                        .find(|status| status.name.eq_ignore_ascii_case(name))
+                        .map(|status| status.id);
+                    match existing {
+                        Some(status_id) => Some(status_id),
+                        None if create_missing_statuses => {
+                            let status = KanbanStatusRepository::create(
+                                conn,
+                                workspace_id,
+                                kanban.id,
+                                name,
+                                None,
+                            )?;
+                            let status_id = status.id;
+                            summary.created_statuses.push(status.name.clone());
+                            statuses.push(status);
+                            Some(status_id)
+                        }
+                        None => {
+                            summary
+                                .skipped
+                                .push(skip(format!("Unknown status: {}", name)));
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            let slug = row.slug.clone().unwrap_or_else(|| encode_for_slug(title));
+
+            let (memo_id, created, described) =
+                match MemoRepository::find_by_slug(conn, workspace_id, &slug)? {
+                    Some(memo) => {
+                        let described = match &row.description {
+                            Some(description) if memo.description.as_ref() != Some(description) => {
+                                MemoRepository::update_description(conn, memo.id, description)?;
+                                true
+                            }
+                            _ => false,
+                        };
+                        (memo.id, false, described)
+                    }
+                    None => {
+                        let content = build_content(row.description.as_deref());
+                        let memo =
+                            MemoRepository::create(conn, workspace_id, &slug, title, &content)?;
+                        if let Some(description) = &row.description {
+                            MemoRepository::update_description(conn, memo.id, description)?;
+                        }
+                        if global_kanban.id != kanban.id {
+                            if let Some(default_status_id) = global_kanban.default_status_id {
+                                KanbanAssignmentRepository::upsert_status(
+                                    conn,
+                                    workspace_id,
+                                    memo.id,
+                                    global_kanban.id,
+                                    Some(default_status_id),
+                                    None,
+                                )?;
+                            }
+                        }
+                        (memo.id, true, false)
+                    }
+                };
+
+            let current =
+                KanbanAssignmentRepository::find_status(conn, workspace_id, memo_id, kanban.id)?;
+            let keep_current =
+                current == Some(status_id) || (row.status.is_none() && current.is_some());
+            if !keep_current {
+                match status_id {
+                    Some(status_id) => {
+                        KanbanAssignmentRepository::append_to_status(
+                            conn,
+                            workspace_id,
+                            memo_id,
+                            kanban.id,
+                            status_id,
+                        )?;
+                    }
+                    None => KanbanAssignmentRepository::upsert_status(
+                        conn,
+                        workspace_id,
+                        memo_id,
+                        kanban.id,
+                        None,
+                        None,
+                    )?,
+                }
+            }
+
+            if created {
+                summary.created.push(slug);
+            } else if keep_current && !described {
+                summary.unchanged.push(slug);
+            } else {
+                summary.updated.push(slug);
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+fn csv_error(error: csv::Error) -> AppError {
+    AppError::validation(format!("Invalid CSV: {}", error))
+}
+
+/// Same slug form the frontend builds from a title: spaces become `_` and characters that
+/// are unsafe in a path are percent-encoded.
+fn encode_for_slug(title: &str) -> String {
+    const RESERVED: &str = "\"`;/:@&=+$,<>#%{}|^~[]?";
+    let mut slug = String::with_capacity(title.len());
+    for ch in title.chars() {
+        if ch == ' ' {
+            slug.push('_');
+        } else if RESERVED.contains(ch) {
+            slug.push_str(&format!("%{:02X}", ch as u32));
+        } else {
+            slug.push(ch);
+        }
+    }
+    slug
+}
+
+fn build_content(description: Option<&str>) -> String {
+    let paragraphs: Vec<Value> = description
+        .unwrap_or("")
+        .lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                json!({ "type": "paragraph" })
+            } else {
+                json!({
+                    "type": "paragraph",
+                    "content": [{ "type": "text", "text": line }]
+                })
+            }
+        })
+        .collect();
+    let paragraphs = if paragraphs.is_empty() {
+        vec![json!({ "type": "paragraph" })]
+    } else {
+        paragraphs
+    };
+    json!({ "type": "doc", "content": paragraphs }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use super::KanbanTransferRepository;
+    use crate::migrations::apply_migrations;
+    use crate::repositories::{KanbanRepository, MemoRepository, WorkspaceRepository};
+
+    #[test]
+    fn trello_csv_import_creates_memos_and_round_trips_through_export() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        let workspace = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let kanban = KanbanRepository::ensure_global_status_board(&conn, workspace.id)
+            .expect("board should exist");
+
+        let rows = KanbanTransferRepository::parse_csv(
+            "\u{feff}Card ID,Card Name,List Name,Card Description\n\
+             1,Fix login,Now,\"Steps:\nopen the page\"\n\
+             2,Write docs,Backlog,\n\
+             3,,Now,\n\
+             4,Plan Q3: goals,Review,\n",
+        )
+        .expect("CSV should parse");
+        assert_eq!(rows.len(), 4);
+        assert_eq!(
+            rows[0].description.as_deref(),
+            Some("Steps:\nopen the page")
+        );
+
+        let summary =
+            KanbanTransferRepository::import_rows(&conn, workspace.id, &kanban, &rows, false)
+                .expect("import should succeed");
+        assert_eq!(summary.created, vec!["Fix_login".to_string()]);
+        assert_eq!(
+            summary
+                .skipped
+                .iter()
+                .map(|row| (row.line, row.reason.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (4, "Unknown status: Backlog"),
+                (5, "Missing title."),
+                (6, "Unknown status: Review"),
+            ]
+        );
+
+        let summary =
+            KanbanTransferRepository::import_rows(&conn, workspace.id, &kanban, &rows, true)
+                .expect("import should succeed");
+        assert_eq!(
+            summary.created,
+            vec!["Write_docs".to_string(), "Plan_Q3%3A_goals".to_string()]
+        );
+        assert_eq!(summary.unchanged, vec!["Fix_login".to_string()]);
+        assert_eq!(
+            summary.created_statuses,
+            vec!["Backlog".to_string(), "Review".to_string()]
+        );
+        let memo = MemoRepository::find_by_slug(&conn, workspace.id, "Fix_login")
+            .expect("memo should load")
+            .expect("memo should exist");
+        assert_eq!(memo.plain_text, "Steps: open the page");
+        assert_eq!(memo.description.as_deref(), Some("Steps:\nopen the page"));
+
+        let rows = KanbanTransferRepository::parse_csv(
+            "Card Name,List Name,Card Description\nFix login,Now,Steps: reload first\n",
+        )
+        .expect("CSV should parse");
+        let summary =
+            KanbanTransferRepository::import_rows(&conn, workspace.id, &kanban, &rows, false)
+                .expect("import should succeed");
+        assert_eq!(summary.updated, vec!["Fix_login".to_string()]);
+        let memo = MemoRepository::find_by_slug(&conn, workspace.id, "Fix_login")
+            .expect("memo should load")
+            .expect("memo should exist");
+        assert_eq!(memo.description.as_deref(), Some("Steps: reload first"));
+        let found: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM memo_fts WHERE memo_fts MATCH 'reload'",
+                [],
+                |row| row.get(0),
+            )
+            .expect("search should run");
+        assert_eq!(found, 1);
+
+        let export = KanbanTransferRepository::export_board(&conn, workspace.id, &kanban)
+            .expect("export should succeed");
+        let csv = KanbanTransferRepository::to_csv(&export).expect("CSV should be written");
+        let reimported = KanbanTransferRepository::parse_csv(&csv).expect("export should parse");
+        assert_eq!(
+            reimported
+                .iter()
+                .map(|row| (row.slug.as_deref(), row.status.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some("Fix_login"), Some("Now")),
+                (Some("Write_docs"), Some("Backlog")),
+                (Some("Plan_Q3%3A_goals"), Some("Review")),
+            ]
+        );
+    }
+}
@@ -1,10 +1,11 @@
+use crate::errors::AppError;
 use crate::models::link::{Link, LinkId, MemoLinkCount};
 use rusqlite::{Connection, Result};
 
 pub struct LinkRepository;
 
 impl LinkRepository {
-    pub fn list(conn: &Connection, memo_id: i32) -> Result<Vec<Link>, String> {
+    pub fn list(conn: &Connection, memo_id: i32) -> Result<Vec<Link>, AppError> {
         let query = "
             WITH
               MemoId AS (SELECT ? AS id),
@@ -63,7 +64,7 @@ impl LinkRepository {
             JOIN TwoHopLinks ON Memo.id = TwoHopLinks.twohop_memoid;
         ";
 
-        let mut stmt = conn.prepare(query).map_err(|e| e.to_string())?;
+        let mut stmt = conn.prepare(query)?;
 
         let links = stmt
             .query_map([memo_id], |row| {
@@ -76,10 +77,8 @@ impl LinkRepository {
                     link_type: row.get(5)?,
                     link_id: row.get(6)?,
                 })
-            })
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(links)
     }
@@ -126,7 +125,7 @@ impl LinkRepository {
     pub fn list_counts_by_workspace(
         conn: &Connection,
         workspace_id: i32,
-    ) -> Result<Vec<MemoLinkCount>, String> {
+    ) -> Result<Vec<MemoLinkCount>, AppError> {
         let query = "
             WITH WorkspaceMemos AS (
                 SELECT id
@@ -155,7 +154,7 @@ impl LinkRepository {
             ORDER BY WorkspaceMemos.id
         ";
 
-        let mut stmt = conn.prepare(query).map_err(|e| e.to_string())?;
+        let mut stmt = conn.prepare(query)?;
 
         let counts = stmt
             .query_map([workspace_id], |row| {
@@ -164,10 +163,8 @@ impl LinkRepository {
                     direct_link_count: row.get(1)?,
                     backlink_count: row.get(2)?,
                 })
-            })
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(counts)
     }
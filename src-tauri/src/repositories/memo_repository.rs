@@ -1,3 +1,6 @@
+use std::collections::HashSet;
+
+use crate::errors::AppError;
 use crate::models::memo::{MemoDetail, MemoIndexItem, MemoSearchItem, ModifiedMemoItem};
 use crate::repositories::FileRepository;
 use rusqlite::{Connection, OptionalExtension, Result};
@@ -35,18 +38,26 @@ impl From<serde_json::Error> for MemoError {
     }
 }
 
+impl From<MemoError> for AppError {
+    fn from(e: MemoError) -> Self {
+        match e {
+            MemoError::Sqlite(e) => AppError::from(e),
+            MemoError::Json(e) => AppError::from(e),
+        }
+    }
+}
+
 pub struct MemoRepository;
 
 impl MemoRepository {
-    pub fn list(conn: &Connection, workspace_id: i32) -> Result<Vec<MemoIndexItem>, String> {
+    pub fn list(conn: &Connection, workspace_id: i32) -> Result<Vec<MemoIndexItem>, AppError> {
         let mut stmt = conn
             .prepare(
                 "SELECT id, slug_title, title, description, thumbnail_image, created_at, updated_at, modified_at
                 FROM memo
                 WHERE workspace_id = ?
                 ORDER BY modified_at DESC",
-            )
-            .map_err(|e| e.to_string())?;
+            )?;
 
         let memos = stmt
             .query_map([workspace_id], |row| {
@@ -60,10 +71,8 @@ impl MemoRepository {
                     updated_at: row.get(6)?,
                     modified_at: row.get(7)?,
                 })
-            })
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(memos)
     }
@@ -104,6 +113,14 @@ impl MemoRepository {
         Ok(memo)
     }
 
+    pub fn list_slug_titles(conn: &Connection, workspace_id: i32) -> Result<HashSet<String>> {
+        let mut stmt = conn.prepare("SELECT slug_title FROM memo WHERE workspace_id = ?")?;
+        let slugs = stmt
+            .query_map([workspace_id], |row| row.get(0))?
+            .collect::<Result<HashSet<String>, _>>()?;
+        Ok(slugs)
+    }
+
     pub fn find_by_id(
         conn: &Connection,
         workspace_id: i32,
@@ -288,12 +305,31 @@ impl MemoRepository {
         }
 
         FileRepository::sync_memo_files(&tx, memo_id, content)
-            .map_err(|e| rusqlite::Error::InvalidParameterName(e))?;
+            .map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))?;
 
         tx.commit()?;
         Ok(())
     }
 
+    /// Sets only the description, keeping the search index in step. Unlike `save`, it runs on
+    /// the caller's connection or transaction, so bulk imports can use it.
+    pub fn update_description(conn: &Connection, memo_id: i32, description: &str) -> Result<()> {
+        conn.execute(
+            "UPDATE memo SET description = ?, modified_at = CURRENT_TIMESTAMP WHERE id = ?",
+            (description, memo_id),
+        )?;
+
+        conn.execute("DELETE FROM memo_fts WHERE memo_id = ?", [memo_id])?;
+        conn.execute(
+            "INSERT INTO memo_fts (title, description, body_text, memo_id, workspace_id, slug_title)
+            SELECT title, description, body_text, id, workspace_id, slug_title
+            FROM memo
+            WHERE id = ?",
+            [memo_id],
+        )?;
+        Ok(())
+    }
+
     pub fn delete(conn: &mut Connection, memo_id: i32) -> Result<()> {
         let tx = conn.transaction()?;
 
@@ -317,23 +353,23 @@ impl MemoRepository {
         query: &str,
         limit: i32,
         offset: i32,
-    ) -> Result<Vec<MemoSearchItem>, String> {
+    ) -> Result<Vec<MemoSearchItem>, AppError> {
         if query.trim().is_empty() {
             return Ok(Vec::new());
         }
 
-        let mut stmt = conn
-            .prepare(
-                "SELECT memo.id, memo.slug_title, memo.title, memo.description, memo.modified_at,
+        let mut stmt = conn.prepare(
+            "SELECT memo.id, memo.slug_title, memo.title, memo.description, memo.modified_at,
                 snippet(memo_fts, 2, '', '', '…', 20) AS snippet
                 FROM memo_fts
                 JOIN memo ON memo_fts.memo_id = memo.id
+                JOIN workspace ON workspace.id = memo.workspace_id
                 WHERE memo_fts MATCH ?
                   AND memo.workspace_id = ?
+                  AND workspace.archived_at IS NULL
                 ORDER BY bm25(memo_fts) ASC, memo.modified_at DESC
                 LIMIT ? OFFSET ?",
-            )
-            .map_err(|e| e.to_string())?;
+        )?;
 
         let memos = stmt
             .query_map((query, workspace_id, limit, offset), |row| {
@@ -345,10 +381,8 @@ impl MemoRepository {
                     modified_at: row.get(4)?,
                     snippet: row.get(5)?,
                 })
-            })
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(memos)
     }
@@ -361,7 +395,7 @@ impl MemoRepository {
         limit: i32,
         offset: i32,
         include_plain_text: bool,
-    ) -> Result<Vec<ModifiedMemoItem>, String> {
+    ) -> Result<Vec<ModifiedMemoItem>, AppError> {
         let mut stmt = conn
             .prepare(
                 "SELECT id, slug_title, title, description, content, created_at, updated_at, modified_at
@@ -371,8 +405,7 @@ impl MemoRepository {
                   AND modified_at < datetime(?)
                 ORDER BY modified_at DESC, id DESC
                 LIMIT ? OFFSET ?",
-            )
-            .map_err(|e| e.to_string())?;
+            )?;
 
         let memos = stmt
             .query_map(
@@ -391,61 +424,51 @@ impl MemoRepository {
                         modified_at: row.get(7)?,
                     })
                 },
-            )
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(memos)
     }
 
-    pub fn rebuild_search_index(conn: &mut Connection) -> Result<(), String> {
-        let tx = conn.transaction().map_err(|e| e.to_string())?;
-        tx.execute("DELETE FROM memo_fts", [])
-            .map_err(|e| e.to_string())?;
+    pub fn rebuild_search_index(conn: &mut Connection) -> Result<(), AppError> {
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM memo_fts", [])?;
 
         {
-            let mut stmt = tx
-                .prepare(
-                    "SELECT id, workspace_id, slug_title, title, description, content
+            let mut stmt = tx.prepare(
+                "SELECT id, workspace_id, slug_title, title, description, content
                     FROM memo",
-                )
-                .map_err(|e| e.to_string())?;
-
-            let memo_iter = stmt
-                .query_map([], |row| {
-                    Ok((
-                        row.get::<_, i32>(0)?,
-                        row.get::<_, i32>(1)?,
-                        row.get::<_, String>(2)?,
-                        row.get::<_, String>(3)?,
-                        row.get::<_, Option<String>>(4)?,
-                        row.get::<_, String>(5)?,
-                    ))
-                })
-                .map_err(|e| e.to_string())?;
+            )?;
+
+            let memo_iter = stmt.query_map([], |row| {
+                Ok((
+                    row.get::<_, i32>(0)?,
+                    row.get::<_, i32>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, String>(5)?,
+                ))
+            })?;
 
             for memo in memo_iter {
-                let (memo_id, workspace_id, slug_title, title, description, content) =
-                    memo.map_err(|e| e.to_string())?;
+                let (memo_id, workspace_id, slug_title, title, description, content) = memo?;
                 let body_text = extract_plain_text_from_json_str(&content);
 
                 tx.execute(
                     "UPDATE memo SET body_text = ? WHERE id = ?",
                     (&body_text, memo_id),
-                )
-                .map_err(|e| e.to_string())?;
+                )?;
 
                 tx.execute(
                     "INSERT INTO memo_fts (title, description, body_text, memo_id, workspace_id, slug_title)
                     VALUES (?, ?, ?, ?, ?, ?)",
                     (title, description, &body_text, memo_id, workspace_id, slug_title),
-                )
-                .map_err(|e| e.to_string())?;
+                )?;
             }
         }
 
-        tx.commit().map_err(|e| e.to_string())?;
+        tx.commit()?;
         Ok(())
     }
 }
@@ -494,6 +517,72 @@ fn collect_text_nodes(node: &Value, out: &mut Vec<String>) {
     }
 }
 
+/// Points `/{from_workspace_slug}/{slug}` link hrefs at `to_workspace_slug` when `slug` is one
+/// of `target_slugs`. Hrefs whose memo is missing there are left as-is and pushed to `unresolved`.
+pub(crate) fn rewrite_workspace_hrefs(
+    node: &mut Value,
+    from_workspace_slug: &str,
+    to_workspace_slug: &str,
+    target_slugs: &HashSet<String>,
+    unresolved: &mut Vec<String>,
+) {
+    match node {
+        Value::Array(children) => {
+            for child in children {
+                rewrite_workspace_hrefs(
+                    child,
+                    from_workspace_slug,
+                    to_workspace_slug,
+                    target_slugs,
+                    unresolved,
+                );
+            }
+        }
+        Value::Object(obj) => {
+            if let Some(marks) = obj.get_mut("marks").and_then(Value::as_array_mut) {
+                for mark in marks
+                    .iter_mut()
+                    .filter(|m| m.get("type").and_then(Value::as_str) == Some("link"))
+                {
+                    let Some(attrs) = mark.get_mut("attrs").and_then(Value::as_object_mut) else {
+                        continue;
+                    };
+                    let Some(href) = attrs.get("href").and_then(Value::as_str) else {
+                        continue;
+                    };
+                    let mut parts = href.splitn(3, '/');
+                    let (Some(""), Some(workspace_slug), Some(memo_slug)) =
+                        (parts.next(), parts.next(), parts.next())
+                    else {
+                        continue;
+                    };
+                    if workspace_slug != from_workspace_slug {
+                        continue;
+                    }
+
+                    if target_slugs.contains(memo_slug) {
+                        let rewritten = format!("/{}/{}", to_workspace_slug, memo_slug);
+                        attrs.insert("href".to_string(), Value::String(rewritten));
+                    } else if !unresolved.iter().any(|item| item == href) {
+                        unresolved.push(href.to_string());
+                    }
+                }
+            }
+
+            if let Some(content) = obj.get_mut("content") {
+                rewrite_workspace_hrefs(
+                    content,
+                    from_workspace_slug,
+                    to_workspace_slug,
+                    target_slugs,
+                    unresolved,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
 fn update_nodes(
     node: &mut Value,
     workspace_slug: &str,
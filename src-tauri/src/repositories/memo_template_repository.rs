@@ -1,21 +1,55 @@
-use crate::models::memo_template::{MemoTemplateDetail, MemoTemplateIndexItem};
+use std::collections::HashMap;
+
+use crate::errors::AppError;
+use crate::models::memo_template::{
+    ExpandedMemoTemplate, MemoTemplateCopyResult, MemoTemplateDetail, MemoTemplateIndexItem,
+    MemoTemplatePack, MemoTemplatePackImportSummary, MemoTemplatePackItem, MemoTemplateWorkflow,
+    MEMO_TEMPLATE_PACK_FORMAT, MEMO_TEMPLATE_PACK_VERSION,
+};
+use crate::repositories::memo_repository::rewrite_workspace_hrefs;
+use crate::repositories::{
+    CalendarDayRepository, KanbanAssignmentRepository, KanbanRepository, MemoRepository,
+    MilestoneRepository,
+};
 use rusqlite::{Connection, OptionalExtension, Result};
+use serde_json::Value;
 
 pub struct MemoTemplateRepository;
 
+/// Values substituted into `{{...}}` placeholders when a memo is created from a template.
+pub struct MemoTemplateVariables<'a> {
+    pub title: &'a str,
+    pub workspace_name: &'a str,
+    pub prompt_values: &'a HashMap<String, String>,
+}
+
+const SUPPORTED_TEMPLATE_VARIABLES: [&str; 6] = [
+    "{{date}}",
+    "{{date:+7d}}",
+    "{{title}}",
+    "{{workspace}}",
+    "{{cursor}}",
+    "{{prompt:<label>}}",
+];
+
+// `{{cursor}}` is first replaced by this private-use character so that its
+// ProseMirror position can be computed once every other variable is expanded.
+const CURSOR_MARKER: char = '\u{E000}';
+
+// Node types that occupy a single position in a ProseMirror document.
+const LEAF_NODE_TYPES: [&str; 3] = ["hardBreak", "horizontalRule", "image"];
+
 impl MemoTemplateRepository {
     pub fn list(
         conn: &Connection,
         workspace_id: i32,
-    ) -> Result<Vec<MemoTemplateIndexItem>, String> {
-        let mut stmt = conn
-            .prepare(
-                "SELECT id, slug_name, name, is_default, created_at, updated_at
+    ) -> Result<Vec<MemoTemplateIndexItem>, AppError> {
+        let mut stmt = conn.prepare(
+            "SELECT id, slug_name, name, is_default, created_at, updated_at
                 FROM memo_template
                 WHERE workspace_id = ?
                 ORDER BY is_default DESC, name ASC, updated_at DESC",
-            )
-            .map_err(|e| e.to_string())?;
+        )?;
 
         let templates = stmt
             .query_map([workspace_id], |row| {
@@ -27,10 +61,8 @@ impl MemoTemplateRepository {
                     created_at: row.get(4)?,
                     updated_at: row.get(5)?,
                 })
-            })
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(templates)
     }
@@ -39,14 +71,13 @@ impl MemoTemplateRepository {
         conn: &Connection,
         workspace_id: i32,
         template_slug_name: &str,
-    ) -> Result<Option<MemoTemplateDetail>, String> {
+    ) -> Result<Option<MemoTemplateDetail>, AppError> {
         let mut stmt = conn
             .prepare(
-                "SELECT id, slug_name, name, json(content) AS content, is_default, workspace_id, created_at, updated_at
+                "SELECT id, slug_name, name, json(content) AS content, is_default, workspace_id, kanban_id, kanban_status_id, milestone_id, attach_to_today, created_at, updated_at
                 FROM memo_template
                 WHERE workspace_id = ? AND slug_name = ?",
-            )
-            .map_err(|e| e.to_string())?;
+            )?;
 
         let template = stmt
             .query_row((workspace_id, template_slug_name), |row| {
@@ -57,12 +88,15 @@ impl MemoTemplateRepository {
                     content: row.get(3)?,
                     is_default: row.get(4)?,
                     workspace_id: row.get(5)?,
-                    created_at: row.get(6)?,
-                    updated_at: row.get(7)?,
+                    kanban_id: row.get(6)?,
+                    kanban_status_id: row.get(7)?,
+                    milestone_id: row.get(8)?,
+                    attach_to_today: row.get(9)?,
+                    created_at: row.get(10)?,
+                    updated_at: row.get(11)?,
                 })
             })
-            .optional()
-            .map_err(|e| e.to_string())?;
+            .optional()?;
 
         Ok(template)
     }
@@ -73,22 +107,20 @@ impl MemoTemplateRepository {
         slug_name: &str,
         name: &str,
         content: &str,
-    ) -> Result<MemoTemplateDetail, String> {
+    ) -> Result<MemoTemplateDetail, AppError> {
         conn.execute(
             "INSERT INTO memo_template (workspace_id, slug_name, name, content)
             VALUES (?, ?, ?, ?)",
             (workspace_id, slug_name, name, content),
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
         let template_id = conn.last_insert_rowid() as i32;
         let mut stmt = conn
             .prepare(
-                "SELECT id, slug_name, name, json(content) AS content, is_default, workspace_id, created_at, updated_at
+                "SELECT id, slug_name, name, json(content) AS content, is_default, workspace_id, kanban_id, kanban_status_id, milestone_id, attach_to_today, created_at, updated_at
                 FROM memo_template
                 WHERE id = ?",
-            )
-            .map_err(|e| e.to_string())?;
+            )?;
 
         stmt.query_row([template_id], |row| {
             Ok(MemoTemplateDetail {
@@ -98,11 +130,15 @@ impl MemoTemplateRepository {
                 content: row.get(3)?,
                 is_default: row.get(4)?,
                 workspace_id: row.get(5)?,
-                created_at: row.get(6)?,
-                updated_at: row.get(7)?,
+                kanban_id: row.get(6)?,
+                kanban_status_id: row.get(7)?,
+                milestone_id: row.get(8)?,
+                attach_to_today: row.get(9)?,
+                created_at: row.get(10)?,
+                updated_at: row.get(11)?,
             })
         })
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
     }
 
     pub fn save(
@@ -111,21 +147,118 @@ impl MemoTemplateRepository {
         slug_name: &str,
         name: &str,
         content: &str,
-    ) -> Result<(), String> {
+    ) -> Result<(), AppError> {
         conn.execute(
             "UPDATE memo_template
             SET slug_name = ?, name = ?, content = ?
             WHERE id = ?",
             (slug_name, name, content, template_id),
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
+
+        Ok(())
+    }
+
+    pub fn save_workflow(
+        conn: &Connection,
+        workspace_id: i32,
+        template_id: i32,
+        workflow: &MemoTemplateWorkflow,
+    ) -> Result<(), AppError> {
+        if workflow.kanban_status_id.is_some() && workflow.kanban_id.is_none() {
+            return Err(AppError::validation(
+                "A template kanban status needs a kanban.",
+            ));
+        }
+
+        if let Some(kanban_id) = workflow.kanban_id {
+            KanbanRepository::find_by_id(conn, workspace_id, kanban_id)?
+                .ok_or_else(|| AppError::not_found(format!("Kanban not found: {}", kanban_id)))?;
+        }
+
+        if let (Some(kanban_id), Some(status_id)) = (workflow.kanban_id, workflow.kanban_status_id)
+        {
+            let count: i32 = conn.query_row(
+                "SELECT COUNT(*) FROM kanban_status
+                    WHERE id = ? AND kanban_id = ? AND workspace_id = ?",
+                (status_id, kanban_id, workspace_id),
+                |row| row.get(0),
+            )?;
+            if count == 0 {
+                return Err(AppError::validation(format!(
+                    "Kanban status {} does not belong to kanban {}",
+                    status_id, kanban_id
+                )));
+            }
+        }
+
+        if let Some(milestone_id) = workflow.milestone_id {
+            let count: i32 = conn.query_row(
+                "SELECT COUNT(*) FROM milestone WHERE id = ? AND workspace_id = ?",
+                (milestone_id, workspace_id),
+                |row| row.get(0),
+            )?;
+            if count == 0 {
+                return Err(AppError::not_found(format!(
+                    "Milestone not found: {}",
+                    milestone_id
+                )));
+            }
+        }
+
+        conn.execute(
+            "UPDATE memo_template
+            SET kanban_id = ?, kanban_status_id = ?, milestone_id = ?, attach_to_today = ?
+            WHERE id = ? AND workspace_id = ?",
+            (
+                workflow.kanban_id,
+                workflow.kanban_status_id,
+                workflow.milestone_id,
+                workflow.attach_to_today,
+                template_id,
+                workspace_id,
+            ),
+        )?;
 
         Ok(())
     }
 
-    pub fn delete(conn: &Connection, template_id: i32) -> Result<(), String> {
-        conn.execute("DELETE FROM memo_template WHERE id = ?", [template_id])
-            .map_err(|e| e.to_string())?;
+    /// Places a newly created memo on the kanban, milestone and calendar day the template declares.
+    pub fn apply_workflow(
+        conn: &Connection,
+        template: &MemoTemplateDetail,
+        memo_id: i32,
+    ) -> Result<(), AppError> {
+        if let Some(kanban_id) = template.kanban_id {
+            if let Some(kanban) =
+                KanbanRepository::find_by_id(conn, template.workspace_id, kanban_id)?
+            {
+                let status_id = template.kanban_status_id.or(kanban.default_status_id);
+                KanbanAssignmentRepository::upsert_status(
+                    conn,
+                    template.workspace_id,
+                    memo_id,
+                    kanban.id,
+                    status_id,
+                    None,
+                )?;
+            }
+        }
+
+        if let Some(milestone_id) = template.milestone_id {
+            MilestoneRepository::add_memo(conn, template.workspace_id, milestone_id, memo_id)?;
+        }
+
+        if template.attach_to_today {
+            let today: String =
+                conn.query_row("SELECT date('now', 'localtime')", [], |row| row.get(0))?;
+            CalendarDayRepository::add_memo(conn, template.workspace_id, &today, memo_id)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn delete(conn: &Connection, template_id: i32) -> Result<(), AppError> {
+        conn.execute("DELETE FROM memo_template WHERE id = ?", [template_id])?;
         Ok(())
     }
 
@@ -133,42 +266,401 @@ impl MemoTemplateRepository {
         conn: &Connection,
         workspace_id: i32,
         template_id: i32,
-    ) -> Result<(), String> {
+    ) -> Result<(), AppError> {
         conn.execute(
             "UPDATE memo_template
             SET is_default = 0
             WHERE workspace_id = ?",
             [workspace_id],
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
         conn.execute(
             "UPDATE memo_template
             SET is_default = 1
             WHERE id = ? AND workspace_id = ?",
             (template_id, workspace_id),
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
         Ok(())
     }
 
-    pub fn clear_default(conn: &Connection, workspace_id: i32) -> Result<(), String> {
+    pub fn clear_default(conn: &Connection, workspace_id: i32) -> Result<(), AppError> {
         conn.execute(
             "UPDATE memo_template
             SET is_default = 0
             WHERE workspace_id = ?",
             [workspace_id],
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
         Ok(())
     }
+
+    /// Copies a template's name and content into another workspace. Kanban, milestone and
+    /// default settings are workspace specific and are not carried over.
+    pub fn copy_to_workspace(
+        conn: &Connection,
+        template: &MemoTemplateDetail,
+        source_workspace_slug: &str,
+        target_workspace_id: i32,
+        target_workspace_slug: &str,
+    ) -> Result<MemoTemplateCopyResult, AppError> {
+        if Self::find_by_slug(conn, target_workspace_id, &template.slug_name)?.is_some() {
+            return Err(AppError::conflict(format!(
+                "Memo template already exists in workspace {}: {}",
+                target_workspace_slug, template.slug_name
+            )));
+        }
+
+        let target_slugs = MemoRepository::list_slug_titles(conn, target_workspace_id)?;
+        let mut unresolved_hrefs = Vec::new();
+        let mut doc: Value = serde_json::from_str(&template.content)?;
+        rewrite_workspace_hrefs(
+            &mut doc,
+            source_workspace_slug,
+            target_workspace_slug,
+            &target_slugs,
+            &mut unresolved_hrefs,
+        );
+        let content = serde_json::to_string(&doc)?;
+
+        let copied = Self::create(
+            conn,
+            target_workspace_id,
+            &template.slug_name,
+            &template.name,
+            &content,
+        )?;
+
+        Ok(MemoTemplateCopyResult {
+            template: copied,
+            unresolved_hrefs,
+        })
+    }
+
+    /// Builds a template pack from the given templates, or from every template when `slug_names` is empty.
+    pub fn export_pack(
+        conn: &Connection,
+        workspace_id: i32,
+        workspace_slug: &str,
+        slug_names: &[String],
+    ) -> Result<MemoTemplatePack, AppError> {
+        let slug_names = if slug_names.is_empty() {
+            Self::list(conn, workspace_id)?
+                .into_iter()
+                .map(|template| template.slug_name)
+                .collect()
+        } else {
+            slug_names.to_vec()
+        };
+
+        let mut templates = Vec::with_capacity(slug_names.len());
+        for slug_name in slug_names {
+            let template =
+                Self::find_by_slug(conn, workspace_id, &slug_name)?.ok_or_else(|| {
+                    AppError::not_found(format!("Memo template not found for slug: {}", slug_name))
+                })?;
+            templates.push(MemoTemplatePackItem {
+                slug_name: template.slug_name,
+                name: template.name,
+                content: serde_json::from_str(&template.content)?,
+            });
+        }
+
+        Ok(MemoTemplatePack {
+            format: MEMO_TEMPLATE_PACK_FORMAT.to_string(),
+            version: MEMO_TEMPLATE_PACK_VERSION,
+            source_workspace_slug_name: workspace_slug.to_string(),
+            templates,
+        })
+    }
+
+    /// Imports a template pack. Templates whose slug already exists are overwritten when
+    /// `overwrite` is set and skipped otherwise.
+    pub fn import_pack(
+        conn: &Connection,
+        workspace_id: i32,
+        workspace_slug: &str,
+        pack: &MemoTemplatePack,
+        overwrite: bool,
+    ) -> Result<MemoTemplatePackImportSummary, AppError> {
+        if pack.format != MEMO_TEMPLATE_PACK_FORMAT {
+            return Err(AppError::validation(format!(
+                "Unsupported template pack format: {}",
+                pack.format
+            )));
+        }
+        if pack.version > MEMO_TEMPLATE_PACK_VERSION {
+            return Err(AppError::validation(format!(
+                "Template pack version {} is newer than this app supports ({}).",
+                pack.version, MEMO_TEMPLATE_PACK_VERSION
+            )));
+        }
+
+        let target_slugs = MemoRepository::list_slug_titles(conn, workspace_id)?;
+        let mut summary = MemoTemplatePackImportSummary::default();
+
+        for item in &pack.templates {
+            let existing = Self::find_by_slug(conn, workspace_id, &item.slug_name)?;
+            if existing.is_some() && !overwrite {
+                summary.skipped.push(item.slug_name.clone());
+                continue;
+            }
+
+            let mut doc = item.content.clone();
+            rewrite_workspace_hrefs(
+                &mut doc,
+                &pack.source_workspace_slug_name,
+                workspace_slug,
+                &target_slugs,
+                &mut summary.unresolved_hrefs,
+            );
+            let content = serde_json::to_string(&doc)?;
+
+            match existing {
+                Some(template) => {
+                    Self::save(conn, template.id, &item.slug_name, &item.name, &content)?;
+                    summary.overwritten.push(item.slug_name.clone());
+                }
+                None => {
+                    Self::create(conn, workspace_id, &item.slug_name, &item.name, &content)?;
+                    summary.imported.push(item.slug_name.clone());
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+
+    pub fn expand_content(
+        conn: &Connection,
+        content: &str,
+        variables: &MemoTemplateVariables,
+    ) -> Result<ExpandedMemoTemplate, AppError> {
+        let mut doc: Value = serde_json::from_str(content)?;
+        expand_text_nodes(conn, &mut doc, variables)?;
+
+        let mut position = 0;
+        let mut cursor_position = None;
+        if let Some(children) = doc.get_mut("content").and_then(Value::as_array_mut) {
+            take_cursor_marker(children, &mut position, &mut cursor_position);
+        }
+
+        Ok(ExpandedMemoTemplate {
+            content: serde_json::to_string(&doc)?,
+            cursor_position,
+        })
+    }
+
+    pub fn list_prompts(content: &str) -> Vec<String> {
+        let Ok(doc) = serde_json::from_str::<Value>(content) else {
+            return Vec::new();
+        };
+
+        let mut prompts = Vec::new();
+        collect_prompts(&doc, &mut prompts);
+        prompts
+    }
+}
+
+fn expand_text_nodes(
+    conn: &Connection,
+    node: &mut Value,
+    variables: &MemoTemplateVariables,
+) -> Result<(), AppError> {
+    let Some(obj) = node.as_object_mut() else {
+        return Ok(());
+    };
+
+    if obj.get("type").and_then(Value::as_str) == Some("text") {
+        if let Some(text) = obj.get("text").and_then(Value::as_str) {
+            let expanded = expand_text(conn, text, variables)?;
+            obj.insert("text".to_string(), Value::String(expanded));
+        }
+    }
+
+    if let Some(children) = obj.get_mut("content").and_then(Value::as_array_mut) {
+        for child in children {
+            expand_text_nodes(conn, child, variables)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn expand_text(
+    conn: &Connection,
+    text: &str,
+    variables: &MemoTemplateVariables,
+) -> Result<String, AppError> {
+    let mut expanded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(length) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + 2 + length].trim();
+        expanded.push_str(&rest[..start]);
+        expanded.push_str(&resolve_variable(conn, name, variables)?);
+        rest = &rest[start + 2 + length + 2..];
+    }
+
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+fn resolve_variable(
+    conn: &Connection,
+    name: &str,
+    variables: &MemoTemplateVariables,
+) -> Result<String, AppError> {
+    match name {
+        "date" => resolve_date(conn, "+0 days"),
+        "title" => Ok(variables.title.to_string()),
+        "workspace" => Ok(variables.workspace_name.to_string()),
+        "cursor" => Ok(CURSOR_MARKER.to_string()),
+        _ => {
+            if let Some(offset) = name.strip_prefix("date:") {
+                let modifier = parse_date_offset(offset.trim()).ok_or_else(|| {
+                    AppError::validation(format!(
+                        "Invalid date offset in template variable {{{{{}}}}}. Use a value such as +7d, -1w, +1m or +1y.",
+                        name
+                    ))
+                })?;
+                return resolve_date(conn, &modifier);
+            }
+
+            if let Some(label) = name.strip_prefix("prompt:") {
+                let label = label.trim();
+                if label.is_empty() {
+                    return Err(AppError::validation(
+                        "Template prompt variables need a label, such as {{prompt:Owner}}.",
+                    ));
+                }
+                return variables.prompt_values.get(label).cloned().ok_or_else(|| {
+                    AppError::validation(format!("Missing value for template prompt: {}", label))
+                });
+            }
+
+            Err(AppError::validation(format!(
+                "Unknown template variable: {{{{{}}}}}. Supported variables: {}",
+                name,
+                SUPPORTED_TEMPLATE_VARIABLES.join(", ")
+            )))
+        }
+    }
+}
+
+fn resolve_date(conn: &Connection, modifier: &str) -> Result<String, AppError> {
+    conn.query_row("SELECT date('now', 'localtime', ?)", [modifier], |row| {
+        row.get(0)
+    })
+    .map_err(AppError::from)
+}
+
+// Converts offsets such as `+7d`, `-2w`, `+1m` and `+1y` into a SQLite date modifier.
+fn parse_date_offset(offset: &str) -> Option<String> {
+    let (sign, rest) = match offset.chars().next()? {
+        '+' => ("+", &offset[1..]),
+        '-' => ("-", &offset[1..]),
+        _ => ("+", offset),
+    };
+    let unit = rest.chars().last()?;
+    let amount = rest[..rest.len() - unit.len_utf8()].parse::<i64>().ok()?;
+
+    match unit {
+        'd' => Some(format!("{}{} days", sign, amount)),
+        'w' => Some(format!("{}{} days", sign, amount * 7)),
+        'm' => Some(format!("{}{} months", sign, amount)),
+        'y' => Some(format!("{}{} years", sign, amount)),
+        _ => None,
+    }
+}
+
+fn take_cursor_marker(
+    children: &mut Vec<Value>,
+    position: &mut usize,
+    cursor_position: &mut Option<usize>,
+) {
+    for child in children.iter_mut() {
+        if let Some(text) = child.get("text").and_then(Value::as_str) {
+            if !text.contains(CURSOR_MARKER) {
+                *position += text.encode_utf16().count();
+                continue;
+            }
+
+            let mut cleaned = String::with_capacity(text.len());
+            let mut offset = 0;
+            for ch in text.chars() {
+                if ch == CURSOR_MARKER {
+                    cursor_position.get_or_insert(*position + offset);
+                } else {
+                    offset += ch.len_utf16();
+                    cleaned.push(ch);
+                }
+            }
+            child["text"] = Value::String(cleaned);
+            *position += offset;
+            continue;
+        }
+
+        let node_type = child
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        if LEAF_NODE_TYPES.contains(&node_type) {
+            *position += 1;
+            continue;
+        }
+
+        *position += 1;
+        if let Some(grandchildren) = child.get_mut("content").and_then(Value::as_array_mut) {
+            take_cursor_marker(grandchildren, position, cursor_position);
+        }
+        *position += 1;
+    }
+
+    // ProseMirror rejects empty text nodes, which are left behind when a
+    // text node only contained `{{cursor}}` or a variable that expanded to "".
+    children.retain(|child| child.get("text").and_then(Value::as_str) != Some(""));
+}
+
+fn collect_prompts(node: &Value, out: &mut Vec<String>) {
+    if let Some(text) = node.get("text").and_then(Value::as_str) {
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+            let Some(length) = rest[start + 2..].find("}}") else {
+                break;
+            };
+            let name = rest[start + 2..start + 2 + length].trim();
+            if let Some(label) = name.strip_prefix("prompt:").map(str::trim) {
+                if !label.is_empty() && !out.iter().any(|prompt| prompt == label) {
+                    out.push(label.to_string());
+                }
+            }
+            rest = &rest[start + 2 + length + 2..];
+        }
+    }
+
+    if let Some(children) = node.get("content").and_then(Value::as_array) {
+        for child in children {
+            collect_prompts(child, out);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::MemoTemplateRepository;
+    use std::collections::HashMap;
+
+    use super::{MemoTemplateRepository, MemoTemplateVariables};
+    use crate::errors::ErrorCode;
     use crate::migrations::apply_migrations;
+    use crate::models::memo_template::MemoTemplateWorkflow;
+    use crate::repositories::{
+        CalendarDayRepository, KanbanAssignmentRepository, KanbanRepository, MemoRepository,
+        MilestoneRepository,
+    };
     use rusqlite::Connection;
+    use serde_json::{json, Value};
 
     #[test]
     fn set_default_marks_only_one_template_in_workspace() {
@@ -233,4 +725,217 @@ mod tests {
         let templates = MemoTemplateRepository::list(&conn, 1).expect("templates should load");
         assert!(templates.iter().all(|template| !template.is_default));
     }
+
+    #[test]
+    fn expand_content_substitutes_variables_and_locates_cursor() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        let content = json!({
+            "type": "doc",
+            "content": [
+                {
+                    "type": "heading",
+                    "content": [{ "type": "text", "text": "{{title}} in {{workspace}}" }]
+                },
+                {
+                    "type": "paragraph",
+                    "content": [
+                        { "type": "text", "text": "Owner: {{prompt:Owner}}, due {{date:+7d}}" }
+                    ]
+                },
+                {
+                    "type": "paragraph",
+                    "content": [{ "type": "text", "text": "{{cursor}}" }]
+                }
+            ]
+        })
+        .to_string();
+        let prompt_values = HashMap::from([("Owner".to_string(), "Ann".to_string())]);
+
+        let expanded = MemoTemplateRepository::expand_content(
+            &conn,
+            &content,
+            &MemoTemplateVariables {
+                title: "Weekly",
+                workspace_name: "Work",
+                prompt_values: &prompt_values,
+            },
+        )
+        .expect("template should expand");
+
+        let due: String = conn
+            .query_row("SELECT date('now', 'localtime', '+7 days')", [], |row| {
+                row.get(0)
+            })
+            .expect("date should compute");
+        let doc: Value = serde_json::from_str(&expanded.content).expect("content should be JSON");
+        assert_eq!(doc["content"][0]["content"][0]["text"], "Weekly in Work");
+        assert_eq!(
+            doc["content"][1]["content"][0]["text"],
+            format!("Owner: Ann, due {}", due)
+        );
+        assert_eq!(doc["content"][2]["content"], json!([]));
+        // heading (1 + 14 + 1) + paragraph (1 + 26 + 1) + opening of the last paragraph.
+        assert_eq!(expanded.cursor_position, Some(45));
+    }
+
+    #[test]
+    fn expand_content_rejects_unknown_variables() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        let content = json!({
+            "type": "doc",
+            "content": [
+                { "type": "paragraph", "content": [{ "type": "text", "text": "{{author}}" }] }
+            ]
+        })
+        .to_string();
+
+        let result = MemoTemplateRepository::expand_content(
+            &conn,
+            &content,
+            &MemoTemplateVariables {
+                title: "Weekly",
+                workspace_name: "Work",
+                prompt_values: &HashMap::new(),
+            },
+        );
+
+        let Err(error) = result else {
+            panic!("unknown variable should fail");
+        };
+
+        assert_eq!(error.code, ErrorCode::Validation);
+        assert!(error
+            .message
+            .starts_with("Unknown template variable: {{author}}."));
+        assert!(error.message.contains("{{prompt:<label>}}"));
+    }
+
+    #[test]
+    fn apply_workflow_places_memo_on_kanban_milestone_and_today() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        conn.execute(
+            "INSERT INTO workspace (id, slug_name, name) VALUES (1, 'sample-workspace', 'Sample')",
+            [],
+        )
+        .expect("workspace insert should succeed");
+
+        let kanban =
+            KanbanRepository::ensure_global_status_board(&conn, 1).expect("board should exist");
+        let now_status_id = kanban
+            .focus_status_id
+            .expect("board should have a Now status");
+        let milestone_id = MilestoneRepository::create(&conn, 1, "2026-12-01", "Release")
+            .expect("milestone should be created");
+        let template = MemoTemplateRepository::create(&conn, 1, "bug-report", "Bug report", "\"\"")
+            .expect("template should be created");
+        MemoTemplateRepository::save_workflow(
+            &conn,
+            1,
+            template.id,
+            &MemoTemplateWorkflow {
+                kanban_id: Some(kanban.id),
+                kanban_status_id: Some(now_status_id),
+                milestone_id: Some(milestone_id),
+                attach_to_today: true,
+            },
+        )
+        .expect("workflow should save");
+
+        let template = MemoTemplateRepository::find_by_slug(&conn, 1, "bug-report")
+            .expect("template should load")
+            .expect("template should exist");
+        let memo = MemoRepository::create(&conn, 1, "crash", "Crash", "\"\"")
+            .expect("memo should be created");
+        MemoTemplateRepository::apply_workflow(&conn, &template, memo.id)
+            .expect("workflow should apply");
+
+        let assignments = KanbanAssignmentRepository::list_items_by_kanban(&conn, 1, kanban.id)
+            .expect("assignments should load");
+        assert!(assignments
+            .iter()
+            .any(|item| item.memo_id == memo.id && item.kanban_status_id == Some(now_status_id)));
+        let milestone_memos: i32 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM milestone_memo WHERE milestone_id = ? AND memo_id = ?",
+                (milestone_id, memo.id),
+                |row| row.get(0),
+            )
+            .expect("milestone memos should count");
+        assert_eq!(milestone_memos, 1);
+        let dates = CalendarDayRepository::list_dates_by_memo(&conn, 1, memo.id)
+            .expect("calendar dates should load");
+        assert_eq!(dates.len(), 1);
+    }
+
+    #[test]
+    fn copy_and_pack_import_rewrite_internal_hrefs() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        conn.execute(
+            "INSERT INTO workspace (id, slug_name, name)
+            VALUES (1, 'team', 'Team'), (2, 'personal', 'Personal')",
+            [],
+        )
+        .expect("workspace insert should succeed");
+        MemoRepository::create(&conn, 2, "handbook", "Handbook", "\"\"")
+            .expect("memo should be created");
+
+        let link = |href: &str| {
+            json!({
+                "type": "text",
+                "text": href,
+                "marks": [{ "type": "link", "attrs": { "href": href } }]
+            })
+        };
+        let content = json!({
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [link("/team/handbook"), link("/team/roadmap")]
+            }]
+        })
+        .to_string();
+        let template = MemoTemplateRepository::create(&conn, 1, "meeting", "Meeting", &content)
+            .expect("template should be created");
+
+        let copied =
+            MemoTemplateRepository::copy_to_workspace(&conn, &template, "team", 2, "personal")
+                .expect("template should copy");
+        let doc: Value =
+            serde_json::from_str(&copied.template.content).expect("content should be JSON");
+        let hrefs = doc["content"][0]["content"]
+            .as_array()
+            .expect("paragraph should have content")
+            .iter()
+            .map(|node| {
+                node["marks"][0]["attrs"]["href"]
+                    .as_str()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(hrefs, vec!["/personal/handbook", "/team/roadmap"]);
+        assert_eq!(copied.unresolved_hrefs, vec!["/team/roadmap"]);
+        assert!(
+            MemoTemplateRepository::copy_to_workspace(&conn, &template, "team", 2, "personal")
+                .is_err()
+        );
+
+        let pack =
+            MemoTemplateRepository::export_pack(&conn, 1, "team", &[]).expect("pack should export");
+        let summary = MemoTemplateRepository::import_pack(&conn, 2, "personal", &pack, false)
+            .expect("pack should import");
+        assert_eq!(summary.skipped, vec!["meeting"]);
+        assert!(summary.imported.is_empty());
+    }
 }
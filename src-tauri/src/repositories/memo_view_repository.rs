@@ -1,5 +1,6 @@
 use rusqlite::{params, Connection, OptionalExtension};
 
+use crate::errors::AppError;
 use crate::models::memo::{
     CurrentMemoContext, CurrentMemoDetail, CurrentMemoPlainText, MemoContextLinkGroup,
     MemoContextLinks, MemoContextRelatedMemo, MemoDetail,
@@ -13,14 +14,13 @@ impl MemoViewRepository {
         conn: &mut Connection,
         workspace_id: i32,
         memo_id: i32,
-    ) -> Result<(), String> {
-        let tx = conn.transaction().map_err(|e| e.to_string())?;
+    ) -> Result<(), AppError> {
+        let tx = conn.transaction()?;
 
         tx.execute(
             "INSERT INTO memo_view_event (workspace_id, memo_id) VALUES (?, ?)",
             params![workspace_id, memo_id],
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
         tx.execute(
             "INSERT INTO memo_view_state (slot, workspace_id, memo_id, viewed_at)
@@ -30,13 +30,12 @@ impl MemoViewRepository {
                memo_id = excluded.memo_id,
                viewed_at = CURRENT_TIMESTAMP",
             params![workspace_id, memo_id],
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
-        tx.commit().map_err(|e| e.to_string())
+        tx.commit().map_err(AppError::from)
     }
 
-    pub fn get_current_memo(conn: &Connection) -> Result<Option<CurrentMemoDetail>, String> {
+    pub fn get_current_memo(conn: &Connection) -> Result<Option<CurrentMemoDetail>, AppError> {
         conn.query_row(
             "SELECT
                 workspace.slug_name,
@@ -85,12 +84,12 @@ impl MemoViewRepository {
             },
         )
         .optional()
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
     }
 
     pub fn get_current_memo_plain_text(
         conn: &Connection,
-    ) -> Result<Option<CurrentMemoPlainText>, String> {
+    ) -> Result<Option<CurrentMemoPlainText>, AppError> {
         let Some(current) = Self::get_current_memo(conn)? else {
             return Ok(None);
         };
@@ -110,7 +109,7 @@ impl MemoViewRepository {
         conn: &Connection,
         include_related_memo_plain_text: bool,
         max_related_memos_per_group: usize,
-    ) -> Result<Option<CurrentMemoContext>, String> {
+    ) -> Result<Option<CurrentMemoContext>, AppError> {
         let Some(current) = Self::get_current_memo(conn)? else {
             return Ok(None);
         };
@@ -198,11 +197,9 @@ fn build_related_memo(
     title: String,
     description: Option<String>,
     thumbnail_image: Option<String>,
-) -> Result<MemoContextRelatedMemo, String> {
+) -> Result<MemoContextRelatedMemo, AppError> {
     let plain_text = if include_plain_text {
-        MemoRepository::find_by_id(conn, workspace_id, memo_id)
-            .map_err(|e| e.to_string())?
-            .map(|memo| memo.plain_text)
+        MemoRepository::find_by_id(conn, workspace_id, memo_id)?.map(|memo| memo.plain_text)
     } else {
         None
     };
@@ -1,27 +1,49 @@
+pub mod asset_repository;
 pub mod bookmark_repository;
 pub mod calendar_day_repository;
+pub mod calendar_export_repository;
+pub mod calendar_import_repository;
+pub mod doctor_repository;
 pub mod file_repository;
 pub mod focus_daily_state_repository;
+pub mod inbox_activity_repository;
 pub mod kanban_assignment_repository;
+pub mod kanban_automation_repository;
+pub mod kanban_lane_repository;
+pub mod kanban_report_repository;
 pub mod kanban_repository;
 pub mod kanban_status_repository;
+pub mod kanban_template_repository;
+pub mod kanban_transfer_repository;
 pub mod link_repository;
 pub mod memo_repository;
 pub mod memo_template_repository;
 pub mod memo_view_repository;
 pub mod milestone_repository;
 pub mod workspace_repository;
+pub mod workspace_transfer_repository;
 
+pub use asset_repository::AssetRepository;
 pub use bookmark_repository::BookmarkRepository;
 pub use calendar_day_repository::CalendarDayRepository;
+pub use calendar_export_repository::CalendarExportRepository;
+pub use calendar_import_repository::CalendarImportRepository;
+pub use doctor_repository::DoctorRepository;
 pub use file_repository::FileRepository;
 pub use focus_daily_state_repository::FocusDailyStateRepository;
+pub use inbox_activity_repository::InboxActivityRepository;
 pub use kanban_assignment_repository::KanbanAssignmentRepository;
+pub use kanban_automation_repository::KanbanAutomationRepository;
+pub use kanban_lane_repository::KanbanLaneRepository;
+pub use kanban_report_repository::KanbanReportRepository;
 pub use kanban_repository::KanbanRepository;
 pub use kanban_status_repository::KanbanStatusRepository;
+pub use kanban_template_repository::KanbanTemplateRepository;
+pub use kanban_transfer_repository::KanbanTransferRepository;
 pub use link_repository::LinkRepository;
 pub use memo_repository::MemoRepository;
 pub use memo_template_repository::MemoTemplateRepository;
 pub use memo_view_repository::MemoViewRepository;
 pub use milestone_repository::MilestoneRepository;
 pub use workspace_repository::WorkspaceRepository;
+pub use workspace_transfer_repository::WorkspaceTransferRepository;
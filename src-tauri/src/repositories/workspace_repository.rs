@@ -1,13 +1,23 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
+use std::hash::{Hash, Hasher};
+
+use crate::errors::AppError;
+use crate::models::workspace::WorkspaceDeletionSummary;
 use crate::models::Workspace;
+use crate::repositories::asset_repository::collect_asset_file_names;
 use rusqlite::{Connection, OptionalExtension, Result};
 
 pub struct WorkspaceRepository;
 
 impl WorkspaceRepository {
-    pub fn list(conn: &Connection) -> Result<Vec<Workspace>, String> {
-        let mut stmt = conn
-            .prepare("SELECT id, slug_name, name, created_at, updated_at FROM workspace")
-            .map_err(|e| e.to_string())?;
+    /// Lists workspaces that are not archived.
+    pub fn list(conn: &Connection) -> Result<Vec<Workspace>, AppError> {
+        let mut stmt = conn.prepare(
+            "SELECT id, slug_name, name, archived_at, created_at, updated_at
+                FROM workspace
+                WHERE archived_at IS NULL",
+        )?;
 
         let workspaces = stmt
             .query_map([], |row| {
@@ -15,20 +25,43 @@ impl WorkspaceRepository {
                     id: row.get(0)?,
                     slug_name: row.get(1)?,
                     name: row.get(2)?,
-                    created_at: row.get(3)?,
-                    updated_at: row.get(4)?,
+                    archived_at: row.get(3)?,
+                    created_at: row.get(4)?,
+                    updated_at: row.get(5)?,
                 })
-            })
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(workspaces)
+    }
+
+    pub fn list_archived(conn: &Connection) -> Result<Vec<Workspace>, AppError> {
+        let mut stmt = conn.prepare(
+            "SELECT id, slug_name, name, archived_at, created_at, updated_at
+                FROM workspace
+                WHERE archived_at IS NOT NULL
+                ORDER BY archived_at DESC",
+        )?;
+
+        let workspaces = stmt
+            .query_map([], |row| {
+                Ok(Workspace {
+                    id: row.get(0)?,
+                    slug_name: row.get(1)?,
+                    name: row.get(2)?,
+                    archived_at: row.get(3)?,
+                    created_at: row.get(4)?,
+                    updated_at: row.get(5)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(workspaces)
     }
 
     pub fn find_by_slug(conn: &Connection, slug_name: &str) -> Result<Option<Workspace>> {
         let mut stmt = conn.prepare(
-            "SELECT id, slug_name, name, created_at, updated_at
+            "SELECT id, slug_name, name, archived_at, created_at, updated_at
             FROM workspace
             WHERE slug_name = ?
             ",
@@ -40,8 +73,9 @@ impl WorkspaceRepository {
                     id: row.get(0)?,
                     slug_name: row.get(1)?,
                     name: row.get(2)?,
-                    created_at: row.get(3)?,
-                    updated_at: row.get(4)?,
+                    archived_at: row.get(3)?,
+                    created_at: row.get(4)?,
+                    updated_at: row.get(5)?,
                 })
             })
             .optional()?;
@@ -58,7 +92,7 @@ impl WorkspaceRepository {
 
         let workspace_id = conn.last_insert_rowid() as i32;
         let mut stmt = conn.prepare(
-            "SELECT id, slug_name, name, created_at, updated_at
+            "SELECT id, slug_name, name, archived_at, created_at, updated_at
             FROM workspace
             WHERE id = ?",
         )?;
@@ -68,8 +102,9 @@ impl WorkspaceRepository {
                 id: row.get(0)?,
                 slug_name: row.get(1)?,
                 name: row.get(2)?,
-                created_at: row.get(3)?,
-                updated_at: row.get(4)?,
+                archived_at: row.get(3)?,
+                created_at: row.get(4)?,
+                updated_at: row.get(5)?,
             })
         })?;
 
@@ -92,6 +127,88 @@ impl WorkspaceRepository {
         Ok(())
     }
 
+    pub fn set_archived(conn: &Connection, workspace_id: i32, archived: bool) -> Result<bool> {
+        let updated = if archived {
+            conn.execute(
+                "UPDATE workspace
+                SET archived_at = COALESCE(archived_at, CURRENT_TIMESTAMP)
+                WHERE id = ?",
+                [workspace_id],
+            )?
+        } else {
+            conn.execute(
+                "UPDATE workspace SET archived_at = NULL WHERE id = ?",
+                [workspace_id],
+            )?
+        };
+        Ok(updated > 0)
+    }
+
+    pub fn deletion_summary(
+        conn: &Connection,
+        workspace: &Workspace,
+    ) -> Result<WorkspaceDeletionSummary> {
+        let count =
+            |sql: &str| -> Result<i64> { conn.query_row(sql, [workspace.id], |row| row.get(0)) };
+        let memo_count = count("SELECT COUNT(*) FROM memo WHERE workspace_id = ?")?;
+        let file_link_count = count(
+            "SELECT COUNT(*)
+            FROM memo_files
+            JOIN memo ON memo.id = memo_files.memo_id
+            WHERE memo.workspace_id = ?",
+        )?;
+        let kanban_count = count("SELECT COUNT(*) FROM kanban WHERE workspace_id = ?")?;
+        let last_modified: Option<String> = conn.query_row(
+            "SELECT MAX(updated_at) FROM memo WHERE workspace_id = ?",
+            [workspace.id],
+            |row| row.get(0),
+        )?;
+
+        let mut own_assets = BTreeSet::new();
+        let mut other_assets = BTreeSet::new();
+        let mut stmt = conn.prepare(
+            "SELECT workspace_id, content, thumbnail_image FROM memo
+            UNION ALL
+            SELECT workspace_id, content, NULL FROM memo_template",
+        )?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let workspace_id: i32 = row.get(0)?;
+            let target = if workspace_id == workspace.id {
+                &mut own_assets
+            } else {
+                &mut other_assets
+            };
+            for index in [1, 2] {
+                if let Some(text) = row.get::<_, Option<String>>(index)? {
+                    collect_asset_file_names(&text, target);
+                }
+            }
+        }
+        let asset_count = own_assets.difference(&other_assets).count() as i64;
+
+        let mut hasher = DefaultHasher::new();
+        (
+            workspace.id,
+            &workspace.slug_name,
+            memo_count,
+            file_link_count,
+            kanban_count,
+            asset_count,
+            last_modified,
+        )
+            .hash(&mut hasher);
+
+        Ok(WorkspaceDeletionSummary {
+            workspace_slug_name: workspace.slug_name.clone(),
+            memo_count,
+            file_link_count,
+            kanban_count,
+            asset_count,
+            confirmation_token: format!("{:016x}", hasher.finish()),
+        })
+    }
+
     pub fn delete(conn: &mut Connection, workspace_id: i32) -> Result<()> {
         conn.execute(
             "DELETE FROM workspace
@@ -102,3 +219,56 @@ impl WorkspaceRepository {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::WorkspaceRepository;
+    use crate::migrations::apply_migrations;
+    use crate::repositories::MemoRepository;
+    use rusqlite::Connection;
+
+    #[test]
+    fn archived_workspaces_are_hidden_and_deletion_token_tracks_changes() {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+
+        let workspace =
+            WorkspaceRepository::create(&conn, "team", "Team").expect("workspace should exist");
+        MemoRepository::create(
+            &conn,
+            workspace.id,
+            "index",
+            "Index",
+            r#"{"type":"doc","content":[{"type":"image","attrs":{"src":"asset://localhost/monobox/a.png"}}]}"#,
+        )
+        .expect("memo should be created");
+
+        WorkspaceRepository::set_archived(&conn, workspace.id, true)
+            .expect("workspace should archive");
+        assert!(WorkspaceRepository::list(&conn)
+            .expect("workspaces should load")
+            .is_empty());
+        assert_eq!(
+            WorkspaceRepository::list_archived(&conn)
+                .expect("archived workspaces should load")
+                .len(),
+            1
+        );
+
+        let summary =
+            WorkspaceRepository::deletion_summary(&conn, &workspace).expect("summary should build");
+        assert_eq!(summary.memo_count, 1);
+        assert_eq!(summary.asset_count, 1);
+
+        MemoRepository::create(&conn, workspace.id, "notes", "Notes", "\"\"")
+            .expect("memo should be created");
+        let changed =
+            WorkspaceRepository::deletion_summary(&conn, &workspace).expect("summary should build");
+        assert_ne!(summary.confirmation_token, changed.confirmation_token);
+    }
+}
@@ -0,0 +1,1133 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use base64::{engine::general_purpose, Engine};
+use rusqlite::{params, Connection};
+use serde_json::Value;
+
+use crate::errors::AppError;
+use crate::models::kanban_automation::KanbanAutomationRuleInput;
+use crate::models::workspace_transfer::{
+    ExportedAsset, ExportedBookmark, ExportedCalendarDay, ExportedFile, ExportedFileEntry,
+    ExportedKanban, ExportedKanbanAssignment, ExportedKanbanAutomationRule, ExportedKanbanStatus,
+    ExportedKanbanTransitionRule, ExportedLink, ExportedMemo, ExportedMemoTemplate,
+    ExportedMilestone, ExportedWorkspace, WorkspaceExport, WORKSPACE_EXPORT_FORMAT,
+    WORKSPACE_EXPORT_VERSION,
+};
+use crate::models::Workspace;
+use crate::repositories::asset_repository::collect_asset_file_names;
+use crate::repositories::memo_repository::{
+    extract_plain_text_from_json_str, rewrite_workspace_hrefs,
+};
+use crate::repositories::{
+    KanbanAutomationRepository, KanbanStatusRepository, WorkspaceRepository,
+};
+
+pub struct WorkspaceTransferRepository;
+
+impl WorkspaceTransferRepository {
+    /// Copies every memo, link, template, kanban, calendar day, milestone and bookmark of
+    /// `source` into a new workspace. Managed files and assets are shared, not duplicated.
+    pub fn clone_workspace(
+        conn: &Connection,
+        source: &Workspace,
+        slug_name: &str,
+        name: &str,
+    ) -> Result<Workspace, AppError> {
+        let export = Self::export_workspace(conn, source)?;
+        Self::import_workspace(conn, &export, slug_name, name)
+    }
+
+    /// Reads a workspace into its portable representation. File and asset contents are not
+    /// included; see `attach_payloads`.
+    pub fn export_workspace(
+        conn: &Connection,
+        workspace: &Workspace,
+    ) -> Result<WorkspaceExport, AppError> {
+        let mut memo_slugs = HashMap::new();
+        let mut memos = Vec::new();
+        {
+            let mut stmt = conn
+                .prepare(
+                    "SELECT id, slug_title, title, json(content) AS content, description, thumbnail_image, created_at, modified_at
+                    FROM memo
+                    WHERE workspace_id = ?
+                    ORDER BY id ASC",
+                )?;
+            let rows = stmt
+                .query_map([workspace.id], |row| {
+                    Ok((
+                        row.get::<_, i32>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, Option<String>>(2)?,
+                        row.get::<_, Option<String>>(3)?,
+                        row.get::<_, Option<String>>(4)?,
+                        row.get::<_, Option<String>>(5)?,
+                        row.get::<_, Option<String>>(6)?,
+                        row.get::<_, Option<String>>(7)?,
+                    ))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for (
+                id,
+                slug_title,
+                title,
+                content,
+                description,
+                thumbnail_image,
+                created_at,
+                modified_at,
+            ) in rows
+            {
+                memo_slugs.insert(id, slug_title.clone());
+                memos.push(ExportedMemo {
+                    slug_title,
+                    title: title.unwrap_or_default(),
+                    content: parse_content(content.as_deref()),
+                    description,
+                    thumbnail_image,
+                    created_at,
+                    modified_at,
+                    file_ids: Vec::new(),
+                });
+            }
+        }
+
+        let memo_slug = |memo_id: i32| memo_slugs.get(&memo_id).cloned();
+
+        for (memo_id, file_id) in query_pairs::<i32, String>(
+            conn,
+            "SELECT memo_files.memo_id, memo_files.file_id
+            FROM memo_files
+            JOIN memo ON memo.id = memo_files.memo_id
+            WHERE memo.workspace_id = ?
+            ORDER BY memo_files.created_at ASC",
+            workspace.id,
+        )? {
+            if let Some(slug) = memo_slug(memo_id) {
+                if let Some(memo) = memos.iter_mut().find(|memo| memo.slug_title == slug) {
+                    memo.file_ids.push(file_id);
+                }
+            }
+        }
+
+        let links = query_pairs::<i32, i32>(
+            conn,
+            "SELECT link.from_memo_id, link.to_memo_id
+            FROM link
+            JOIN memo ON memo.id = link.from_memo_id
+            WHERE memo.workspace_id = ?
+            ORDER BY link.id ASC",
+            workspace.id,
+        )?
+        .into_iter()
+        .filter_map(|(from_memo_id, to_memo_id)| {
+            Some(ExportedLink {
+                from_slug_title: memo_slug(from_memo_id)?,
+                to_slug_title: memo_slug(to_memo_id)?,
+            })
+        })
+        .collect();
+
+        let mut status_names = HashMap::new();
+        let mut kanban_names = HashMap::new();
+        let mut kanbans = Vec::new();
+        {
+            let mut stmt = conn.prepare(
+                "SELECT id, name, order_index, default_status_id, focus_status_id, done_status_id
+                    FROM kanban
+                    WHERE workspace_id = ?
+                    ORDER BY order_index ASC, id ASC",
+            )?;
+            let rows = stmt
+                .query_map([workspace.id], |row| {
+                    Ok((
+                        row.get::<_, i32>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, i64>(2)?,
+                        row.get::<_, Option<i32>>(3)?,
+                        row.get::<_, Option<i32>>(4)?,
+                        row.get::<_, Option<i32>>(5)?,
+                    ))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for (
+                kanban_id,
+                name,
+                order_index,
+                default_status_id,
+                focus_status_id,
+                done_status_id,
+            ) in rows
+            {
+                let mut status_stmt = conn.prepare(
+                    "SELECT id, name, color, order_index, wip_limit
+                        FROM kanban_status
+                        WHERE kanban_id = ?
+                        ORDER BY order_index ASC, id ASC",
+                )?;
+                let statuses = status_stmt
+                    .query_map([kanban_id], |row| {
+                        Ok((
+                            row.get::<_, i32>(0)?,
+                            ExportedKanbanStatus {
+                                name: row.get(1)?,
+                                color: row.get(2)?,
+                                order_index: row.get(3)?,
+                                wip_limit: row.get(4)?,
+                            },
+                        ))
+                    })?
+                    .collect::<Result<Vec<_>, _>>()?;
+                for (status_id, status) in &statuses {
+                    status_names.insert(*status_id, status.name.clone());
+                }
+
+                let mut assignment_stmt = conn.prepare(
+                    "SELECT memo_id, kanban_status_id, position, due_on, scheduled_on
+                        FROM kanban_assignment
+                        WHERE kanban_id = ?
+                        ORDER BY id ASC",
+                )?;
+                let assignments = assignment_stmt
+                    .query_map([kanban_id], |row| {
+                        Ok((
+                            row.get::<_, i32>(0)?,
+                            row.get::<_, Option<i32>>(1)?,
+                            row.get::<_, Option<i64>>(2)?,
+                            row.get::<_, Option<String>>(3)?,
+                            row.get::<_, Option<String>>(4)?,
+                        ))
+                    })?
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .filter_map(|(memo_id, status_id, position, due_on, scheduled_on)| {
+                        Some(ExportedKanbanAssignment {
+                            memo_slug_title: memo_slug(memo_id)?,
+                            status_name: status_id.and_then(|id| status_names.get(&id).cloned()),
+                            position,
+                            due_on,
+                            scheduled_on,
+                        })
+                    })
+                    .collect();
+
+                let transition_rules =
+                    KanbanStatusRepository::list_transition_rules(conn, workspace.id, kanban_id)?
+                        .into_iter()
+                        .filter_map(|rule| {
+                            Some(ExportedKanbanTransitionRule {
+                                from_status_name: status_names.get(&rule.from_status_id)?.clone(),
+                                to_status_name: status_names.get(&rule.to_status_id)?.clone(),
+                            })
+                        })
+                        .collect();
+
+                let status_name = |status_id: Option<i32>| match status_id {
+                    Some(status_id) => status_names.get(&status_id).cloned().map(Some),
+                    None => Some(None),
+                };
+                let automation_rules =
+                    KanbanAutomationRepository::list_by_kanban(conn, workspace.id, kanban_id)?
+                        .into_iter()
+                        .filter_map(|rule| {
+                            Some(ExportedKanbanAutomationRule {
+                                trigger_status_name: status_name(rule.trigger_status_id)?,
+                                condition_status_name: status_name(rule.condition_status_id)?,
+                                trigger_type: rule.trigger_type,
+                                condition_type: rule.condition_type,
+                                action_type: rule.action_type,
+                                enabled: rule.enabled,
+                            })
+                        })
+                        .collect();
+
+                kanban_names.insert(kanban_id, name.clone());
+                kanbans.push(ExportedKanban {
+                    name,
+                    order_index,
+                    default_status_name: default_status_id
+                        .and_then(|id| status_names.get(&id).cloned()),
+                    focus_status_name: focus_status_id
+                        .and_then(|id| status_names.get(&id).cloned()),
+                    done_status_name: done_status_id.and_then(|id| status_names.get(&id).cloned()),
+                    statuses: statuses.into_iter().map(|(_, status)| status).collect(),
+                    assignments,
+                    transition_rules,
+                    automation_rules,
+                });
+            }
+        }
+
+        let mut calendar_days = Vec::new();
+        {
+            let mut stmt = conn.prepare(
+                "SELECT id, date, note, is_non_working
+                    FROM calendar_day
+                    WHERE workspace_id = ?
+                    ORDER BY date ASC",
+            )?;
+            let rows = stmt
+                .query_map([workspace.id], |row| {
+                    Ok((
+                        row.get::<_, i32>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, Option<String>>(2)?,
+                        row.get::<_, bool>(3)?,
+                    ))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for (calendar_day_id, date, note, is_non_working) in rows {
+                let memo_slug_titles = query_column::<i32>(
+                    conn,
+                    "SELECT memo_id FROM calendar_day_memo WHERE calendar_day_id = ? ORDER BY created_at ASC",
+                    calendar_day_id,
+                )?
+                .into_iter()
+                .filter_map(memo_slug)
+                .collect();
+                calendar_days.push(ExportedCalendarDay {
+                    date,
+                    note,
+                    is_non_working,
+                    memo_slug_titles,
+                });
+            }
+        }
+
+        let mut milestone_indexes = HashMap::new();
+        let mut milestones = Vec::new();
+        {
+            let mut stmt = conn.prepare(
+                "SELECT id, date, title, completed_at
+                    FROM milestone
+                    WHERE workspace_id = ?
+                    ORDER BY date ASC, id ASC",
+            )?;
+            let rows = stmt
+                .query_map([workspace.id], |row| {
+                    Ok((
+                        row.get::<_, i32>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, Option<String>>(3)?,
+                    ))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for (milestone_id, date, title, completed_at) in rows {
+                let memo_slug_titles = query_column::<i32>(
+                    conn,
+                    "SELECT memo_id FROM milestone_memo WHERE milestone_id = ? ORDER BY created_at ASC",
+                    milestone_id,
+                )?
+                .into_iter()
+                .filter_map(memo_slug)
+                .collect();
+                milestone_indexes.insert(milestone_id, milestones.len());
+                milestones.push(ExportedMilestone {
+                    date,
+                    title,
+                    completed_at,
+                    memo_slug_titles,
+                });
+            }
+        }
+
+        let templates = {
+            let mut stmt = conn
+                .prepare(
+                    "SELECT slug_name, name, json(content) AS content, is_default, kanban_id, kanban_status_id, milestone_id, attach_to_today
+                    FROM memo_template
+                    WHERE workspace_id = ?
+                    ORDER BY id ASC",
+                )?;
+            let templates = stmt
+                .query_map([workspace.id], |row| {
+                    let content: Option<String> = row.get(2)?;
+                    let kanban_id: Option<i32> = row.get(4)?;
+                    let kanban_status_id: Option<i32> = row.get(5)?;
+                    let milestone_id: Option<i32> = row.get(6)?;
+                    Ok(ExportedMemoTemplate {
+                        slug_name: row.get(0)?,
+                        name: row.get(1)?,
+                        content: parse_content(content.as_deref()),
+                        is_default: row.get(3)?,
+                        kanban_name: kanban_id.and_then(|id| kanban_names.get(&id).cloned()),
+                        kanban_status_name: kanban_status_id
+                            .and_then(|id| status_names.get(&id).cloned()),
+                        milestone_index: milestone_id
+                            .and_then(|id| milestone_indexes.get(&id).copied()),
+                        attach_to_today: row.get(7)?,
+                    })
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            templates
+        };
+
+        let bookmarks = {
+            let mut stmt = conn.prepare(
+                "SELECT memo_id, order_index
+                    FROM bookmark
+                    WHERE workspace_id = ?
+                    ORDER BY order_index ASC, id ASC",
+            )?;
+            let rows = stmt
+                .query_map([workspace.id], |row| {
+                    Ok((row.get::<_, i32>(0)?, row.get::<_, Option<i64>>(1)?))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            rows.into_iter()
+                .filter_map(|(memo_id, order_index)| {
+                    Some(ExportedBookmark {
+                        memo_slug_title: memo_slug(memo_id)?,
+                        order_index,
+                    })
+                })
+                .collect()
+        };
+
+        let files = {
+            let mut stmt = conn
+                .prepare(
+                    "SELECT DISTINCT files.id, files.type, files.display_name, files.note, files.relative_path, files.url, files.imported_at
+                    FROM files
+                    JOIN memo_files ON memo_files.file_id = files.id
+                    JOIN memo ON memo.id = memo_files.memo_id
+                    WHERE memo.workspace_id = ?
+                    ORDER BY files.imported_at ASC",
+                )?;
+            let files = stmt
+                .query_map([workspace.id], |row| {
+                    Ok(ExportedFile {
+                        id: row.get(0)?,
+                        file_type: row.get(1)?,
+                        display_name: row.get(2)?,
+                        note: row.get(3)?,
+                        relative_path: row.get(4)?,
+                        url: row.get(5)?,
+                        imported_at: row.get(6)?,
+                        entries: Vec::new(),
+                    })
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            files
+        };
+
+        Ok(WorkspaceExport {
+            format: WORKSPACE_EXPORT_FORMAT.to_string(),
+            version: WORKSPACE_EXPORT_VERSION,
+            workspace: ExportedWorkspace {
+                slug_name: workspace.slug_name.clone(),
+                name: workspace.name.clone(),
+            },
+            memos,
+            links,
+            templates,
+            kanbans,
+            calendar_days,
+            milestones,
+            bookmarks,
+            files,
+            assets: Vec::new(),
+        })
+    }
+
+    /// Creates a workspace from an export, pointing internal memo hrefs at `slug_name`.
+    /// File records that already exist in this database are reused.
+    pub fn import_workspace(
+        conn: &Connection,
+        export: &WorkspaceExport,
+        slug_name: &str,
+        name: &str,
+    ) -> Result<Workspace, AppError> {
+        if export.format != WORKSPACE_EXPORT_FORMAT {
+            return Err(AppError::validation(format!(
+                "Unsupported workspace export format: {}",
+                export.format
+            )));
+        }
+        if export.version > WORKSPACE_EXPORT_VERSION {
+            return Err(AppError::validation(format!(
+                "Workspace export version {} is newer than this app supports ({}).",
+                export.version, WORKSPACE_EXPORT_VERSION
+            )));
+        }
+        if WorkspaceRepository::find_by_slug(conn, slug_name)?.is_some() {
+            return Err(AppError::conflict(format!(
+                "Workspace already exists for slug: {}",
+                slug_name
+            )));
+        }
+
+        let workspace = WorkspaceRepository::create(conn, slug_name, name)?;
+        let source_slug = export.workspace.slug_name.as_str();
+        let memo_slugs: HashSet<String> = export
+            .memos
+            .iter()
+            .map(|memo| memo.slug_title.clone())
+            .collect();
+        let rewrite = |content: &Value| -> Result<String, AppError> {
+            let mut doc = content.clone();
+            let mut unresolved = Vec::new();
+            rewrite_workspace_hrefs(
+                &mut doc,
+                source_slug,
+                slug_name,
+                &memo_slugs,
+                &mut unresolved,
+            );
+            serde_json::to_string(&doc).map_err(AppError::from)
+        };
+
+        for file in &export.files {
+            conn.execute(
+                "INSERT OR IGNORE INTO files (id, type, display_name, note, relative_path, url, imported_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?)",
+                params![
+                    &file.id,
+                    &file.file_type,
+                    &file.display_name,
+                    &file.note,
+                    &file.relative_path,
+                    &file.url,
+                    &file.imported_at
+                ],
+            )?;
+        }
+
+        let mut memo_ids = HashMap::new();
+        for memo in &export.memos {
+            let content = rewrite(&memo.content)?;
+            let body_text = extract_plain_text_from_json_str(&content);
+            conn.execute(
+                "INSERT INTO memo (workspace_id, slug_title, title, content, description, thumbnail_image, body_text, created_at, modified_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, COALESCE(?, CURRENT_TIMESTAMP), COALESCE(?, CURRENT_TIMESTAMP))",
+                params![
+                    workspace.id,
+                    &memo.slug_title,
+                    &memo.title,
+                    &content,
+                    &memo.description,
+                    &memo.thumbnail_image,
+                    &body_text,
+                    &memo.created_at,
+                    &memo.modified_at
+                ],
+            )?;
+            let memo_id = conn.last_insert_rowid() as i32;
+            memo_ids.insert(memo.slug_title.as_str(), memo_id);
+
+            conn.execute(
+                "INSERT INTO memo_fts (title, description, body_text, memo_id, workspace_id, slug_title)
+                VALUES (?, ?, ?, ?, ?, ?)",
+                params![
+                    &memo.title,
+                    &memo.description,
+                    &body_text,
+                    memo_id,
+                    workspace.id,
+                    &memo.slug_title
+                ],
+            )?;
+
+            for file_id in &memo.file_ids {
+                conn.execute(
+                    "INSERT OR IGNORE INTO memo_files (memo_id, file_id)
+                    SELECT ?, id FROM files WHERE id = ?",
+                    params![memo_id, file_id],
+                )?;
+            }
+        }
+
+        let memo_id = |slug: &str| memo_ids.get(slug).copied();
+
+        for link in &export.links {
+            if let (Some(from_memo_id), Some(to_memo_id)) =
+                (memo_id(&link.from_slug_title), memo_id(&link.to_slug_title))
+            {
+                conn.execute(
+                    "INSERT OR IGNORE INTO link (from_memo_id, to_memo_id) VALUES (?, ?)",
+                    (from_memo_id, to_memo_id),
+                )?;
+            }
+        }
+
+        let mut kanban_ids = HashMap::new();
+        let mut status_ids = HashMap::new();
+        for kanban in &export.kanbans {
+            conn.execute(
+                "INSERT INTO kanban (workspace_id, name, order_index) VALUES (?, ?, ?)",
+                (workspace.id, &kanban.name, kanban.order_index),
+            )?;
+            let kanban_id = conn.last_insert_rowid() as i32;
+            kanban_ids.insert(kanban.name.as_str(), kanban_id);
+
+            for status in &kanban.statuses {
+                conn.execute(
+                    "INSERT INTO kanban_status
+                        (workspace_id, kanban_id, name, color, order_index, wip_limit)
+                    VALUES (?, ?, ?, ?, ?, ?)",
+                    (
+                        workspace.id,
+                        kanban_id,
+                        &status.name,
+                        &status.color,
+                        status.order_index,
+                        status.wip_limit,
+                    ),
+                )?;
+                status_ids.insert(
+                    (kanban.name.as_str(), status.name.as_str()),
+                    conn.last_insert_rowid() as i32,
+                );
+            }
+
+            let status_id_by_name = |status_name: &str| {
+                status_ids
+                    .get(&(kanban.name.as_str(), status_name))
+                    .copied()
+            };
+            let status_id =
+                |status_name: &Option<String>| status_name.as_deref().and_then(status_id_by_name);
+
+            conn.execute(
+                "UPDATE kanban
+                SET default_status_id = ?, focus_status_id = ?, done_status_id = ?
+                WHERE id = ?",
+                (
+                    status_id(&kanban.default_status_name),
+                    status_id(&kanban.focus_status_name),
+                    status_id(&kanban.done_status_name),
+                    kanban_id,
+                ),
+            )?;
+
+            for rule in &kanban.transition_rules {
+                if let (Some(from_status_id), Some(to_status_id)) = (
+                    status_id_by_name(&rule.from_status_name),
+                    status_id_by_name(&rule.to_status_name),
+                ) {
+                    conn.execute(
+                        "INSERT OR IGNORE INTO kanban_status_transition_rule
+                            (workspace_id, kanban_id, from_status_id, to_status_id)
+                        VALUES (?, ?, ?, ?)",
+                        (workspace.id, kanban_id, from_status_id, to_status_id),
+                    )?;
+                }
+            }
+
+            for rule in &kanban.automation_rules {
+                let remap = |status_name: &Option<String>| match status_name {
+                    Some(status_name) => status_id_by_name(status_name).map(Some),
+                    None => Some(None),
+                };
+                let (Some(trigger_status_id), Some(condition_status_id)) = (
+                    remap(&rule.trigger_status_name),
+                    remap(&rule.condition_status_name),
+                ) else {
+                    continue;
+                };
+                KanbanAutomationRepository::create(
+                    conn,
+                    workspace.id,
+                    kanban_id,
+                    &KanbanAutomationRuleInput {
+                        trigger_type: rule.trigger_type.clone(),
+                        trigger_status_id,
+                        condition_type: rule.condition_type.clone(),
+                        condition_status_id,
+                        action_type: rule.action_type.clone(),
+                        enabled: rule.enabled,
+                    },
+                )?;
+            }
+
+            for assignment in &kanban.assignments {
+                let Some(assigned_memo_id) = memo_id(&assignment.memo_slug_title) else {
+                    continue;
+                };
+                conn.execute(
+                    "INSERT INTO kanban_assignment
+                        (workspace_id, memo_id, kanban_id, kanban_status_id, position, due_on, scheduled_on)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)",
+                    (
+                        workspace.id,
+                        assigned_memo_id,
+                        kanban_id,
+                        status_id(&assignment.status_name),
+                        assignment.position,
+                        &assignment.due_on,
+                        &assignment.scheduled_on,
+                    ),
+                )?;
+            }
+        }
+
+        for day in &export.calendar_days {
+            conn.execute(
+                "INSERT INTO calendar_day (workspace_id, date, note, is_non_working) VALUES (?, ?, ?, ?)",
+                (workspace.id, &day.date, &day.note, day.is_non_working),
+            )?;
+            let calendar_day_id = conn.last_insert_rowid() as i32;
+            for slug in &day.memo_slug_titles {
+                if let Some(day_memo_id) = memo_id(slug) {
+                    conn.execute(
+                        "INSERT OR IGNORE INTO calendar_day_memo (calendar_day_id, memo_id) VALUES (?, ?)",
+                        (calendar_day_id, day_memo_id),
+                    )?;
+                }
+            }
+        }
+
+        let mut milestone_ids = Vec::with_capacity(export.milestones.len());
+        for milestone in &export.milestones {
+            conn.execute(
+                "INSERT INTO milestone (workspace_id, date, title, completed_at) VALUES (?, ?, ?, ?)",
+                (
+                    workspace.id,
+                    &milestone.date,
+                    &milestone.title,
+                    &milestone.completed_at,
+                ),
+            )?;
+            let milestone_id = conn.last_insert_rowid() as i32;
+            milestone_ids.push(milestone_id);
+            for slug in &milestone.memo_slug_titles {
+                if let Some(milestone_memo_id) = memo_id(slug) {
+                    conn.execute(
+                        "INSERT OR IGNORE INTO milestone_memo (milestone_id, memo_id) VALUES (?, ?)",
+                        (milestone_id, milestone_memo_id),
+                    )?;
+                }
+            }
+        }
+
+        for template in &export.templates {
+            let kanban_id = template
+                .kanban_name
+                .as_deref()
+                .and_then(|kanban_name| kanban_ids.get(kanban_name))
+                .copied();
+            let kanban_status_id = match (&template.kanban_name, &template.kanban_status_name) {
+                (Some(kanban_name), Some(status_name)) => status_ids
+                    .get(&(kanban_name.as_str(), status_name.as_str()))
+                    .copied(),
+                _ => None,
+            };
+            let milestone_id = template
+                .milestone_index
+                .and_then(|index| milestone_ids.get(index))
+                .copied();
+            conn.execute(
+                "INSERT INTO memo_template (workspace_id, slug_name, name, content, is_default, kanban_id, kanban_status_id, milestone_id, attach_to_today)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                params![
+                    workspace.id,
+                    &template.slug_name,
+                    &template.name,
+                    rewrite(&template.content)?,
+                    template.is_default,
+                    kanban_id,
+                    kanban_status_id,
+                    milestone_id,
+                    template.attach_to_today
+                ],
+            )?;
+        }
+
+        for bookmark in &export.bookmarks {
+            if let Some(bookmark_memo_id) = memo_id(&bookmark.memo_slug_title) {
+                conn.execute(
+                    "INSERT OR IGNORE INTO bookmark (workspace_id, memo_id, order_index) VALUES (?, ?, ?)",
+                    (workspace.id, bookmark_memo_id, bookmark.order_index),
+                )?;
+            }
+        }
+
+        Ok(workspace)
+    }
+
+    /// Embeds the referenced assets and managed file contents so the export is self-contained.
+    /// Files missing from storage are exported without contents.
+    pub fn attach_payloads(
+        export: &mut WorkspaceExport,
+        asset_dir: &Path,
+        storage_root: &Path,
+    ) -> Result<(), AppError> {
+        let mut asset_names = BTreeSet::new();
+        for memo in &export.memos {
+            collect_asset_file_names(&memo.content.to_string(), &mut asset_names);
+            if let Some(thumbnail_image) = &memo.thumbnail_image {
+                collect_asset_file_names(thumbnail_image, &mut asset_names);
+            }
+        }
+        for template in &export.templates {
+            collect_asset_file_names(&template.content.to_string(), &mut asset_names);
+        }
+
+        export.assets.clear();
+        for file_name in asset_names {
+            let Ok(bytes) = fs::read(asset_dir.join(&file_name)) else {
+                continue;
+            };
+            export.assets.push(ExportedAsset {
+                file_name,
+                data: general_purpose::STANDARD.encode(bytes),
+            });
+        }
+
+        for file in &mut export.files {
+            file.entries.clear();
+            let Some(relative_path) = file.relative_path.as_deref() else {
+                continue;
+            };
+            let path = storage_root.join(relative_path);
+            if path.is_file() {
+                let bytes = fs::read(&path)?;
+                file.entries.push(ExportedFileEntry {
+                    path: String::new(),
+                    data: general_purpose::STANDARD.encode(bytes),
+                });
+            } else if path.is_dir() {
+                collect_directory_entries(&path, &path, &mut file.entries)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes embedded assets and managed files to disk, never overwriting existing paths.
+    /// Returns the paths that were created so the caller can remove them if the import fails.
+    pub fn restore_payloads(
+        export: &WorkspaceExport,
+        asset_dir: &Path,
+        storage_root: &Path,
+    ) -> Result<Vec<PathBuf>, AppError> {
+        let mut created = Vec::new();
+        let result = write_payloads(export, asset_dir, storage_root, &mut created);
+        if let Err(error) = result {
+            Self::remove_restored_payloads(&created);
+            return Err(error);
+        }
+        Ok(created)
+    }
+
+    pub fn remove_restored_payloads(paths: &[PathBuf]) {
+        for path in paths.iter().rev() {
+            if path.is_dir() {
+                let _ = fs::remove_dir_all(path);
+            } else {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+}
+
+fn write_payloads(
+    export: &WorkspaceExport,
+    asset_dir: &Path,
+    storage_root: &Path,
+    created: &mut Vec<PathBuf>,
+) -> Result<(), AppError> {
+    if !export.assets.is_empty() {
+        fs::create_dir_all(asset_dir)
+            .map_err(|e| AppError::io(format!("Failed to create directory: {}", e)))?;
+    }
+    for asset in &export.assets {
+        if asset.file_name.contains('/') || asset.file_name.contains('\\') {
+            return Err(AppError::validation(format!(
+                "Invalid asset file name: {}",
+                asset.file_name
+            )));
+        }
+        let path = asset_dir.join(&asset.file_name);
+        if path.exists() {
+            continue;
+        }
+        write_base64(&path, &asset.data)?;
+        created.push(path);
+    }
+
+    for file in &export.files {
+        let Some(relative_path) = file.relative_path.as_deref() else {
+            continue;
+        };
+        if file.entries.is_empty() {
+            continue;
+        }
+        let path = safe_join(storage_root, relative_path)?;
+        if path.exists() {
+            continue;
+        }
+
+        if file.file_type == "local_directory" {
+            fs::create_dir_all(&path)?;
+            created.push(path.clone());
+            for entry in &file.entries {
+                let entry_path = safe_join(&path, &entry.path)?;
+                if let Some(parent) = entry_path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                write_base64(&entry_path, &entry.data)?;
+            }
+        } else {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            write_base64(&path, &file.entries[0].data)?;
+            created.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn write_base64(path: &Path, data: &str) -> Result<(), AppError> {
+    let bytes = general_purpose::STANDARD
+        .decode(data)
+        .map_err(|e| AppError::validation(format!("Failed to decode file data: {}", e)))?;
+    fs::write(path, bytes)
+        .map_err(|e| AppError::io(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+// Rejects absolute paths and `..` so an export cannot write outside the target folder.
+fn safe_join(root: &Path, relative_path: &str) -> Result<PathBuf, AppError> {
+    let relative = Path::new(relative_path);
+    if relative
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_)))
+    {
+        return Err(AppError::validation(format!(
+            "Invalid path in workspace export: {}",
+            relative_path
+        )));
+    }
+    Ok(root.join(relative))
+}
+
+fn collect_directory_entries(
+    root: &Path,
+    dir: &Path,
+    out: &mut Vec<ExportedFileEntry>,
+) -> Result<(), AppError> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_directory_entries(root, &path, out)?;
+            continue;
+        }
+
+        let relative_path = path
+            .strip_prefix(root)
+            .map_err(|e| AppError::io(e.to_string()))?
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join("/");
+        let bytes = fs::read(&path)?;
+        out.push(ExportedFileEntry {
+            path: relative_path,
+            data: general_purpose::STANDARD.encode(bytes),
+        });
+    }
+
+    Ok(())
+}
+
+fn parse_content(content: Option<&str>) -> Value {
+    content
+        .and_then(|content| serde_json::from_str(content).ok())
+        .unwrap_or_else(|| Value::String(String::new()))
+}
+
+fn query_pairs<A: rusqlite::types::FromSql, B: rusqlite::types::FromSql>(
+    conn: &Connection,
+    sql: &str,
+    id: i32,
+) -> Result<Vec<(A, B)>, AppError> {
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt
+        .query_map([id], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+fn query_column<A: rusqlite::types::FromSql>(
+    conn: &Connection,
+    sql: &str,
+    id: i32,
+) -> Result<Vec<A>, AppError> {
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt
+        .query_map([id], |row| row.get(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+    use serde_json::json;
+
+    use super::WorkspaceTransferRepository;
+    use crate::migrations::apply_migrations;
+    use crate::models::kanban_automation::KanbanAutomationRuleInput;
+    use crate::repositories::{
+        BookmarkRepository, CalendarDayRepository, KanbanAssignmentRepository,
+        KanbanAutomationRepository, KanbanRepository, KanbanStatusRepository, MemoRepository,
+        MilestoneRepository, WorkspaceRepository,
+    };
+
+    fn setup_conn() -> Connection {
+        let conn = Connection::open_in_memory().expect("in-memory DB should open");
+        conn.execute(
+            "CREATE TABLE schema_migrations (version TEXT PRIMARY KEY)",
+            [],
+        )
+        .expect("schema_migrations should be creatable");
+        apply_migrations(&conn).expect("migrations should apply");
+        conn.execute_batch("PRAGMA foreign_keys = ON;")
+            .expect("foreign keys should enable");
+        conn
+    }
+
+    #[test]
+    fn clone_workspace_copies_rows_and_rewrites_hrefs() {
+        let conn = setup_conn();
+        let source = WorkspaceRepository::create(&conn, "team", "Team")
+            .expect("workspace should be created");
+        let content = json!({
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [{
+                    "type": "text",
+                    "text": "notes",
+                    "marks": [{ "type": "link", "attrs": { "href": "/team/notes" } }]
+                }]
+            }]
+        })
+        .to_string();
+        let index = MemoRepository::create(&conn, source.id, "index", "Index", &content)
+            .expect("memo should be created");
+        let notes = MemoRepository::create(&conn, source.id, "notes", "Notes", "\"\"")
+            .expect("memo should be created");
+        conn.execute(
+            "INSERT INTO link (from_memo_id, to_memo_id) VALUES (?, ?)",
+            (index.id, notes.id),
+        )
+        .expect("link should be created");
+        let kanban = KanbanRepository::ensure_global_status_board(&conn, source.id)
+            .expect("board should exist");
+        KanbanAssignmentRepository::upsert_status(
+            &conn,
+            source.id,
+            notes.id,
+            kanban.id,
+            kanban.focus_status_id,
+            Some(1),
+        )
+        .expect("assignment should be created");
+        conn.execute(
+            "INSERT INTO kanban_status_transition_rule
+                (workspace_id, kanban_id, from_status_id, to_status_id)
+            VALUES (?, ?, ?, ?)",
+            (
+                source.id,
+                kanban.id,
+                kanban.default_status_id,
+                kanban.focus_status_id,
+            ),
+        )
+        .expect("transition rule should be created");
+        KanbanAutomationRepository::create(
+            &conn,
+            source.id,
+            kanban.id,
+            &KanbanAutomationRuleInput {
+                trigger_type: "leave_status".to_string(),
+                trigger_status_id: kanban.focus_status_id,
+                condition_type: "always".to_string(),
+                condition_status_id: None,
+                action_type: "add_to_today".to_string(),
+                enabled: false,
+            },
+        )
+        .expect("automation rule should be created");
+        CalendarDayRepository::add_memo(&conn, source.id, "2026-10-01", index.id)
+            .expect("calendar memo should be added");
+        let milestone_id = MilestoneRepository::create(&conn, source.id, "2026-12-01", "Launch")
+            .expect("milestone should be created");
+        MilestoneRepository::add_memo(&conn, source.id, milestone_id, notes.id)
+            .expect("milestone memo should be added");
+        BookmarkRepository::create(&conn, source.id, index.id).expect("bookmark should be added");
+
+        let cloned =
+            WorkspaceTransferRepository::clone_workspace(&conn, &source, "team-copy", "Team copy")
+                .expect("workspace should clone");
+
+        let cloned_index = MemoRepository::find_by_slug(&conn, cloned.id, "index")
+            .expect("memo should load")
+            .expect("memo should exist");
+        assert!(cloned_index.content.contains("/team-copy/notes"));
+        let cloned_links: i32 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM link WHERE from_memo_id = ?",
+                [cloned_index.id],
+                |row| row.get(0),
+            )
+            .expect("links should count");
+        assert_eq!(cloned_links, 1);
+
+        let cloned_kanban = KanbanRepository::ensure_global_status_board(&conn, cloned.id)
+            .expect("board should exist");
+        assert_ne!(cloned_kanban.id, kanban.id);
+        let assignments =
+            KanbanAssignmentRepository::list_items_by_kanban(&conn, cloned.id, cloned_kanban.id)
+                .expect("assignments should load");
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(
+            assignments[0].kanban_status_id,
+            cloned_kanban.focus_status_id
+        );
+        let rules =
+            KanbanStatusRepository::list_transition_rules(&conn, cloned.id, cloned_kanban.id)
+                .expect("transition rules should load");
+        assert_eq!(rules.len(), 1);
+        assert_eq!(
+            Some(rules[0].from_status_id),
+            cloned_kanban.default_status_id
+        );
+        assert_eq!(Some(rules[0].to_status_id), cloned_kanban.focus_status_id);
+        let automation_rules =
+            KanbanAutomationRepository::list_by_kanban(&conn, cloned.id, cloned_kanban.id)
+                .expect("automation rules should load");
+        assert_eq!(automation_rules.len(), 1);
+        assert_eq!(
+            automation_rules[0].trigger_status_id,
+            cloned_kanban.focus_status_id
+        );
+        assert!(!automation_rules[0].enabled);
+
+        for table in ["calendar_day", "milestone", "bookmark"] {
+            let count: i32 = conn
+                .query_row(
+                    &format!("SELECT COUNT(*) FROM {} WHERE workspace_id = ?", table),
+                    [cloned.id],
+                    |row| row.get(0),
+                )
+                .expect("rows should count");
+            assert_eq!(count, 1, "{} should be cloned", table);
+        }
+
+        assert!(
+            WorkspaceTransferRepository::clone_workspace(&conn, &source, "team-copy", "Again")
+                .is_err()
+        );
+    }
+}
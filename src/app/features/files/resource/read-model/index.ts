@@ -63,12 +63,14 @@ export function useManagedFilesPageReadModel(
   currentPage: ComputedRef<number>,
   pageSize: number,
   showUnlinkedOnly: ComputedRef<boolean>,
+  contentQuery: ComputedRef<string>,
 ) {
   const { snapshot: filesSnap } = useQuery(workspaceManagedFilesQuery, {
     workspaceSlug,
     limit: pageSize,
     offset: computed(() => (currentPage.value - 1) * pageSize),
     unlinkedOnly: showUnlinkedOnly,
+    query: contentQuery,
   });
 
   const { snapshot: memosSnap } = useQuery(workspaceMemosQuery, {
@@ -8,6 +8,7 @@ type LoadFilesPageDataOptions = {
   currentPage: Ref<number> | ComputedRef<number>;
   pageSize: number;
   showUnlinkedOnly: Ref<boolean> | ComputedRef<boolean>;
+  contentQuery: Ref<string> | ComputedRef<string>;
 };
 
 export async function loadFilesPageData(options: LoadFilesPageDataOptions) {
@@ -21,6 +22,7 @@ export async function loadFilesPageData(options: LoadFilesPageDataOptions) {
       limit: options.pageSize,
       offset: (options.currentPage.value - 1) * options.pageSize,
       unlinkedOnly: options.showUnlinkedOnly.value,
+      query: options.contentQuery.value,
     }),
     workspaceMemosQuery.fetch({
       workspaceSlug: options.workspaceSlug.value,
@@ -1,13 +1,15 @@
 export type ManagedFilesListState = {
   currentPage: number;
   showUnlinkedOnly: boolean;
+  contentQuery: string;
 };
 
 export type ManagedFilesListEvent =
   | { type: 'list/load-requested' }
   | { type: 'list/previous-page-requested' }
   | { type: 'list/next-page-requested'; payload: { totalPages: number } }
-  | { type: 'list/unlinked-toggle-requested' };
+  | { type: 'list/unlinked-toggle-requested' }
+  | { type: 'list/content-query-changed'; payload: { query: string } };
 
 export type ManagedFilesListEffect = {
   type: 'effect/load-page';
@@ -21,6 +23,7 @@ export type ManagedFilesListApplyResult = {
 export const initialManagedFilesListState: ManagedFilesListState = {
   currentPage: 1,
   showUnlinkedOnly: false,
+  contentQuery: '',
 };
 
 export function applyManagedFilesListEvent(
@@ -58,10 +61,23 @@ export function applyManagedFilesListEvent(
     case 'list/unlinked-toggle-requested':
       return {
         state: {
+          ...state,
           currentPage: 1,
           showUnlinkedOnly: !state.showUnlinkedOnly,
         },
         effects: [{ type: 'effect/load-page' }],
       };
+    case 'list/content-query-changed':
+      if (state.contentQuery === event.payload.query) {
+        return { state, effects: [] };
+      }
+      return {
+        state: {
+          ...state,
+          currentPage: 1,
+          contentQuery: event.payload.query,
+        },
+        effects: [{ type: 'effect/load-page' }],
+      };
   }
 }
@@ -226,6 +226,7 @@ export function useInboxPanel(options: UseInboxPanelOptions) {
 
       options.toast.add({
         title: selectedMemoSlug.value ? 'Item imported and linked.' : 'Item imported.',
+        description: file.import_notice ?? undefined,
       });
       closeLinkModal();
     }
@@ -17,18 +17,26 @@ type UseManagedFilesListStateOptions = {
   toast: ReturnType<typeof useToast>;
 };
 
+// The trigram index only matches queries of at least three characters; shorter ones
+// filter the current page instead.
+const minContentQueryLength = 3;
+const contentSearchDebounceMs = 300;
+
 export function useManagedFilesListState(options: UseManagedFilesListStateOptions) {
   const pageSize = 20;
   const state = ref(initialManagedFilesListState);
   const currentPage = computed(() => state.value.currentPage);
   const searchQuery = ref('');
   const showUnlinkedOnly = computed(() => state.value.showUnlinkedOnly);
+  const contentQuery = computed(() => state.value.contentQuery);
+  let contentSearchTimerId: number | undefined;
 
   const filesReadModel = useManagedFilesPageReadModel(
     options.workspaceSlug,
     currentPage,
     pageSize,
     showUnlinkedOnly,
+    contentQuery,
   );
 
   const items = computed(() => filesReadModel.value.data.items);
@@ -40,7 +48,7 @@ export function useManagedFilesListState(options: UseManagedFilesListStateOption
   const pageEnd = computed(() => Math.min(totalCount.value, currentPage.value * pageSize));
   const filteredItems = computed(() => {
     const needle = searchQuery.value.trim().toLowerCase();
-    if (!needle) {
+    if (!needle || contentQuery.value) {
       return items.value;
     }
 
@@ -66,6 +74,7 @@ export function useManagedFilesListState(options: UseManagedFilesListStateOption
         currentPage,
         pageSize,
         showUnlinkedOnly,
+        contentQuery,
       });
     }
     catch (error) {
@@ -89,6 +98,17 @@ export function useManagedFilesListState(options: UseManagedFilesListStateOption
     }
   };
 
+  watch(searchQuery, (nextQuery) => {
+    window.clearTimeout(contentSearchTimerId);
+    const trimmed = nextQuery.trim();
+    contentSearchTimerId = window.setTimeout(() => {
+      void dispatch({
+        type: 'list/content-query-changed',
+        payload: { query: trimmed.length >= minContentQueryLength ? trimmed : '' },
+      });
+    }, contentSearchDebounceMs);
+  });
+
   const loadPage = async () => {
     await dispatch({ type: 'list/load-requested' });
   };
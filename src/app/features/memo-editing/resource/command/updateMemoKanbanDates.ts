@@ -0,0 +1,19 @@
+import { command } from '~/resources/command';
+
+type UpdateMemoKanbanDatesInput = {
+  workspaceSlug: string;
+  memoSlug: string;
+  kanbanId: number;
+  dueOn: string | null;
+  scheduledOn: string | null;
+};
+
+export async function updateMemoKanbanDates(input: UpdateMemoKanbanDatesInput) {
+  await command.kanbanAssignment.updateDates({
+    workspaceSlugName: input.workspaceSlug,
+    memoSlugTitle: input.memoSlug,
+    kanbanId: input.kanbanId,
+    dueOn: input.dueOn,
+    scheduledOn: input.scheduledOn,
+  });
+}
@@ -5,6 +5,7 @@ type UpsertMemoKanbanStatusInput = {
   memoSlug: string;
   kanbanId: number;
   kanbanStatusId: number;
+  force?: boolean;
 };
 
 export async function upsertMemoKanbanStatus(input: UpsertMemoKanbanStatusInput) {
@@ -14,5 +15,6 @@ export async function upsertMemoKanbanStatus(input: UpsertMemoKanbanStatusInput)
     kanbanId: input.kanbanId,
     kanbanStatusId: input.kanbanStatusId,
     position: null,
+    force: input.force,
   });
 }
@@ -18,12 +18,14 @@ export function useMemoEditingKanban(options: UseMemoEditingKanbanOptions) {
   const kanbans = computed(() => kanbanReadModel.value.data.items);
 
   const {
+    kanbanEntries,
     kanbanSelections,
     isKanbanLoading,
     isKanbanUpdating,
     loadKanbanEntries,
     getStatuses,
     applyKanbanStatus,
+    applyKanbanDates,
   } = useMemoKanbanAssignments({
     workspaceSlug: options.workspaceSlug,
     memoSlug: options.memoSlug,
@@ -34,11 +36,13 @@ export function useMemoEditingKanban(options: UseMemoEditingKanbanOptions) {
   return {
     kanbanReadModel,
     kanbans,
+    kanbanEntries,
     kanbanSelections,
     isKanbanLoading,
     isKanbanUpdating,
     loadKanbanEntries,
     getStatuses,
     applyKanbanStatus,
+    applyKanbanDates,
   };
 }
@@ -1,6 +1,7 @@
 import { computed, reactive, ref, watch } from 'vue';
 
 import { removeMemoKanbanStatus } from '../../resource/command/removeMemoKanbanStatus';
+import { updateMemoKanbanDates } from '../../resource/command/updateMemoKanbanDates';
 import { upsertMemoKanbanStatus } from '../../resource/command/upsertMemoKanbanStatus';
 import {
   loadKanbanStatuses as fetchKanbanStatuses,
@@ -17,6 +18,7 @@ import type { KanbanStatus } from '~/models/kanbanStatus';
 
 import { useResourceManager } from '~/resource-runtime/infra/useResourceManager';
 import { workspaceKanbanStatusesQuery } from '~/resources/kanban-status/queries';
+import { AppError } from '~/utils/error';
 import { iconKey } from '~/utils/icon';
 
 type UseMemoKanbanAssignmentsOptions = {
@@ -85,7 +87,7 @@ export function useMemoKanbanAssignments(options: UseMemoKanbanAssignmentsOption
     return kanbanStatusesById.value[kanbanId] ?? [];
   };
 
-  const applyKanbanStatus = async (kanbanId: number, nextStatusId: number | null) => {
+  const applyKanbanStatus = async (kanbanId: number, nextStatusId: number | null, force?: boolean) => {
     if (!options.workspaceSlug.value || !options.memoSlug.value) return;
     if (isKanbanUpdating(kanbanId)) return;
     if (typeof nextStatusId === 'number' && Number.isNaN(nextStatusId)) return;
@@ -109,6 +111,7 @@ export function useMemoKanbanAssignments(options: UseMemoKanbanAssignmentsOption
           memoSlug: options.memoSlug.value,
           kanbanId,
           kanbanStatusId: nextStatusId,
+          force,
         });
       }
       await loadKanbanEntries();
@@ -117,6 +120,25 @@ export function useMemoKanbanAssignments(options: UseMemoKanbanAssignmentsOption
     catch (error) {
       console.error(error);
       kanbanSelections[kanbanId] = previousStatusId;
+      if (
+        !force
+        && error instanceof AppError
+        && (error.reason === 'WIP_LIMIT_EXCEEDED' || error.reason === 'TRANSITION_NOT_ALLOWED')
+      ) {
+        options.toast.add({
+          title: 'Move blocked by board rules.',
+          description: error.message,
+          color: 'warning',
+          icon: iconKey.failed,
+          actions: [{
+            label: 'Move anyway',
+            onClick: () => {
+              void applyKanbanStatus(kanbanId, nextStatusId, true);
+            },
+          }],
+        });
+        return;
+      }
       options.toast.add({
         title: 'Failed to update Kanban.',
         description: 'Please try again.',
@@ -129,6 +151,38 @@ export function useMemoKanbanAssignments(options: UseMemoKanbanAssignmentsOption
     }
   };
 
+  const applyKanbanDates = async (
+    kanbanId: number,
+    dates: { dueOn: string | null; scheduledOn: string | null },
+  ) => {
+    if (!options.workspaceSlug.value || !options.memoSlug.value) return;
+    if (isKanbanUpdating(kanbanId)) return;
+
+    updatingKanbans[kanbanId] = true;
+    try {
+      await updateMemoKanbanDates({
+        workspaceSlug: options.workspaceSlug.value,
+        memoSlug: options.memoSlug.value,
+        kanbanId,
+        dueOn: dates.dueOn || null,
+        scheduledOn: dates.scheduledOn || null,
+      });
+      await loadKanbanEntries();
+    }
+    catch (error) {
+      console.error(error);
+      options.toast.add({
+        title: 'Failed to update Kanban dates.',
+        description: error instanceof Error ? error.message : 'Please try again.',
+        color: 'error',
+        icon: iconKey.failed,
+      });
+    }
+    finally {
+      updatingKanbans[kanbanId] = false;
+    }
+  };
+
   watch(options.kanbans, () => {
     syncKanbanSelections();
     void loadKanbanStatuses();
@@ -152,5 +206,6 @@ export function useMemoKanbanAssignments(options: UseMemoKanbanAssignmentsOption
     loadKanbanStatuses,
     getStatuses,
     applyKanbanStatus,
+    applyKanbanDates,
   };
 }
@@ -0,0 +1,11 @@
+import { open } from '@tauri-apps/plugin-dialog';
+
+/** Returns null when the open dialog is dismissed. */
+export async function chooseCalendarIcsFile() {
+  const path = await open({
+    multiple: false,
+    directory: false,
+    filters: [{ name: 'iCalendar', extensions: ['ics'] }],
+  });
+  return typeof path === 'string' ? path : null;
+}
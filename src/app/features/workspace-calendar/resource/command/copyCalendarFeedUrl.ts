@@ -0,0 +1,12 @@
+import { command } from '~/resources/command';
+
+type CopyCalendarFeedUrlInput = {
+  workspaceSlug: string;
+};
+
+/** Copies the tokenized subscription URL served next to the MCP server. */
+export async function copyCalendarFeedUrl(input: CopyCalendarFeedUrlInput) {
+  const url = await command.calendarDay.feedUrl({ workspaceSlugName: input.workspaceSlug });
+  await navigator.clipboard.writeText(url);
+  return url;
+}
@@ -0,0 +1,23 @@
+import { save } from '@tauri-apps/plugin-dialog';
+
+import { command } from '~/resources/command';
+
+type ExportCalendarIcsInput = {
+  workspaceSlug: string;
+};
+
+/** Returns false when the save dialog is dismissed. */
+export async function exportCalendarIcs(input: ExportCalendarIcsInput) {
+  const path = await save({
+    title: 'Export calendar',
+    defaultPath: `${input.workspaceSlug}-calendar.ics`,
+    filters: [{ name: 'iCalendar', extensions: ['ics'] }],
+  });
+  if (!path) return false;
+
+  await command.calendarDay.exportIcs({
+    workspaceSlugName: input.workspaceSlug,
+    path,
+  });
+  return true;
+}
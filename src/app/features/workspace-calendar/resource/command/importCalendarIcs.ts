@@ -0,0 +1,19 @@
+import type { CalendarImportNoteMode } from '~/models/calendarDay';
+
+import { command } from '~/resources/command';
+
+type ImportCalendarIcsInput = {
+  workspaceSlug: string;
+  path: string;
+  noteMode: CalendarImportNoteMode;
+  dryRun: boolean;
+};
+
+export async function importCalendarIcs(input: ImportCalendarIcsInput) {
+  return await command.calendarDay.importIcs({
+    workspaceSlugName: input.workspaceSlug,
+    path: input.path,
+    noteMode: input.noteMode,
+    dryRun: input.dryRun,
+  });
+}
@@ -0,0 +1,4 @@
+export { chooseCalendarIcsFile } from './chooseCalendarIcsFile';
+export { copyCalendarFeedUrl } from './copyCalendarFeedUrl';
+export { exportCalendarIcs } from './exportCalendarIcs';
+export { importCalendarIcs } from './importCalendarIcs';
@@ -1,4 +1,5 @@
 import { buildCalendarMonths, countWorkingDaysBetween, getLocalDateString } from '../calendarUtils';
+import { chooseCalendarIcsFile, copyCalendarFeedUrl, exportCalendarIcs } from '../resource/command';
 import { loadWorkspaceCalendarData } from '../resource/read/loadWorkspaceCalendarData';
 import { useWorkspaceCalendarReadModel } from '../resource/read-model';
 
@@ -10,6 +11,7 @@ import { useResourceManager } from '~/resource-runtime/infra/useResourceManager'
 import { workspaceCalendarDaysQuery } from '~/resources/calendar-day/queries';
 import { command } from '~/resources/command';
 import { handleError } from '~/utils/error';
+import { iconKey } from '~/utils/icon';
 import { getEncodedWorkspaceSlugFromPath } from '~/utils/route';
 
 const emptyDay = (date: string): CalendarDay => ({
@@ -18,6 +20,7 @@ const emptyDay = (date: string): CalendarDay => ({
   note: null,
   is_non_working: false,
   memos: [],
+  kanban_items: [],
 });
 
 export const useWorkspaceCalendarPage = async () => {
@@ -207,6 +210,61 @@ export const useWorkspaceCalendarPage = async () => {
     }
   };
 
+  const isExporting = ref(false);
+
+  const exportIcs = async () => {
+    isExporting.value = true;
+    try {
+      const saved = await exportCalendarIcs({ workspaceSlug: workspaceSlug.value });
+      if (!saved) return;
+      toast.add({
+        title: 'Exported calendar.',
+        duration: 1000,
+        icon: iconKey.success,
+      });
+    }
+    catch (error) {
+      const appError = handleError(error);
+      toast.add({
+        title: 'Failed to export calendar.',
+        description: appError.message,
+        color: 'error',
+      });
+    }
+    finally {
+      isExporting.value = false;
+    }
+  };
+
+  const copyFeedUrl = async () => {
+    try {
+      await copyCalendarFeedUrl({ workspaceSlug: workspaceSlug.value });
+      toast.add({
+        title: 'Copied calendar subscription URL.',
+        duration: 1200,
+        icon: iconKey.success,
+      });
+    }
+    catch (error) {
+      const appError = handleError(error);
+      toast.add({
+        title: 'Failed to copy calendar subscription URL.',
+        description: appError.message,
+        color: 'error',
+      });
+    }
+  };
+
+  const importPath = ref<string | null>(null);
+  const isImportDialogOpen = ref(false);
+
+  const startImport = async () => {
+    const path = await chooseCalendarIcsFile();
+    if (!path) return;
+    importPath.value = path;
+    isImportDialogOpen.value = true;
+  };
+
   watch(selectedYear, async () => {
     showEarlierDates.value = false;
     await loadWorkspaceCalendarData({ workspaceSlug, year: selectedYear });
@@ -243,5 +301,11 @@ export const useWorkspaceCalendarPage = async () => {
     saveSelectedDay,
     addMemo,
     removeMemo,
+    isExporting,
+    exportIcs,
+    copyFeedUrl,
+    importPath,
+    isImportDialogOpen,
+    startImport,
   };
 };
@@ -85,7 +85,7 @@ export const toStatusId = (columnId: string): number | null => {
   return Number.isNaN(parsed) ? null : parsed;
 };
 
-export const getNeighborPositions = (
+export const getNeighborItems = (
   items: KanbanItem[],
   draggedId: string,
   beforeItemId?: string,
@@ -105,6 +105,15 @@ export const getNeighborPositions = (
     prev = filtered.length > 0 ? filtered[filtered.length - 1] : undefined;
   }
 
+  return { prev, next };
+};
+
+export const getNeighborPositions = (
+  items: KanbanItem[],
+  draggedId: string,
+  beforeItemId?: string,
+) => {
+  const { prev, next } = getNeighborItems(items, draggedId, beforeItemId);
   const prevPos = prev?.position ?? null;
   const nextPos = next?.position ?? null;
 
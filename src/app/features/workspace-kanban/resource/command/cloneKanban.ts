@@ -0,0 +1,19 @@
+import { command } from '~/resources/command';
+
+type CloneKanbanInput = {
+  workspaceSlug: string;
+  kanbanId: number;
+  name: string;
+  targetWorkspaceSlug: string;
+  includeAssignments: boolean;
+};
+
+export async function cloneKanban(input: CloneKanbanInput) {
+  return await command.kanban.clone({
+    workspaceSlugName: input.workspaceSlug,
+    kanbanId: input.kanbanId,
+    name: input.name,
+    targetWorkspaceSlugName: input.targetWorkspaceSlug,
+    includeAssignments: input.includeAssignments,
+  });
+}
@@ -3,11 +3,13 @@ import { command } from '~/resources/command';
 type CreateKanbanInput = {
   workspaceSlug: string;
   name: string;
+  templateId?: number | null;
 };
 
 export async function createKanban(input: CreateKanbanInput) {
   return await command.kanban.create({
     workspaceSlugName: input.workspaceSlug,
     name: input.name,
+    templateId: input.templateId,
   });
 }
@@ -0,0 +1,17 @@
+import type { KanbanAutomationRuleInput } from '~/models/kanbanAutomation';
+
+import { command } from '~/external/tauri/command';
+
+type CreateKanbanAutomationRuleInput = {
+  workspaceSlug: string;
+  kanbanId: number;
+  rule: KanbanAutomationRuleInput;
+};
+
+export async function createKanbanAutomationRule(input: CreateKanbanAutomationRuleInput) {
+  return await command.kanbanAutomation.create({
+    workspaceSlugName: input.workspaceSlug,
+    kanbanId: input.kanbanId,
+    rule: input.rule,
+  });
+}
@@ -0,0 +1,15 @@
+import { command } from '~/external/tauri/command';
+
+type CreateKanbanTemplateFromBoardInput = {
+  workspaceSlug: string;
+  kanbanId: number;
+  name: string;
+};
+
+export async function createKanbanTemplateFromBoard(input: CreateKanbanTemplateFromBoardInput) {
+  return await command.kanbanTemplate.createFromBoard({
+    workspaceSlugName: input.workspaceSlug,
+    kanbanId: input.kanbanId,
+    name: input.name,
+  });
+}
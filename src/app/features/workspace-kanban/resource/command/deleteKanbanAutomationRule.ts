@@ -0,0 +1,13 @@
+import { command } from '~/external/tauri/command';
+
+type DeleteKanbanAutomationRuleInput = {
+  workspaceSlug: string;
+  id: number;
+};
+
+export async function deleteKanbanAutomationRule(input: DeleteKanbanAutomationRuleInput) {
+  await command.kanbanAutomation.delete({
+    workspaceSlugName: input.workspaceSlug,
+    id: input.id,
+  });
+}
@@ -0,0 +1,13 @@
+import { command } from '~/external/tauri/command';
+
+type DeleteKanbanTemplateInput = {
+  workspaceSlug: string;
+  id: number;
+};
+
+export async function deleteKanbanTemplate(input: DeleteKanbanTemplateInput) {
+  await command.kanbanTemplate.delete({
+    workspaceSlugName: input.workspaceSlug,
+    id: input.id,
+  });
+}
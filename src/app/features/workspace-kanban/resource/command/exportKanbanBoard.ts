@@ -0,0 +1,30 @@
+import { save } from '@tauri-apps/plugin-dialog';
+
+import type { KanbanExportFormat } from '~/models/kanbanTransfer';
+
+import { command } from '~/resources/command';
+
+type ExportKanbanBoardInput = {
+  workspaceSlug: string;
+  kanbanId: number;
+  format: KanbanExportFormat;
+  defaultFileName: string;
+};
+
+/** Returns false when the save dialog is dismissed. */
+export async function exportKanbanBoard(input: ExportKanbanBoardInput) {
+  const path = await save({
+    title: `Export ${input.format.toUpperCase()}`,
+    defaultPath: `${input.defaultFileName}.${input.format}`,
+    filters: [{ name: input.format.toUpperCase(), extensions: [input.format] }],
+  });
+  if (!path) return false;
+
+  await command.kanban.exportBoard({
+    workspaceSlugName: input.workspaceSlug,
+    kanbanId: input.kanbanId,
+    path,
+    format: input.format,
+  });
+  return true;
+}
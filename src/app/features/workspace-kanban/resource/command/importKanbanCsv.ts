@@ -0,0 +1,26 @@
+import { open } from '@tauri-apps/plugin-dialog';
+
+import { command } from '~/resources/command';
+
+type ImportKanbanCsvInput = {
+  workspaceSlug: string;
+  kanbanId: number;
+  createMissingStatuses: boolean;
+};
+
+/** Returns null when the open dialog is dismissed. */
+export async function importKanbanCsv(input: ImportKanbanCsvInput) {
+  const path = await open({
+    multiple: false,
+    directory: false,
+    filters: [{ name: 'CSV', extensions: ['csv'] }],
+  });
+  if (typeof path !== 'string') return null;
+
+  return await command.kanban.importCsv({
+    workspaceSlugName: input.workspaceSlug,
+    kanbanId: input.kanbanId,
+    path,
+    createMissingStatuses: input.createMissingStatuses,
+  });
+}
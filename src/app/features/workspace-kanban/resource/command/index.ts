@@ -1,8 +1,20 @@
+export { cloneKanban } from './cloneKanban';
 export { createKanban } from './createKanban';
+export { createKanbanAutomationRule } from './createKanbanAutomationRule';
 export { createKanbanStatus } from './createKanbanStatus';
+export { createKanbanTemplateFromBoard } from './createKanbanTemplateFromBoard';
 export { deleteKanban } from './deleteKanban';
+export { deleteKanbanAutomationRule } from './deleteKanbanAutomationRule';
 export { deleteKanbanStatus } from './deleteKanbanStatus';
+export { deleteKanbanTemplate } from './deleteKanbanTemplate';
+export { exportKanbanBoard } from './exportKanbanBoard';
+export { importKanbanCsv } from './importKanbanCsv';
+export { moveKanbanCard } from './moveKanbanCard';
+export { moveKanbanCardsToStatus } from './moveKanbanCardsToStatus';
 export { reorderKanbanStatuses } from './reorderKanbanStatuses';
+export { setKanbanTransitionRules } from './setKanbanTransitionRules';
+export { updateKanbanAutomationRule } from './updateKanbanAutomationRule';
 export { updateKanbanStatus } from './updateKanbanStatus';
 export { updateKanbanStatusRoles } from './updateKanbanStatusRoles';
+export { updateKanbanStatusWipLimit } from './updateKanbanStatusWipLimit';
 export { upsertKanbanAssignmentStatus } from './upsertKanbanAssignmentStatus';
@@ -0,0 +1,24 @@
+import { command } from '~/resources/command';
+
+type MoveKanbanCardInput = {
+  workspaceSlug: string;
+  memoSlug: string;
+  kanbanId: number;
+  kanbanStatusId: number;
+  afterMemoSlug?: string | null;
+  beforeMemoSlug?: string | null;
+  force?: boolean;
+};
+
+export async function moveKanbanCard(input: MoveKanbanCardInput) {
+  const result = await command.kanbanAssignment.moveCard({
+    workspaceSlugName: input.workspaceSlug,
+    memoSlugTitle: input.memoSlug,
+    kanbanId: input.kanbanId,
+    kanbanStatusId: input.kanbanStatusId,
+    afterMemoSlugTitle: input.afterMemoSlug,
+    beforeMemoSlugTitle: input.beforeMemoSlug,
+    force: input.force,
+  });
+  return result.positions;
+}
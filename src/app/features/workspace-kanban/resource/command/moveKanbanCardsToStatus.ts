@@ -0,0 +1,20 @@
+import { command } from '~/resources/command';
+
+type MoveKanbanCardsToStatusInput = {
+  workspaceSlug: string;
+  kanbanId: number;
+  memoSlugs: string[];
+  kanbanStatusId: number;
+  force?: boolean;
+};
+
+export async function moveKanbanCardsToStatus(input: MoveKanbanCardsToStatusInput) {
+  const result = await command.kanbanAssignment.moveCardsToStatus({
+    workspaceSlugName: input.workspaceSlug,
+    kanbanId: input.kanbanId,
+    memoSlugTitles: input.memoSlugs,
+    kanbanStatusId: input.kanbanStatusId,
+    force: input.force,
+  });
+  return result.moved_count;
+}
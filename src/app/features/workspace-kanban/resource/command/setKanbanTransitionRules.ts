@@ -0,0 +1,17 @@
+import type { KanbanStatusTransitionRule } from '~/models/kanbanStatus';
+
+import { command } from '~/resources/command';
+
+type SetKanbanTransitionRulesInput = {
+  workspaceSlug: string;
+  kanbanId: number;
+  rules: KanbanStatusTransitionRule[];
+};
+
+export async function setKanbanTransitionRules(input: SetKanbanTransitionRulesInput) {
+  await command.kanbanStatus.setTransitionRules({
+    workspaceSlugName: input.workspaceSlug,
+    kanbanId: input.kanbanId,
+    rules: input.rules,
+  });
+}
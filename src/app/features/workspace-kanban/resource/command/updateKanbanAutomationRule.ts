@@ -0,0 +1,17 @@
+import type { KanbanAutomationRuleInput } from '~/models/kanbanAutomation';
+
+import { command } from '~/external/tauri/command';
+
+type UpdateKanbanAutomationRuleInput = {
+  workspaceSlug: string;
+  id: number;
+  rule: KanbanAutomationRuleInput;
+};
+
+export async function updateKanbanAutomationRule(input: UpdateKanbanAutomationRuleInput) {
+  return await command.kanbanAutomation.update({
+    workspaceSlugName: input.workspaceSlug,
+    id: input.id,
+    rule: input.rule,
+  });
+}
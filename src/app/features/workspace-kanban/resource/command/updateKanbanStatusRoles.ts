@@ -5,6 +5,7 @@ type UpdateKanbanStatusRolesInput = {
   kanbanId: number;
   defaultStatusId?: number | null;
   focusStatusId?: number | null;
+  doneStatusId?: number | null;
 };
 
 export async function updateKanbanStatusRoles(input: UpdateKanbanStatusRolesInput) {
@@ -13,5 +14,6 @@ export async function updateKanbanStatusRoles(input: UpdateKanbanStatusRolesInpu
     id: input.kanbanId,
     defaultStatusId: input.defaultStatusId,
     focusStatusId: input.focusStatusId,
+    doneStatusId: input.doneStatusId,
   });
 }
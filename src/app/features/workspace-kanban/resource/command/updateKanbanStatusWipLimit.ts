@@ -0,0 +1,17 @@
+import { command } from '~/resources/command';
+
+type UpdateKanbanStatusWipLimitInput = {
+  workspaceSlug: string;
+  kanbanId: number;
+  id: number;
+  wipLimit: number | null;
+};
+
+export async function updateKanbanStatusWipLimit(input: UpdateKanbanStatusWipLimitInput) {
+  await command.kanbanStatus.updateWipLimit({
+    workspaceSlugName: input.workspaceSlug,
+    kanbanId: input.kanbanId,
+    id: input.id,
+    wipLimit: input.wipLimit,
+  });
+}
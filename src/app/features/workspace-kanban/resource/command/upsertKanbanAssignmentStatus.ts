@@ -6,6 +6,7 @@ type UpsertKanbanAssignmentStatusInput = {
   kanbanId: number;
   kanbanStatusId?: number | null;
   position?: number | null;
+  force?: boolean;
 };
 
 export async function upsertKanbanAssignmentStatus(input: UpsertKanbanAssignmentStatusInput) {
@@ -15,5 +16,6 @@ export async function upsertKanbanAssignmentStatus(input: UpsertKanbanAssignment
     kanbanId: input.kanbanId,
     kanbanStatusId: input.kanbanStatusId,
     position: input.position,
+    force: input.force,
   });
 }
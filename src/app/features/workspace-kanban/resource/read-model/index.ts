@@ -2,12 +2,14 @@ import { computed } from 'vue';
 
 import type { ComputedRef } from 'vue';
 import type { Kanban } from '~/models/kanban';
+import type { KanbanLane, KanbanLaneGroupBy } from '~/models/kanbanLane';
 import type { KanbanStatus } from '~/models/kanbanStatus';
 import type { ResourceSnapshot } from '~/resource-runtime/infra/types';
 
 import { defineReadModel } from '~/resource-runtime/read-model';
 import { useQuery } from '~/resource-runtime/useQuery';
 import { workspaceKanbansQuery } from '~/resources/kanban/queries';
+import { kanbanLanesQuery } from '~/resources/kanban-assignment/queries';
 import { workspaceKanbanStatusesQuery } from '~/resources/kanban-status/queries';
 
 export type WorkspaceKanbanCollectionReadModel = {
@@ -32,6 +34,22 @@ export type WorkspaceKanbanStatusCollectionReadModel = {
   };
 };
 
+export type WorkspaceKanbanLaneCollectionReadModel = {
+  data: {
+    items: KanbanLane[];
+  };
+  flags: {
+    isLoading: boolean;
+    isStale: boolean;
+    hasError: boolean;
+  };
+};
+
+export type KanbanLaneGrouping = {
+  groupBy: KanbanLaneGroupBy;
+  groupKanbanId: number | null;
+};
+
 const emptyStatusSnapshot: ResourceSnapshot<KanbanStatus[]> = {
   current: [],
   status: 'idle',
@@ -75,3 +93,38 @@ export function useWorkspaceKanbanStatusCollectionReadModel(
     snapshots: [statusSnapshot],
   });
 }
+
+const emptyLaneSnapshot: ResourceSnapshot<KanbanLane[]> = {
+  current: [],
+  status: 'idle',
+  updatedAt: null,
+  loadingSince: null,
+};
+
+export function useWorkspaceKanbanLaneCollectionReadModel(
+  workspaceSlug: ComputedRef<string>,
+  kanbanId: ComputedRef<number | null>,
+  grouping: ComputedRef<KanbanLaneGrouping | null>,
+) {
+  const isEnabled = computed(() => kanbanId.value !== null && grouping.value !== null);
+  const { snapshot: querySnapshot } = useQuery(kanbanLanesQuery, {
+    workspaceSlug,
+    kanbanId: computed(() => kanbanId.value ?? 0),
+    groupBy: computed(() => grouping.value?.groupBy ?? 'milestone'),
+    groupKanbanId: computed(() => grouping.value?.groupKanbanId ?? null),
+  }, { enabled: isEnabled });
+
+  const laneSnapshot = computed(() => {
+    if (!isEnabled.value) {
+      return emptyLaneSnapshot;
+    }
+    return querySnapshot.value;
+  });
+
+  const items = computed<KanbanLane[]>(() => laneSnapshot.value.current ?? []);
+
+  return defineReadModel<WorkspaceKanbanLaneCollectionReadModel['data']>({
+    data: computed(() => ({ items: items.value })),
+    snapshots: [laneSnapshot],
+  });
+}
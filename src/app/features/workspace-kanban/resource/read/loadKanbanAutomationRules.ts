@@ -0,0 +1,17 @@
+import type { KanbanAutomationRule } from '~/models/kanbanAutomation';
+
+import { command } from '~/external/tauri/command';
+
+type LoadKanbanAutomationRulesOptions = {
+  workspaceSlug: string;
+  kanbanId: number;
+};
+
+export async function loadKanbanAutomationRules(
+  options: LoadKanbanAutomationRulesOptions,
+): Promise<KanbanAutomationRule[]> {
+  return await command.kanbanAutomation.list({
+    workspaceSlugName: options.workspaceSlug,
+    kanbanId: options.kanbanId,
+  });
+}
@@ -0,0 +1,13 @@
+import type { KanbanTemplate } from '~/models/kanbanTemplate';
+
+import { command } from '~/external/tauri/command';
+
+type LoadKanbanTemplatesOptions = {
+  workspaceSlug: string;
+};
+
+export async function loadKanbanTemplates(options: LoadKanbanTemplatesOptions): Promise<KanbanTemplate[]> {
+  return await command.kanbanTemplate.list({
+    workspaceSlugName: options.workspaceSlug,
+  });
+}
@@ -0,0 +1,17 @@
+import type { KanbanStatusTransitionRule } from '~/models/kanbanStatus';
+
+import { command } from '~/resources/command';
+
+type LoadKanbanTransitionRulesOptions = {
+  workspaceSlug: string;
+  kanbanId: number;
+};
+
+export async function loadKanbanTransitionRules(
+  options: LoadKanbanTransitionRulesOptions,
+): Promise<KanbanStatusTransitionRule[]> {
+  return await command.kanbanStatus.listTransitionRules({
+    workspaceSlugName: options.workspaceSlug,
+    kanbanId: options.kanbanId,
+  });
+}
@@ -3,6 +3,7 @@ import { computed, ref } from 'vue';
 
 import { useKanbanOrdering } from './useKanbanOrdering';
 import { buildKanbanColumnsFromEntries } from '../../kanbanUtils';
+import { moveKanbanCard } from '../../resource/command/moveKanbanCard';
 import { upsertKanbanAssignmentStatus } from '../../resource/command/upsertKanbanAssignmentStatus';
 
 import type { KanbanColumn } from '../../kanbanUtils';
@@ -13,6 +14,10 @@ vi.mock('../../resource/command/upsertKanbanAssignmentStatus', () => ({
   upsertKanbanAssignmentStatus: vi.fn(),
 }));
 
+vi.mock('../../resource/command/moveKanbanCard', () => ({
+  moveKanbanCard: vi.fn(),
+}));
+
 const makeStatus = (overrides: Partial<KanbanStatus> = {}): KanbanStatus => ({
   id: 1,
   workspace_id: 1,
@@ -83,6 +88,7 @@ const setupOrdering = (entriesValue: KanbanAssignmentItem[], statusesValue: Kanb
 
 describe('useKanbanOrdering', () => {
   const upsertStatusMock = vi.mocked(upsertKanbanAssignmentStatus);
+  const moveCardMock = vi.mocked(moveKanbanCard);
 
   beforeEach(() => {
     vi.clearAllMocks();
@@ -145,6 +151,7 @@ describe('useKanbanOrdering', () => {
 
     expect(entries[0].kanban_status_id).toBeNull();
     expect(upsertStatusMock).not.toHaveBeenCalled();
+    expect(moveCardMock).not.toHaveBeenCalled();
     expect(ordering.buildColumns).toHaveBeenCalledTimes(1);
   });
 
@@ -155,6 +162,7 @@ describe('useKanbanOrdering', () => {
     ];
     const statuses = [makeStatus({ id: 1 }), makeStatus({ id: 2, name: 'Done' })];
     const ordering = setupOrdering(entries, statuses);
+    moveCardMock.mockResolvedValue([{ memo_id: 1, position: 2000 }]);
 
     await ordering.handleDrop({
       from: { columnId: 'status:1', itemId: '1' },
@@ -163,17 +171,17 @@ describe('useKanbanOrdering', () => {
 
     expect(entries[0].kanban_status_id).toBe(2);
     expect(entries[0].position).toBe(2000);
-    expect(upsertStatusMock).toHaveBeenCalledWith({
+    expect(moveCardMock).toHaveBeenCalledWith({
       workspaceSlug: 'ws',
       memoSlug: 'memo-1',
       kanbanId: 1,
       kanbanStatusId: 2,
-      position: 2000,
+      afterMemoSlug: 'memo-2',
+      beforeMemoSlug: null,
     });
   });
 
-  it('reindexes when neighbor gaps are too small', async () => {
-    vi.useFakeTimers();
+  it('applies the positions the backend renumbered', async () => {
     const entries = [
       makeEntry({ memo_id: 1, slug_title: 'memo-1', position: 1000, modified_at: '2024-01-01T00:00:00Z' }),
       makeEntry({ memo_id: 2, slug_title: 'memo-2', position: 1001, modified_at: '2024-01-02T00:00:00Z' }),
@@ -181,31 +189,28 @@ describe('useKanbanOrdering', () => {
     ];
     const statuses = [makeStatus({ id: 1 })];
     const ordering = setupOrdering(entries, statuses);
+    moveCardMock.mockResolvedValue([
+      { memo_id: 3, position: 2000 },
+      { memo_id: 2, position: 3000 },
+    ]);
 
     await ordering.handleDrop({
       from: { columnId: 'status:1', itemId: '3' },
       to: { columnId: 'status:1', beforeItemId: '2' },
     });
 
-    await vi.runAllTimersAsync();
-
     expect(entries[0].position).toBe(1000);
     expect(entries[2].position).toBe(2000);
     expect(entries[1].position).toBe(3000);
-    expect(upsertStatusMock).toHaveBeenCalledWith({
+    expect(moveCardMock).toHaveBeenCalledWith({
       workspaceSlug: 'ws',
       memoSlug: 'memo-3',
       kanbanId: 1,
       kanbanStatusId: 1,
-      position: 2000,
-    });
-    expect(upsertStatusMock).toHaveBeenCalledWith({
-      workspaceSlug: 'ws',
-      memoSlug: 'memo-2',
-      kanbanId: 1,
-      kanbanStatusId: 1,
-      position: 3000,
+      afterMemoSlug: 'memo-1',
+      beforeMemoSlug: 'memo-2',
     });
+    expect(upsertStatusMock).not.toHaveBeenCalled();
   });
 
   it('assigns a new status and appends to the end', async () => {
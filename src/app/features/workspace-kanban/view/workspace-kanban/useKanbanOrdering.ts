@@ -1,31 +1,33 @@
-// Handles Kanban ordering for memo cards: computes positions on drag/assign,
-// persists changes to the backend, seeds missing positions, and schedules
-// reindexing when gaps are too small or positions are missing. Unassigned
+// Handles Kanban ordering for memo cards: moves cards on drag/assign,
+// persists changes to the backend, and seeds missing positions. Unassigned
 // memos (kanban_status_id: null) are treated as non-draggable.
 //
 // Rules:
 // - Positions use 1000-based spacing to allow in-between inserts.
-// - Drag/drop computes a new position based on neighbors in the destination.
-// - If neighbor gap is too small or any null positions exist, reindex the column.
-// - Reindex rewrites positions to (index + 1) * 1000 in sorted order.
+// - Drag/drop sends the destination neighbors to the backend, which picks the
+//   position and renumbers the column when the gap is too small or positions
+//   are missing. The returned positions replace the optimistic one.
 // - Seed assigns positions for null entries per status, newest first.
 // - Assigning a status appends to the end using max position + 1000.
 // - UI updates optimistically; failures revert local state and show a toast.
+// - WIP limit and transition rule conflicts offer a "Move anyway" retry with force.
 import { ref } from 'vue';
 
 import {
   computePosition,
-  getNeighborPositions,
+  getNeighborItems,
   getNextPositionForStatus,
   toStatusId,
   type KanbanColumn,
 } from '../../kanbanUtils';
+import { moveKanbanCard } from '../../resource/command/moveKanbanCard';
 import { upsertKanbanAssignmentStatus } from '../../resource/command/upsertKanbanAssignmentStatus';
 
 import type { ComputedRef, Ref } from 'vue';
-import type { KanbanAssignmentItem } from '~/models/kanbanAssignment';
+import type { KanbanAssignmentItem, KanbanCardPosition } from '~/models/kanbanAssignment';
 import type { KanbanStatus } from '~/models/kanbanStatus';
 
+import { AppError } from '~/utils/error';
 import { iconKey } from '~/utils/icon';
 
 type UseKanbanOrderingOptions = {
@@ -48,12 +50,12 @@ export function useKanbanOrdering(options: UseKanbanOrderingOptions) {
   };
   const isAssigning = ref(false);
   const isSeedingPositions = ref(false);
-  const reindexTimers = new Map<string, number>();
 
   const persistStatusUpdate = async (
     memoSlugTitle: string,
     statusId: number | null,
     position: number | null,
+    force?: boolean,
   ) => {
     if (!options.workspaceSlug.value) return;
     if (options.kanbanId.value == null) return;
@@ -63,6 +65,27 @@ export function useKanbanOrdering(options: UseKanbanOrderingOptions) {
       kanbanId: options.kanbanId.value,
       kanbanStatusId: statusId,
       position,
+      force,
+    });
+  };
+
+  const isOverridableConflict = (error: unknown): error is AppError => {
+    return error instanceof AppError
+      && (error.reason === 'WIP_LIMIT_EXCEEDED' || error.reason === 'TRANSITION_NOT_ALLOWED');
+  };
+
+  const showBlockedMoveToast = (error: AppError, retry: () => Promise<void>) => {
+    options.toast.add({
+      title: 'Move blocked by board rules.',
+      description: error.message,
+      color: 'warning',
+      icon: iconKey.failed,
+      actions: [{
+        label: 'Move anyway',
+        onClick: () => {
+          void retry();
+        },
+      }],
     });
   };
 
@@ -118,57 +141,13 @@ export function useKanbanOrdering(options: UseKanbanOrderingOptions) {
     }
   };
 
-  const scheduleReindex = (statusId: number) => {
-    if (!options.workspaceSlug.value) return;
-    const key = String(statusId);
-    const existing = reindexTimers.get(key);
-    if (existing) {
-      window.clearTimeout(existing);
-    }
-
-    const timer = window.setTimeout(async () => {
-      const reindexStartedAt = performance.now();
-      reindexTimers.delete(key);
-      if (options.kanbanId.value == null) return;
-      const items = options.entries.value.filter(entry => entry.kanban_status_id === statusId);
-      if (items.length === 0) return;
-
-      const sorted = [...items].sort((a, b) => {
-        const aPos = a.position ?? Number.POSITIVE_INFINITY;
-        const bPos = b.position ?? Number.POSITIVE_INFINITY;
-        if (aPos !== bPos) return aPos - bPos;
-        return b.modified_at.localeCompare(a.modified_at);
-      });
-
-      const updates = sorted.map((entry, index) => ({
-        entry,
-        position: (index + 1) * 1000,
-      })).filter((update) => {
-        return update.entry.position !== update.position;
-      });
-
-      if (updates.length === 0) return;
-
-      try {
-        await persistPositions(updates);
-        const reindexFinishedAt = performance.now();
-        logInfo('[Kanban] reindex:db-updated', {
-          statusId,
-          elapsedMs: Math.round(reindexFinishedAt - reindexStartedAt),
-          count: updates.length,
-        });
-
-        for (const update of updates) {
-          update.entry.position = update.position;
-        }
-        options.buildColumns();
-      }
-      catch (error) {
-        console.error(error);
+  const applyPositions = (positions: KanbanCardPosition[]) => {
+    for (const update of positions) {
+      const entry = options.entries.value.find(item => item.memo_id === update.memo_id);
+      if (entry) {
+        entry.position = update.position;
       }
-    }, 250);
-
-    reindexTimers.set(key, timer);
+    }
   };
 
   const handleDrop = async (payload: {
@@ -197,21 +176,9 @@ export function useKanbanOrdering(options: UseKanbanOrderingOptions) {
       return;
     }
 
-    const needsReindex = (() => {
-      if (!destinationColumn) return false;
-      if (destinationColumn.items.some(item => item.position === null)) {
-        return true;
-      }
-      const { prevPos, nextPos } = getNeighborPositions(
-        destinationColumn.items,
-        payload.from.itemId,
-        payload.to.beforeItemId,
-      );
-      if (prevPos !== null && nextPos !== null && nextPos - prevPos <= 1) {
-        return true;
-      }
-      return false;
-    })();
+    const neighbors = destinationColumn
+      ? getNeighborItems(destinationColumn.items, payload.from.itemId, payload.to.beforeItemId)
+      : { prev: undefined, next: undefined };
 
     const target = options.entries.value.find(item => item.memo_id === memo.memo_id);
     const previousState = target
@@ -223,45 +190,63 @@ export function useKanbanOrdering(options: UseKanbanOrderingOptions) {
 
     if (!target) return;
 
-    target.kanban_status_id = nextStatusId;
-    target.position = nextPosition;
-    options.buildColumns();
+    const persistMove = async (force?: boolean) => {
+      target.kanban_status_id = nextStatusId;
+      target.position = nextPosition;
+      options.buildColumns();
 
-    const persistMove = async () => {
-      await persistStatusUpdate(memo.slug_title, nextStatusId, nextPosition);
+      if (!options.workspaceSlug.value || options.kanbanId.value == null) return;
+      const positions = await moveKanbanCard({
+        workspaceSlug: options.workspaceSlug.value,
+        memoSlug: memo.slug_title,
+        kanbanId: options.kanbanId.value,
+        kanbanStatusId: nextStatusId,
+        afterMemoSlug: neighbors.prev?.slug ?? null,
+        beforeMemoSlug: neighbors.next?.slug ?? null,
+        force,
+      });
       const dropFinishedAt = performance.now();
       logInfo('[Kanban] drop:db-updated', {
         memoId: memo.memo_id,
         elapsedMs: Math.round(dropFinishedAt - dropStartedAt),
+        renumbered: positions.length - 1,
       });
 
-      if (needsReindex) {
-        scheduleReindex(nextStatusId);
-      }
+      applyPositions(positions);
+      options.buildColumns();
     };
 
-    try {
-      await persistMove();
-    }
-    catch (error) {
-      console.error(error);
-      if (previousState) {
-        target.kanban_status_id = previousState.kanban_status_id;
-        target.position = previousState.position;
-        options.buildColumns();
+    const attemptMove = async (force?: boolean) => {
+      try {
+        await persistMove(force);
       }
-      options.toast.add({
-        title: 'Failed to move memo.',
-        description: 'Please try again.',
-        color: 'error',
-        icon: iconKey.failed,
-      });
-    }
+      catch (error) {
+        console.error(error);
+        if (previousState) {
+          target.kanban_status_id = previousState.kanban_status_id;
+          target.position = previousState.position;
+          options.buildColumns();
+        }
+        if (!force && isOverridableConflict(error)) {
+          showBlockedMoveToast(error, () => attemptMove(true));
+          return;
+        }
+        options.toast.add({
+          title: 'Failed to move memo.',
+          description: 'Please try again.',
+          color: 'error',
+          icon: iconKey.failed,
+        });
+      }
+    };
+
+    await attemptMove();
   };
 
   const assignStatus = async (
     memo: { memoId: number; slug: string; title: string; description?: string; modifiedAt: string },
     statusId: number,
+    force?: boolean,
   ) => {
     if (!options.workspaceSlug.value) return;
     if (options.kanbanId.value == null) return;
@@ -280,7 +265,7 @@ export function useKanbanOrdering(options: UseKanbanOrderingOptions) {
         existing.position = nextPosition;
       }
 
-      await persistStatusUpdate(memo.slug, statusId, nextPosition);
+      await persistStatusUpdate(memo.slug, statusId, nextPosition, force);
 
       if (!existing) {
         options.entries.value.push({
@@ -303,6 +288,10 @@ export function useKanbanOrdering(options: UseKanbanOrderingOptions) {
         existing.position = previousState.position;
       }
       options.buildColumns();
+      if (!force && isOverridableConflict(error)) {
+        showBlockedMoveToast(error, () => assignStatus(memo, statusId, true));
+        return;
+      }
       options.toast.add({
         title: 'Failed to assign memo.',
         description: 'Please try again.',
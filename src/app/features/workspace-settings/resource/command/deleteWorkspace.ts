@@ -1,5 +1,10 @@
 import { command } from '~/resources/command';
 
-export async function deleteWorkspace(slugName: string) {
-  await command.workspace.delete({ slugName });
+type DeleteWorkspaceInput = {
+  slugName: string;
+  confirmationToken: string;
+};
+
+export async function deleteWorkspace(input: DeleteWorkspaceInput) {
+  await command.workspace.delete(input);
 }
@@ -0,0 +1,4 @@
+export { deleteWorkspace } from './deleteWorkspace';
+export { listArchivedWorkspaces } from './listArchivedWorkspaces';
+export { prepareWorkspaceDeletion } from './prepareWorkspaceDeletion';
+export { setWorkspaceArchived } from './setWorkspaceArchived';
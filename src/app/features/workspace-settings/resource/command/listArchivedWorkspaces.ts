@@ -0,0 +1,5 @@
+import { command } from '~/resources/command';
+
+export async function listArchivedWorkspaces() {
+  return await command.workspace.listArchived();
+}
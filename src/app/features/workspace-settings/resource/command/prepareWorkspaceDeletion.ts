@@ -0,0 +1,5 @@
+import { command } from '~/resources/command';
+
+export async function prepareWorkspaceDeletion(slugName: string) {
+  return await command.workspace.prepareDeletion({ slugName });
+}
@@ -0,0 +1,10 @@
+import { command } from '~/resources/command';
+
+type SetWorkspaceArchivedInput = {
+  slugName: string;
+  archived: boolean;
+};
+
+export async function setWorkspaceArchived(input: SetWorkspaceArchivedInput) {
+  return await command.workspace.setArchived(input);
+}
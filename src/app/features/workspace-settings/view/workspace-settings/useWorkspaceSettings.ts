@@ -1,9 +1,14 @@
 import { computed, ref, watch } from 'vue';
 
-import { deleteWorkspace as executeDeleteWorkspace } from '../../resource/command/deleteWorkspace';
+import {
+  deleteWorkspace as executeDeleteWorkspace,
+  prepareWorkspaceDeletion,
+  setWorkspaceArchived,
+} from '../../resource/command';
 import { useCurrentWorkspaceReadModel } from '../../resource/read-model';
 
 import type { useToast } from '#imports';
+import type { WorkspaceDeletionSummary } from '~/models/workspace';
 
 import { workspaceQuery } from '~/resources/workspace/queries';
 import { iconKey } from '~/utils/icon';
@@ -37,36 +42,73 @@ export function useWorkspaceSettings(options: UseWorkspaceSettingsOptions) {
     hasWorkspaceContext.value && currentWorkspaceReadModel.value.flags.isLoading,
   );
 
-  const activePanel = ref<'mcp-server' | 'global-shortcuts' | 'appearance' | 'storage-paths' | 'files' | 'memo-templates' | 'statuses' | 'danger-zone'>(
+  const activePanel = ref<'mcp-server' | 'global-shortcuts' | 'appearance' | 'storage-paths' | 'files' | 'archived-workspaces' | 'memo-templates' | 'statuses' | 'danger-zone'>(
     hasWorkspaceContext.value ? 'memo-templates' : 'mcp-server',
   );
   const isDeleteConfirmationOpen = ref(false);
+  const deletionSummary = ref<WorkspaceDeletionSummary | null>(null);
+  const isPreparingDeletion = ref(false);
+  const isDeleting = ref(false);
+  const isArchiving = ref(false);
 
   watch(hasWorkspaceContext, (next) => {
     activePanel.value = next ? 'memo-templates' : 'mcp-server';
   });
 
-  const openDeleteConfirmation = () => {
-    isDeleteConfirmationOpen.value = true;
+  const notifyWorkspaceMissing = () => {
+    options.toast.add({
+      title: 'Workspace is not selected.',
+      color: 'error',
+      icon: iconKey.failed,
+    });
   };
 
-  const closeDeleteConfirmation = () => {
-    isDeleteConfirmationOpen.value = false;
-  };
-
-  const deleteWorkspace = async () => {
+  // The summary's token is what `deleteWorkspace` sends back, so the user always confirms
+  // the counts the deletion is checked against.
+  const openDeleteConfirmation = async () => {
     const slugName = currentWorkspace.value?.slug_name;
     if (!slugName) {
+      notifyWorkspaceMissing();
+      return;
+    }
+
+    isPreparingDeletion.value = true;
+    try {
+      deletionSummary.value = await prepareWorkspaceDeletion(slugName);
+      isDeleteConfirmationOpen.value = true;
+    }
+    catch (error) {
+      console.error(error);
       options.toast.add({
-        title: 'Workspace is not selected.',
+        title: 'Failed to prepare deletion.',
+        description: 'Please try again.',
         color: 'error',
         icon: iconKey.failed,
       });
+    }
+    finally {
+      isPreparingDeletion.value = false;
+    }
+  };
+
+  const closeDeleteConfirmation = () => {
+    isDeleteConfirmationOpen.value = false;
+    deletionSummary.value = null;
+  };
+
+  const deleteWorkspace = async () => {
+    const summary = deletionSummary.value;
+    if (!summary) {
+      notifyWorkspaceMissing();
       return;
     }
 
+    isDeleting.value = true;
     try {
-      await executeDeleteWorkspace(slugName);
+      await executeDeleteWorkspace({
+        slugName: summary.workspace_slug_name,
+        confirmationToken: summary.confirmation_token,
+      });
       options.toast.add({
         title: 'Delete successfully.',
         duration: 1000,
@@ -77,13 +119,48 @@ export function useWorkspaceSettings(options: UseWorkspaceSettingsOptions) {
     }
     catch (error) {
       console.error(error);
+      closeDeleteConfirmation();
       options.toast.add({
         title: 'Failed to delete.',
-        description: 'Please delete again.',
+        description: error instanceof Error ? error.message : 'Please delete again.',
         color: 'error',
         icon: iconKey.failed,
       });
     }
+    finally {
+      isDeleting.value = false;
+    }
+  };
+
+  const archiveWorkspace = async () => {
+    const slugName = currentWorkspace.value?.slug_name;
+    if (!slugName) {
+      notifyWorkspaceMissing();
+      return;
+    }
+
+    isArchiving.value = true;
+    try {
+      await setWorkspaceArchived({ slugName, archived: true });
+      options.toast.add({
+        title: 'Workspace archived.',
+        duration: 1000,
+        icon: iconKey.success,
+      });
+      await options.router.replace('/');
+    }
+    catch (error) {
+      console.error(error);
+      options.toast.add({
+        title: 'Failed to archive.',
+        description: 'Please try again.',
+        color: 'error',
+        icon: iconKey.failed,
+      });
+    }
+    finally {
+      isArchiving.value = false;
+    }
   };
 
   const loadWorkspaceSettings = async () => {
@@ -99,9 +176,14 @@ export function useWorkspaceSettings(options: UseWorkspaceSettingsOptions) {
     isWorkspaceLoading,
     activePanel,
     isDeleteConfirmationOpen,
+    deletionSummary,
+    isPreparingDeletion,
+    isDeleting,
+    isArchiving,
     openDeleteConfirmation,
     closeDeleteConfirmation,
     deleteWorkspace,
+    archiveWorkspace,
     loadWorkspaceSettings,
   };
 }
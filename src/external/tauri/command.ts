@@ -2,12 +2,16 @@ import { assetCommand } from './commands/asset';
 import { bookmarkCommand } from './commands/bookmark';
 import { calendarDayCommand } from './commands/calendarDay';
 import { configCommand } from './commands/config';
+import { doctorCommand } from './commands/doctor';
 import { fileCommand } from './commands/file';
 import { focusDailyStateCommand } from './commands/focusDailyState';
 import { htmlExportCommand } from './commands/htmlExport';
 import { kanbanCommand } from './commands/kanban';
 import { kanbanAssignmentCommand } from './commands/kanbanAssignment';
+import { kanbanAutomationCommand } from './commands/kanbanAutomation';
+import { kanbanReportCommand } from './commands/kanbanReport';
 import { kanbanStatusCommand } from './commands/kanbanStatus';
+import { kanbanTemplateCommand } from './commands/kanbanTemplate';
 import { linkCommand } from './commands/link';
 import { memoCommand } from './commands/memo';
 import { memoTemplateCommand } from './commands/memoTemplate';
@@ -18,6 +22,7 @@ import { workspaceCommand } from './commands/workspace';
 export const command = {
   asset: assetCommand,
   config: configCommand,
+  doctor: doctorCommand,
   file: fileCommand,
   focusDailyState: focusDailyStateCommand,
   htmlExport: htmlExportCommand,
@@ -27,6 +32,9 @@ export const command = {
   kanbanStatus: kanbanStatusCommand,
   kanban: kanbanCommand,
   kanbanAssignment: kanbanAssignmentCommand,
+  kanbanAutomation: kanbanAutomationCommand,
+  kanbanReport: kanbanReportCommand,
+  kanbanTemplate: kanbanTemplateCommand,
   link: linkCommand,
   bookmark: bookmarkCommand,
   calendarDay: calendarDayCommand,
@@ -1,7 +1,20 @@
 import { invokeCommand } from '../core/invoker';
 
+import type { OrphanedAssetCleanup, OrphanedAssetScan } from '~/models/asset';
+
 export const assetCommand = {
   readImageAsDataUrl: async (src: string) => {
     return await invokeCommand<string>('read_image_as_data_url', { src });
   },
+
+  scanOrphans: async () => {
+    return await invokeCommand<OrphanedAssetScan>('scan_orphaned_assets');
+  },
+
+  cleanOrphans: async (confirmationToken: string, permanentlyDelete = false) => {
+    return await invokeCommand<OrphanedAssetCleanup>('clean_orphaned_assets', {
+      confirmation_token: confirmationToken,
+      permanently_delete: permanentlyDelete,
+    });
+  },
 } as const;
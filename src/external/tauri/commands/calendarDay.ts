@@ -1,6 +1,6 @@
 import { invokeCommand } from '../core/invoker';
 
-import type { CalendarDay } from '~/models/calendarDay';
+import type { CalendarDay, CalendarImportNoteMode, CalendarImportSummary } from '~/models/calendarDay';
 
 export const calendarDayCommand = {
   list: async (params: { workspaceSlugName: string; year: number }) => {
@@ -46,4 +46,31 @@ export const calendarDayCommand = {
       memo_slug_title: params.memoSlugTitle,
     });
   },
+
+  exportIcs: async (params: { workspaceSlugName: string; path: string }) => {
+    await invokeCommand('export_calendar_ics', {
+      workspace_slug_name: params.workspaceSlugName,
+      path: params.path,
+    });
+  },
+
+  importIcs: async (params: {
+    workspaceSlugName: string;
+    path: string;
+    noteMode: CalendarImportNoteMode;
+    dryRun?: boolean;
+  }) => {
+    return await invokeCommand<CalendarImportSummary>('import_calendar_ics', {
+      workspace_slug_name: params.workspaceSlugName,
+      path: params.path,
+      note_mode: params.noteMode,
+      dry_run: params.dryRun ?? false,
+    });
+  },
+
+  feedUrl: async (params: { workspaceSlugName: string }) => {
+    return await invokeCommand<string>('get_calendar_feed_url', {
+      workspace_slug_name: params.workspaceSlugName,
+    });
+  },
 };
@@ -11,9 +11,28 @@ export type AppConfigPayload = {
   focus_app_shortcut: string;
   new_memo_shortcut: string;
   selection_copy_format: string;
+  import_policy: ImportPolicy;
+  watched_folders: WatchedFolder[];
+  watch_interval_seconds: number;
   mcp_server_url: string;
 };
 
+export type ImportPolicy = 'move' | 'copy' | 'copy_then_trash';
+
+export type InboxRule = {
+  pattern: string;
+  note?: string | null;
+  workspace_slug_name?: string | null;
+  /** A memo slug in the rule's workspace, or `today` for the memo on today's calendar day. */
+  link_to?: string | null;
+};
+
+export type WatchedFolder = {
+  path: string;
+  enabled: boolean;
+  rules: InboxRule[];
+};
+
 export type McpServerInfo = {
   enabled: boolean;
   bind_host: string;
@@ -71,6 +90,17 @@ export const configCommand = {
     return await invokeCommand<AppConfigPayload>('set_selection_copy_format', { format });
   },
 
+  setImportPolicy: async (policy: ImportPolicy) => {
+    return await invokeCommand<AppConfigPayload>('set_import_policy', { policy });
+  },
+
+  setWatchedFolders: async (watchedFolders: WatchedFolder[], watchIntervalSeconds?: number) => {
+    return await invokeCommand<AppConfigPayload>('set_watched_folders', {
+      watched_folders: watchedFolders,
+      watch_interval_seconds: watchIntervalSeconds,
+    });
+  },
+
   setInboxIgnoreFileNames: async (fileNames: string[]) => {
     return await invokeCommand<AppConfigPayload>('set_inbox_ignore_file_names', {
       file_names: fileNames,
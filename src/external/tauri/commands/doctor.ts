@@ -0,0 +1,17 @@
+import { invokeCommand } from '../core/invoker';
+
+import type { DoctorReport } from '~/models/doctor';
+
+export const doctorCommand = {
+  check: async () => {
+    return await invokeCommand<DoctorReport>('run_database_doctor', {
+      repair: false,
+    });
+  },
+
+  repair: async () => {
+    return await invokeCommand<DoctorReport>('run_database_doctor', {
+      repair: true,
+    });
+  },
+};
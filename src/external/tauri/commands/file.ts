@@ -1,12 +1,14 @@
 import { invokeCommand } from '../core/invoker';
 
 import type {
+  InboxActivityPage,
   InboxFilePage,
   ManagedFileDetail,
   MemoLinkedFileItem,
   ManagedFileListPage,
   ManagedFileRecord,
   ResolvedFileOpenTarget,
+  StorageVerificationReport,
 } from '~/models/file';
 
 export const fileCommand = {
@@ -17,6 +19,13 @@ export const fileCommand = {
     });
   },
 
+  listInboxActivity: async (params?: { limit?: number; offset?: number }) => {
+    return await invokeCommand<InboxActivityPage>('list_inbox_activity', {
+      limit: params?.limit,
+      offset: params?.offset,
+    });
+  },
+
   importInboxFile: async (sourcePath: string) => {
     return await invokeCommand<ManagedFileRecord>('import_inbox_file', {
       source_path: sourcePath,
@@ -36,11 +45,12 @@ export const fileCommand = {
     });
   },
 
-  listFiles: async (params?: { limit?: number; offset?: number; unlinkedOnly?: boolean }) => {
+  listFiles: async (params?: { limit?: number; offset?: number; unlinkedOnly?: boolean; query?: string }) => {
     return await invokeCommand<ManagedFileListPage>('list_files', {
       limit: params?.limit,
       offset: params?.offset,
       unlinked_only: params?.unlinkedOnly,
+      query: params?.query,
     });
   },
 
@@ -56,6 +66,16 @@ export const fileCommand = {
     });
   },
 
+  verifyStorage: async () => {
+    return await invokeCommand<StorageVerificationReport>('verify_file_storage');
+  },
+
+  adoptStorageEntry: async (relativePath: string) => {
+    return await invokeCommand<ManagedFileRecord>('adopt_storage_entry', {
+      relative_path: relativePath,
+    });
+  },
+
   openManagedFile: async (fileId: string) => {
     await invokeCommand('open_managed_file', {
       file_id: fileId,
@@ -1,6 +1,8 @@
 import { invokeCommand } from '../core/invoker';
 
 import type { Kanban } from '~/models/kanban';
+import type { KanbanCloneResult } from '~/models/kanbanTemplate';
+import type { KanbanExportFormat, KanbanImportSummary } from '~/models/kanbanTransfer';
 
 export const kanbanCommand = {
   list: async (workspace: { slugName: string }) => {
@@ -9,19 +11,38 @@ export const kanbanCommand = {
     });
   },
 
-  create: async (params: { workspaceSlugName: string; name: string }) => {
+  create: async (params: { workspaceSlugName: string; name: string; templateId?: number | null }) => {
     return await invokeCommand<Kanban>('create_kanban', {
       workspace_slug_name: params.workspaceSlugName,
       name: params.name,
+      template_id: params.templateId ?? null,
     });
   },
 
-  updateStatusRoles: async (params: { workspaceSlugName: string; id: number; defaultStatusId?: number | null; focusStatusId?: number | null }) => {
+  clone: async (params: {
+    workspaceSlugName: string;
+    kanbanId: number;
+    name: string;
+    /** Defaults to the board's own workspace. */
+    targetWorkspaceSlugName?: string | null;
+    includeAssignments?: boolean;
+  }) => {
+    return await invokeCommand<KanbanCloneResult>('clone_kanban', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId,
+      name: params.name,
+      target_workspace_slug_name: params.targetWorkspaceSlugName ?? null,
+      include_assignments: params.includeAssignments ?? false,
+    });
+  },
+
+  updateStatusRoles: async (params: { workspaceSlugName: string; id: number; defaultStatusId?: number | null; focusStatusId?: number | null; doneStatusId?: number | null }) => {
     await invokeCommand('update_kanban_status_roles', {
       workspace_slug_name: params.workspaceSlugName,
       id: params.id,
       default_status_id: params.defaultStatusId ?? null,
       focus_status_id: params.focusStatusId ?? null,
+      done_status_id: params.doneStatusId ?? null,
     });
   },
 
@@ -31,4 +52,22 @@ export const kanbanCommand = {
       id: params.id,
     });
   },
+
+  exportBoard: async (params: { workspaceSlugName: string; kanbanId?: number | null; path: string; format: KanbanExportFormat }) => {
+    await invokeCommand('export_kanban_board', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId ?? null,
+      path: params.path,
+      format: params.format,
+    });
+  },
+
+  importCsv: async (params: { workspaceSlugName: string; kanbanId?: number | null; path: string; createMissingStatuses?: boolean }) => {
+    return await invokeCommand<KanbanImportSummary>('import_kanban_csv', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId ?? null,
+      path: params.path,
+      create_missing_statuses: params.createMissingStatuses ?? false,
+    });
+  },
 };
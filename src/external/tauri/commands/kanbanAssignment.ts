@@ -1,6 +1,15 @@
 import { invokeCommand } from '../core/invoker';
 
-import type { KanbanAssignmentEntry, KanbanAssignmentItem } from '~/models/kanbanAssignment';
+import type {
+  KanbanAssignmentEntry,
+  KanbanAssignmentItem,
+  KanbanBulkMoveResult,
+  KanbanCardMoveResult,
+  KanbanDatedItem,
+  KanbanDateFilter,
+} from '~/models/kanbanAssignment';
+import type { KanbanAutomationOutcome } from '~/models/kanbanAutomation';
+import type { KanbanLane, KanbanLaneGroupBy } from '~/models/kanbanLane';
 
 export const kanbanAssignmentCommand = {
   listItems: async (params: { workspaceSlugName: string; kanbanId: number }) => {
@@ -10,6 +19,21 @@ export const kanbanAssignmentCommand = {
     });
   },
 
+  listLanes: async (params: {
+    workspaceSlugName: string;
+    kanbanId: number;
+    groupBy: KanbanLaneGroupBy;
+    /** Kanban whose statuses form the lanes when grouping by `kanban_status`. */
+    groupKanbanId?: number | null;
+  }) => {
+    return await invokeCommand<KanbanLane[]>('list_kanban_lanes', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId,
+      group_by: params.groupBy,
+      group_kanban_id: params.groupKanbanId ?? null,
+    });
+  },
+
   listEntries: async (params: { workspaceSlugName: string; memoSlugTitle: string }) => {
     return await invokeCommand<KanbanAssignmentEntry[]>('list_kanban_assignment_entries', {
       workspace_slug_name: params.workspaceSlugName,
@@ -23,13 +47,77 @@ export const kanbanAssignmentCommand = {
     kanbanId: number;
     kanbanStatusId?: number | null;
     position?: number | null;
+    /** Skips the WIP limit and transition rule checks. */
+    force?: boolean;
   }) => {
-    await invokeCommand('upsert_kanban_assignment_status', {
+    return await invokeCommand<KanbanAutomationOutcome[]>('upsert_kanban_assignment_status', {
       workspace_slug_name: params.workspaceSlugName,
       memo_slug_title: params.memoSlugTitle,
       kanban_id: params.kanbanId,
       kanban_status_id: params.kanbanStatusId ?? null,
       position: params.position ?? null,
+      force: params.force ?? null,
+    });
+  },
+
+  moveCard: async (params: {
+    workspaceSlugName: string;
+    memoSlugTitle: string;
+    kanbanId: number;
+    kanbanStatusId: number;
+    /** Card that ends up directly above the moved card. */
+    afterMemoSlugTitle?: string | null;
+    /** Card that ends up directly below the moved card. */
+    beforeMemoSlugTitle?: string | null;
+    force?: boolean;
+  }) => {
+    return await invokeCommand<KanbanCardMoveResult>('move_kanban_card', {
+      workspace_slug_name: params.workspaceSlugName,
+      memo_slug_title: params.memoSlugTitle,
+      kanban_id: params.kanbanId,
+      kanban_status_id: params.kanbanStatusId,
+      after_memo_slug_title: params.afterMemoSlugTitle ?? null,
+      before_memo_slug_title: params.beforeMemoSlugTitle ?? null,
+      force: params.force ?? null,
+    });
+  },
+
+  moveCardsToStatus: async (params: {
+    workspaceSlugName: string;
+    kanbanId: number;
+    memoSlugTitles: string[];
+    kanbanStatusId: number;
+    force?: boolean;
+  }) => {
+    return await invokeCommand<KanbanBulkMoveResult>('move_kanban_cards_to_status', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId,
+      memo_slug_titles: params.memoSlugTitles,
+      kanban_status_id: params.kanbanStatusId,
+      force: params.force ?? null,
+    });
+  },
+
+  updateDates: async (params: {
+    workspaceSlugName: string;
+    memoSlugTitle: string;
+    kanbanId: number;
+    dueOn?: string | null;
+    scheduledOn?: string | null;
+  }) => {
+    await invokeCommand('update_kanban_assignment_dates', {
+      workspace_slug_name: params.workspaceSlugName,
+      memo_slug_title: params.memoSlugTitle,
+      kanban_id: params.kanbanId,
+      due_on: params.dueOn ?? null,
+      scheduled_on: params.scheduledOn ?? null,
+    });
+  },
+
+  listDatedItems: async (params: { workspaceSlugName: string; filter: KanbanDateFilter }) => {
+    return await invokeCommand<KanbanDatedItem[]>('list_kanban_dated_items', {
+      workspace_slug_name: params.workspaceSlugName,
+      filter: params.filter,
     });
   },
 
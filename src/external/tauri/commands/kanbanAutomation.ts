@@ -0,0 +1,35 @@
+import { invokeCommand } from '../core/invoker';
+
+import type { KanbanAutomationRule, KanbanAutomationRuleInput } from '~/models/kanbanAutomation';
+
+export const kanbanAutomationCommand = {
+  list: async (params: { workspaceSlugName: string; kanbanId?: number }) => {
+    return await invokeCommand<KanbanAutomationRule[]>('list_kanban_automation_rules', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId ?? null,
+    });
+  },
+
+  create: async (params: { workspaceSlugName: string; kanbanId?: number; rule: KanbanAutomationRuleInput }) => {
+    return await invokeCommand<KanbanAutomationRule>('create_kanban_automation_rule', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId ?? null,
+      rule: params.rule,
+    });
+  },
+
+  update: async (params: { workspaceSlugName: string; id: number; rule: KanbanAutomationRuleInput }) => {
+    return await invokeCommand<KanbanAutomationRule>('update_kanban_automation_rule', {
+      workspace_slug_name: params.workspaceSlugName,
+      id: params.id,
+      rule: params.rule,
+    });
+  },
+
+  delete: async (params: { workspaceSlugName: string; id: number }) => {
+    await invokeCommand('delete_kanban_automation_rule', {
+      workspace_slug_name: params.workspaceSlugName,
+      id: params.id,
+    });
+  },
+};
@@ -0,0 +1,30 @@
+import { invokeCommand } from '../core/invoker';
+
+import type { KanbanReport, KanbanStatusTransition } from '~/models/kanbanReport';
+
+export const kanbanReportCommand = {
+  get: async (params: { workspaceSlugName: string; kanbanId: number; dateFrom?: string; dateTo?: string }) => {
+    return await invokeCommand<KanbanReport>('get_kanban_report', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId,
+      date_from: params.dateFrom,
+      date_to: params.dateTo,
+    });
+  },
+
+  listTransitions: async (params: {
+    workspaceSlugName: string;
+    kanbanId: number;
+    memoSlugTitle?: string;
+    limit?: number;
+    offset?: number;
+  }) => {
+    return await invokeCommand<KanbanStatusTransition[]>('list_kanban_status_transitions', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId,
+      memo_slug_title: params.memoSlugTitle,
+      limit: params.limit,
+      offset: params.offset,
+    });
+  },
+};
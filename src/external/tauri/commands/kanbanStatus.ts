@@ -1,6 +1,6 @@
 import { invokeCommand } from '../core/invoker';
 
-import type { KanbanStatus } from '~/models/kanbanStatus';
+import type { KanbanStatus, KanbanStatusTransitionRule } from '~/models/kanbanStatus';
 
 const normalizeColor = (color?: string) => {
   const trimmed = color?.trim();
@@ -43,6 +43,33 @@ export const kanbanStatusCommand = {
     });
   },
 
+  updateWipLimit: async (params: { workspaceSlugName: string; id: number; wipLimit: number | null }) => {
+    await invokeCommand('update_kanban_status_wip_limit', {
+      workspace_slug_name: params.workspaceSlugName,
+      id: params.id,
+      wip_limit: params.wipLimit,
+    });
+  },
+
+  listTransitionRules: async (params: { workspaceSlugName: string; kanbanId?: number }) => {
+    return await invokeCommand<KanbanStatusTransitionRule[]>('list_kanban_transition_rules', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId ?? null,
+    });
+  },
+
+  setTransitionRules: async (params: {
+    workspaceSlugName: string;
+    kanbanId?: number;
+    rules: KanbanStatusTransitionRule[];
+  }) => {
+    await invokeCommand('set_kanban_transition_rules', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId ?? null,
+      rules: params.rules,
+    });
+  },
+
   delete: async (params: { workspaceSlugName: string; id: number }) => {
     await invokeCommand('delete_kanban_status', {
       workspace_slug_name: params.workspaceSlugName,
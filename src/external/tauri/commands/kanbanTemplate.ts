@@ -0,0 +1,43 @@
+import { invokeCommand } from '../core/invoker';
+
+import type { KanbanTemplate, KanbanTemplateStatus } from '~/models/kanbanTemplate';
+
+export const kanbanTemplateCommand = {
+  list: async (params: { workspaceSlugName: string }) => {
+    return await invokeCommand<KanbanTemplate[]>('list_kanban_templates', {
+      workspace_slug_name: params.workspaceSlugName,
+    });
+  },
+
+  create: async (params: { workspaceSlugName: string; name: string; statuses: KanbanTemplateStatus[] }) => {
+    return await invokeCommand<KanbanTemplate>('create_kanban_template', {
+      workspace_slug_name: params.workspaceSlugName,
+      name: params.name,
+      statuses: params.statuses,
+    });
+  },
+
+  createFromBoard: async (params: { workspaceSlugName: string; kanbanId: number; name: string }) => {
+    return await invokeCommand<KanbanTemplate>('create_kanban_template_from_board', {
+      workspace_slug_name: params.workspaceSlugName,
+      kanban_id: params.kanbanId,
+      name: params.name,
+    });
+  },
+
+  update: async (params: { workspaceSlugName: string; id: number; name: string; statuses: KanbanTemplateStatus[] }) => {
+    return await invokeCommand<KanbanTemplate>('update_kanban_template', {
+      workspace_slug_name: params.workspaceSlugName,
+      id: params.id,
+      name: params.name,
+      statuses: params.statuses,
+    });
+  },
+
+  delete: async (params: { workspaceSlugName: string; id: number }) => {
+    await invokeCommand('delete_kanban_template', {
+      workspace_slug_name: params.workspaceSlugName,
+      id: params.id,
+    });
+  },
+};
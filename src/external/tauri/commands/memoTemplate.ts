@@ -1,6 +1,6 @@
 import { invokeCommand } from '../core/invoker';
 
-import type { MemoTemplateDetail, MemoTemplateIndexItem } from '~/models/memoTemplate';
+import type { MemoTemplateDetail, MemoTemplateIndexItem, MemoTemplateWorkflow } from '~/models/memoTemplate';
 
 import { encodeForSlug } from '~/utils/slug';
 
@@ -37,6 +37,18 @@ export const memoTemplateCommand = {
     });
   },
 
+  saveWorkflow: async (template: {
+    workspaceSlugName: string;
+    templateSlugName: string;
+    workflow: MemoTemplateWorkflow;
+  }) => {
+    return await invokeCommand('save_memo_template_workflow', {
+      workspace_slug_name: template.workspaceSlugName,
+      template_slug_name: template.templateSlugName,
+      workflow: template.workflow,
+    });
+  },
+
   delete: async (template: { workspaceSlugName: string; templateSlugName: string }) => {
     return await invokeCommand('delete_memo_template', {
       workspace_slug_name: template.workspaceSlugName,
@@ -1,6 +1,6 @@
 import { defineCommand, invokeCommand } from '../core/invoker';
 
-import type { Workspace } from '~/models/workspace';
+import type { Workspace, WorkspaceDeletionSummary } from '~/models/workspace';
 
 import { encodeForSlug } from '~/utils/slug';
 
@@ -20,9 +20,25 @@ export const workspaceCommand = {
     });
   },
 
-  delete: async (workspace: { slugName: string }) => {
+  listArchived: defineCommand<Workspace[]>('get_archived_workspaces'),
+
+  setArchived: async (workspace: { slugName: string; archived: boolean }) => {
+    return await invokeCommand<Workspace>('set_workspace_archived', {
+      workspace_slug_name: workspace.slugName,
+      archived: workspace.archived,
+    });
+  },
+
+  prepareDeletion: async (workspace: { slugName: string }) => {
+    return await invokeCommand<WorkspaceDeletionSummary>('prepare_workspace_deletion', {
+      workspace_slug_name: workspace.slugName,
+    });
+  },
+
+  delete: async (workspace: { slugName: string; confirmationToken: string }) => {
     await invokeCommand('delete_workspace', {
       workspace_slug_name: workspace.slugName,
+      confirmation_token: workspace.confirmationToken,
     });
   },
 };
@@ -0,0 +1,16 @@
+export type OrphanedAsset = {
+  file_name: string;
+  size: number;
+};
+
+export type OrphanedAssetScan = {
+  files: OrphanedAsset[];
+  total_size: number;
+  confirmation_token: string;
+};
+
+export type OrphanedAssetCleanup = {
+  removed_count: number;
+  freed_size: number;
+  quarantine_path: string | null;
+};
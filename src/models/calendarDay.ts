@@ -1,3 +1,5 @@
+import type { KanbanDatedItem } from './kanbanAssignment';
+
 export type CalendarDayMemo = {
   id: number;
   slug_title: string;
@@ -10,4 +12,29 @@ export type CalendarDay = {
   note?: string | null;
   is_non_working: boolean;
   memos: CalendarDayMemo[];
+  /** Kanban cards due or scheduled on this date. */
+  kanban_items?: KanbanDatedItem[];
+};
+
+export type CalendarImportNoteMode = 'overwrite' | 'merge';
+
+export type CalendarImportDay = {
+  date: string;
+  previous_note: string | null;
+  note: string | null;
+  was_non_working: boolean;
+};
+
+export type CalendarImportSkippedEvent = {
+  line: number;
+  summary: string | null;
+  reason: string;
+};
+
+export type CalendarImportSummary = {
+  dry_run: boolean;
+  created: CalendarImportDay[];
+  updated: CalendarImportDay[];
+  unchanged: string[];
+  skipped: CalendarImportSkippedEvent[];
 };
@@ -0,0 +1,57 @@
+export type ForeignKeyViolation = {
+  table: string;
+  rowid: number | null;
+  parent: string;
+};
+
+export type SearchIndexIssue = {
+  memo_id: number;
+  issue:
+    | 'missing_index_row'
+    | 'duplicate_index_row'
+    | 'stale_index_row'
+    | 'orphaned_index_row'
+    | 'stale_body_text';
+};
+
+export type LinkIssue = {
+  from_memo_id: number;
+  to_memo_id: number;
+  issue: 'missing_row' | 'not_in_content';
+};
+
+export type MemoFileIssue = {
+  memo_id: number;
+  file_id: string;
+  issue: 'missing_row' | 'not_in_content';
+};
+
+export type MissingFile = {
+  file_id: string;
+  display_name: string;
+  relative_path: string | null;
+};
+
+export type KanbanIssue = {
+  kanban_id: number;
+  assignment_id: number | null;
+  memo_id: number | null;
+  issue:
+    | 'status_from_other_kanban'
+    | 'workspace_mismatch'
+    | 'invalid_default_status'
+    | 'invalid_focus_status'
+    | 'invalid_done_status';
+};
+
+export type DoctorReport = {
+  healthy: boolean;
+  integrity_errors: string[];
+  foreign_key_violations: ForeignKeyViolation[];
+  search_index_issues: SearchIndexIssue[];
+  link_issues: LinkIssue[];
+  memo_file_issues: MemoFileIssue[];
+  missing_files: MissingFile[];
+  kanban_issues: KanbanIssue[];
+  repaired: string[];
+};
@@ -23,6 +23,7 @@ export type ManagedFileRecord = {
   relative_path?: string | null;
   url?: string | null;
   imported_at: string;
+  import_notice?: string | null;
 };
 
 export type ManagedFileListItem = {
@@ -31,6 +32,10 @@ export type ManagedFileListItem = {
   display_name: string;
   imported_at: string;
   related_memo_count: number;
+  /** Only set for search results. */
+  snippet?: string | null;
+  /** Only set for search results. */
+  related_memos?: RelatedMemoSummary[];
 };
 
 export type MemoLinkedFileItem = {
@@ -68,3 +73,53 @@ export type ResolvedFileOpenTarget = {
   open_kind: 'path' | 'url';
   value: string;
 };
+
+export type StorageEntry = {
+  relative_path: string;
+  entry_type: 'local_file' | 'local_directory';
+  embedded_file_id: string | null;
+};
+
+export type MissingManagedFile = {
+  file_id: string;
+  display_name: string;
+  relative_path: string | null;
+  candidates: string[];
+};
+
+export type RelinkedManagedFile = {
+  file_id: string;
+  display_name: string;
+  previous_relative_path: string | null;
+  relative_path: string;
+};
+
+export type StorageVerificationReport = {
+  checked_count: number;
+  missing: MissingManagedFile[];
+  relinked: RelinkedManagedFile[];
+  unknown: StorageEntry[];
+  unmanaged: StorageEntry[];
+};
+
+export type InboxActivityStatus = 'imported' | 'duplicate' | 'failed';
+
+export type InboxActivity = {
+  id: number;
+  watched_folder: string;
+  source_path: string;
+  rule_pattern: string | null;
+  status: InboxActivityStatus;
+  file_id: string | null;
+  display_name: string | null;
+  memo_id: number | null;
+  message: string | null;
+  created_at: string;
+};
+
+export type InboxActivityPage = {
+  items: InboxActivity[];
+  total_count: number;
+  limit: number;
+  offset: number;
+};
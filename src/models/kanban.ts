@@ -5,6 +5,7 @@ export type Kanban = {
   order_index: number;
   default_status_id?: number | null;
   focus_status_id?: number | null;
+  done_status_id?: number | null;
   created_at: string;
   updated_at: string;
 };
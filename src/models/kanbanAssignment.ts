@@ -1,3 +1,5 @@
+import type { KanbanAutomationOutcome } from './kanbanAutomation';
+
 export type KanbanAssignmentItem = {
   memo_id: number;
   slug_title: string;
@@ -7,6 +9,8 @@ export type KanbanAssignmentItem = {
   position?: number | null;
   modified_at: string;
   kanban_id: number;
+  due_on?: string | null;
+  scheduled_on?: string | null;
 };
 
 export type KanbanAssignmentEntry = {
@@ -16,4 +20,37 @@ export type KanbanAssignmentEntry = {
   kanban_status_name?: string | null;
   kanban_status_color?: string | null;
   position?: number | null;
+  due_on?: string | null;
+  scheduled_on?: string | null;
+};
+
+export type KanbanDateFilter = 'overdue' | 'due_this_week' | 'scheduled_today';
+
+export type KanbanDatedItem = {
+  memo_id: number;
+  slug_title: string;
+  title: string;
+  kanban_id: number;
+  kanban_name: string;
+  kanban_status_id?: number | null;
+  kanban_status_name?: string | null;
+  due_on?: string | null;
+  scheduled_on?: string | null;
+  is_done: boolean;
+};
+
+export type KanbanCardPosition = {
+  memo_id: number;
+  position: number;
+};
+
+export type KanbanCardMoveResult = {
+  /** Every position that changed, the moved card first. */
+  positions: KanbanCardPosition[];
+  outcomes: KanbanAutomationOutcome[];
+};
+
+export type KanbanBulkMoveResult = {
+  moved_count: number;
+  outcomes: KanbanAutomationOutcome[];
 };
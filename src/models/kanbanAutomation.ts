@@ -0,0 +1,36 @@
+export type KanbanAutomationTrigger = 'enter_status' | 'enter_done_status' | 'leave_status';
+
+export type KanbanAutomationCondition = 'always' | 'from_status' | 'has_milestone';
+
+export type KanbanAutomationAction =
+  | 'complete_milestones'
+  | 'mark_focus_done_today'
+  | 'add_to_today'
+  | 'add_bookmark';
+
+export type KanbanAutomationRule = {
+  id: number;
+  workspace_id: number;
+  kanban_id: number;
+  trigger_type: KanbanAutomationTrigger;
+  /** Set for `enter_status` and `leave_status`. */
+  trigger_status_id: number | null;
+  condition_type: KanbanAutomationCondition;
+  /** Set for `from_status`. */
+  condition_status_id: number | null;
+  action_type: KanbanAutomationAction;
+  enabled: boolean;
+  created_at: string;
+  updated_at: string;
+};
+
+export type KanbanAutomationRuleInput = Pick<
+  KanbanAutomationRule,
+  'trigger_type' | 'trigger_status_id' | 'condition_type' | 'condition_status_id' | 'action_type' | 'enabled'
+>;
+
+/** A rule whose action changed something during a status update. */
+export type KanbanAutomationOutcome = {
+  rule_id: number;
+  action_type: KanbanAutomationAction;
+};
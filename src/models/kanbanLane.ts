@@ -0,0 +1,19 @@
+import type { KanbanAssignmentItem } from './kanbanAssignment';
+
+export type KanbanLaneGroupBy = 'milestone' | 'bookmark' | 'kanban_status';
+
+export type KanbanLaneBucket = {
+  /** `null` holds the cards on the board without a status. */
+  kanban_status_id: number | null;
+  count: number;
+  items: KanbanAssignmentItem[];
+};
+
+export type KanbanLane = {
+  /** `milestone:<id>`, `bookmarked`, `status:<id>`, `no_status`, or `none` for cards without a value. */
+  key: string;
+  label: string;
+  total_count: number;
+  /** One bucket per board status, in board order. */
+  buckets: KanbanLaneBucket[];
+};
@@ -0,0 +1,49 @@
+export type KanbanStatusTransition = {
+  id: number;
+  kanban_id: number;
+  memo_id: number;
+  memo_slug_title: string;
+  from_status_id: number | null;
+  from_status_name: string | null;
+  to_status_id: number | null;
+  to_status_name: string | null;
+  transitioned_at: string;
+};
+
+export type KanbanThroughputWeek = {
+  week_start: string;
+  completed_count: number;
+};
+
+export type KanbanStatusDuration = {
+  status_id: number;
+  status_name: string;
+  stay_count: number;
+  average_hours: number | null;
+};
+
+export type KanbanFlowTimes = {
+  completed_count: number;
+  average_lead_time_hours: number | null;
+  median_lead_time_hours: number | null;
+  average_cycle_time_hours: number | null;
+  median_cycle_time_hours: number | null;
+};
+
+export type KanbanCumulativeFlowDay = {
+  date: string;
+  counts: { status_id: number; count: number }[];
+};
+
+export type KanbanReport = {
+  kanban_id: number;
+  date_from: string;
+  date_to: string;
+  default_status_id: number | null;
+  done_status_id: number | null;
+  statuses: { id: number; name: string; color: string | null }[];
+  throughput: KanbanThroughputWeek[];
+  time_in_status: KanbanStatusDuration[];
+  flow_times: KanbanFlowTimes;
+  cumulative_flow: KanbanCumulativeFlowDay[];
+};
@@ -5,6 +5,14 @@ export type KanbanStatus = {
   name: string;
   color?: string | null;
   order_index: number;
+  /** Maximum number of memos allowed in this status; null means unlimited. */
+  wip_limit?: number | null;
   created_at: string;
   updated_at: string;
 };
+
+/** Allowed move between statuses. A status without outgoing rules accepts any move. */
+export type KanbanStatusTransitionRule = {
+  from_status_id: number;
+  to_status_id: number;
+};
@@ -0,0 +1,29 @@
+import type { Kanban } from './kanban';
+
+export type KanbanTemplateStatus = {
+  name: string;
+  color: string | null;
+  wip_limit: number | null;
+  /** Becomes the board's default status. */
+  is_default: boolean;
+  /** Becomes the board's focus status. */
+  is_focus: boolean;
+  /** Becomes the board's done status. */
+  is_done: boolean;
+};
+
+export type KanbanTemplate = {
+  id: number;
+  workspace_id: number;
+  name: string;
+  statuses: KanbanTemplateStatus[];
+  created_at: string;
+  updated_at: string;
+};
+
+export type KanbanCloneResult = {
+  kanban: Kanban;
+  copied_assignment_count: number;
+  /** Cards left behind because the target workspace has no memo with the same slug. */
+  skipped_memo_slugs: string[];
+};
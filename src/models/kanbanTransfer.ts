@@ -0,0 +1,15 @@
+export type KanbanExportFormat = 'csv' | 'json';
+
+export type KanbanImportSkippedRow = {
+  line: number;
+  title: string | null;
+  reason: string;
+};
+
+export type KanbanImportSummary = {
+  created: string[];
+  updated: string[];
+  unchanged: string[];
+  created_statuses: string[];
+  skipped: KanbanImportSkippedRow[];
+};
@@ -5,6 +5,10 @@ export type MemoTemplateDetail = {
   content: string;
   is_default: boolean;
   workspace_id: number;
+  kanban_id: number | null;
+  kanban_status_id: number | null;
+  milestone_id: number | null;
+  attach_to_today: boolean;
   created_at: string;
   updated_at: string;
 };
@@ -17,3 +21,10 @@ export type MemoTemplateIndexItem = {
   created_at: string;
   updated_at: string;
 };
+
+export type MemoTemplateWorkflow = {
+  kanban_id: number | null;
+  kanban_status_id: number | null;
+  milestone_id: number | null;
+  attach_to_today: boolean;
+};
@@ -2,6 +2,16 @@ export type Workspace = {
   id: number;
   slug_name: string;
   name: string;
+  archived_at: string | null;
   created_at: string;
   updated_at: string;
 };
+
+export type WorkspaceDeletionSummary = {
+  workspace_slug_name: string;
+  memo_count: number;
+  file_link_count: number;
+  kanban_count: number;
+  asset_count: number;
+  confirmation_token: string;
+};
@@ -2,6 +2,8 @@ import { command as tauriCommand } from '~/external/tauri/command';
 import { publishResourceChanges } from '~/resource-runtime/query-runtime';
 import { changeRefs } from '~/resources/changes';
 
+import type { CalendarImportNoteMode } from '~/models/calendarDay';
+
 const publishCalendarChange = (workspaceSlug: string) => {
   void publishResourceChanges([changeRefs.calendarDayCollectionChanged(workspaceSlug)]);
 };
@@ -27,4 +29,13 @@ export const calendarDayCommand = {
     await tauriCommand.calendarDay.removeMemo(params);
     publishCalendarChange(params.workspaceSlugName);
   },
+  exportIcs: (params: { workspaceSlugName: string; path: string }) => tauriCommand.calendarDay.exportIcs(params),
+  importIcs: async (params: { workspaceSlugName: string; path: string; noteMode: CalendarImportNoteMode; dryRun?: boolean }) => {
+    const summary = await tauriCommand.calendarDay.importIcs(params);
+    if (!summary.dry_run && summary.created.length + summary.updated.length > 0) {
+      publishCalendarChange(params.workspaceSlugName);
+    }
+    return summary;
+  },
+  feedUrl: (params: { workspaceSlugName: string }) => tauriCommand.calendarDay.feedUrl(params),
 } as const;
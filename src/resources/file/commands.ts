@@ -5,7 +5,7 @@ export const fileCommand = {
   importInboxFile: (sourcePath: string) => tauriCommand.file.importInboxFile(sourcePath),
   importInboxEntry: (sourcePath: string) => tauriCommand.file.importInboxEntry(sourcePath),
   createExternalLink: (params: { displayName: string; url: string }) => tauriCommand.file.createExternalLink(params),
-  listFiles: (params?: { limit?: number; offset?: number; unlinkedOnly?: boolean }) => tauriCommand.file.listFiles(params),
+  listFiles: (params?: { limit?: number; offset?: number; unlinkedOnly?: boolean; query?: string }) => tauriCommand.file.listFiles(params),
   getFileDetail: (fileId: string) => tauriCommand.file.getFileDetail(fileId),
   resolveOpenTarget: (fileId: string) => tauriCommand.file.resolveOpenTarget(fileId),
   openManagedFile: (fileId: string) => tauriCommand.file.openManagedFile(fileId),
@@ -10,6 +10,8 @@ export type WorkspaceManagedFileQueryArgs = {
   limit: number;
   offset: number;
   unlinkedOnly: boolean;
+  /** Full-text query over file names, notes and extracted text. Empty lists every file. */
+  query: string;
 };
 
 export type InboxFileQueryArgs = {
@@ -33,14 +35,15 @@ export const memoFilesQuery = defineQuery<MemoQueryArgs, MemoLinkedFileItem[]>({
 });
 
 export const workspaceManagedFilesQuery = defineQuery<WorkspaceManagedFileQueryArgs, ManagedFileListPage>({
-  key: ({ workspaceSlug, limit, offset, unlinkedOnly }) =>
-    ['workspace', workspaceSlug, 'files', limit, offset, unlinkedOnly] as const,
+  key: ({ workspaceSlug, limit, offset, unlinkedOnly, query }) =>
+    ['workspace', workspaceSlug, 'files', limit, offset, unlinkedOnly, query] as const,
   resources: ({ workspaceSlug }) => [resourceRefs.fileCollection(workspaceSlug)],
   when: ({ workspaceSlug }) => workspaceSlug.length > 0,
-  load: ({ limit, offset, unlinkedOnly }) => command.file.listFiles({
+  load: ({ limit, offset, unlinkedOnly, query }) => command.file.listFiles({
     limit,
     offset,
     unlinkedOnly,
+    query: query || undefined,
   }),
 });
 
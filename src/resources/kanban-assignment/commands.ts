@@ -2,8 +2,30 @@ import { command as tauriCommand } from '~/external/tauri/command';
 import { publishResourceChanges } from '~/resource-runtime/query-runtime';
 import { changeRefs } from '~/resources/changes';
 
+import type { KanbanDateFilter } from '~/models/kanbanAssignment';
+import type { KanbanAutomationOutcome } from '~/models/kanbanAutomation';
+import type { KanbanLaneGroupBy } from '~/models/kanbanLane';
+import type { ChangeRef } from '~/resources/changes';
+
+const automationChangeRefs = (workspaceSlugName: string, outcomes: KanbanAutomationOutcome[]): ChangeRef[] => {
+  return outcomes.map((outcome) => {
+    switch (outcome.action_type) {
+      case 'complete_milestones':
+        return changeRefs.milestoneCollectionChanged(workspaceSlugName);
+      case 'mark_focus_done_today':
+        return changeRefs.focusDailyStateCollectionChanged(workspaceSlugName);
+      case 'add_to_today':
+        return changeRefs.calendarDayCollectionChanged(workspaceSlugName);
+      case 'add_bookmark':
+        return changeRefs.bookmarkCollectionChanged(workspaceSlugName);
+    }
+  });
+};
+
 export const kanbanAssignmentCommand = {
   listItems: (params: { workspaceSlugName: string; kanbanId: number }) => tauriCommand.kanbanAssignment.listItems(params),
+  listLanes: (params: { workspaceSlugName: string; kanbanId: number; groupBy: KanbanLaneGroupBy; groupKanbanId?: number | null }) =>
+    tauriCommand.kanbanAssignment.listLanes(params),
   listEntries: (params: { workspaceSlugName: string; memoSlugTitle: string }) =>
     tauriCommand.kanbanAssignment.listEntries(params),
   upsertStatus: async (params: {
@@ -12,13 +34,67 @@ export const kanbanAssignmentCommand = {
     kanbanId: number;
     kanbanStatusId?: number | null;
     position?: number | null;
+    /** Skips the WIP limit and transition rule checks. */
+    force?: boolean;
+  }) => {
+    const outcomes = await tauriCommand.kanbanAssignment.upsertStatus(params);
+    void publishResourceChanges([
+      changeRefs.kanbanEntryCollectionChanged(params.workspaceSlugName, params.memoSlugTitle),
+      changeRefs.kanbanAssignmentCollectionChanged(params.workspaceSlugName, params.kanbanId),
+      ...automationChangeRefs(params.workspaceSlugName, outcomes ?? []),
+    ]);
+    return outcomes;
+  },
+  moveCard: async (params: {
+    workspaceSlugName: string;
+    memoSlugTitle: string;
+    kanbanId: number;
+    kanbanStatusId: number;
+    afterMemoSlugTitle?: string | null;
+    beforeMemoSlugTitle?: string | null;
+    force?: boolean;
+  }) => {
+    const result = await tauriCommand.kanbanAssignment.moveCard(params);
+    void publishResourceChanges([
+      changeRefs.kanbanEntryCollectionChanged(params.workspaceSlugName, params.memoSlugTitle),
+      changeRefs.kanbanAssignmentCollectionChanged(params.workspaceSlugName, params.kanbanId),
+      ...automationChangeRefs(params.workspaceSlugName, result?.outcomes ?? []),
+    ]);
+    return result;
+  },
+  moveCardsToStatus: async (params: {
+    workspaceSlugName: string;
+    kanbanId: number;
+    memoSlugTitles: string[];
+    kanbanStatusId: number;
+    force?: boolean;
+  }) => {
+    const result = await tauriCommand.kanbanAssignment.moveCardsToStatus(params);
+    void publishResourceChanges([
+      ...params.memoSlugTitles.map(memoSlugTitle =>
+        changeRefs.kanbanEntryCollectionChanged(params.workspaceSlugName, memoSlugTitle),
+      ),
+      changeRefs.kanbanAssignmentCollectionChanged(params.workspaceSlugName, params.kanbanId),
+      ...automationChangeRefs(params.workspaceSlugName, result?.outcomes ?? []),
+    ]);
+    return result;
+  },
+  updateDates: async (params: {
+    workspaceSlugName: string;
+    memoSlugTitle: string;
+    kanbanId: number;
+    dueOn?: string | null;
+    scheduledOn?: string | null;
   }) => {
-    await tauriCommand.kanbanAssignment.upsertStatus(params);
+    await tauriCommand.kanbanAssignment.updateDates(params);
     void publishResourceChanges([
       changeRefs.kanbanEntryCollectionChanged(params.workspaceSlugName, params.memoSlugTitle),
       changeRefs.kanbanAssignmentCollectionChanged(params.workspaceSlugName, params.kanbanId),
+      changeRefs.calendarDayCollectionChanged(params.workspaceSlugName),
     ]);
   },
+  listDatedItems: (params: { workspaceSlugName: string; filter: KanbanDateFilter }) =>
+    tauriCommand.kanbanAssignment.listDatedItems(params),
   remove: async (params: { workspaceSlugName: string; memoSlugTitle: string; kanbanId: number }) => {
     await tauriCommand.kanbanAssignment.remove(params);
     void publishResourceChanges([
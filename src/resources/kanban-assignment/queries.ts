@@ -1,4 +1,5 @@
 import type { KanbanAssignmentEntry, KanbanAssignmentItem } from '~/models/kanbanAssignment';
+import type { KanbanLane, KanbanLaneGroupBy } from '~/models/kanbanLane';
 
 import { defineQuery } from '~/resource-runtime/query';
 import { command } from '~/resources/command';
@@ -14,6 +15,13 @@ export type KanbanAssignmentItemsQueryArgs = {
   kanbanId: number;
 };
 
+export type KanbanLanesQueryArgs = {
+  workspaceSlug: string;
+  kanbanId: number;
+  groupBy: KanbanLaneGroupBy;
+  groupKanbanId: number | null;
+};
+
 export const memoKanbanEntriesQuery = defineQuery<MemoKanbanEntriesQueryArgs, KanbanAssignmentEntry[]>({
   key: ({ workspaceSlug, memoSlug }) => ['workspace', workspaceSlug, 'memo', memoSlug, 'kanban-entries'] as const,
   resources: ({ workspaceSlug, memoSlug }) => [resourceRefs.kanbanEntryCollection(workspaceSlug, memoSlug)],
@@ -33,3 +41,28 @@ export const kanbanAssignmentItemsQuery = defineQuery<KanbanAssignmentItemsQuery
     kanbanId,
   }),
 });
+
+export const kanbanLanesQuery = defineQuery<KanbanLanesQueryArgs, KanbanLane[]>({
+  key: ({ workspaceSlug, kanbanId, groupBy, groupKanbanId }) =>
+    ['workspace', workspaceSlug, 'kanban', kanbanId, 'lanes', groupBy, groupKanbanId] as const,
+  resources: ({ workspaceSlug, kanbanId, groupBy, groupKanbanId }) => [
+    resourceRefs.kanbanAssignmentCollection(workspaceSlug, kanbanId),
+    resourceRefs.kanbanStatusCollection(workspaceSlug, kanbanId),
+    ...(groupBy === 'milestone' ? [resourceRefs.milestoneCollection(workspaceSlug)] : []),
+    ...(groupBy === 'bookmark' ? [resourceRefs.bookmarkCollection(workspaceSlug)] : []),
+    ...(groupBy === 'kanban_status' && groupKanbanId !== null
+      ? [
+          resourceRefs.kanbanAssignmentCollection(workspaceSlug, groupKanbanId),
+          resourceRefs.kanbanStatusCollection(workspaceSlug, groupKanbanId),
+        ]
+      : []),
+  ],
+  when: ({ workspaceSlug, kanbanId, groupBy, groupKanbanId }) =>
+    workspaceSlug.length > 0 && kanbanId > 0 && (groupBy !== 'kanban_status' || groupKanbanId !== null),
+  load: ({ workspaceSlug, kanbanId, groupBy, groupKanbanId }) => command.kanbanAssignment.listLanes({
+    workspaceSlugName: workspaceSlug,
+    kanbanId,
+    groupBy,
+    groupKanbanId,
+  }),
+});
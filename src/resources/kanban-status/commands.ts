@@ -2,6 +2,8 @@ import { command as tauriCommand } from '~/external/tauri/command';
 import { publishResourceChanges } from '~/resource-runtime/query-runtime';
 import { changeRefs } from '~/resources/changes';
 
+import type { KanbanStatusTransitionRule } from '~/models/kanbanStatus';
+
 export const kanbanStatusCommand = {
   list: (workspace: { slugName: string; kanbanId?: number }) => tauriCommand.kanbanStatus.list(workspace),
   create: async (params: { workspaceSlugName: string; kanbanId: number; name: string; color?: string }) => {
@@ -23,6 +25,20 @@ export const kanbanStatusCommand = {
       changeRefs.kanbanStatusCollectionChanged(params.workspaceSlugName, params.kanbanId),
     ]);
   },
+  updateWipLimit: async (params: { workspaceSlugName: string; kanbanId: number; id: number; wipLimit: number | null }) => {
+    await tauriCommand.kanbanStatus.updateWipLimit(params);
+    void publishResourceChanges([
+      changeRefs.kanbanStatusCollectionChanged(params.workspaceSlugName, params.kanbanId),
+    ]);
+  },
+  listTransitionRules: (params: { workspaceSlugName: string; kanbanId: number }) =>
+    tauriCommand.kanbanStatus.listTransitionRules(params),
+  setTransitionRules: async (params: { workspaceSlugName: string; kanbanId: number; rules: KanbanStatusTransitionRule[] }) => {
+    await tauriCommand.kanbanStatus.setTransitionRules(params);
+    void publishResourceChanges([
+      changeRefs.kanbanStatusCollectionChanged(params.workspaceSlugName, params.kanbanId),
+    ]);
+  },
   delete: async (params: { workspaceSlugName: string; kanbanId: number; id: number }) => {
     await tauriCommand.kanbanStatus.delete(params);
     void publishResourceChanges([
@@ -2,14 +2,33 @@ import { command as tauriCommand } from '~/external/tauri/command';
 import { publishResourceChanges } from '~/resource-runtime/query-runtime';
 import { changeRefs } from '~/resources/changes';
 
+import type { KanbanExportFormat } from '~/models/kanbanTransfer';
+
 export const kanbanCommand = {
   list: (workspace: { slugName: string }) => tauriCommand.kanban.list(workspace),
-  create: async (params: { workspaceSlugName: string; name: string }) => {
+  create: async (params: { workspaceSlugName: string; name: string; templateId?: number | null }) => {
     const created = await tauriCommand.kanban.create(params);
     void publishResourceChanges([changeRefs.kanbanCollectionChanged(params.workspaceSlugName)]);
     return created;
   },
-  updateStatusRoles: async (params: { workspaceSlugName: string; id: number; defaultStatusId?: number | null; focusStatusId?: number | null }) => {
+  clone: async (params: {
+    workspaceSlugName: string;
+    kanbanId: number;
+    name: string;
+    targetWorkspaceSlugName?: string | null;
+    includeAssignments?: boolean;
+  }) => {
+    const result = await tauriCommand.kanban.clone(params);
+    const targetWorkspaceSlugName = params.targetWorkspaceSlugName ?? params.workspaceSlugName;
+    void publishResourceChanges([
+      changeRefs.kanbanCollectionChanged(targetWorkspaceSlugName),
+      ...(result.copied_assignment_count > 0
+        ? [changeRefs.kanbanAssignmentCollectionChanged(targetWorkspaceSlugName, result.kanban.id)]
+        : []),
+    ]);
+    return result;
+  },
+  updateStatusRoles: async (params: { workspaceSlugName: string; id: number; defaultStatusId?: number | null; focusStatusId?: number | null; doneStatusId?: number | null }) => {
     await tauriCommand.kanban.updateStatusRoles(params);
     void publishResourceChanges([changeRefs.kanbanCollectionChanged(params.workspaceSlugName)]);
   },
@@ -17,4 +36,18 @@ export const kanbanCommand = {
     await tauriCommand.kanban.delete(params);
     void publishResourceChanges([changeRefs.kanbanCollectionChanged(params.workspaceSlugName)]);
   },
+  exportBoard: (params: { workspaceSlugName: string; kanbanId?: number | null; path: string; format: KanbanExportFormat }) =>
+    tauriCommand.kanban.exportBoard(params),
+  importCsv: async (params: { workspaceSlugName: string; kanbanId: number; path: string; createMissingStatuses?: boolean }) => {
+    const summary = await tauriCommand.kanban.importCsv(params);
+    void publishResourceChanges([
+      ...summary.created.map(memoSlug => changeRefs.memoCreated(params.workspaceSlugName, memoSlug)),
+      ...[...summary.created, ...summary.updated].map(memoSlug =>
+        changeRefs.kanbanEntryCollectionChanged(params.workspaceSlugName, memoSlug),
+      ),
+      changeRefs.kanbanStatusCollectionChanged(params.workspaceSlugName, params.kanbanId),
+      changeRefs.kanbanAssignmentCollectionChanged(params.workspaceSlugName, params.kanbanId),
+    ]);
+    return summary;
+  },
 } as const;
@@ -10,7 +10,14 @@ export const workspaceCommand = {
     void publishResourceChanges([changeRefs.workspaceCollectionChanged()]);
     return created;
   },
-  delete: async (workspace: { slugName: string }) => {
+  listArchived: () => tauriCommand.workspace.listArchived(),
+  setArchived: async (workspace: { slugName: string; archived: boolean }) => {
+    const updated = await tauriCommand.workspace.setArchived(workspace);
+    void publishResourceChanges([changeRefs.workspaceCollectionChanged()]);
+    return updated;
+  },
+  prepareDeletion: (workspace: { slugName: string }) => tauriCommand.workspace.prepareDeletion(workspace),
+  delete: async (workspace: { slugName: string; confirmationToken: string }) => {
     await tauriCommand.workspace.delete(workspace);
     void publishResourceChanges([changeRefs.workspaceCollectionChanged()]);
   },
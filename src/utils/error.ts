@@ -12,6 +12,11 @@ export class AppError extends Error {
    */
   public readonly error: string;
 
+  /**
+   * Finer grained machine-readable cause reported by a command, such as `DUPLICATE_SHORTCUT`.
+   */
+  public readonly reason?: string;
+
   /**
    * Indicates whether the error is operational (recoverable and expected)
    * or a programmer/fatal error (unexpected and critical).
@@ -21,16 +26,17 @@ export class AppError extends Error {
   /**
    * Creates a new AppError instance.
    *
-   * @param args - An object containing the error code and human-readable message.
+   * @param args - An object containing the error code, human-readable message and optional reason.
    * @param isOperational - True if the error is an expected operational error; false if it is a critical system error.
    */
   constructor(
-    args: { error: string; message: string },
+    args: { error: string; message: string; reason?: string },
     isOperational: boolean,
   ) {
     super(args.message);
     Object.setPrototypeOf(this, new.target.prototype);
     this.error = args.error;
+    this.reason = args.reason;
     this.isOperational = isOperational;
 
     // Capture this error's stack (Node.js only)
@@ -99,13 +105,38 @@ export const handleError = (error: unknown): AppError => {
   return appError;
 };
 
+type CommandErrorPayload = {
+  code: string;
+  message: string;
+  reason?: string;
+};
+
+const isCommandErrorPayload = (error: unknown): error is CommandErrorPayload => {
+  return typeof error === 'object'
+    && error !== null
+    && typeof (error as CommandErrorPayload).code === 'string'
+    && typeof (error as CommandErrorPayload).message === 'string';
+};
+
 /**
  * Creates normalized error information for command invocation failures.
+ * Commands reject with `{ code, message, reason? }`, where `code` is one of
+ * `NOT_FOUND`, `CONFLICT`, `VALIDATION`, `STORAGE`, `IO` or `CONFIG`.
  *
  * @param error - An unknown error value caught from invoke
- * @returns An object containing error code and message
+ * @returns An object containing error code, message and optional reason
  */
-export const createCommandErrorInfo = (error: unknown): { error: string; message: string } => {
+export const createCommandErrorInfo = (
+  error: unknown,
+): { error: string; message: string; reason?: string } => {
+  if (isCommandErrorPayload(error)) {
+    return {
+      error: error.code,
+      message: error.message,
+      reason: error.reason,
+    };
+  }
+
   if (typeof error === 'string') {
     return {
       error: 'CommandError',